@@ -213,6 +213,14 @@ pub fn main() {
         write_zeta3,
         gen_zeta3_data
     );
+    // zeta3 through the const-generic methods, to compare with the above
+    impl_code!(
+        calibration,
+        "zeta_const3",
+        read_zeta_const3,
+        write_zeta_const3,
+        gen_zeta3_data
+    );
 
     // delta with gamma tables disabled
     bench!(
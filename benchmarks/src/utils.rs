@@ -1,5 +1,26 @@
 use super::*;
 
+/// Adapter exposing [`ZetaRead::read_zeta_const`] with `K` equal to 3 with the
+/// same signature as [`ZetaRead::read_zeta3`], so that the two can be
+/// benchmarked with the same macros
+pub trait ZetaConst3Read<BO: BitOrder>: ZetaRead<BO> {
+    #[inline(always)]
+    fn read_zeta_const3<const USE_TABLE: bool>(&mut self) -> anyhow::Result<u64> {
+        self.read_zeta_const::<3, USE_TABLE>()
+    }
+}
+impl<BO: BitOrder, B: ZetaRead<BO>> ZetaConst3Read<BO> for B {}
+
+/// Adapter exposing [`ZetaWrite::write_zeta_const`] with `K` equal to 3 with
+/// the same signature as [`ZetaWrite::write_zeta3`]
+pub trait ZetaConst3Write<BO: BitOrder>: ZetaWrite<BO> {
+    #[inline(always)]
+    fn write_zeta_const3<const USE_TABLE: bool>(&mut self, value: u64) -> anyhow::Result<()> {
+        self.write_zeta_const::<3, USE_TABLE>(value)
+    }
+}
+impl<BO: BitOrder, B: ZetaWrite<BO>> ZetaConst3Write<BO> for B {}
+
 /// Routine for measuring the measurement overhead.
 pub fn calibrate_overhead() -> u128 {
     let mut nanos = MetricsStream::with_capacity(CALIBRATION_ITERS);
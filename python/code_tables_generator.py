@@ -83,6 +83,48 @@ pub fn read_table_%(bo)s<B: BitRead<%(BO)s>>(backend: &mut B) -> Result<Option<u
 }
 """
 
+skip_func_merged_table = """
+#[inline(always)]
+/// Autogenerated function to skip a code using the read table, if the result
+/// is `true` the code was skipped, otherwise we were not able to find its
+/// length and you should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn skip_table_%(bo)s<B: BitRead<%(BO)s>>(backend: &mut B) -> Result<bool> {
+    if let Ok(idx) = backend.peek_bits(READ_BITS) {
+        let idx: u64 = idx.upcast();
+        let (_, len) = READ_%(BO)s[idx as usize];
+        if len != MISSING_VALUE_LEN {
+            backend.skip_bits_after_table_lookup(len as usize)?;
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+"""
+
+skip_func_two_table = """
+#[inline(always)]
+/// Autogenerated function to skip a code using the read table, if the result
+/// is `true` the code was skipped, otherwise we were not able to find its
+/// length and you should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn skip_table_%(bo)s<B: BitRead<%(BO)s>>(backend: &mut B) -> Result<bool> {
+    if let Ok(idx) = backend.peek_bits(READ_BITS) {
+        let idx: u64 = idx.upcast();
+        let len = READ_LEN_%(BO)s[idx as usize];
+        if len != MISSING_VALUE_LEN {
+            backend.skip_bits_after_table_lookup(len as usize)?;
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+"""
+
 write_func_merged_table = """
 #[inline(always)]
 /// Autogenerated function to lookup a write table, if the result is `Some` the
@@ -128,10 +170,12 @@ def gen_table(
     read_func,
     write_func,
     merged_table,
+    file_name=None,
 ):
     """Main routine that generates the tables for a given code."""
+    file_name = file_name or "{}_tables.rs".format(code_name)
 
-    with open(os.path.join(ROOT, "{}_tables.rs".format(code_name)), "w") as f:
+    with open(os.path.join(ROOT, file_name), "w") as f:
         f.write(
             "// THIS FILE HAS BEEN GENERATED WITH THE SCRIPT {}\n".format(
                 os.path.basename(__file__)
@@ -143,6 +187,8 @@ def gen_table(
                 code_name
             )
         )
+        # some tables (e.g. unary) are already u64, so the cast might be a no-op
+        f.write("#![allow(clippy::unnecessary_cast)]\n")
         f.write("use anyhow::Result;\n")
         f.write("use crate::traits::{BitRead, BitWrite, M2L, L2M, UpcastableInto};\n")
 
@@ -167,13 +213,16 @@ def gen_table(
 
         if merged_table:
             read_func_template = read_func_merged_table
+            skip_func_template = skip_func_merged_table
             write_func_template = write_func_merged_table
         else:
             read_func_template = read_func_two_table
+            skip_func_template = skip_func_two_table
             write_func_template = write_func_two_table
 
         for bo in ["l2m", "m2l"]:
             f.write(read_func_template % {"bo": bo, "BO": bo.upper()})
+            f.write(skip_func_template % {"bo": bo, "BO": bo.upper()})
             f.write(write_func_template % {"bo": bo, "BO": bo.upper()})
 
         # Write the read tables
@@ -291,6 +340,73 @@ def gen_table(
         f.write("];\n")
 
 
+def gen_multi_table(read_bits, max_symbols, code_name, read_func):
+    """Generate the multi-symbol decoding tables for a given code: for each
+    window of read_bits bits, the number of complete codewords it starts with,
+    up to max_symbols, their values and their total length."""
+    with open(os.path.join(ROOT, "{}_multi_tables.rs".format(code_name)), "w") as f:
+        f.write(
+            "// THIS FILE HAS BEEN GENERATED WITH THE SCRIPT {}\n".format(
+                os.path.basename(__file__)
+            )
+        )
+        f.write("// ~~~~~~~~~~~~~~~~~~~ DO NOT MODIFY ~~~~~~~~~~~~~~~~~~~~~~\n")
+        f.write(
+            "// Pre-computed constants used to decode several {} codes with a single lookup\n".format(  # NoQA
+                code_name
+            )
+        )
+        f.write("/// How many bits are needed to read the tables in this\n")
+        f.write("pub const READ_BITS: usize = {};\n".format(read_bits))
+        f.write("/// Maximum number of values decoded with a single lookup\n")
+        f.write("pub const MAX_SYMBOLS: usize = {};\n".format(max_symbols))
+
+        for BO in ["M2L", "L2M"]:
+            entries = []
+            for window in range(0, 2**read_bits):
+                bits = ("{:0%sb}" % read_bits).format(window)
+                values = []
+                while len(values) < max_symbols:
+                    try:
+                        value, bits = read_func(bits, BO == "M2L")
+                    except ValueError:
+                        break
+                    values.append(value)
+                entries.append((read_bits - len(bits), values))
+
+            f.write(
+                "///Table containing the total length of the codes decoded with a lookup\n"
+            )
+            f.write("pub const READ_LEN_%s: &[u8] = &[" % BO)
+            for l, _ in entries:
+                f.write("{}, ".format(l))
+            f.write("];\n")
+            f.write(
+                "///Table containing the number of codes decoded with a lookup\n"
+            )
+            f.write("pub const READ_COUNT_%s: &[u8] = &[" % BO)
+            for _, values in entries:
+                f.write("{}, ".format(len(values)))
+            f.write("];\n")
+            f.write(
+                "///Table containing the values decoded with a lookup, MAX_SYMBOLS\n"
+                "///for each entry, padded with zeros\n"
+            )
+            f.write(
+                "pub const READ_VALUES_%s: &[%s] = &["
+                % (
+                    BO,
+                    get_best_fitting_type(
+                        max(max(values, default=0) for _, values in entries).bit_length()
+                    ),
+                )
+            )
+            for _, values in entries:
+                for value in values + [0] * (max_symbols - len(values)):
+                    f.write("{}, ".format(value))
+            f.write("];\n")
+
+
 ################################################################################
 
 
@@ -366,21 +482,6 @@ for i in range(256):
     assert len(wl2m) == l
 
 
-def gen_unary(read_bits, write_max_val, len_max_val=None, merged_table=False):
-    """Configuration of `gen_table` for unary"""
-    len_max_val = len_max_val or write_max_val
-    return gen_table(
-        read_bits,
-        write_max_val,
-        len_max_val,
-        "unary",
-        len_unary,
-        read_unary,
-        write_unary,
-        merged_table,
-    )
-
-
 ################################################################################
 
 
@@ -439,21 +540,6 @@ for i in range(256):
     assert len(wl2m) == l
 
 
-def gen_gamma(read_bits, write_max_val, len_max_val=None, merged_table=False):
-    """Configuration of `gen_table` for gamma"""
-    len_max_val = len_max_val or write_max_val
-    return gen_table(
-        read_bits,
-        write_max_val,
-        len_max_val,
-        "gamma",
-        len_gamma,
-        read_gamma,
-        write_gamma,
-        merged_table,
-    )
-
-
 ################################################################################
 
 
@@ -512,17 +598,161 @@ for i in range(256):
     assert len(wl2m) == l
 
 
-def gen_delta(read_bits, write_max_val, len_max_val=None, merged_table=False):
-    """Configuration of `gen_table` for delta"""
+################################################################################
+
+
+def read_omega(bitstream, m2l):
+    """Read an omega code"""
+    n = 1
+    while True:
+        b, bitstream = read_fixed(1, bitstream, m2l)
+        if b == 0:
+            return n - 1, bitstream
+        f, bitstream = read_fixed(n, bitstream, m2l)
+        n = (1 << n) | f
+
+
+def write_omega(value, bitstream, m2l):
+    """Write an omega code"""
+
+    def write_groups(n, bitstream):
+        if n <= 1:
+            return bitstream
+        l = floor(log2(n))  # NoQA: E741
+        bitstream = write_groups(l, bitstream)
+        bitstream = write_fixed(1, 1, bitstream, m2l)
+        return write_fixed(n - (1 << l), l, bitstream, m2l)
+
+    bitstream = write_groups(value + 1, bitstream)
+    return write_fixed(0, 1, bitstream, m2l)
+
+
+def len_omega(value):
+    """Length of the omega code of `value`"""
+    n = value + 1
+    res = 1
+    while n > 1:
+        l = floor(log2(n))  # NoQA: E741
+        res += l + 1
+        n = l
+    return res
+
+
+# Test that the impl is reasonable
+assert write_omega(0, "", True) == "0"
+assert write_omega(0, "", False) == "0"
+assert write_omega(1, "", True) == "100"
+assert write_omega(1, "", False) == "001"
+assert write_omega(2, "", True) == "110"
+assert write_omega(2, "", False) == "011"
+assert write_omega(3, "", True) == "101000"
+assert write_omega(6, "", True) == "101110"
+assert write_omega(15, "", True) == "10100100000"
+
+# Little consistency check
+for i in range(256):
+    wm2l = write_omega(i, "", True)
+    rm2l = read_omega(wm2l, True)[0]
+    wl2m = write_omega(i, "", False)
+    rl2m = read_omega(wl2m, False)[0]
+    l = len_omega(i)  # NoQA: E741
+    assert i == rm2l
+    assert i == rl2m
+    assert len(wm2l) == l
+    assert len(wl2m) == l
+
+
+def gen_omega(read_bits, write_max_val, len_max_val=None, merged_table=False):
+    """Configuration of `gen_table` for omega"""
+    len_max_val = len_max_val or write_max_val
+    return gen_table(
+        read_bits,
+        write_max_val,
+        len_max_val,
+        "omega",
+        len_omega,
+        read_omega,
+        write_omega,
+        merged_table,
+    )
+
+
+################################################################################
+
+
+def fibonacci_numbers(max_value):
+    """The Fibonacci numbers F(2), F(3), ... up to `max_value`"""
+    fib = [1, 2]
+    while fib[-1] + fib[-2] <= max_value:
+        fib.append(fib[-1] + fib[-2])
+    return [f for f in fib if f <= max_value]
+
+
+def read_fibonacci(bitstream, m2l):
+    """Read a Fibonacci code"""
+    fib = fibonacci_numbers(2**64)
+    value, prev, i = 0, 0, 0
+    while True:
+        b, bitstream = read_fixed(1, bitstream, m2l)
+        if b == 1 and prev == 1:
+            return value - 1, bitstream
+        if b == 1:
+            value += fib[i]
+        prev = b
+        i += 1
+
+
+def write_fibonacci(value, bitstream, m2l):
+    """Write a Fibonacci code"""
+    value += 1
+    fib = fibonacci_numbers(value)
+    bits = [0] * len(fib)
+    for i in reversed(range(len(fib))):
+        if fib[i] <= value:
+            bits[i] = 1
+            value -= fib[i]
+    for b in bits + [1]:
+        bitstream = write_fixed(b, 1, bitstream, m2l)
+    return bitstream
+
+
+def len_fibonacci(value):
+    """Length of the Fibonacci code of `value`"""
+    return len(fibonacci_numbers(value + 1)) + 1
+
+
+# Test that the impl is reasonable
+assert write_fibonacci(0, "", True) == "11"
+assert write_fibonacci(1, "", True) == "011"
+assert write_fibonacci(2, "", True) == "0011"
+assert write_fibonacci(3, "", True) == "1011"
+assert write_fibonacci(10, "", True) == "001011"
+assert write_fibonacci(3, "", False) == "1101"
+
+# Little consistency check
+for i in range(256):
+    wm2l = write_fibonacci(i, "", True)
+    rm2l = read_fibonacci(wm2l, True)[0]
+    wl2m = write_fibonacci(i, "", False)
+    rl2m = read_fibonacci(wl2m, False)[0]
+    l = len_fibonacci(i)  # NoQA: E741
+    assert i == rm2l
+    assert i == rl2m
+    assert len(wm2l) == l
+    assert len(wl2m) == l
+
+
+def gen_fibonacci(read_bits, write_max_val, len_max_val=None, merged_table=False):
+    """Configuration of `gen_table` for Fibonacci"""
     len_max_val = len_max_val or write_max_val
     return gen_table(
         read_bits,
         write_max_val,
         len_max_val,
-        "delta",
-        len_delta,
-        read_delta,
-        write_delta,
+        "fibonacci",
+        len_fibonacci,
+        read_fibonacci,
+        write_fibonacci,
         merged_table,
     )
 
@@ -674,48 +904,234 @@ for i in range(256):
     assert len(wl2m) == l
 
 
-def gen_zeta(read_bits, write_max_val, len_max_val=None, k=3, merged_table=False):
-    """Configuration of `gen_table` for delta"""
+################################################################################
+
+
+def read_rice(bitstream, log2_b, m2l):
+    """Read a Rice code"""
+    q, bitstream = read_unary(bitstream, m2l)
+    if log2_b == 0:
+        return q, bitstream
+    r, bitstream = read_fixed(log2_b, bitstream, m2l)
+    return (q << log2_b) | r, bitstream
+
+
+def write_rice(value, log2_b, bitstream, m2l):
+    """Write a Rice code"""
+    bitstream = write_unary(value >> log2_b, bitstream, m2l)
+    if log2_b != 0:
+        bitstream = write_fixed(value & ((1 << log2_b) - 1), log2_b, bitstream, m2l)
+    return bitstream
+
+
+def len_rice(value, log2_b):
+    """Length of the Rice code of `value`"""
+    return (value >> log2_b) + 1 + log2_b
+
+
+# Little consistency check
+for log2_b in range(6):
+    for i in range(256):
+        wm2l = write_rice(i, log2_b, "", True)
+        rm2l = read_rice(wm2l, log2_b, True)[0]
+        wl2m = write_rice(i, log2_b, "", False)
+        rl2m = read_rice(wl2m, log2_b, False)[0]
+        l = len_rice(i, log2_b)  # NoQA: E741
+        assert i == rm2l
+        assert i == rl2m
+        assert len(wm2l) == l
+        assert len(wl2m) == l
+
+read_func_rice = """
+#[inline(always)]
+/// Autogenerated function to lookup the read table, if the result is `Some`
+/// the unary prefix and the `log2_b` low bits of the value were both in the
+/// peeked window, otherwise we were not able to decode the value and you
+/// should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn read_table_%(bo)s<B: BitRead<%(BO)s>>(backend: &mut B, log2_b: u64) -> Result<Option<u64>> {
+    if let Ok(idx) = backend.peek_bits(READ_BITS) {
+        let idx: u64 = idx.upcast();
+        let len = READ_LEN_%(BO)s[idx as usize] as usize;
+        if len != MISSING_VALUE_LEN as usize && len + log2_b as usize <= READ_BITS {
+            let low_bits = %(low_bits)s & ((1 << log2_b) - 1);
+            backend.skip_bits_after_table_lookup(len + log2_b as usize)?;
+            return Ok(Some((((len - 1) as u64) << log2_b) | low_bits));
+        }
+    }
+    Ok(None)
+}
+"""
+
+
+def gen_rice(read_bits):
+    """Generate the tables for Rice codes. As the parameter is known only at
+    runtime, the tables decode the unary prefix of the code, and the low bits
+    of the value are taken from the same peeked window."""
+    with open(os.path.join(ROOT, "rice_tables.rs"), "w") as f:
+        f.write(
+            "// THIS FILE HAS BEEN GENERATED WITH THE SCRIPT {}\n".format(
+                os.path.basename(__file__)
+            )
+        )
+        f.write("// ~~~~~~~~~~~~~~~~~~~ DO NOT MODIFY ~~~~~~~~~~~~~~~~~~~~~~\n")
+        f.write(
+            "// Pre-computed constants used to speedup the reading of rice codes\n"
+        )
+        f.write("use anyhow::Result;\n")
+        f.write("use crate::traits::{BitRead, M2L, L2M, UpcastableInto};\n")
+        f.write("/// How many bits are needed to read the tables in this\n")
+        f.write("pub const READ_BITS: usize = {};\n".format(read_bits))
+        f.write(
+            "/// The len we assign to a code that cannot be decoded through the table\n"
+        )
+        f.write("pub const MISSING_VALUE_LEN: u8 = 255;\n")
+
+        for bo, low_bits in [
+            ("l2m", "(idx >> len)"),
+            ("m2l", "(idx >> (READ_BITS - len - log2_b as usize))"),
+        ]:
+            f.write(
+                read_func_rice % {"bo": bo, "BO": bo.upper(), "low_bits": low_bits}
+            )
+
+        for BO in ["M2L", "L2M"]:
+            f.write(
+                "///Table contaings the lens of the unary prefixes used to speed up the reading of rice codes\n"  # NoQA
+            )
+            f.write("pub const READ_LEN_%s: &[u8] = &[" % BO)
+            for value in range(0, 2**read_bits):
+                bits = ("{:0%sb}" % read_bits).format(value)
+                try:
+                    _, bits_left = read_unary(bits, BO == "M2L")
+                    f.write("{}, ".format(read_bits - len(bits_left)))
+                except ValueError:
+                    f.write("255, ")
+            f.write("];\n")
+
+################################################################################
+
+
+def read_pi(bitstream, k, m2l):
+    """Read a pi code"""
+    l, bitstream = read_unary(bitstream, m2l)
+    l += 1
+    v = 0
+    if k != 0:
+        v, bitstream = read_minimal_binary(2**k, bitstream, m2l)
+    h = l * 2**k - v
+    r = h - 1
+    if r == 0:
+        return 0, bitstream
+    f, bitstream = read_fixed(r, bitstream, m2l)
+    return (1 << r) + f - 1, bitstream
+
+
+def write_pi(value, k, bitstream, m2l):
+    """Write a pi code"""
+    value += 1
+    r = floor(log2(value))
+    h = r + 1
+    l = ceil(h / 2**k)  # NoQA: E741
+    bitstream = write_unary(l - 1, bitstream, m2l)
+    if k != 0:
+        bitstream = write_minimal_binary(l * 2**k - h, 2**k, bitstream, m2l)
+    if r != 0:
+        bitstream = write_fixed(value - (1 << r), r, bitstream, m2l)
+    return bitstream
+
+
+def len_pi(value, k):
+    """Length of the pi code of `value`"""
+    value += 1
+    r = floor(log2(value))
+    h = r + 1
+    l = ceil(h / 2**k)  # NoQA: E741
+    return l + k + r
+
+
+# Test that the impl is reasonable
+assert write_pi(0, 2, "", True) == "111"
+assert write_pi(1, 2, "", True) == "1100"
+assert write_pi(2, 2, "", True) == "1101"
+assert write_pi(3, 2, "", True) == "10100"
+assert write_pi(15, 2, "", True) == "01110000"
+
+# Little consistency check
+for k in range(5):
+    for i in range(256):
+        l = len_pi(i, k)  # NoQA: E741
+
+        wm2l = write_pi(i, k, "", True)
+        rm2l = read_pi(wm2l, k, True)[0]
+
+        assert i == rm2l, "%s %s %s" % (i, rm2l, wm2l)
+        assert len(wm2l) == l
+
+        wl2m = write_pi(i, k, "", False)
+        rl2m = read_pi(wl2m, k, False)[0]
+
+        assert i == rl2m, "%s %s %s" % (i, rl2m, wl2m)
+        assert len(wl2m) == l
+
+
+def gen_pi(read_bits, write_max_val, len_max_val=None, k=2, merged_table=False):
+    """Configuration of `gen_table` for pi"""
     len_max_val = len_max_val or write_max_val
     gen_table(
         read_bits,
         write_max_val,
         len_max_val,
-        "zeta",
-        lambda value: len_zeta(value, k),
-        lambda bitstream, m2l: read_zeta(bitstream, k, m2l),
-        lambda value, bitstream, m2l: write_zeta(value, k, bitstream, m2l),
+        "pi",
+        lambda value: len_pi(value, k),
+        lambda bitstream, m2l: read_pi(bitstream, k, m2l),
+        lambda value, bitstream, m2l: write_pi(value, k, bitstream, m2l),
         merged_table,
     )
-    with open(os.path.join(ROOT, "zeta_tables.rs"), "a") as f:
-        f.write("/// The K of the zeta codes for these tables\n")
+    with open(os.path.join(ROOT, "pi_tables.rs"), "a") as f:
+        f.write("/// The K of the pi codes for these tables\n")
         f.write("pub const K: u64 = {};".format(k))
 
 ################################################################################
 
 def generate_default_tables():
+    # The tables of unary, gamma, delta and zeta codes are computed at compile
+    # time by src/codes/tables.rs, using the environment variables
+    # {UNARY,GAMMA,DELTA,ZETA}_CODE_TABLE_{BITS,MAX}
     merged_table = os.environ.get("MERGED_TABLES", 0) == "0"
-    gen_unary(
-        read_bits=int(os.environ.get("UNARY_CODE_TABLE_BITS", "0")), 
-        write_max_val=int(os.environ.get("UNARY_CODE_TABLE_MAX", "63")),
-        merged_table=merged_table,
+    gen_multi_table(
+        read_bits=int(os.environ.get("UNARY_MULTI_TABLE_BITS", "12")),
+        max_symbols=int(os.environ.get("UNARY_MULTI_TABLE_SYMBOLS", "8")),
+        code_name="unary",
+        read_func=read_unary,
+    )
+    gen_multi_table(
+        read_bits=int(os.environ.get("GAMMA_MULTI_TABLE_BITS", "12")),
+        max_symbols=int(os.environ.get("GAMMA_MULTI_TABLE_SYMBOLS", "8")),
+        code_name="gamma",
+        read_func=read_gamma,
     )
-    gen_gamma(
-        read_bits=int(os.environ.get("GAMMA_CODE_TABLE_BITS", "11")), 
-        write_max_val=int(os.environ.get("GAMMA_CODE_TABLE_MAX", "255")),
+    gen_omega(
+        read_bits=int(os.environ.get("OMEGA_CODE_TABLE_BITS", "11")), 
+        write_max_val=int(os.environ.get("OMEGA_CODE_TABLE_MAX", "255")),
         merged_table=merged_table,
     )
-    gen_delta(
-        read_bits=int(os.environ.get("DELTA_CODE_TABLE_BITS", "11")), 
-        write_max_val=int(os.environ.get("DELTA_CODE_TABLE_MAX", "255")),
+    gen_fibonacci(
+        read_bits=int(os.environ.get("FIBONACCI_CODE_TABLE_BITS", "12")), 
+        write_max_val=int(os.environ.get("FIBONACCI_CODE_TABLE_MAX", "255")),
         merged_table=merged_table,
     )
-    gen_zeta(
-        read_bits=int(os.environ.get("ZETA_CODE_TABLE_BITS", "12")), 
-        write_max_val=int(os.environ.get("ZETA_CODE_TABLE_MAX", "255")),
-        k=3,
+    gen_pi(
+        read_bits=int(os.environ.get("PI_CODE_TABLE_BITS", "12")), 
+        write_max_val=int(os.environ.get("PI_CODE_TABLE_MAX", "255")),
+        k=2,
         merged_table=merged_table,
     )
+    gen_rice(
+        read_bits=int(os.environ.get("RICE_CODE_TABLE_BITS", "12")),
+    )
 
 if __name__ == "__main__":
     # Generate the default tables
@@ -23,38 +23,28 @@ else:
 
 for code in ["unary", "gamma", "delta", "delta_gamma", "zeta3"]:
     plt.figure(figsize=(10, 8), dpi=200, facecolor="white")
-    for tables_n in [1, 2]:
-        if tables_n == 1:
-            table_txt = "merged"
-            marker = "o"
-        else:
-            table_txt = "sep"
-            marker = "s"
-
-        for pat in [
-            "%s::L2M::Table" % code,
-            "%s::M2L::Table" % code,
-        ]:
-            values = df[
-                (df.pat == pat) & (df.tables_num == tables_n)
-            ]
-            m = min(values.ns_median)
-            i = np.argmin(values.ns_median)
-            plt.errorbar(
-                values[x_label],
-                values.ns_median,  # values.ns_std,
-                label="{}::{} (min: {:.3f}ns {} {})".format(
-                    "::".join(pat.split("::")[1:]), table_txt, m, i,
-                    "bits" if x_label == "n_bits" else "max",
-                ),
-                marker=marker,
-            )
-            plt.fill_between(
-                values[x_label],
-                values.ns_perc25,
-                values.ns_perc75,
-                alpha=0.3,
-            )
+    for pat in [
+        "%s::L2M::Table" % code,
+        "%s::M2L::Table" % code,
+    ]:
+        values = df[df.pat == pat]
+        m = min(values.ns_median)
+        i = np.argmin(values.ns_median)
+        plt.errorbar(
+            values[x_label],
+            values.ns_median,  # values.ns_std,
+            label="{} (min: {:.3f}ns {} {})".format(
+                "::".join(pat.split("::")[1:]), m, i,
+                "bits" if x_label == "n_bits" else "max",
+            ),
+            marker="o",
+        )
+        plt.fill_between(
+            values[x_label],
+            values.ns_perc25,
+            values.ns_perc75,
+            alpha=0.3,
+        )
 
     for pat in [
         "%s::L2M::NoTable" % code,
@@ -78,7 +68,7 @@ for code in ["unary", "gamma", "delta", "delta_gamma", "zeta3"]:
         )
 
     ratios = (
-        df[df.pat.str.contains(code) & (df.tables_num == tables_n)]
+        df[df.pat.str.contains(code)]
         .groupby(x_label)
         .mean()
     )
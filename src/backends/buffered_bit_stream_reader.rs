@@ -6,7 +6,7 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
-use crate::codes::unary_tables;
+use crate::codes::{batch, unary_multi_tables, unary_tables, GammaRead};
 use crate::traits::*;
 use anyhow::{bail, Context, Result};
 
@@ -34,7 +34,7 @@ impl<E: BitOrder, BW: Word, WR: WordRead + Clone> core::clone::Clone
             backend: self.backend.clone(),
             buffer: BW::ZERO,
             valid_bits: 0,
-            _marker: core::marker::PhantomData::default(),
+            _marker: core::marker::PhantomData,
         }
     }
 }
@@ -55,7 +55,7 @@ impl<E: BitOrder, BW: Word, WR: WordRead> BufferedBitStreamRead<E, BW, WR> {
             backend,
             buffer: BW::ZERO,
             valid_bits: 0,
-            _marker: core::marker::PhantomData::default(),
+            _marker: core::marker::PhantomData,
         }
     }
 }
@@ -153,6 +153,7 @@ where
         // clean the buffer data
         n_bits -= self.valid_bits;
         self.valid_bits = 0;
+        self.buffer = BW::ZERO;
         // skip words as needed
         while n_bits > WR::Word::BITS {
             let _ = self.backend.read_next_word()?;
@@ -213,7 +214,8 @@ where
         // compose the remaining bits
         let upcasted: u64 = new_word.upcast();
         let final_bits: u64 = (upcasted >> self.valid_bits).downcast();
-        result = (result << n_bits) | final_bits;
+        // Valid left shift of n_bits, even when n_bits is 64
+        result = ((result << (n_bits - 1)) << 1) | final_bits;
         // and put the rest in the buffer
         self.buffer = new_word.upcast();
         self.buffer = (self.buffer << (BW::BITS - self.valid_bits - 1)) << 1;
@@ -228,27 +230,72 @@ where
                 return Ok(res);
             }
         }
-        let mut result: u64 = 0;
-        loop {
-            // count the zeros from the left
-            let zeros: usize = self.buffer.leading_zeros();
-
-            // if we encountered an 1 in the valid_bits we can return
-            if zeros < self.valid_bits {
-                result += zeros as u64;
-                self.buffer <<= zeros + 1;
-                self.valid_bits -= zeros + 1;
-                return Ok(result);
-            }
+        // count the zeros from the left
+        let zeros: usize = self.buffer.leading_zeros();
+
+        // if we encountered an 1 in the valid_bits we can return
+        if zeros < self.valid_bits {
+            self.buffer <<= zeros + 1;
+            self.valid_bits -= zeros + 1;
+            return Ok(zeros as u64);
+        }
 
-            result += self.valid_bits as u64;
+        // otherwise the valid bits are all zeros: consume them and then
+        // whole words, until we find the word containing the ending 1
+        let mut result = self.valid_bits as u64;
+        let mut word = self.backend.read_next_word()?;
+        while word == WR::Word::ZERO {
+            result += WR::Word::BITS as u64;
+            word = self.backend.read_next_word()?;
+        }
+        let new_word: BW = word.to_be().upcast();
+        self.buffer = new_word << (BW::BITS - WR::Word::BITS);
+        let zeros: usize = self.buffer.leading_zeros();
+        self.buffer <<= zeros + 1;
+        self.valid_bits = WR::Word::BITS - zeros - 1;
+        Ok(result + zeros as u64)
+    }
 
-            // otherwise we didn't encounter the ending 1 yet so we need to
-            // refill and iter again
-            let new_word: BW = self.backend.read_next_word()?.to_be().upcast();
-            self.valid_bits = WR::Word::BITS;
-            self.buffer = new_word << (BW::BITS - WR::Word::BITS);
+    #[inline]
+    fn read_unary_into<const USE_TABLE: bool>(&mut self, values: &mut [u64]) -> Result<()> {
+        if USE_TABLE {
+            return batch::read_multi_table(
+                self,
+                values,
+                unary_multi_tables::READ_BITS,
+                unary_multi_tables::MAX_SYMBOLS,
+                (
+                    unary_multi_tables::READ_LEN_M2L,
+                    unary_multi_tables::READ_COUNT_M2L,
+                    unary_multi_tables::READ_VALUES_M2L,
+                ),
+                |backend| backend.read_unary::<true>(),
+            );
+        }
+        for value in values.iter_mut() {
+            *value = self.read_unary::<false>()?;
         }
+        Ok(())
+    }
+
+    #[inline]
+    fn skip_unary_n<const USE_TABLE: bool>(&mut self, n: usize) -> Result<()> {
+        if USE_TABLE {
+            return batch::skip_multi_table(
+                self,
+                n,
+                unary_multi_tables::READ_BITS,
+                (
+                    unary_multi_tables::READ_LEN_M2L,
+                    unary_multi_tables::READ_COUNT_M2L,
+                ),
+                |backend| backend.skip_unary::<true>(),
+            );
+        }
+        for _ in 0..n {
+            self.read_unary::<false>()?;
+        }
+        Ok(())
     }
 }
 
@@ -323,6 +370,7 @@ where
         // clean the buffer data
         n_bits -= self.valid_bits;
         self.valid_bits = 0;
+        self.buffer = BW::ZERO;
         // skip words as needed
         while n_bits > WR::Word::BITS {
             let _ = self.backend.read_next_word()?;
@@ -419,26 +467,170 @@ where
                 return Ok(res);
             }
         }
-        let mut result: u64 = 0;
-        loop {
-            // count the zeros from the left
-            let zeros: usize = self.buffer.trailing_zeros();
-
-            // if we encountered an 1 in the valid_bits we can return
-            if zeros < self.valid_bits {
-                result += zeros as u64;
-                self.buffer >>= zeros + 1;
-                self.valid_bits -= zeros + 1;
-                return Ok(result);
-            }
+        // count the zeros from the right
+        let zeros: usize = self.buffer.trailing_zeros();
+
+        // if we encountered an 1 in the valid_bits we can return
+        if zeros < self.valid_bits {
+            self.buffer >>= zeros + 1;
+            self.valid_bits -= zeros + 1;
+            return Ok(zeros as u64);
+        }
 
-            result += self.valid_bits as u64;
+        // otherwise the valid bits are all zeros: consume them and then
+        // whole words, until we find the word containing the ending 1
+        let mut result = self.valid_bits as u64;
+        let mut word = self.backend.read_next_word()?;
+        while word == WR::Word::ZERO {
+            result += WR::Word::BITS as u64;
+            word = self.backend.read_next_word()?;
+        }
+        let new_word: BW = word.to_le().upcast();
+        let zeros: usize = new_word.trailing_zeros();
+        self.buffer = new_word >> (zeros + 1);
+        self.valid_bits = WR::Word::BITS - zeros - 1;
+        Ok(result + zeros as u64)
+    }
 
-            // otherwise we didn't encounter the ending 1 yet so we need to
-            // refill and iter again
-            let new_word: BW = self.backend.read_next_word()?.to_le().upcast();
-            self.valid_bits = WR::Word::BITS;
-            self.buffer = new_word;
+    #[inline]
+    fn read_unary_into<const USE_TABLE: bool>(&mut self, values: &mut [u64]) -> Result<()> {
+        if USE_TABLE {
+            return batch::read_multi_table(
+                self,
+                values,
+                unary_multi_tables::READ_BITS,
+                unary_multi_tables::MAX_SYMBOLS,
+                (
+                    unary_multi_tables::READ_LEN_L2M,
+                    unary_multi_tables::READ_COUNT_L2M,
+                    unary_multi_tables::READ_VALUES_L2M,
+                ),
+                |backend| backend.read_unary::<true>(),
+            );
+        }
+        for value in values.iter_mut() {
+            *value = self.read_unary::<false>()?;
         }
+        Ok(())
+    }
+
+    #[inline]
+    fn skip_unary_n<const USE_TABLE: bool>(&mut self, n: usize) -> Result<()> {
+        if USE_TABLE {
+            return batch::skip_multi_table(
+                self,
+                n,
+                unary_multi_tables::READ_BITS,
+                (
+                    unary_multi_tables::READ_LEN_L2M,
+                    unary_multi_tables::READ_COUNT_L2M,
+                ),
+                |backend| backend.skip_unary::<true>(),
+            );
+        }
+        for _ in 0..n {
+            self.read_unary::<false>()?;
+        }
+        Ok(())
+    }
+}
+
+impl<BW: Word, WR: WordRead> BufferedBitStreamRead<M2L, BW, WR>
+where
+    BW: DowncastableInto<WR::Word> + CastableInto<u64>,
+    WR::Word: UpcastableInto<BW> + UpcastableInto<u64>,
+{
+    /// Read `values.len()` unary codes, decoding several codes with a single
+    /// lookup in the tables of [`unary_multi_tables`] when they are short.
+    /// This is the same as [`BitRead::read_unary_into`] with `USE_TABLE`
+    /// true.
+    ///
+    /// # Errors
+    /// This function fails only if the backend has problems reading bits, as
+    /// when the stream ended unexpectedly
+    pub fn read_unary_batch(&mut self, values: &mut [u64]) -> Result<()> {
+        self.read_unary_into::<true>(values)
+    }
+
+    /// Read `values.len()` γ codes, decoding several codes with a single
+    /// lookup in the tables of
+    /// [`gamma_multi_tables`](crate::codes::gamma_multi_tables) when they are
+    /// short. This is the same as [`GammaRead::read_gamma_into`] with
+    /// `USE_TABLE` true.
+    ///
+    /// ### Example
+    /// ```
+    /// use dsi_bitstream::prelude::*;
+    ///
+    /// let mut buffer: Vec<u64> = vec![];
+    /// {
+    ///     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+    ///     for value in 0..1000 {
+    ///         writer.write_gamma::<true>(value % 5).unwrap();
+    ///     }
+    /// }
+    ///
+    /// let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+    /// let mut values = vec![0; 1000];
+    /// reader.read_gamma_batch(&mut values).unwrap();
+    /// assert!(values.iter().enumerate().all(|(i, &value)| value == i as u64 % 5));
+    /// ```
+    ///
+    /// # Errors
+    /// This function fails only if the backend has problems reading bits, as
+    /// when the stream ended unexpectedly, or if a code does not fit in a
+    /// [`u64`]
+    pub fn read_gamma_batch(&mut self, values: &mut [u64]) -> Result<()> {
+        self.read_gamma_into::<true>(values)
+    }
+}
+
+impl<BW: Word, WR: WordRead> BufferedBitStreamRead<L2M, BW, WR>
+where
+    BW: DowncastableInto<WR::Word> + CastableInto<u64>,
+    WR::Word: UpcastableInto<BW> + UpcastableInto<u64>,
+{
+    /// Read `values.len()` unary codes, decoding several codes with a single
+    /// lookup in the tables of [`unary_multi_tables`] when they are short.
+    /// This is the same as [`BitRead::read_unary_into`] with `USE_TABLE`
+    /// true.
+    ///
+    /// # Errors
+    /// This function fails only if the backend has problems reading bits, as
+    /// when the stream ended unexpectedly
+    pub fn read_unary_batch(&mut self, values: &mut [u64]) -> Result<()> {
+        self.read_unary_into::<true>(values)
+    }
+
+    /// Read `values.len()` γ codes, decoding several codes with a single
+    /// lookup in the tables of
+    /// [`gamma_multi_tables`](crate::codes::gamma_multi_tables) when they are
+    /// short. This is the same as [`GammaRead::read_gamma_into`] with
+    /// `USE_TABLE` true.
+    ///
+    /// ### Example
+    /// ```
+    /// use dsi_bitstream::prelude::*;
+    ///
+    /// let mut buffer: Vec<u64> = vec![];
+    /// {
+    ///     let mut writer = BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut buffer));
+    ///     for value in 0..1000 {
+    ///         writer.write_gamma::<true>(value % 5).unwrap();
+    ///     }
+    /// }
+    ///
+    /// let mut reader = <BufferedBitStreamRead<L2M, u128, _>>::new(MemWordRead::new(&buffer));
+    /// let mut values = vec![0; 1000];
+    /// reader.read_gamma_batch(&mut values).unwrap();
+    /// assert!(values.iter().enumerate().all(|(i, &value)| value == i as u64 % 5));
+    /// ```
+    ///
+    /// # Errors
+    /// This function fails only if the backend has problems reading bits, as
+    /// when the stream ended unexpectedly, or if a code does not fit in a
+    /// [`u64`]
+    pub fn read_gamma_batch(&mut self, values: &mut [u64]) -> Result<()> {
+        self.read_gamma_into::<true>(values)
     }
 }
@@ -6,7 +6,7 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
-use crate::codes::unary_tables;
+use crate::codes::{batch, unary_tables};
 use crate::traits::*;
 use anyhow::{bail, Result};
 
@@ -31,7 +31,7 @@ impl<BO: BBSWDrop<WR>, WR: WordWrite> BufferedBitStreamWrite<BO, WR> {
             backend,
             buffer: 0,
             bits_in_buffer: 0,
-            _marker: core::marker::PhantomData::default(),
+            _marker: core::marker::PhantomData,
         }
     }
 
@@ -115,10 +115,8 @@ impl<WR: WordWrite<Word = u64>> BitWrite<M2L> for BufferedBitStreamWrite<M2L, WR
     #[inline]
     fn write_unary<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
         debug_assert_ne!(value, u64::MAX);
-        if USE_TABLE {
-            if unary_tables::write_table_m2l(self, value)? {
-                return Ok(());
-            }
+        if USE_TABLE && unary_tables::write_table_m2l(self, value)? {
+            return Ok(());
         }
 
         let mut code_length = value + 1;
@@ -153,6 +151,17 @@ impl<WR: WordWrite<Word = u64>> BitWrite<M2L> for BufferedBitStreamWrite<M2L, WR
 
         Ok(())
     }
+
+    #[inline]
+    fn write_unary_all<const USE_TABLE: bool>(&mut self, values: &[u64]) -> Result<()> {
+        // short unary codes need no table to be packed
+        batch::write_packed_m2l(
+            self,
+            values,
+            |value| (value < 64).then_some((1, value as usize + 1)),
+            |backend, value| backend.write_unary::<USE_TABLE>(value),
+        )
+    }
 }
 
 impl<WR: WordWrite<Word = u64>> BBSWDrop<WR> for L2M {
@@ -213,10 +222,8 @@ impl<WR: WordWrite<Word = u64>> BitWrite<L2M> for BufferedBitStreamWrite<L2M, WR
     #[inline]
     fn write_unary<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
         debug_assert_ne!(value, u64::MAX);
-        if USE_TABLE {
-            if unary_tables::write_table_l2m(self, value)? {
-                return Ok(());
-            }
+        if USE_TABLE && unary_tables::write_table_l2m(self, value)? {
+            return Ok(());
         }
         let mut code_length = value + 1;
 
@@ -250,4 +257,15 @@ impl<WR: WordWrite<Word = u64>> BitWrite<L2M> for BufferedBitStreamWrite<L2M, WR
 
         Ok(())
     }
+
+    #[inline]
+    fn write_unary_all<const USE_TABLE: bool>(&mut self, values: &[u64]) -> Result<()> {
+        // short unary codes need no table to be packed
+        batch::write_packed_l2m(
+            self,
+            values,
+            |value| (value < 64).then(|| (1 << value, value as usize + 1)),
+            |backend, value| backend.write_unary::<USE_TABLE>(value),
+        )
+    }
 }
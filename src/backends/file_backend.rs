@@ -36,7 +36,7 @@ impl<W: Word, B> FileBackend<W, B> {
     pub fn new(file: B) -> Self {
         Self {
             file,
-            _marker: core::marker::PhantomData::default(),
+            _marker: core::marker::PhantomData,
         }
     }
 }
@@ -46,7 +46,7 @@ impl<W: Word, B: Clone> Clone for FileBackend<W, B> {
     fn clone(&self) -> Self {
         Self {
             file: self.file.clone(),
-            _marker: core::marker::PhantomData::default(),
+            _marker: core::marker::PhantomData,
         }
     }
 }
@@ -78,7 +78,7 @@ impl<W: Word, B: std::io::Write> WordWrite for FileBackend<W, B> {
 
     #[inline]
     fn write_word(&mut self, word: W) -> Result<()> {
-        self.file.write(word.to_ne_bytes().as_ref())?;
+        self.file.write_all(word.to_ne_bytes().as_ref())?;
         Ok(())
     }
 }
@@ -60,7 +60,8 @@ impl<W: Word, B: AsRef<[W]>> MemWordRead<W, B> {
     }
 }
 
-///
+/// An Implementation of [`WordRead`] for a slice of memory `&[u64]` that
+/// returns zeros once the slice is exhausted instead of failing
 #[derive(Debug, Clone)]
 pub struct MemWordReadInfinite<W: Word, B: AsRef<[W]>> {
     data: B,
@@ -97,13 +98,11 @@ impl<W: Word, B: AsRef<[W]>> WordRead for MemWordReadInfinite<W, B> {
 
 impl<W: Word, B: AsRef<[W]>> WordStream for MemWordReadInfinite<W, B> {
     #[inline(always)]
-    #[must_use]
     fn len(&self) -> usize {
         self.data.as_ref().len()
     }
 
     #[inline(always)]
-    #[must_use]
     fn get_position(&self) -> usize {
         self.word_index
     }
@@ -240,13 +239,11 @@ impl<W: Word, B: AsRef<[W]>> WordRead for MemWordRead<W, B> {
 
 impl<W: Word, B: AsRef<[W]>> WordStream for MemWordRead<W, B> {
     #[inline]
-    #[must_use]
     fn len(&self) -> usize {
         self.data.as_ref().len()
     }
 
     #[inline]
-    #[must_use]
     fn get_position(&self) -> usize {
         self.word_index
     }
@@ -284,13 +281,11 @@ impl<W: Word, B: AsMut<[W]>> WordRead for MemWordWrite<W, B> {
 
 impl<W: Word, B: AsRef<[W]> + AsMut<[W]>> WordStream for MemWordWrite<W, B> {
     #[inline]
-    #[must_use]
     fn len(&self) -> usize {
         self.data.as_ref().len()
     }
 
     #[inline]
-    #[must_use]
     fn get_position(&self) -> usize {
         self.word_index
     }
@@ -365,13 +360,11 @@ impl<W: Word, B: AsMut<alloc::vec::Vec<W>> + AsRef<alloc::vec::Vec<W>>> WordStre
     for MemWordWriteVec<W, B>
 {
     #[inline]
-    #[must_use]
     fn len(&self) -> usize {
         self.data.as_ref().len()
     }
 
     #[inline]
-    #[must_use]
     fn get_position(&self) -> usize {
         self.word_index
     }
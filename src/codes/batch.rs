@@ -0,0 +1,176 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Batch decoding and encoding
+//! Common parts of the implementations of the slice-oriented methods, such as
+//! [`GammaRead::read_gamma_into`](super::GammaRead::read_gamma_into) and
+//! [`GammaWrite::write_gamma_all`](super::GammaWrite::write_gamma_all).
+//!
+//! Reading and skipping use the multi-symbol decoding tables, when available,
+//! to decode or skip several codes with a single lookup. Writing packs the codewords found in
+//! the encoding tables in a word, so that the backend is called once every
+//! 64 bits instead of once per code.
+
+use crate::traits::*;
+use anyhow::Result;
+
+/// Fill `values` using the multi-symbol decoding tables `lens`, `counts` and
+/// `symbols` (see [`gamma_multi_tables`](super::gamma_multi_tables)), which
+/// decode up to `max_symbols` codes from a window of `read_bits` bits,
+/// falling back to `read_one` when the window does not contain a complete
+/// code or contains more codes than needed.
+///
+/// # Errors
+/// Forward the errors of `read_one` and `skip_bits_after_table_lookup`
+#[inline(always)]
+pub(crate) fn read_multi_table<BO: BitOrder, B: BitRead<BO> + ?Sized>(
+    backend: &mut B,
+    values: &mut [u64],
+    read_bits: usize,
+    max_symbols: usize,
+    (lens, counts, symbols): (&[u8], &[u8], &[u8]),
+    read_one: impl Fn(&mut B) -> Result<u64>,
+) -> Result<()> {
+    let mut i = 0;
+    while i < values.len() {
+        if let Ok(idx) = backend.peek_bits(read_bits) {
+            let idx: u64 = idx.upcast();
+            let idx = idx as usize;
+            let count = counts[idx] as usize;
+            let entry = &symbols[idx * max_symbols..][..max_symbols];
+            if count != 0 && values.len() - i >= max_symbols {
+                // copy all the symbols, the ones after count will be
+                // overwritten
+                for (value, symbol) in values[i..i + max_symbols].iter_mut().zip(entry) {
+                    *value = *symbol as u64;
+                }
+                backend.skip_bits_after_table_lookup(lens[idx] as usize)?;
+                i += count;
+                continue;
+            }
+            if count != 0 && values.len() - i >= count {
+                for (value, symbol) in values[i..i + count].iter_mut().zip(entry) {
+                    *value = *symbol as u64;
+                }
+                backend.skip_bits_after_table_lookup(lens[idx] as usize)?;
+                i += count;
+                continue;
+            }
+        }
+        values[i] = read_one(backend)?;
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Skip `n` codes using the multi-symbol decoding tables `lens` and `counts`,
+/// which skip up to as many codes as there are in a window of `read_bits`
+/// bits, falling back to `skip_one` when the window does not contain a
+/// complete code or contains more codes than needed.
+///
+/// # Errors
+/// Forward the errors of `skip_one` and `skip_bits_after_table_lookup`
+#[inline(always)]
+pub(crate) fn skip_multi_table<BO: BitOrder, B: BitRead<BO> + ?Sized>(
+    backend: &mut B,
+    mut n: usize,
+    read_bits: usize,
+    (lens, counts): (&[u8], &[u8]),
+    skip_one: impl Fn(&mut B) -> Result<()>,
+) -> Result<()> {
+    while n > 0 {
+        if let Ok(idx) = backend.peek_bits(read_bits) {
+            let idx: u64 = idx.upcast();
+            let count = counts[idx as usize] as usize;
+            if count != 0 && count <= n {
+                backend.skip_bits_after_table_lookup(lens[idx as usize] as usize)?;
+                n -= count;
+                continue;
+            }
+        }
+        skip_one(backend)?;
+        n -= 1;
+    }
+    Ok(())
+}
+
+/// Write `values`, packing in a word the codewords returned by `lookup`,
+/// which returns the bits and the length of the codeword of a value, if
+/// available, and using `write_one` for the other values.
+///
+/// # Errors
+/// Forward the errors of `write_bits` and `write_one`
+#[inline(always)]
+pub(crate) fn write_packed_m2l<B: BitWrite<M2L> + ?Sized>(
+    backend: &mut B,
+    values: &[u64],
+    lookup: impl Fn(u64) -> Option<(u64, usize)>,
+    write_one: impl Fn(&mut B, u64) -> Result<()>,
+) -> Result<()> {
+    let mut word = 0_u64;
+    let mut word_len = 0;
+    for &value in values {
+        match lookup(value) {
+            Some((bits, len)) => {
+                if word_len + len > 64 {
+                    backend.write_bits(word, word_len)?;
+                    word = 0;
+                    word_len = 0;
+                }
+                // valid left shift of len, even when len is 64
+                word = ((word << (len - 1)) << 1) | bits;
+                word_len += len;
+            }
+            None => {
+                backend.write_bits(word, word_len)?;
+                word = 0;
+                word_len = 0;
+                write_one(backend, value)?;
+            }
+        }
+    }
+    backend.write_bits(word, word_len)
+}
+
+/// Write `values`, packing in a word the codewords returned by `lookup`,
+/// which returns the bits and the length of the codeword of a value, if
+/// available, and using `write_one` for the other values.
+///
+/// # Errors
+/// Forward the errors of `write_bits` and `write_one`
+#[inline(always)]
+pub(crate) fn write_packed_l2m<B: BitWrite<L2M> + ?Sized>(
+    backend: &mut B,
+    values: &[u64],
+    lookup: impl Fn(u64) -> Option<(u64, usize)>,
+    write_one: impl Fn(&mut B, u64) -> Result<()>,
+) -> Result<()> {
+    let mut word = 0_u64;
+    let mut word_len = 0;
+    for &value in values {
+        match lookup(value) {
+            Some((bits, len)) => {
+                if word_len + len > 64 {
+                    backend.write_bits(word, word_len)?;
+                    word = 0;
+                    word_len = 0;
+                }
+                // word_len is smaller than 64, as len is positive
+                word |= bits << word_len;
+                word_len += len;
+            }
+            None => {
+                backend.write_bits(word, word_len)?;
+                word = 0;
+                word_len = 0;
+                write_one(backend, value)?;
+            }
+        }
+    }
+    backend.write_bits(word, word_len)
+}
@@ -0,0 +1,202 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Code
+//! A code selected at runtime, for example from a configuration file, a file
+//! header or a command-line argument. [`Code`] dispatches reads, writes and
+//! lengths to the corresponding traits, always using the decoding tables.
+//! To select a code at compile time, see [`StaticCode`].
+//!
+//! Codes are named as follows:
+//! - `unary`;
+//! - `gamma`;
+//! - `delta`;
+//! - `zeta` followed by k, for example `zeta3`;
+//! - `minimal_binary` followed by the maximum, for example `minimal_binary10`.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let codes = ["unary", "gamma", "delta", "zeta3", "zeta5", "minimal_binary1000"]
+//!     .iter()
+//!     .map(|name| name.parse::<Code>().unwrap())
+//!     .collect::<Vec<_>>();
+//! assert_eq!(codes[3], Code::Zeta { k: 3 });
+//! assert_eq!(codes[5].to_string(), "minimal_binary1000");
+//! assert!("zeta0".parse::<Code>().is_err());
+//! assert!("omega".parse::<Code>().is_err());
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in 0..1000 {
+//!         for code in &codes {
+//!             code.write(&mut writer, value).unwrap();
+//!         }
+//!     }
+//! }
+//!
+//! let mut reader = <UnbufferedBitStreamRead<L2M, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..1000 {
+//!     for code in &codes {
+//!         assert_eq!(code.read(&mut reader).unwrap(), value);
+//!     }
+//! }
+//! assert_eq!(
+//!     reader.get_position(),
+//!     (0..1000)
+//!         .map(|value| codes.iter().map(|code| code.len(value).unwrap()).sum::<usize>())
+//!         .sum::<usize>()
+//! );
+//!
+//! // the parameters of codes built directly are checked when using them
+//! assert!(Code::Zeta { k: 0 }.len(1).is_err());
+//! let mut writer = BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut buffer));
+//! assert!(Code::Zeta { k: 65 }.write(&mut writer, 1).is_err());
+//! ```
+
+use super::zeta::check_zeta_k;
+use super::*;
+use crate::traits::*;
+use anyhow::{anyhow, bail, Result};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// A code selected at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Code {
+    /// Unary code
+    Unary,
+    /// Elias γ code
+    Gamma,
+    /// Elias δ code
+    Delta,
+    /// ζ code with index `k`, which must be in [1, 64]
+    Zeta {
+        /// The index of the code
+        k: u64,
+    },
+    /// Minimal binary code of values smaller than `max`, which must be
+    /// nonzero
+    MinimalBinary {
+        /// The number of values that can be coded
+        max: u64,
+    },
+}
+
+impl Code {
+    /// Read a value coded with this code from `reader`
+    ///
+    /// # Errors
+    /// This function fails if the parameters of the code are not valid or
+    /// if the BitRead backend has problems reading bits, as when the stream
+    /// ended unexpectedly
+    #[inline]
+    pub fn read<BO: BitOrder, B: DeltaRead<BO> + ZetaRead<BO>>(
+        &self,
+        reader: &mut B,
+    ) -> Result<u64> {
+        match *self {
+            Code::Unary => reader.read_unary::<true>(),
+            Code::Gamma => reader.read_gamma::<true>(),
+            Code::Delta => reader.read_delta::<true, true>(),
+            Code::Zeta { k: 3 } => reader.read_zeta3::<true>(),
+            Code::Zeta { k } => reader.read_zeta::<true>(k),
+            Code::MinimalBinary { max } => reader.read_minimal_binary(max),
+        }
+    }
+
+    /// Write `value` with this code on `writer`
+    ///
+    /// # Errors
+    /// This function fails if the parameters of the code are not valid or
+    /// if the BitWrite backend has problems writing bits, as when the stream
+    /// ended unexpectedly
+    #[inline]
+    pub fn write<BO: BitOrder, B: DeltaWrite<BO> + ZetaWrite<BO>>(
+        &self,
+        writer: &mut B,
+        value: u64,
+    ) -> Result<()> {
+        match *self {
+            Code::Unary => writer.write_unary::<true>(value),
+            Code::Gamma => writer.write_gamma::<true>(value),
+            Code::Delta => writer.write_delta::<true, true>(value),
+            Code::Zeta { k: 3 } => writer.write_zeta3::<true>(value),
+            Code::Zeta { k } => writer.write_zeta::<true>(value, k),
+            Code::MinimalBinary { max } => writer.write_minimal_binary(value, max),
+        }
+    }
+
+    /// Returns how long the code for `value` will be
+    ///
+    /// # Errors
+    /// This function fails if the parameters of the code are not valid, as
+    /// the fields of the variants can be set without parsing a name
+    #[inline]
+    pub fn len(&self, value: u64) -> Result<usize> {
+        Ok(match *self {
+            Code::Unary => len_unary::<true>(value),
+            Code::Gamma => len_gamma::<true>(value),
+            Code::Delta => len_delta::<true>(value),
+            Code::Zeta { k } => {
+                check_zeta_k(k)?;
+                len_zeta::<true>(value, k)
+            }
+            Code::MinimalBinary { max } => {
+                if max == 0 {
+                    bail!("The max of a minimal binary value can't be zero.");
+                }
+                len_minimal_binary(value, max)
+            }
+        })
+    }
+}
+
+impl Display for Code {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Code::Unary => write!(f, "unary"),
+            Code::Gamma => write!(f, "gamma"),
+            Code::Delta => write!(f, "delta"),
+            Code::Zeta { k } => write!(f, "zeta{}", k),
+            Code::MinimalBinary { max } => write!(f, "minimal_binary{}", max),
+        }
+    }
+}
+
+impl FromStr for Code {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "unary" => return Ok(Code::Unary),
+            "gamma" => return Ok(Code::Gamma),
+            "delta" => return Ok(Code::Delta),
+            _ => {}
+        }
+        if let Some(k) = s.strip_prefix("zeta") {
+            let k = k
+                .parse::<u64>()
+                .map_err(|_| anyhow!("Invalid index in ζ code name {}.", s))?;
+            check_zeta_k(k)?;
+            return Ok(Code::Zeta { k });
+        }
+        if let Some(max) = s.strip_prefix("minimal_binary") {
+            let max = max
+                .parse::<u64>()
+                .map_err(|_| anyhow!("Invalid maximum in minimal binary code name {}.", s))?;
+            if max == 0 {
+                bail!("The max of a minimal binary value can't be zero.");
+            }
+            return Ok(Code::MinimalBinary { max });
+        }
+        bail!("Unknown code {}.", s);
+    }
+}
@@ -10,9 +10,13 @@
 //! universal coding of x ∈ N+ is obtained by representing x in binary
 //! preceded by a representation of its length in γ.
 
-use super::{delta_tables, fast_floor_log2, len_gamma, GammaRead, GammaWrite};
+use super::{
+    batch, delta_tables, fast_floor_log2_succ, int2nat, len_gamma, nat2int, GammaRead, GammaWrite,
+};
+#[cfg(feature = "two_level_tables")]
+use super::{gamma_tables, two_level};
 use crate::traits::*;
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 #[must_use]
 #[inline]
@@ -26,10 +30,20 @@ pub fn len_delta<const USE_TABLE: bool>(value: u64) -> usize {
             return *idx as usize;
         }
     }
-    let l = fast_floor_log2(value + 1);
+    let l = fast_floor_log2_succ(value);
     l as usize + len_gamma::<USE_TABLE>(l as _)
 }
 
+#[must_use]
+#[inline]
+/// Returns how long the Delta code for the signed `value` will be
+///
+/// `USE_TABLE` enables or disables the use of pre-computed tables
+/// for decoding
+pub fn len_delta_signed<const USE_TABLE: bool>(value: i64) -> usize {
+    len_delta::<USE_TABLE>(int2nat(value))
+}
+
 /// Trait for objects that can read Delta codes
 pub trait DeltaRead<BO: BitOrder>: GammaRead<BO> {
     /// Read a delta code from the stream.
@@ -39,8 +53,62 @@ pub trait DeltaRead<BO: BitOrder>: GammaRead<BO> {
     ///
     /// # Errors
     /// This function fails only if the BitRead backend has problems reading
-    /// bits, as when the stream ended unexpectedly
+    /// bits, as when the stream ended unexpectedly, or if the code does not
+    /// fit in a [`u64`]
     fn read_delta<const USE_TABLE: bool, const USE_GAMMA_TABLE: bool>(&mut self) -> Result<u64>;
+
+    /// Read a delta code from the stream and map it to a signed value
+    /// using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`DeltaRead::read_delta`]
+    #[inline]
+    fn read_delta_signed<const USE_TABLE: bool, const USE_GAMMA_TABLE: bool>(
+        &mut self,
+    ) -> Result<i64> {
+        self.read_delta::<USE_TABLE, USE_GAMMA_TABLE>().map(nat2int)
+    }
+
+    /// Read `values.len()` δ codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`DeltaRead::read_delta`]
+    #[inline]
+    fn read_delta_into<const USE_TABLE: bool, const USE_GAMMA_TABLE: bool>(
+        &mut self,
+        values: &mut [u64],
+    ) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_delta::<USE_TABLE, USE_GAMMA_TABLE>()?;
+        }
+        Ok(())
+    }
+
+    /// Skip a δ code from the stream without decoding it.
+    ///
+    /// `USE_TABLE` enables or disables the use of pre-computed tables
+    /// for decoding
+    ///
+    /// # Errors
+    /// This function fails only if the BitRead backend has problems reading
+    /// or skipping bits, as when the stream ended unexpectedly
+    fn skip_delta<const USE_TABLE: bool, const USE_GAMMA_TABLE: bool>(&mut self) -> Result<()>;
+
+    /// Skip `n` δ codes from the stream without decoding them.
+    ///
+    /// # Errors
+    /// The same as [`DeltaRead::skip_delta`]
+    #[inline]
+    fn skip_delta_n<const USE_TABLE: bool, const USE_GAMMA_TABLE: bool>(
+        &mut self,
+        n: usize,
+    ) -> Result<()> {
+        for _ in 0..n {
+            self.skip_delta::<USE_TABLE, USE_GAMMA_TABLE>()?;
+        }
+        Ok(())
+    }
 }
 
 impl<B: GammaRead<M2L>> DeltaRead<M2L> for B {
@@ -50,9 +118,21 @@ impl<B: GammaRead<M2L>> DeltaRead<M2L> for B {
             if let Some(res) = delta_tables::read_table_m2l(self)? {
                 return Ok(res);
             }
+            #[cfg(feature = "two_level_tables")]
+            if let Some(res) = two_level::read_delta_table_m2l(self, gamma_tables::READ2)? {
+                return Ok(res);
+            }
         }
         default_read_delta::<M2L, _, USE_GAMMA_TABLE>(self)
     }
+
+    #[inline]
+    fn skip_delta<const USE_TABLE: bool, const USE_GAMMA_TABLE: bool>(&mut self) -> Result<()> {
+        if USE_TABLE && delta_tables::skip_table_m2l(self)? {
+            return Ok(());
+        }
+        default_skip_delta::<M2L, _, USE_GAMMA_TABLE>(self)
+    }
 }
 impl<B: GammaRead<L2M>> DeltaRead<L2M> for B {
     #[inline]
@@ -61,9 +141,21 @@ impl<B: GammaRead<L2M>> DeltaRead<L2M> for B {
             if let Some(res) = delta_tables::read_table_l2m(self)? {
                 return Ok(res);
             }
+            #[cfg(feature = "two_level_tables")]
+            if let Some(res) = two_level::read_delta_table_l2m(self, gamma_tables::READ2)? {
+                return Ok(res);
+            }
         }
         default_read_delta::<L2M, _, USE_GAMMA_TABLE>(self)
     }
+
+    #[inline]
+    fn skip_delta<const USE_TABLE: bool, const USE_GAMMA_TABLE: bool>(&mut self) -> Result<()> {
+        if USE_TABLE && delta_tables::skip_table_l2m(self)? {
+            return Ok(());
+        }
+        default_skip_delta::<L2M, _, USE_GAMMA_TABLE>(self)
+    }
 }
 
 #[inline(always)]
@@ -75,8 +167,23 @@ fn default_read_delta<BO: BitOrder, B: GammaRead<BO>, const USE_GAMMA_TABLE: boo
     backend: &mut B,
 ) -> Result<u64> {
     let n_bits = backend.read_gamma::<USE_GAMMA_TABLE>()?;
-    debug_assert!(n_bits <= 64);
-    Ok(backend.read_bits(n_bits as usize)? + (1 << n_bits) - 1)
+    let short_value = backend.read_bits(n_bits as usize)?;
+    match short_value.checked_add(((1_u128 << n_bits) - 1) as u64) {
+        Some(value) => Ok(value),
+        None => bail!("The delta code does not fit in a u64."),
+    }
+}
+
+/// Default impl, so specialized impls can call it
+///
+/// # Errors
+/// Forward `read_gamma` and `skip_bits` errors.
+#[inline(always)]
+fn default_skip_delta<BO: BitOrder, B: GammaRead<BO>, const USE_GAMMA_TABLE: bool>(
+    backend: &mut B,
+) -> Result<()> {
+    let n_bits = backend.read_gamma::<USE_GAMMA_TABLE>()?;
+    backend.skip_bits(n_bits as usize)
 }
 
 /// Trait for objects that can write Delta codes
@@ -93,6 +200,34 @@ pub trait DeltaWrite<BO: BitOrder>: GammaWrite<BO> {
         &mut self,
         value: u64,
     ) -> Result<()>;
+
+    /// Write a signed value on the stream, mapping it to a natural number
+    /// using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`DeltaWrite::write_delta`]
+    #[inline]
+    fn write_delta_signed<const USE_TABLE: bool, const USE_GAMMA_TABLE: bool>(
+        &mut self,
+        value: i64,
+    ) -> Result<()> {
+        self.write_delta::<USE_TABLE, USE_GAMMA_TABLE>(int2nat(value))
+    }
+
+    /// Write all the `values` on the stream as δ codes.
+    ///
+    /// # Errors
+    /// The same as [`DeltaWrite::write_delta`]
+    #[inline]
+    fn write_delta_all<const USE_TABLE: bool, const USE_GAMMA_TABLE: bool>(
+        &mut self,
+        values: &[u64],
+    ) -> Result<()> {
+        for &value in values {
+            self.write_delta::<USE_TABLE, USE_GAMMA_TABLE>(value)?;
+        }
+        Ok(())
+    }
 }
 
 impl<B: GammaWrite<M2L>> DeltaWrite<M2L> for B {
@@ -101,13 +236,33 @@ impl<B: GammaWrite<M2L>> DeltaWrite<M2L> for B {
         &mut self,
         value: u64,
     ) -> Result<()> {
-        if USE_TABLE {
-            if delta_tables::write_table_m2l(self, value)? {
-                return Ok(());
-            }
+        if USE_TABLE && delta_tables::write_table_m2l(self, value)? {
+            return Ok(());
         }
         default_write_delta::<M2L, _, USE_GAMMA_TABLE>(self, value)
     }
+
+    #[inline]
+    fn write_delta_all<const USE_TABLE: bool, const USE_GAMMA_TABLE: bool>(
+        &mut self,
+        values: &[u64],
+    ) -> Result<()> {
+        if USE_TABLE {
+            return batch::write_packed_m2l(
+                self,
+                values,
+                |value| {
+                    let bits = *delta_tables::WRITE_M2L.get(value as usize)?;
+                    Some((bits, delta_tables::WRITE_LEN_M2L[value as usize] as usize))
+                },
+                default_write_delta::<M2L, _, USE_GAMMA_TABLE>,
+            );
+        }
+        for &value in values {
+            default_write_delta::<M2L, _, USE_GAMMA_TABLE>(self, value)?;
+        }
+        Ok(())
+    }
 }
 impl<B: GammaWrite<L2M>> DeltaWrite<L2M> for B {
     #[inline]
@@ -115,13 +270,33 @@ impl<B: GammaWrite<L2M>> DeltaWrite<L2M> for B {
         &mut self,
         value: u64,
     ) -> Result<()> {
-        if USE_TABLE {
-            if delta_tables::write_table_l2m(self, value)? {
-                return Ok(());
-            }
+        if USE_TABLE && delta_tables::write_table_l2m(self, value)? {
+            return Ok(());
         }
         default_write_delta::<L2M, _, USE_GAMMA_TABLE>(self, value)
     }
+
+    #[inline]
+    fn write_delta_all<const USE_TABLE: bool, const USE_GAMMA_TABLE: bool>(
+        &mut self,
+        values: &[u64],
+    ) -> Result<()> {
+        if USE_TABLE {
+            return batch::write_packed_l2m(
+                self,
+                values,
+                |value| {
+                    let bits = *delta_tables::WRITE_L2M.get(value as usize)?;
+                    Some((bits, delta_tables::WRITE_LEN_L2M[value as usize] as usize))
+                },
+                default_write_delta::<L2M, _, USE_GAMMA_TABLE>,
+            );
+        }
+        for &value in values {
+            default_write_delta::<L2M, _, USE_GAMMA_TABLE>(self, value)?;
+        }
+        Ok(())
+    }
 }
 
 /// Default impl, so specialized impls can call it
@@ -131,13 +306,11 @@ impl<B: GammaWrite<L2M>> DeltaWrite<L2M> for B {
 #[inline(always)]
 fn default_write_delta<BO: BitOrder, B: GammaWrite<BO>, const USE_GAMMA_TABLE: bool>(
     backend: &mut B,
-    mut value: u64,
+    value: u64,
 ) -> Result<()> {
-    value += 1;
-    let number_of_bits_to_write = fast_floor_log2(value);
-    debug_assert!(number_of_bits_to_write <= u8::MAX as _);
-    // remove the most significant 1
-    let short_value = value - (1 << number_of_bits_to_write);
+    let number_of_bits_to_write = fast_floor_log2_succ(value);
+    // remove the most significant 1 of value + 1
+    let short_value = (value as u128 + 1 - (1 << number_of_bits_to_write)) as u64;
     // Write the code
     backend.write_gamma::<USE_GAMMA_TABLE>(number_of_bits_to_write as _)?;
     backend.write_bits(short_value, number_of_bits_to_write as usize)?;
@@ -0,0 +1,377 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Runtime tables
+//! [`DynamicTable`] contains decoding and encoding tables for unary, γ, δ or
+//! ζ codes built at runtime, so that their size can be tuned to the data
+//! being read instead of being fixed when compiling (see the
+//! [crate documentation](crate)). Values are read, skipped and written with
+//! a table using the [`DynamicTableRead`] and [`DynamicTableWrite`] traits;
+//! values that are not in the table are handled by the methods of the code.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! // γ codes, decoding up to 14 bits at a time and encoding values up to 1023
+//! let table = DynamicTable::gamma(14, 1023).unwrap();
+//! assert_eq!(table.len(1000), len_gamma::<false>(1000));
+//! // the encoding tables contain at most 2²⁰ + 1 values
+//! assert!(DynamicTable::gamma(12, 1 << 31).is_err());
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in (0..100_000).step_by(97) {
+//!         writer.write_dynamic(&table, value).unwrap();
+//!     }
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+//! for value in (0..100_000).step_by(97) {
+//!     assert_eq!(reader.read_gamma::<true>().unwrap(), value);
+//! }
+//! reader.seek_bit(0).unwrap();
+//! reader.skip_dynamic(&table).unwrap();
+//! assert_eq!(reader.read_dynamic(&table).unwrap(), 97);
+//! ```
+
+use super::tables::{read_code, write_code, TableCode, MAX_READ_BITS, MISSING_VALUE_LEN};
+use super::zeta::check_zeta_k;
+use super::{
+    len_delta, len_gamma, len_unary, len_zeta, DeltaRead, DeltaWrite, ZetaRead, ZetaWrite,
+};
+use crate::traits::*;
+use alloc::vec::Vec;
+use anyhow::{bail, Result};
+
+/// The maximum value writable using the tables, which bounds the memory
+/// used by the encoding tables (17 bytes per value)
+const MAX_WRITE_MAX: u64 = 1 << 20;
+
+/// Decoding and encoding tables for a code, built at runtime
+#[derive(Debug, Clone)]
+pub struct DynamicTable {
+    /// The code of the tables
+    code: TableCode,
+    /// How many bits are needed to read the tables
+    read_bits: usize,
+    /// The values of the codes at the start of each window of `read_bits`
+    /// bits in M2L order
+    read_m2l: Vec<u16>,
+    /// The lengths of the codes in `read_m2l`, or [`MISSING_VALUE_LEN`]
+    read_len_m2l: Vec<u8>,
+    /// The values of the codes at the start of each window of `read_bits`
+    /// bits in L2M order
+    read_l2m: Vec<u16>,
+    /// The lengths of the codes in `read_l2m`, or [`MISSING_VALUE_LEN`]
+    read_len_l2m: Vec<u8>,
+    /// The codewords of the values up to the maximum value in M2L order
+    write_m2l: Vec<u64>,
+    /// The codewords of the values up to the maximum value in L2M order
+    write_l2m: Vec<u64>,
+    /// The lengths of the codewords, which do not depend on the bit order
+    write_len: Vec<u8>,
+}
+
+impl DynamicTable {
+    /// Build the tables of unary codes decoding `read_bits` bits at a time
+    /// and encoding the values up to `write_max`.
+    ///
+    /// # Errors
+    /// This function fails if `read_bits` is larger than 16, if `write_max`
+    /// is larger than 2²⁰ or if the code of `write_max` is longer than 64 bits
+    pub fn unary(read_bits: usize, write_max: u64) -> Result<Self> {
+        Self::new(TableCode::Unary, read_bits, write_max)
+    }
+
+    /// Build the tables of γ codes decoding `read_bits` bits at a time
+    /// and encoding the values up to `write_max`.
+    ///
+    /// # Errors
+    /// This function fails if `read_bits` is larger than 16, if `write_max`
+    /// is larger than 2²⁰ or if the code of `write_max` is longer than 64 bits
+    pub fn gamma(read_bits: usize, write_max: u64) -> Result<Self> {
+        Self::new(TableCode::Gamma, read_bits, write_max)
+    }
+
+    /// Build the tables of δ codes decoding `read_bits` bits at a time
+    /// and encoding the values up to `write_max`.
+    ///
+    /// # Errors
+    /// This function fails if `read_bits` is larger than 16, if `write_max`
+    /// is larger than 2²⁰ or if the code of `write_max` is longer than 64 bits
+    pub fn delta(read_bits: usize, write_max: u64) -> Result<Self> {
+        Self::new(TableCode::Delta, read_bits, write_max)
+    }
+
+    /// Build the tables of ζ codes with index `k` decoding `read_bits` bits
+    /// at a time and encoding the values up to `write_max`.
+    ///
+    /// # Errors
+    /// This function fails if `k` is not in [1, 64], if `read_bits` is larger
+    /// than 16, if `write_max` is larger than 2²⁰ or if the code of
+    /// `write_max` is longer than 64 bits
+    pub fn zeta(k: u64, read_bits: usize, write_max: u64) -> Result<Self> {
+        check_zeta_k(k)?;
+        Self::new(TableCode::Zeta(k), read_bits, write_max)
+    }
+
+    fn new(code: TableCode, read_bits: usize, write_max: u64) -> Result<Self> {
+        if read_bits > MAX_READ_BITS {
+            bail!(
+                "Decoding tables can use at most {} bits, not {}.",
+                MAX_READ_BITS,
+                read_bits
+            );
+        }
+        if write_max > MAX_WRITE_MAX {
+            bail!(
+                "Encoding tables can contain values up to {}, not {}.",
+                MAX_WRITE_MAX,
+                write_max
+            );
+        }
+        // codes are non-decreasing in length, so it is enough to check
+        // the last one (unary codes are checked first to avoid overflows)
+        if matches!(code, TableCode::Unary) && write_max >= 64 || default_len(code, write_max) > 64
+        {
+            bail!("The code of {} is longer than 64 bits.", write_max);
+        }
+
+        let read_len = 1 << read_bits;
+        let mut read_m2l = Vec::with_capacity(read_len);
+        let mut read_len_m2l = Vec::with_capacity(read_len);
+        let mut read_l2m = Vec::with_capacity(read_len);
+        let mut read_len_l2m = Vec::with_capacity(read_len);
+        for window in 0..read_len as u64 {
+            for (m2l, values, lens) in [
+                (true, &mut read_m2l, &mut read_len_m2l),
+                (false, &mut read_l2m, &mut read_len_l2m),
+            ] {
+                let (value, len) = read_code(code, window, read_bits, m2l)
+                    .map_or((0, MISSING_VALUE_LEN), |(value, len)| {
+                        (value as u16, len as u8)
+                    });
+                values.push(value);
+                lens.push(len);
+            }
+        }
+
+        let write_len = write_max as usize + 1;
+        let mut write_m2l = Vec::with_capacity(write_len);
+        let mut write_l2m = Vec::with_capacity(write_len);
+        let mut write_lens = Vec::with_capacity(write_len);
+        for value in 0..=write_max {
+            let (word, len) = write_code(code, value, true);
+            write_m2l.push(word as u64);
+            write_l2m.push(write_code(code, value, false).0 as u64);
+            write_lens.push(len as u8);
+        }
+
+        Ok(Self {
+            code,
+            read_bits,
+            read_m2l,
+            read_len_m2l,
+            read_l2m,
+            read_len_l2m,
+            write_m2l,
+            write_l2m,
+            write_len: write_lens,
+        })
+    }
+
+    /// Return how many bits are needed to read the tables
+    #[must_use]
+    #[inline]
+    pub fn read_bits(&self) -> usize {
+        self.read_bits
+    }
+
+    /// Return the maximum value writable using the tables
+    #[must_use]
+    #[inline]
+    pub fn write_max(&self) -> u64 {
+        self.write_len.len() as u64 - 1
+    }
+
+    /// Return how long the code for `value` will be
+    #[must_use]
+    #[inline]
+    pub fn len(&self, value: u64) -> usize {
+        match self.write_len.get(value as usize) {
+            Some(len) => *len as usize,
+            None => default_len(self.code, value),
+        }
+    }
+
+    /// Read a code with the tables, falling back to the default
+    /// implementation if the code is not in the tables
+    #[inline(always)]
+    fn read<BO: BitOrder, B: DeltaRead<BO> + ZetaRead<BO>>(
+        &self,
+        backend: &mut B,
+        values: &[u16],
+        lens: &[u8],
+    ) -> Result<u64> {
+        if let Ok(idx) = backend.peek_bits(self.read_bits) {
+            let idx: u64 = idx.upcast();
+            let len = lens[idx as usize];
+            if len != MISSING_VALUE_LEN {
+                backend.skip_bits_after_table_lookup(len as usize)?;
+                return Ok(values[idx as usize] as u64);
+            }
+        }
+        match self.code {
+            TableCode::Unary => backend.read_unary::<false>(),
+            TableCode::Gamma => backend.read_gamma::<false>(),
+            TableCode::Delta => backend.read_delta::<false, true>(),
+            TableCode::Zeta(k) => backend.read_zeta::<false>(k),
+        }
+    }
+
+    /// Skip a code with the tables, falling back to the default
+    /// implementation if the code is not in the tables
+    #[inline(always)]
+    fn skip<BO: BitOrder, B: DeltaRead<BO> + ZetaRead<BO>>(
+        &self,
+        backend: &mut B,
+        lens: &[u8],
+    ) -> Result<()> {
+        if let Ok(idx) = backend.peek_bits(self.read_bits) {
+            let idx: u64 = idx.upcast();
+            let len = lens[idx as usize];
+            if len != MISSING_VALUE_LEN {
+                return backend.skip_bits_after_table_lookup(len as usize);
+            }
+        }
+        match self.code {
+            TableCode::Unary => backend.skip_unary::<false>(),
+            TableCode::Gamma => backend.skip_gamma::<false>(),
+            TableCode::Delta => backend.skip_delta::<false, true>(),
+            TableCode::Zeta(k) => backend.skip_zeta::<false>(k),
+        }
+    }
+
+    /// Write a value with the tables, falling back to the default
+    /// implementation if the value is not in the tables
+    #[inline(always)]
+    fn write<BO: BitOrder, B: DeltaWrite<BO> + ZetaWrite<BO>>(
+        &self,
+        backend: &mut B,
+        value: u64,
+        words: &[u64],
+    ) -> Result<()> {
+        if let Some(word) = words.get(value as usize) {
+            return backend.write_bits(*word, self.write_len[value as usize] as usize);
+        }
+        match self.code {
+            TableCode::Unary => backend.write_unary::<false>(value),
+            TableCode::Gamma => backend.write_gamma::<false>(value),
+            TableCode::Delta => backend.write_delta::<false, true>(value),
+            TableCode::Zeta(k) => backend.write_zeta::<false>(value, k),
+        }
+    }
+}
+
+/// Return how long the code for `value` will be without using tables
+#[inline(always)]
+fn default_len(code: TableCode, value: u64) -> usize {
+    match code {
+        TableCode::Unary => len_unary::<false>(value),
+        TableCode::Gamma => len_gamma::<false>(value),
+        TableCode::Delta => len_delta::<false>(value),
+        TableCode::Zeta(k) => len_zeta::<false>(value, k),
+    }
+}
+
+/// Trait for objects that can read codes using a [`DynamicTable`]
+pub trait DynamicTableRead<BO: BitOrder>: DeltaRead<BO> + ZetaRead<BO> {
+    /// Read a code from the stream using `table`.
+    ///
+    /// # Errors
+    /// The same as the read method of the code of `table`
+    fn read_dynamic(&mut self, table: &DynamicTable) -> Result<u64>;
+
+    /// Read `values.len()` codes from the stream into `values` using `table`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`DynamicTableRead::read_dynamic`]
+    #[inline]
+    fn read_dynamic_into(&mut self, table: &DynamicTable, values: &mut [u64]) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_dynamic(table)?;
+        }
+        Ok(())
+    }
+
+    /// Skip a code from the stream without decoding it using `table`.
+    ///
+    /// # Errors
+    /// The same as the skip method of the code of `table`
+    fn skip_dynamic(&mut self, table: &DynamicTable) -> Result<()>;
+}
+
+impl<B: DeltaRead<M2L> + ZetaRead<M2L>> DynamicTableRead<M2L> for B {
+    #[inline]
+    fn read_dynamic(&mut self, table: &DynamicTable) -> Result<u64> {
+        table.read(self, &table.read_m2l, &table.read_len_m2l)
+    }
+
+    #[inline]
+    fn skip_dynamic(&mut self, table: &DynamicTable) -> Result<()> {
+        table.skip(self, &table.read_len_m2l)
+    }
+}
+impl<B: DeltaRead<L2M> + ZetaRead<L2M>> DynamicTableRead<L2M> for B {
+    #[inline]
+    fn read_dynamic(&mut self, table: &DynamicTable) -> Result<u64> {
+        table.read(self, &table.read_l2m, &table.read_len_l2m)
+    }
+
+    #[inline]
+    fn skip_dynamic(&mut self, table: &DynamicTable) -> Result<()> {
+        table.skip(self, &table.read_len_l2m)
+    }
+}
+
+/// Trait for objects that can write codes using a [`DynamicTable`]
+pub trait DynamicTableWrite<BO: BitOrder>: DeltaWrite<BO> + ZetaWrite<BO> {
+    /// Write a value on the stream using `table`.
+    ///
+    /// # Errors
+    /// The same as the write method of the code of `table`
+    fn write_dynamic(&mut self, table: &DynamicTable, value: u64) -> Result<()>;
+
+    /// Write all the `values` on the stream using `table`.
+    ///
+    /// # Errors
+    /// The same as [`DynamicTableWrite::write_dynamic`]
+    #[inline]
+    fn write_dynamic_all(&mut self, table: &DynamicTable, values: &[u64]) -> Result<()> {
+        for &value in values {
+            self.write_dynamic(table, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: DeltaWrite<M2L> + ZetaWrite<M2L>> DynamicTableWrite<M2L> for B {
+    #[inline]
+    fn write_dynamic(&mut self, table: &DynamicTable, value: u64) -> Result<()> {
+        table.write(self, value, &table.write_m2l)
+    }
+}
+impl<B: DeltaWrite<L2M> + ZetaWrite<L2M>> DynamicTableWrite<L2M> for B {
+    #[inline]
+    fn write_dynamic(&mut self, table: &DynamicTable, value: u64) -> Result<()> {
+        table.write(self, value, &table.write_l2m)
+    }
+}
@@ -0,0 +1,194 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Even–Rodeh
+//! Even–Rodeh universal coding of x ∈ N: values smaller than four are written
+//! in binary on three bits. Otherwise, x is written in binary followed by a
+//! zero, and preceded by the same representation of its length in bits,
+//! recursively, until the length is three, which is not written.
+//!
+//! Since every group but the first one starts with a one, the decoder reads
+//! a bit after each group and stops as soon as it finds a zero.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in 0..1000 {
+//!         writer.write_even_rodeh(value).unwrap();
+//!     }
+//!     writer.write_even_rodeh(u64::MAX).unwrap();
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<L2M, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut unbuffered = <UnbufferedBitStreamRead<L2M, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_even_rodeh().unwrap(), value);
+//!     assert_eq!(unbuffered.read_even_rodeh().unwrap(), value);
+//! }
+//! assert_eq!(unbuffered.read_even_rodeh().unwrap(), u64::MAX);
+//! assert_eq!(
+//!     unbuffered.get_position(),
+//!     (0..1000).map(len_even_rodeh).sum::<usize>() + len_even_rodeh(u64::MAX)
+//! );
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     writer.write_even_rodeh(3).unwrap();
+//!     writer.write_even_rodeh(8).unwrap();
+//! }
+//! let mut reader = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+//! assert_eq!(reader.read_bits(11).unwrap(), 0b011_100_1000_0);
+//! ```
+
+use super::{fast_floor_log2, int2nat, nat2int};
+use crate::traits::*;
+use anyhow::{bail, Result};
+
+/// Returns how long the Even–Rodeh code for `value` will be
+#[must_use]
+#[inline]
+pub fn len_even_rodeh(value: u64) -> usize {
+    if value < 4 {
+        return 3;
+    }
+    let mut res = 1;
+    let mut n = value;
+    while n > 7 {
+        let l = fast_floor_log2(n) as u64 + 1;
+        res += l as usize;
+        n = l;
+    }
+    res + 3
+}
+
+/// Returns how long the Even–Rodeh code for the signed `value` will be
+#[must_use]
+#[inline]
+pub fn len_even_rodeh_signed(value: i64) -> usize {
+    len_even_rodeh(int2nat(value))
+}
+
+/// Trait for objects that can read Even–Rodeh codes
+pub trait EvenRodehRead<BO: BitOrder>: BitRead<BO> {
+    /// Read an Even–Rodeh code from the stream.
+    ///
+    /// # Errors
+    /// This function fails only if the BitRead backend has problems reading
+    /// bits, as when the stream ended unexpectedly, or if the code does not
+    /// fit in a [`u64`]
+    #[inline]
+    fn read_even_rodeh(&mut self) -> Result<u64> {
+        let mut n = self.read_bits(3)?;
+        if n < 4 {
+            return Ok(n);
+        }
+        while self.read_bits(1)? != 0 {
+            if n > 64 {
+                bail!("The Even–Rodeh code does not fit in a u64.");
+            }
+            let l = n - 1;
+            n = (1 << l) | self.read_bits(l as usize)?;
+        }
+        Ok(n)
+    }
+
+    /// Read an Even–Rodeh code from the stream and map it to a signed value
+    /// using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`EvenRodehRead::read_even_rodeh`]
+    #[inline]
+    fn read_even_rodeh_signed(&mut self) -> Result<i64> {
+        self.read_even_rodeh().map(nat2int)
+    }
+
+    /// Read `values.len()` Even–Rodeh codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`EvenRodehRead::read_even_rodeh`]
+    #[inline]
+    fn read_even_rodeh_into(&mut self, values: &mut [u64]) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_even_rodeh()?;
+        }
+        Ok(())
+    }
+}
+
+/// Trait for objects that can write Even–Rodeh codes
+pub trait EvenRodehWrite<BO: BitOrder>: BitWrite<BO> {
+    /// Write a value on the stream
+    ///
+    /// # Errors
+    /// This function fails only if the BitWrite backend has problems writing
+    /// bits, as when the stream ended unexpectedly
+    #[inline]
+    fn write_even_rodeh(&mut self, value: u64) -> Result<()> {
+        if value < 4 {
+            return self.write_bits(value, 3);
+        }
+        write_even_rodeh_groups(self, value)?;
+        self.write_bits(0, 1)
+    }
+
+    /// Write a signed value on the stream as an Even–Rodeh code, mapping it
+    /// to a natural number using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`EvenRodehWrite::write_even_rodeh`]
+    #[inline]
+    fn write_even_rodeh_signed(&mut self, value: i64) -> Result<()> {
+        self.write_even_rodeh(int2nat(value))
+    }
+
+    /// Write all the `values` on the stream as Even–Rodeh codes.
+    ///
+    /// # Errors
+    /// The same as [`EvenRodehWrite::write_even_rodeh`]
+    #[inline]
+    fn write_even_rodeh_all(&mut self, values: &[u64]) -> Result<()> {
+        for &value in values {
+            self.write_even_rodeh(value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Write the groups of an Even–Rodeh code for `n`, which must be at least
+/// four, starting from the innermost one, that is written on three bits.
+/// The following groups are written as a one followed by the binary
+/// representation without the most significant bit, so that the first bit of
+/// each group is the same in both bit orders. The recursion depth is at most
+/// four for a [`u64`].
+///
+/// # Errors
+/// Forward `write_bits` errors.
+#[inline(always)]
+fn write_even_rodeh_groups<BO: BitOrder, B: BitWrite<BO> + ?Sized>(
+    backend: &mut B,
+    n: u64,
+) -> Result<()> {
+    debug_assert!(n >= 4);
+    if n < 8 {
+        return backend.write_bits(n, 3);
+    }
+    let l = fast_floor_log2(n);
+    write_even_rodeh_groups(backend, l as u64 + 1)?;
+    backend.write_bits(1, 1)?;
+    // remove the most significant 1
+    backend.write_bits(n - (1 << l), l as usize)
+}
+
+impl<BO: BitOrder, B: BitRead<BO>> EvenRodehRead<BO> for B {}
+impl<BO: BitOrder, B: BitWrite<BO>> EvenRodehWrite<BO> for B {}
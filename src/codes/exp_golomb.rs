@@ -0,0 +1,174 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Exponential Golomb
+//! The exponential Golomb code of order k of x ∈ N is obtained by writing
+//! floor(x / 2^k) in γ, followed by the lowest k bits of x. Order 0
+//! coincides with γ, and it is the `ue(v)` code used by many video formats.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! // order 0 is bit-for-bit identical to gamma
+//! let mut gamma: Vec<u64> = vec![];
+//! let mut exp_golomb: Vec<u64> = vec![];
+//! {
+//!     let mut gamma = BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut gamma));
+//!     let mut exp_golomb =
+//!         BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut exp_golomb));
+//!     for value in 0..1000 {
+//!         gamma.write_gamma::<true>(value).unwrap();
+//!         exp_golomb.write_exp_golomb(value, 0).unwrap();
+//!     }
+//! }
+//! assert_eq!(gamma, exp_golomb);
+//!
+//! let mut reader = <BufferedBitStreamRead<L2M, u128, _>>::new(MemWordRead::new(&gamma));
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_exp_golomb(0).unwrap(), value);
+//! }
+//!
+//! // higher orders
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in 0..1000 {
+//!         writer.write_exp_golomb(value, 4).unwrap();
+//!     }
+//! }
+//!
+//! let mut reader = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_exp_golomb(4).unwrap(), value);
+//! }
+//! assert_eq!(
+//!     reader.get_position(),
+//!     (0..1000).map(|value| len_exp_golomb(value, 4)).sum::<usize>()
+//! );
+//! ```
+
+use super::{fast_floor_log2_succ, int2nat, len_gamma, nat2int};
+use crate::traits::*;
+use anyhow::{bail, Result};
+
+/// Returns how long the exponential Golomb code of order `k` for `value`
+/// will be
+#[must_use]
+#[inline]
+pub fn len_exp_golomb(value: u64, k: u64) -> usize {
+    debug_assert!(k < 64);
+    len_gamma::<false>(value >> k) + k as usize
+}
+
+/// Returns how long the exponential Golomb code of order `k` for the signed
+/// `value` will be
+#[must_use]
+#[inline]
+pub fn len_exp_golomb_signed(value: i64, k: u64) -> usize {
+    len_exp_golomb(int2nat(value), k)
+}
+
+/// Trait for objects that can read exponential Golomb codes
+pub trait ExpGolombRead<BO: BitOrder>: BitRead<BO> {
+    /// Read an exponential Golomb code of order `k` from the stream.
+    ///
+    /// # Errors
+    /// This function fails if `k` is not smaller than 64, if the code does
+    /// not fit in a [`u64`], or if the BitRead backend has problems reading
+    /// bits, as when the stream ended unexpectedly
+    #[inline]
+    fn read_exp_golomb(&mut self, k: u64) -> Result<u64> {
+        if k >= 64 {
+            bail!(
+                "The order of an exponential Golomb code has to be in [0, 63] and {} is not.",
+                k
+            );
+        }
+        let len = self.read_unary::<false>()?;
+        let high = self.read_bits(len as usize)? as u128 + (1 << len) - 1;
+        if high >> (64 - k) != 0 {
+            bail!("The exponential Golomb code does not fit in a u64.");
+        }
+        Ok(((high as u64) << k) | self.read_bits(k as usize)?)
+    }
+
+    /// Read an exponential Golomb code of order `k` from the stream and map
+    /// it to a signed value using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`ExpGolombRead::read_exp_golomb`]
+    #[inline]
+    fn read_exp_golomb_signed(&mut self, k: u64) -> Result<i64> {
+        self.read_exp_golomb(k).map(nat2int)
+    }
+
+    /// Read `values.len()` exponential Golomb codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`ExpGolombRead::read_exp_golomb`]
+    #[inline]
+    fn read_exp_golomb_into(&mut self, values: &mut [u64], k: u64) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_exp_golomb(k)?;
+        }
+        Ok(())
+    }
+}
+
+/// Trait for objects that can write exponential Golomb codes
+pub trait ExpGolombWrite<BO: BitOrder>: BitWrite<BO> {
+    /// Write a value on the stream as an exponential Golomb code of order `k`.
+    ///
+    /// # Errors
+    /// This function fails if `k` is not smaller than 64 or if the BitWrite
+    /// backend has problems writing bits, as when the stream ended
+    /// unexpectedly
+    #[inline]
+    fn write_exp_golomb(&mut self, value: u64, k: u64) -> Result<()> {
+        if k >= 64 {
+            bail!(
+                "The order of an exponential Golomb code has to be in [0, 63] and {} is not.",
+                k
+            );
+        }
+        let high = value >> k;
+        let number_of_bits_to_write = fast_floor_log2_succ(high);
+        // remove the most significant 1 of high + 1
+        let short_value = (high as u128 + 1 - (1 << number_of_bits_to_write)) as u64;
+        self.write_unary::<false>(number_of_bits_to_write as _)?;
+        self.write_bits(short_value, number_of_bits_to_write as usize)?;
+        self.write_bits(value & ((1 << k) - 1), k as usize)
+    }
+
+    /// Write a signed value on the stream as an exponential Golomb code of
+    /// order `k`, mapping it to a natural number using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`ExpGolombWrite::write_exp_golomb`]
+    #[inline]
+    fn write_exp_golomb_signed(&mut self, value: i64, k: u64) -> Result<()> {
+        self.write_exp_golomb(int2nat(value), k)
+    }
+
+    /// Write all the `values` on the stream as exponential Golomb codes.
+    ///
+    /// # Errors
+    /// The same as [`ExpGolombWrite::write_exp_golomb`]
+    #[inline]
+    fn write_exp_golomb_all(&mut self, values: &[u64], k: u64) -> Result<()> {
+        for &value in values {
+            self.write_exp_golomb(value, k)?;
+        }
+        Ok(())
+    }
+}
+
+impl<BO: BitOrder, B: BitRead<BO>> ExpGolombRead<BO> for B {}
+impl<BO: BitOrder, B: BitWrite<BO>> ExpGolombWrite<BO> for B {}
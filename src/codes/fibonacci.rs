@@ -0,0 +1,276 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Fibonacci
+//! The Fibonacci code of x ∈ N+ is obtained by writing the Zeckendorf
+//! representation of x, that is, x as a sum of non-consecutive Fibonacci
+//! numbers, as a sequence of bits starting from F(2) = 1, followed by a one.
+//! Since the representation has no consecutive ones, every codeword ends with
+//! the only occurrence of `11`, which makes the code robust to bit errors and
+//! self-synchronizing. To code x ∈ N, as the other codes of this crate, we
+//! write the code of x + 1.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in 0..1000 {
+//!         writer.write_fibonacci::<true>(value).unwrap();
+//!     }
+//!     writer.write_fibonacci::<false>(u64::MAX).unwrap();
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<L2M, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut unbuffered = <UnbufferedBitStreamRead<L2M, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_fibonacci::<true>().unwrap(), value);
+//!     assert_eq!(unbuffered.read_fibonacci::<false>().unwrap(), value);
+//! }
+//! assert_eq!(unbuffered.read_fibonacci::<false>().unwrap(), u64::MAX);
+//! assert_eq!(
+//!     unbuffered.get_position(),
+//!     (0..1000).map(len_fibonacci::<false>).sum::<usize>() + len_fibonacci::<true>(u64::MAX)
+//! );
+//! ```
+
+use super::{fibonacci_tables, int2nat, nat2int};
+use crate::traits::*;
+use anyhow::{bail, Result};
+
+/// Number of Fibonacci numbers, starting from F(2), that fit in a [`u64`]
+const FIBONACCI_LEN: usize = 92;
+
+/// The Fibonacci numbers F(2), F(3), ..., F(93)
+const FIBONACCI: [u64; FIBONACCI_LEN] = {
+    let mut fib = [0; FIBONACCI_LEN];
+    fib[0] = 1;
+    fib[1] = 2;
+    let mut i = 2;
+    while i < FIBONACCI_LEN {
+        fib[i] = fib[i - 1] + fib[i - 2];
+        i += 1;
+    }
+    fib
+};
+
+/// Return the index of the largest Fibonacci number not greater than `n`,
+/// which must be nonzero and at most 2^64.
+#[inline(always)]
+fn largest_fibonacci_index(n: u128) -> usize {
+    debug_assert!(n > 0);
+    FIBONACCI.partition_point(|&f| f as u128 <= n) - 1
+}
+
+/// Returns how long the Fibonacci code for `value` will be
+///
+/// `USE_TABLE` enables or disables the use of pre-computed tables
+/// for decoding
+#[must_use]
+#[inline]
+pub fn len_fibonacci<const USE_TABLE: bool>(value: u64) -> usize {
+    if USE_TABLE {
+        if let Some(idx) = fibonacci_tables::LEN.get(value as usize) {
+            return *idx as usize;
+        }
+    }
+    largest_fibonacci_index(value as u128 + 1) + 2
+}
+
+/// Returns how long the Fibonacci code for the signed `value` will be
+///
+/// `USE_TABLE` enables or disables the use of pre-computed tables
+/// for decoding
+#[must_use]
+#[inline]
+pub fn len_fibonacci_signed<const USE_TABLE: bool>(value: i64) -> usize {
+    len_fibonacci::<USE_TABLE>(int2nat(value))
+}
+
+/// Trait for objects that can read Fibonacci codes
+pub trait FibonacciRead<BO: BitOrder>: BitRead<BO> {
+    /// Read a Fibonacci code from the stream.
+    ///
+    /// `USE_TABLE` enables or disables the use of pre-computed tables
+    /// for decoding
+    ///
+    /// # Errors
+    /// This function fails only if the BitRead backend has problems reading
+    /// bits, as when the stream ended unexpectedly, or if the code does not
+    /// fit in a [`u64`]
+    fn read_fibonacci<const USE_TABLE: bool>(&mut self) -> Result<u64>;
+
+    /// Read a Fibonacci code from the stream and map it to a signed value
+    /// using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`FibonacciRead::read_fibonacci`]
+    #[inline]
+    fn read_fibonacci_signed<const USE_TABLE: bool>(&mut self) -> Result<i64> {
+        self.read_fibonacci::<USE_TABLE>().map(nat2int)
+    }
+
+    /// Read `values.len()` Fibonacci codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`FibonacciRead::read_fibonacci`]
+    #[inline]
+    fn read_fibonacci_into<const USE_TABLE: bool>(&mut self, values: &mut [u64]) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_fibonacci::<USE_TABLE>()?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: BitRead<M2L>> FibonacciRead<M2L> for B {
+    #[inline]
+    fn read_fibonacci<const USE_TABLE: bool>(&mut self) -> Result<u64> {
+        if USE_TABLE {
+            if let Some(res) = fibonacci_tables::read_table_m2l(self)? {
+                return Ok(res);
+            }
+        }
+        default_read_fibonacci(self)
+    }
+}
+impl<B: BitRead<L2M>> FibonacciRead<L2M> for B {
+    #[inline]
+    fn read_fibonacci<const USE_TABLE: bool>(&mut self) -> Result<u64> {
+        if USE_TABLE {
+            if let Some(res) = fibonacci_tables::read_table_l2m(self)? {
+                return Ok(res);
+            }
+        }
+        default_read_fibonacci(self)
+    }
+}
+
+/// Common part of the M2L and L2M impl
+///
+/// # Errors
+/// Forward `read_bits` errors.
+#[inline(always)]
+fn default_read_fibonacci<BO: BitOrder, B: BitRead<BO>>(backend: &mut B) -> Result<u64> {
+    // the sum of all the Fibonacci numbers in the table does not overflow
+    let mut value: u128 = 0;
+    let mut prev_bit = 0;
+    for fib in FIBONACCI.iter().copied() {
+        let bit = backend.read_bits(1)?;
+        if bit & prev_bit != 0 {
+            return fibonacci_value(value);
+        }
+        if bit != 0 {
+            value += fib as u128;
+        }
+        prev_bit = bit;
+    }
+    // the last Fibonacci number must be followed by the terminating one
+    if backend.read_bits(1)? & prev_bit != 0 {
+        return fibonacci_value(value);
+    }
+    bail!("The Fibonacci code does not fit in a u64.");
+}
+
+/// Return the value whose Zeckendorf representation sums to `n`, which is
+/// nonzero, or an error if it does not fit in a [`u64`].
+#[inline(always)]
+fn fibonacci_value(n: u128) -> Result<u64> {
+    match u64::try_from(n - 1) {
+        Ok(value) => Ok(value),
+        Err(_) => bail!("The Fibonacci code does not fit in a u64."),
+    }
+}
+
+/// Trait for objects that can write Fibonacci codes
+pub trait FibonacciWrite<BO: BitOrder>: BitWrite<BO> {
+    /// Write a value on the stream
+    ///
+    /// `USE_TABLE` enables or disables the use of pre-computed tables
+    /// for decoding
+    ///
+    /// # Errors
+    /// This function fails only if the BitWrite backend has problems writing
+    /// bits, as when the stream ended unexpectedly
+    fn write_fibonacci<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()>;
+
+    /// Write a signed value on the stream, mapping it to a natural number
+    /// using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`FibonacciWrite::write_fibonacci`]
+    #[inline]
+    fn write_fibonacci_signed<const USE_TABLE: bool>(&mut self, value: i64) -> Result<()> {
+        self.write_fibonacci::<USE_TABLE>(int2nat(value))
+    }
+
+    /// Write all the `values` on the stream as Fibonacci codes.
+    ///
+    /// # Errors
+    /// The same as [`FibonacciWrite::write_fibonacci`]
+    #[inline]
+    fn write_fibonacci_all<const USE_TABLE: bool>(&mut self, values: &[u64]) -> Result<()> {
+        for &value in values {
+            self.write_fibonacci::<USE_TABLE>(value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: BitWrite<M2L>> FibonacciWrite<M2L> for B {
+    #[inline]
+    fn write_fibonacci<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
+        if USE_TABLE && fibonacci_tables::write_table_m2l(self, value)? {
+            return Ok(());
+        }
+        let (code, len) = fibonacci_code(value);
+        // the first bit of the code must be the most significant one
+        let code = code.reverse_bits() >> (128 - len);
+        if len > 64 {
+            self.write_bits((code >> 64) as u64, len - 64)?;
+            self.write_bits(code as u64, 64)
+        } else {
+            self.write_bits(code as u64, len)
+        }
+    }
+}
+impl<B: BitWrite<L2M>> FibonacciWrite<L2M> for B {
+    #[inline]
+    fn write_fibonacci<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
+        if USE_TABLE && fibonacci_tables::write_table_l2m(self, value)? {
+            return Ok(());
+        }
+        let (code, len) = fibonacci_code(value);
+        if len > 64 {
+            self.write_bits(code as u64, 64)?;
+            self.write_bits((code >> 64) as u64, len - 64)
+        } else {
+            self.write_bits(code as u64, len)
+        }
+    }
+}
+
+/// Compute the Fibonacci code of `value` and its length. The i-th bit of the
+/// code, starting from the least significant one, is the i-th bit to write.
+#[inline(always)]
+fn fibonacci_code(value: u64) -> (u128, usize) {
+    let mut n = value as u128 + 1;
+    let len = largest_fibonacci_index(n) + 2;
+    // the terminating one
+    let mut code = 1_u128 << (len - 1);
+    for i in (0..len - 1).rev() {
+        if FIBONACCI[i] as u128 <= n {
+            code |= 1 << i;
+            n -= FIBONACCI[i] as u128;
+        }
+    }
+    (code, len)
+}
@@ -0,0 +1,777 @@
+// THIS FILE HAS BEEN GENERATED WITH THE SCRIPT code_tables_generator.py
+// ~~~~~~~~~~~~~~~~~~~ DO NOT MODIFY ~~~~~~~~~~~~~~~~~~~~~~
+// Pre-computed constants used to speedup the reading and writing of fibonacci codes
+#![allow(clippy::unnecessary_cast)]
+use crate::traits::{BitRead, BitWrite, UpcastableInto, L2M, M2L};
+use anyhow::Result;
+/// How many bits are needed to read the tables in this
+pub const READ_BITS: usize = 12;
+/// The len we assign to a code that cannot be decoded through the table
+pub const MISSING_VALUE_LEN: u8 = 255;
+/// Maximum value writable using the table(s)
+pub const WRITE_MAX: u64 = 255;
+
+#[inline(always)]
+/// Autogenerated function to lookup a read table, if the result is `Some` the
+/// value was found, otherwise we were not able to decode the value and you
+/// should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn read_table_l2m<B: BitRead<L2M>>(backend: &mut B) -> Result<Option<u64>> {
+    if let Ok(idx) = backend.peek_bits(READ_BITS) {
+        let idx: u64 = idx.upcast();
+        let len = READ_LEN_L2M[idx as usize];
+        if len != MISSING_VALUE_LEN {
+            backend.skip_bits_after_table_lookup(len as usize)?;
+            return Ok(Some(READ_L2M[idx as usize] as u64));
+        }
+    }
+    Ok(None)
+}
+
+#[inline(always)]
+/// Autogenerated function to skip a code using the read table, if the result
+/// is `true` the code was skipped, otherwise we were not able to find its
+/// length and you should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn skip_table_l2m<B: BitRead<L2M>>(backend: &mut B) -> Result<bool> {
+    if let Ok(idx) = backend.peek_bits(READ_BITS) {
+        let idx: u64 = idx.upcast();
+        let len = READ_LEN_L2M[idx as usize];
+        if len != MISSING_VALUE_LEN {
+            backend.skip_bits_after_table_lookup(len as usize)?;
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+#[inline(always)]
+/// Autogenerated function to lookup a read table, if the result is `Some` the
+/// value was found, otherwise we were not able to decode the value and you
+/// should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn write_table_l2m<B: BitWrite<L2M>>(backend: &mut B, value: u64) -> Result<bool> {
+    Ok(if let Some(bits) = WRITE_L2M.get(value as usize) {
+        backend.write_bits(*bits as u64, WRITE_LEN_L2M[value as usize] as usize)?;
+        true
+    } else {
+        false
+    })
+}
+
+#[inline(always)]
+/// Autogenerated function to lookup a read table, if the result is `Some` the
+/// value was found, otherwise we were not able to decode the value and you
+/// should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn read_table_m2l<B: BitRead<M2L>>(backend: &mut B) -> Result<Option<u64>> {
+    if let Ok(idx) = backend.peek_bits(READ_BITS) {
+        let idx: u64 = idx.upcast();
+        let len = READ_LEN_M2L[idx as usize];
+        if len != MISSING_VALUE_LEN {
+            backend.skip_bits_after_table_lookup(len as usize)?;
+            return Ok(Some(READ_M2L[idx as usize] as u64));
+        }
+    }
+    Ok(None)
+}
+
+#[inline(always)]
+/// Autogenerated function to skip a code using the read table, if the result
+/// is `true` the code was skipped, otherwise we were not able to find its
+/// length and you should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn skip_table_m2l<B: BitRead<M2L>>(backend: &mut B) -> Result<bool> {
+    if let Ok(idx) = backend.peek_bits(READ_BITS) {
+        let idx: u64 = idx.upcast();
+        let len = READ_LEN_M2L[idx as usize];
+        if len != MISSING_VALUE_LEN {
+            backend.skip_bits_after_table_lookup(len as usize)?;
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+#[inline(always)]
+/// Autogenerated function to lookup a read table, if the result is `Some` the
+/// value was found, otherwise we were not able to decode the value and you
+/// should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn write_table_m2l<B: BitWrite<M2L>>(backend: &mut B, value: u64) -> Result<bool> {
+    Ok(if let Some(bits) = WRITE_M2L.get(value as usize) {
+        backend.write_bits(*bits as u64, WRITE_LEN_M2L[value as usize] as usize)?;
+        true
+    } else {
+        false
+    })
+}
+///Table containing the values used to speed up the reading of fibonacci codes
+pub const READ_M2L: &[u16] = &[
+    0, 0, 0, 143, 0, 0, 88, 88, 0, 0, 0, 198, 54, 54, 54, 54, 0, 0, 0, 177, 0, 0, 122, 122, 33, 33,
+    33, 33, 33, 33, 33, 33, 0, 0, 0, 164, 0, 0, 109, 109, 0, 0, 0, 219, 75, 75, 75, 75, 20, 20, 20,
+    20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 0, 0, 0, 156, 0, 0, 101, 101, 0, 0, 0, 211,
+    67, 67, 67, 67, 0, 0, 0, 190, 0, 0, 135, 135, 46, 46, 46, 46, 46, 46, 46, 46, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 0, 0, 0, 151, 0, 0, 96, 96, 0, 0, 0, 206, 62, 62, 62, 62, 0, 0, 0, 185, 0, 0,
+    130, 130, 41, 41, 41, 41, 41, 41, 41, 41, 0, 0, 0, 172, 0, 0, 117, 117, 0, 0, 0, 227, 83, 83,
+    83, 83, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 0, 0, 148, 0, 0, 93,
+    93, 0, 0, 0, 203, 59, 59, 59, 59, 0, 0, 0, 182, 0, 0, 127, 127, 38, 38, 38, 38, 38, 38, 38, 38,
+    0, 0, 0, 169, 0, 0, 114, 114, 0, 0, 0, 224, 80, 80, 80, 80, 25, 25, 25, 25, 25, 25, 25, 25, 25,
+    25, 25, 25, 25, 25, 25, 25, 0, 0, 0, 161, 0, 0, 106, 106, 0, 0, 0, 216, 72, 72, 72, 72, 0, 0,
+    0, 195, 0, 0, 140, 140, 51, 51, 51, 51, 51, 51, 51, 51, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+    17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0,
+    0, 146, 0, 0, 91, 91, 0, 0, 0, 201, 57, 57, 57, 57, 0, 0, 0, 180, 0, 0, 125, 125, 36, 36, 36,
+    36, 36, 36, 36, 36, 0, 0, 0, 167, 0, 0, 112, 112, 0, 0, 0, 222, 78, 78, 78, 78, 23, 23, 23, 23,
+    23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 0, 0, 0, 159, 0, 0, 104, 104, 0, 0, 0, 214, 70,
+    70, 70, 70, 0, 0, 0, 193, 0, 0, 138, 138, 49, 49, 49, 49, 49, 49, 49, 49, 15, 15, 15, 15, 15,
+    15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
+    15, 15, 15, 0, 0, 0, 154, 0, 0, 99, 99, 0, 0, 0, 209, 65, 65, 65, 65, 0, 0, 0, 188, 0, 0, 133,
+    133, 44, 44, 44, 44, 44, 44, 44, 44, 0, 0, 0, 175, 0, 0, 120, 120, 0, 0, 0, 230, 86, 86, 86,
+    86, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 0, 0, 0, 145, 0, 0, 90, 90, 0, 0, 0, 200, 56, 56, 56, 56,
+    0, 0, 0, 179, 0, 0, 124, 124, 35, 35, 35, 35, 35, 35, 35, 35, 0, 0, 0, 166, 0, 0, 111, 111, 0,
+    0, 0, 221, 77, 77, 77, 77, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 0,
+    0, 0, 158, 0, 0, 103, 103, 0, 0, 0, 213, 69, 69, 69, 69, 0, 0, 0, 192, 0, 0, 137, 137, 48, 48,
+    48, 48, 48, 48, 48, 48, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14,
+    14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 0, 0, 0, 153, 0, 0, 98, 98, 0, 0, 0,
+    208, 64, 64, 64, 64, 0, 0, 0, 187, 0, 0, 132, 132, 43, 43, 43, 43, 43, 43, 43, 43, 0, 0, 0,
+    174, 0, 0, 119, 119, 0, 0, 0, 229, 85, 85, 85, 85, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    30, 30, 30, 30, 30, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 0, 0, 0, 150, 0, 0, 95, 95, 0, 0, 0, 205, 61, 61, 61, 61, 0, 0, 0, 184, 0,
+    0, 129, 129, 40, 40, 40, 40, 40, 40, 40, 40, 0, 0, 0, 171, 0, 0, 116, 116, 0, 0, 0, 226, 82,
+    82, 82, 82, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 0, 0, 0, 163, 0, 0,
+    108, 108, 0, 0, 0, 218, 74, 74, 74, 74, 0, 0, 0, 197, 0, 0, 142, 142, 53, 53, 53, 53, 53, 53,
+    53, 53, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19,
+    19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 144, 0, 0, 89, 89, 0, 0, 0, 199, 55, 55, 55,
+    55, 0, 0, 0, 178, 0, 0, 123, 123, 34, 34, 34, 34, 34, 34, 34, 34, 0, 0, 0, 165, 0, 0, 110, 110,
+    0, 0, 0, 220, 76, 76, 76, 76, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21,
+    0, 0, 0, 157, 0, 0, 102, 102, 0, 0, 0, 212, 68, 68, 68, 68, 0, 0, 0, 191, 0, 0, 136, 136, 47,
+    47, 47, 47, 47, 47, 47, 47, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13,
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 0, 0, 0, 152, 0, 0, 97, 97, 0, 0,
+    0, 207, 63, 63, 63, 63, 0, 0, 0, 186, 0, 0, 131, 131, 42, 42, 42, 42, 42, 42, 42, 42, 0, 0, 0,
+    173, 0, 0, 118, 118, 0, 0, 0, 228, 84, 84, 84, 84, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+    29, 29, 29, 29, 29, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 0, 0, 0, 149, 0, 0, 94, 94, 0, 0, 0, 204, 60, 60, 60, 60, 0, 0, 0, 183, 0,
+    0, 128, 128, 39, 39, 39, 39, 39, 39, 39, 39, 0, 0, 0, 170, 0, 0, 115, 115, 0, 0, 0, 225, 81,
+    81, 81, 81, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 0, 0, 0, 162, 0, 0,
+    107, 107, 0, 0, 0, 217, 73, 73, 73, 73, 0, 0, 0, 196, 0, 0, 141, 141, 52, 52, 52, 52, 52, 52,
+    52, 52, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18,
+    18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 0, 0, 147, 0, 0, 92, 92, 0, 0, 0, 202, 58, 58, 58,
+    58, 0, 0, 0, 181, 0, 0, 126, 126, 37, 37, 37, 37, 37, 37, 37, 37, 0, 0, 0, 168, 0, 0, 113, 113,
+    0, 0, 0, 223, 79, 79, 79, 79, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24,
+    0, 0, 0, 160, 0, 0, 105, 105, 0, 0, 0, 215, 71, 71, 71, 71, 0, 0, 0, 194, 0, 0, 139, 139, 50,
+    50, 50, 50, 50, 50, 50, 50, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+    16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 0, 0, 0, 155, 0, 0, 100, 100, 0, 0,
+    0, 210, 66, 66, 66, 66, 0, 0, 0, 189, 0, 0, 134, 134, 45, 45, 45, 45, 45, 45, 45, 45, 0, 0, 0,
+    176, 0, 0, 121, 121, 0, 0, 0, 231, 87, 87, 87, 87, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32,
+    32, 32, 32, 32, 32, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+///Table contaings the lens used to speed up the reading of fibonacci codes
+pub const READ_LEN_M2L: &[u8] = &[
+    255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 255, 255, 255, 12, 255,
+    255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12,
+    10, 10, 10, 10, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 255, 255, 255, 12, 255, 255,
+    11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 255, 255, 255, 12, 255, 255, 11, 11, 9, 9, 9, 9, 9,
+    9, 9, 9, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 255, 255, 255,
+    12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255,
+    255, 12, 10, 10, 10, 10, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 255, 255, 255, 12,
+    255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 255, 255, 255, 12, 255, 255, 11, 11, 9, 9,
+    9, 9, 9, 9, 9, 9, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255,
+    12, 10, 10, 10, 10, 255, 255, 255, 12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 255, 255, 255,
+    12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 255, 255, 255, 12, 255, 255, 11, 11,
+    9, 9, 9, 9, 9, 9, 9, 9, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255,
+    255, 12, 10, 10, 10, 10, 255, 255, 255, 12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 255,
+    255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 255, 255, 255, 12, 255, 255,
+    11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10,
+    10, 10, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 255, 255, 255, 12, 255, 255, 11, 11,
+    255, 255, 255, 12, 10, 10, 10, 10, 255, 255, 255, 12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9,
+    255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10,
+    255, 255, 255, 12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 255, 255, 255, 12, 255, 255, 11,
+    11, 255, 255, 255, 12, 10, 10, 10, 10, 255, 255, 255, 12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9,
+    9, 9, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10,
+    10, 10, 255, 255, 255, 12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 255, 255, 255, 12, 255,
+    255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 255, 255, 255, 12, 255,
+    255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12,
+    10, 10, 10, 10, 255, 255, 255, 12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 255, 255, 255, 12,
+    255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 255, 255, 255,
+    12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255,
+    255, 12, 10, 10, 10, 10, 255, 255, 255, 12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 255, 255,
+    255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 255,
+    255, 255, 12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 255, 255, 255, 12, 255, 255, 11, 11,
+    255, 255, 255, 12, 10, 10, 10, 10, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 255, 255,
+    255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 255, 255, 255, 12, 255, 255, 11,
+    11, 9, 9, 9, 9, 9, 9, 9, 9, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10,
+    10, 10, 255, 255, 255, 12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 255, 255, 255, 12, 255,
+    255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 255, 255, 255, 12, 255,
+    255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 255, 255, 255, 12, 255, 255, 11, 11, 255, 255, 255, 12,
+    10, 10, 10, 10, 255, 255, 255, 12, 255, 255, 11, 11, 9, 9, 9, 9, 9, 9, 9, 9, 255, 255, 255, 12,
+    255, 255, 11, 11, 255, 255, 255, 12, 10, 10, 10, 10, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2,
+];
+///Table containing the values used to speed up the reading of fibonacci codes
+pub const READ_L2M: &[u16] = &[
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1,
+    0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6,
+    0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6,
+    0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5,
+    6, 0, 2, 3, 1, 0, 20, 21, 22, 0, 23, 24, 1, 0, 25, 26, 27, 0, 2, 3, 1, 0, 28, 29, 30, 0, 31,
+    32, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8,
+    9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 7,
+    8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2,
+    3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 33, 34, 35, 0, 36, 37, 1, 0, 38, 39,
+    40, 0, 2, 3, 1, 0, 41, 42, 43, 0, 44, 45, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 46, 47, 48, 0, 49, 50,
+    1, 0, 51, 52, 53, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 20, 21, 22,
+    0, 23, 24, 1, 0, 25, 26, 27, 0, 2, 3, 1, 0, 28, 29, 30, 0, 31, 32, 1, 0, 4, 5, 6, 0, 2, 3, 1,
+    0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6,
+    0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6,
+    0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5,
+    6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5,
+    6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11,
+    1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10,
+    11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 20, 21, 22, 0, 23, 24, 1, 0, 25, 26, 27, 0, 2, 3, 1, 0, 28,
+    29, 30, 0, 31, 32, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2,
+    3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 54, 55, 56, 0, 57, 58, 1, 0, 59, 60,
+    61, 0, 2, 3, 1, 0, 62, 63, 64, 0, 65, 66, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 67, 68, 69, 0, 70, 71,
+    1, 0, 72, 73, 74, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 75, 76, 77,
+    0, 78, 79, 1, 0, 80, 81, 82, 0, 2, 3, 1, 0, 83, 84, 85, 0, 86, 87, 1, 0, 4, 5, 6, 0, 2, 3, 1,
+    0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6,
+    0, 2, 3, 1, 0, 33, 34, 35, 0, 36, 37, 1, 0, 38, 39, 40, 0, 2, 3, 1, 0, 41, 42, 43, 0, 44, 45,
+    1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 46, 47, 48, 0, 49, 50, 1, 0, 51, 52, 53, 0, 2, 3, 1, 0, 7, 8, 9,
+    0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 20, 21, 22, 0, 23, 24, 1, 0, 25, 26, 27, 0, 2, 3, 1,
+    0, 28, 29, 30, 0, 31, 32, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18,
+    19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16,
+    1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 20, 21,
+    22, 0, 23, 24, 1, 0, 25, 26, 27, 0, 2, 3, 1, 0, 28, 29, 30, 0, 31, 32, 1, 0, 4, 5, 6, 0, 2, 3,
+    1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5,
+    6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5,
+    6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4,
+    5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4,
+    5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10,
+    11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 33, 34, 35, 0, 36, 37, 1, 0, 38, 39, 40, 0, 2, 3, 1, 0, 41,
+    42, 43, 0, 44, 45, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 46, 47, 48, 0, 49, 50, 1, 0, 51, 52, 53, 0, 2,
+    3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 20, 21, 22, 0, 23, 24, 1, 0, 25, 26,
+    27, 0, 2, 3, 1, 0, 28, 29, 30, 0, 31, 32, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16,
+    1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 88, 89, 90,
+    0, 91, 92, 1, 0, 93, 94, 95, 0, 2, 3, 1, 0, 96, 97, 98, 0, 99, 100, 1, 0, 4, 5, 6, 0, 2, 3, 1,
+    0, 101, 102, 103, 0, 104, 105, 1, 0, 106, 107, 108, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4,
+    5, 6, 0, 2, 3, 1, 0, 109, 110, 111, 0, 112, 113, 1, 0, 114, 115, 116, 0, 2, 3, 1, 0, 117, 118,
+    119, 0, 120, 121, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2,
+    3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 122, 123, 124, 0, 125, 126, 1, 0,
+    127, 128, 129, 0, 2, 3, 1, 0, 130, 131, 132, 0, 133, 134, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 135,
+    136, 137, 0, 138, 139, 1, 0, 140, 141, 142, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6,
+    0, 2, 3, 1, 0, 20, 21, 22, 0, 23, 24, 1, 0, 25, 26, 27, 0, 2, 3, 1, 0, 28, 29, 30, 0, 31, 32,
+    1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9,
+    0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 54, 55, 56, 0, 57, 58, 1, 0, 59, 60, 61, 0, 2, 3, 1,
+    0, 62, 63, 64, 0, 65, 66, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 67, 68, 69, 0, 70, 71, 1, 0, 72, 73,
+    74, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 75, 76, 77, 0, 78, 79, 1,
+    0, 80, 81, 82, 0, 2, 3, 1, 0, 83, 84, 85, 0, 86, 87, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14,
+    0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0,
+    33, 34, 35, 0, 36, 37, 1, 0, 38, 39, 40, 0, 2, 3, 1, 0, 41, 42, 43, 0, 44, 45, 1, 0, 4, 5, 6,
+    0, 2, 3, 1, 0, 46, 47, 48, 0, 49, 50, 1, 0, 51, 52, 53, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1,
+    0, 4, 5, 6, 0, 2, 3, 1, 0, 20, 21, 22, 0, 23, 24, 1, 0, 25, 26, 27, 0, 2, 3, 1, 0, 28, 29, 30,
+    0, 31, 32, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1,
+    0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3,
+    1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3,
+    1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2,
+    3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18,
+    19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 20, 21, 22, 0, 23, 24,
+    1, 0, 25, 26, 27, 0, 2, 3, 1, 0, 28, 29, 30, 0, 31, 32, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13,
+    14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3,
+    1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3,
+    1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5,
+    6, 0, 2, 3, 1, 0, 33, 34, 35, 0, 36, 37, 1, 0, 38, 39, 40, 0, 2, 3, 1, 0, 41, 42, 43, 0, 44,
+    45, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 46, 47, 48, 0, 49, 50, 1, 0, 51, 52, 53, 0, 2, 3, 1, 0, 7, 8,
+    9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 20, 21, 22, 0, 23, 24, 1, 0, 25, 26, 27, 0, 2, 3,
+    1, 0, 28, 29, 30, 0, 31, 32, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18,
+    19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16,
+    1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 20, 21,
+    22, 0, 23, 24, 1, 0, 25, 26, 27, 0, 2, 3, 1, 0, 28, 29, 30, 0, 31, 32, 1, 0, 4, 5, 6, 0, 2, 3,
+    1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5,
+    6, 0, 2, 3, 1, 0, 54, 55, 56, 0, 57, 58, 1, 0, 59, 60, 61, 0, 2, 3, 1, 0, 62, 63, 64, 0, 65,
+    66, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 67, 68, 69, 0, 70, 71, 1, 0, 72, 73, 74, 0, 2, 3, 1, 0, 7, 8,
+    9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 75, 76, 77, 0, 78, 79, 1, 0, 80, 81, 82, 0, 2, 3,
+    1, 0, 83, 84, 85, 0, 86, 87, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18,
+    19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 33, 34, 35, 0, 36, 37, 1,
+    0, 38, 39, 40, 0, 2, 3, 1, 0, 41, 42, 43, 0, 44, 45, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 46, 47, 48,
+    0, 49, 50, 1, 0, 51, 52, 53, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0,
+    20, 21, 22, 0, 23, 24, 1, 0, 25, 26, 27, 0, 2, 3, 1, 0, 28, 29, 30, 0, 31, 32, 1, 0, 4, 5, 6,
+    0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1,
+    0, 4, 5, 6, 0, 2, 3, 1, 0, 143, 144, 145, 0, 146, 147, 1, 0, 148, 149, 150, 0, 2, 3, 1, 0, 151,
+    152, 153, 0, 154, 155, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 156, 157, 158, 0, 159, 160, 1, 0, 161,
+    162, 163, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 164, 165, 166, 0,
+    167, 168, 1, 0, 169, 170, 171, 0, 2, 3, 1, 0, 172, 173, 174, 0, 175, 176, 1, 0, 4, 5, 6, 0, 2,
+    3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4,
+    5, 6, 0, 2, 3, 1, 0, 177, 178, 179, 0, 180, 181, 1, 0, 182, 183, 184, 0, 2, 3, 1, 0, 185, 186,
+    187, 0, 188, 189, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 190, 191, 192, 0, 193, 194, 1, 0, 195, 196,
+    197, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 20, 21, 22, 0, 23, 24, 1,
+    0, 25, 26, 27, 0, 2, 3, 1, 0, 28, 29, 30, 0, 31, 32, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14,
+    0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0,
+    198, 199, 200, 0, 201, 202, 1, 0, 203, 204, 205, 0, 2, 3, 1, 0, 206, 207, 208, 0, 209, 210, 1,
+    0, 4, 5, 6, 0, 2, 3, 1, 0, 211, 212, 213, 0, 214, 215, 1, 0, 216, 217, 218, 0, 2, 3, 1, 0, 7,
+    8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 219, 220, 221, 0, 222, 223, 1, 0, 224, 225, 226,
+    0, 2, 3, 1, 0, 227, 228, 229, 0, 230, 231, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16,
+    1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 33, 34, 35,
+    0, 36, 37, 1, 0, 38, 39, 40, 0, 2, 3, 1, 0, 41, 42, 43, 0, 44, 45, 1, 0, 4, 5, 6, 0, 2, 3, 1,
+    0, 46, 47, 48, 0, 49, 50, 1, 0, 51, 52, 53, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6,
+    0, 2, 3, 1, 0, 20, 21, 22, 0, 23, 24, 1, 0, 25, 26, 27, 0, 2, 3, 1, 0, 28, 29, 30, 0, 31, 32,
+    1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9,
+    0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 88, 89, 90, 0, 91, 92, 1, 0, 93, 94, 95, 0, 2, 3, 1,
+    0, 96, 97, 98, 0, 99, 100, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 101, 102, 103, 0, 104, 105, 1, 0, 106,
+    107, 108, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 109, 110, 111, 0,
+    112, 113, 1, 0, 114, 115, 116, 0, 2, 3, 1, 0, 117, 118, 119, 0, 120, 121, 1, 0, 4, 5, 6, 0, 2,
+    3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4,
+    5, 6, 0, 2, 3, 1, 0, 122, 123, 124, 0, 125, 126, 1, 0, 127, 128, 129, 0, 2, 3, 1, 0, 130, 131,
+    132, 0, 133, 134, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 135, 136, 137, 0, 138, 139, 1, 0, 140, 141,
+    142, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 20, 21, 22, 0, 23, 24, 1,
+    0, 25, 26, 27, 0, 2, 3, 1, 0, 28, 29, 30, 0, 31, 32, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14,
+    0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0,
+    54, 55, 56, 0, 57, 58, 1, 0, 59, 60, 61, 0, 2, 3, 1, 0, 62, 63, 64, 0, 65, 66, 1, 0, 4, 5, 6,
+    0, 2, 3, 1, 0, 67, 68, 69, 0, 70, 71, 1, 0, 72, 73, 74, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1,
+    0, 4, 5, 6, 0, 2, 3, 1, 0, 75, 76, 77, 0, 78, 79, 1, 0, 80, 81, 82, 0, 2, 3, 1, 0, 83, 84, 85,
+    0, 86, 87, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1,
+    0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 33, 34, 35, 0, 36, 37, 1, 0, 38, 39, 40,
+    0, 2, 3, 1, 0, 41, 42, 43, 0, 44, 45, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 46, 47, 48, 0, 49, 50, 1,
+    0, 51, 52, 53, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0, 20, 21, 22, 0,
+    23, 24, 1, 0, 25, 26, 27, 0, 2, 3, 1, 0, 28, 29, 30, 0, 31, 32, 1, 0, 4, 5, 6, 0, 2, 3, 1, 0,
+    12, 13, 14, 0, 15, 16, 1, 0, 17, 18, 19, 0, 2, 3, 1, 0, 7, 8, 9, 0, 10, 11, 1, 0, 4, 5, 6, 0,
+    2, 3, 1, 0,
+];
+///Table contaings the lens used to speed up the reading of fibonacci codes
+pub const READ_LEN_L2M: &[u8] = &[
+    255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3,
+    2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 6,
+    6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255,
+    2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3,
+    2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2,
+    255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4,
+    4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3,
+    2, 5, 5, 5, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 8, 8, 8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3,
+    2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3,
+    2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255,
+    255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255,
+    255, 255, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255,
+    255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3,
+    2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3,
+    2, 9, 9, 9, 2, 9, 9, 3, 2, 9, 9, 9, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 5, 5, 5, 2, 4, 4, 3,
+    2, 9, 9, 9, 2, 9, 9, 3, 2, 9, 9, 9, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3,
+    2, 8, 8, 8, 2, 8, 8, 3, 2, 8, 8, 8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 5, 5, 5, 2, 4, 4, 3,
+    2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3,
+    2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255,
+    3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2,
+    6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255,
+    255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7,
+    3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2,
+    255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4,
+    4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3,
+    2, 5, 5, 5, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 8, 8, 8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3,
+    2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3,
+    2, 5, 5, 5, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 10, 10, 10, 2, 4, 4, 3, 2, 10, 10, 10,
+    2, 10, 10, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 10, 10, 10, 2, 4, 4, 3,
+    2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 10, 10, 10, 2,
+    4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7,
+    7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 9, 9,
+    9, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 9, 9,
+    9, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 8, 8,
+    8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7,
+    7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255,
+    3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2,
+    255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5,
+    5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255, 255,
+    255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2,
+    6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255,
+    255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2,
+    255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2,
+    8, 8, 8, 2, 8, 8, 3, 2, 8, 8, 8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2,
+    7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2,
+    255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3,
+    2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 6,
+    6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255,
+    2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3,
+    2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3,
+    2, 9, 9, 9, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3,
+    2, 9, 9, 9, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3,
+    2, 8, 8, 8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3,
+    2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 11, 11, 11, 2, 11,
+    11, 3, 2, 11, 11, 11, 2, 4, 4, 3, 2, 11, 11, 11, 2, 11, 11, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 11,
+    11, 11, 2, 11, 11, 3, 2, 11, 11, 11, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4,
+    3, 2, 11, 11, 11, 2, 11, 11, 3, 2, 11, 11, 11, 2, 4, 4, 3, 2, 11, 11, 11, 2, 11, 11, 3, 2, 5,
+    5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5,
+    5, 5, 2, 4, 4, 3, 2, 11, 11, 11, 2, 11, 11, 3, 2, 11, 11, 11, 2, 4, 4, 3, 2, 11, 11, 11, 2, 11,
+    11, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 11, 11, 11, 2, 11, 11, 3, 2, 11, 11, 11, 2, 4, 4, 3, 2, 6, 6,
+    6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 8, 8, 8, 2, 4, 4, 3, 2, 8, 8,
+    8, 2, 8, 8, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6,
+    6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 10, 10, 10, 2, 4, 4, 3,
+    2, 10, 10, 10, 2, 10, 10, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 10, 10,
+    10, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2,
+    10, 10, 10, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7,
+    7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9,
+    9, 3, 2, 9, 9, 9, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9,
+    9, 3, 2, 9, 9, 9, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8,
+    8, 3, 2, 8, 8, 8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7,
+    7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255,
+    2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2,
+    4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6,
+    3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2,
+    255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2,
+    4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2,
+    255, 255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255,
+    255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2,
+    4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 8, 8, 8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 5, 5, 5, 2,
+    4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2,
+    4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2,
+    255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4,
+    4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255,
+    255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2,
+    7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2,
+    9, 9, 3, 2, 9, 9, 9, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2,
+    9, 9, 3, 2, 9, 9, 9, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 8, 8, 8, 2,
+    8, 8, 3, 2, 8, 8, 8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2,
+    7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255,
+    255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5,
+    5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 6, 6, 6, 2,
+    6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4,
+    3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7,
+    7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255,
+    3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 255, 255, 255, 2, 255, 255, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2,
+    255, 255, 255, 2, 255, 255, 3, 2, 255, 255, 255, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5,
+    5, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 8, 8, 8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 5, 5,
+    5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5,
+    5, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 10, 10, 10, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10,
+    10, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 10, 10, 10, 2, 4, 4, 3, 2, 6, 6,
+    6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 10, 10, 10, 2, 4, 4, 3,
+    2, 10, 10, 10, 2, 10, 10, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4,
+    4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 9, 9, 9, 2, 4,
+    4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 9, 9, 9, 2, 4,
+    4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 8, 8, 8, 2, 4,
+    4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4,
+    4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 12, 12, 12, 2, 12, 12, 3, 2, 12, 12,
+    12, 2, 4, 4, 3, 2, 12, 12, 12, 2, 12, 12, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 12, 12, 12, 2, 12, 12,
+    3, 2, 12, 12, 12, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 12, 12, 12, 2,
+    12, 12, 3, 2, 12, 12, 12, 2, 4, 4, 3, 2, 12, 12, 12, 2, 12, 12, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2,
+    7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2,
+    12, 12, 12, 2, 12, 12, 3, 2, 12, 12, 12, 2, 4, 4, 3, 2, 12, 12, 12, 2, 12, 12, 3, 2, 5, 5, 5,
+    2, 4, 4, 3, 2, 12, 12, 12, 2, 12, 12, 3, 2, 12, 12, 12, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2,
+    5, 5, 5, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 8, 8, 8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2,
+    5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2,
+    5, 5, 5, 2, 4, 4, 3, 2, 12, 12, 12, 2, 12, 12, 3, 2, 12, 12, 12, 2, 4, 4, 3, 2, 12, 12, 12, 2,
+    12, 12, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 12, 12, 12, 2, 12, 12, 3, 2, 12, 12, 12, 2, 4, 4, 3, 2,
+    6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 12, 12, 12, 2, 12, 12, 3, 2, 12, 12, 12, 2, 4,
+    4, 3, 2, 12, 12, 12, 2, 12, 12, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7,
+    2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 9, 9, 9,
+    2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 9, 9, 9,
+    2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 8, 8, 8,
+    2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7,
+    2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 11, 11, 11, 2, 11, 11, 3, 2, 11,
+    11, 11, 2, 4, 4, 3, 2, 11, 11, 11, 2, 11, 11, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 11, 11, 11, 2, 11,
+    11, 3, 2, 11, 11, 11, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 11, 11,
+    11, 2, 11, 11, 3, 2, 11, 11, 11, 2, 4, 4, 3, 2, 11, 11, 11, 2, 11, 11, 3, 2, 5, 5, 5, 2, 4, 4,
+    3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4,
+    3, 2, 11, 11, 11, 2, 11, 11, 3, 2, 11, 11, 11, 2, 4, 4, 3, 2, 11, 11, 11, 2, 11, 11, 3, 2, 5,
+    5, 5, 2, 4, 4, 3, 2, 11, 11, 11, 2, 11, 11, 3, 2, 11, 11, 11, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6,
+    3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 8, 8, 8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8,
+    3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7, 7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6,
+    3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 10, 10, 10, 2, 4, 4, 3, 2, 10, 10,
+    10, 2, 10, 10, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 10, 10, 10, 2, 4, 4,
+    3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 10, 10, 10,
+    2, 4, 4, 3, 2, 10, 10, 10, 2, 10, 10, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7,
+    7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 9,
+    9, 9, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 9, 9, 9, 2, 9, 9, 3, 2, 9,
+    9, 9, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 8,
+    8, 8, 2, 4, 4, 3, 2, 8, 8, 8, 2, 8, 8, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2, 7, 7, 7, 2, 7, 7, 3, 2, 7,
+    7, 7, 2, 4, 4, 3, 2, 6, 6, 6, 2, 6, 6, 3, 2, 5, 5, 5, 2, 4, 4, 3, 2,
+];
+///Table used to speed up the writing of fibonacci codes
+pub const WRITE_M2L: &[u16] = &[
+    3, 3, 3, 11, 3, 19, 11, 3, 35, 19, 11, 43, 3, 67, 35, 19, 83, 11, 75, 43, 3, 131, 67, 35, 163,
+    19, 147, 83, 11, 139, 75, 43, 171, 3, 259, 131, 67, 323, 35, 291, 163, 19, 275, 147, 83, 339,
+    11, 267, 139, 75, 331, 43, 299, 171, 3, 515, 259, 131, 643, 67, 579, 323, 35, 547, 291, 163,
+    675, 19, 531, 275, 147, 659, 83, 595, 339, 11, 523, 267, 139, 651, 75, 587, 331, 43, 555, 299,
+    171, 683, 3, 1027, 515, 259, 1283, 131, 1155, 643, 67, 1091, 579, 323, 1347, 35, 1059, 547,
+    291, 1315, 163, 1187, 675, 19, 1043, 531, 275, 1299, 147, 1171, 659, 83, 1107, 595, 339, 1363,
+    11, 1035, 523, 267, 1291, 139, 1163, 651, 75, 1099, 587, 331, 1355, 43, 1067, 555, 299, 1323,
+    171, 1195, 683, 3, 2051, 1027, 515, 2563, 259, 2307, 1283, 131, 2179, 1155, 643, 2691, 67,
+    2115, 1091, 579, 2627, 323, 2371, 1347, 35, 2083, 1059, 547, 2595, 291, 2339, 1315, 163, 2211,
+    1187, 675, 2723, 19, 2067, 1043, 531, 2579, 275, 2323, 1299, 147, 2195, 1171, 659, 2707, 83,
+    2131, 1107, 595, 2643, 339, 2387, 1363, 11, 2059, 1035, 523, 2571, 267, 2315, 1291, 139, 2187,
+    1163, 651, 2699, 75, 2123, 1099, 587, 2635, 331, 2379, 1355, 43, 2091, 1067, 555, 2603, 299,
+    2347, 1323, 171, 2219, 1195, 683, 2731, 3, 4099, 2051, 1027, 5123, 515, 4611, 2563, 259, 4355,
+    2307, 1283, 5379, 131, 4227, 2179, 1155, 5251, 643, 4739, 2691, 67, 4163, 2115,
+];
+///Table used to speed up the writing of fibonacci codes
+pub const WRITE_LEN_M2L: &[u16] = &[
+    2, 3, 4, 4, 5, 5, 5, 6, 6, 6, 6, 6, 7, 7, 7, 7, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13,
+    13, 13, 13,
+];
+///Table used to speed up the writing of fibonacci codes
+pub const WRITE_L2M: &[u16] = &[
+    3, 6, 12, 13, 24, 25, 26, 48, 49, 50, 52, 53, 96, 97, 98, 100, 101, 104, 105, 106, 192, 193,
+    194, 196, 197, 200, 201, 202, 208, 209, 210, 212, 213, 384, 385, 386, 388, 389, 392, 393, 394,
+    400, 401, 402, 404, 405, 416, 417, 418, 420, 421, 424, 425, 426, 768, 769, 770, 772, 773, 776,
+    777, 778, 784, 785, 786, 788, 789, 800, 801, 802, 804, 805, 808, 809, 810, 832, 833, 834, 836,
+    837, 840, 841, 842, 848, 849, 850, 852, 853, 1536, 1537, 1538, 1540, 1541, 1544, 1545, 1546,
+    1552, 1553, 1554, 1556, 1557, 1568, 1569, 1570, 1572, 1573, 1576, 1577, 1578, 1600, 1601, 1602,
+    1604, 1605, 1608, 1609, 1610, 1616, 1617, 1618, 1620, 1621, 1664, 1665, 1666, 1668, 1669, 1672,
+    1673, 1674, 1680, 1681, 1682, 1684, 1685, 1696, 1697, 1698, 1700, 1701, 1704, 1705, 1706, 3072,
+    3073, 3074, 3076, 3077, 3080, 3081, 3082, 3088, 3089, 3090, 3092, 3093, 3104, 3105, 3106, 3108,
+    3109, 3112, 3113, 3114, 3136, 3137, 3138, 3140, 3141, 3144, 3145, 3146, 3152, 3153, 3154, 3156,
+    3157, 3200, 3201, 3202, 3204, 3205, 3208, 3209, 3210, 3216, 3217, 3218, 3220, 3221, 3232, 3233,
+    3234, 3236, 3237, 3240, 3241, 3242, 3328, 3329, 3330, 3332, 3333, 3336, 3337, 3338, 3344, 3345,
+    3346, 3348, 3349, 3360, 3361, 3362, 3364, 3365, 3368, 3369, 3370, 3392, 3393, 3394, 3396, 3397,
+    3400, 3401, 3402, 3408, 3409, 3410, 3412, 3413, 6144, 6145, 6146, 6148, 6149, 6152, 6153, 6154,
+    6160, 6161, 6162, 6164, 6165, 6176, 6177, 6178, 6180, 6181, 6184, 6185, 6186, 6208, 6209, 6210,
+];
+///Table used to speed up the writing of fibonacci codes
+pub const WRITE_LEN_L2M: &[u16] = &[
+    2, 3, 4, 4, 5, 5, 5, 6, 6, 6, 6, 6, 7, 7, 7, 7, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13,
+    13, 13, 13,
+];
+///Table used to speed up the skipping of fibonacci codes
+pub const LEN: &[u8] = &[
+    2, 3, 4, 4, 5, 5, 5, 6, 6, 6, 6, 6, 7, 7, 7, 7, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13,
+    13, 13, 13,
+];
@@ -13,10 +13,34 @@
 //! More precisely, to represent x we write in unary floor(log(x)) and then in
 //! binary x - 2^ceil(log(x)) (on floor(log(x)) bits)
 //!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let values: Vec<u64> = (0..1000).map(|value| value % 17).collect();
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     writer.write_gamma_all::<true>(&values).unwrap();
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<L2M, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut read = vec![0; 1000];
+//! reader.read_gamma_into::<true>(&mut read[..10]).unwrap();
+//! reader.read_gamma_into::<true>(&mut read[10..]).unwrap();
+//! assert_eq!(read, values);
+//!
+//! // codes can be skipped without decoding them
+//! let mut reader = <BufferedBitStreamRead<L2M, u128, _>>::new(MemWordRead::new(&buffer));
+//! reader.skip_gamma_n::<true>(500).unwrap();
+//! assert_eq!(reader.read_gamma::<true>().unwrap(), values[500]);
+//! ```
 
-use super::{fast_floor_log2, gamma_tables};
+#[cfg(feature = "two_level_tables")]
+use super::two_level;
+use super::{batch, fast_floor_log2_succ, gamma_multi_tables, gamma_tables, int2nat, nat2int};
 use crate::traits::*;
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 /// Returns how long the gamma code for `value` will be
 ///
@@ -24,17 +48,26 @@ use anyhow::Result;
 /// for decoding
 #[must_use]
 #[inline]
-pub fn len_gamma<const USE_TABLE: bool>(mut value: u64) -> usize {
+pub fn len_gamma<const USE_TABLE: bool>(value: u64) -> usize {
     if USE_TABLE {
         if let Some(idx) = gamma_tables::LEN.get(value as usize) {
             return *idx as usize;
         }
     }
-    value += 1;
-    let number_of_blocks_to_write = value.trailing_zeros();
+    let number_of_blocks_to_write = fast_floor_log2_succ(value);
     2 * number_of_blocks_to_write as usize + 1
 }
 
+/// Returns how long the gamma code for the signed `value` will be
+///
+/// `USE_TABLE` enables or disables the use of pre-computed tables
+/// for decoding
+#[must_use]
+#[inline]
+pub fn len_gamma_signed<const USE_TABLE: bool>(value: i64) -> usize {
+    len_gamma::<USE_TABLE>(int2nat(value))
+}
+
 /// Trait for objects that can read Gamma codes
 pub trait GammaRead<BO: BitOrder>: BitRead<BO> {
     /// Read a gamma code from the stream.
@@ -44,8 +77,54 @@ pub trait GammaRead<BO: BitOrder>: BitRead<BO> {
     ///
     /// # Errors
     /// This function fails only if the BitRead backend has problems reading
-    /// bits, as when the stream ended unexpectedly
+    /// bits, as when the stream ended unexpectedly, or if the code does not
+    /// fit in a [`u64`]
     fn read_gamma<const USE_TABLE: bool>(&mut self) -> Result<u64>;
+
+    /// Read a gamma code from the stream and map it to a signed value
+    /// using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`GammaRead::read_gamma`]
+    #[inline]
+    fn read_gamma_signed<const USE_TABLE: bool>(&mut self) -> Result<i64> {
+        self.read_gamma::<USE_TABLE>().map(nat2int)
+    }
+
+    /// Read `values.len()` γ codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`GammaRead::read_gamma`]
+    #[inline]
+    fn read_gamma_into<const USE_TABLE: bool>(&mut self, values: &mut [u64]) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_gamma::<USE_TABLE>()?;
+        }
+        Ok(())
+    }
+
+    /// Skip a γ code from the stream without decoding it.
+    ///
+    /// `USE_TABLE` enables or disables the use of pre-computed tables
+    /// for decoding
+    ///
+    /// # Errors
+    /// This function fails only if the BitRead backend has problems reading
+    /// or skipping bits, as when the stream ended unexpectedly
+    fn skip_gamma<const USE_TABLE: bool>(&mut self) -> Result<()>;
+
+    /// Skip `n` γ codes from the stream without decoding them.
+    ///
+    /// # Errors
+    /// The same as [`GammaRead::skip_gamma`]
+    #[inline]
+    fn skip_gamma_n<const USE_TABLE: bool>(&mut self, n: usize) -> Result<()> {
+        for _ in 0..n {
+            self.skip_gamma::<USE_TABLE>()?;
+        }
+        Ok(())
+    }
 }
 
 /// Common part of the M2L and L2M impl
@@ -55,8 +134,21 @@ pub trait GammaRead<BO: BitOrder>: BitRead<BO> {
 #[inline(always)]
 fn default_read_gamma<BO: BitOrder, B: BitRead<BO>>(backend: &mut B) -> Result<u64> {
     let len = backend.read_unary::<false>()?;
-    debug_assert!(len <= 64);
-    Ok(backend.read_bits(len as usize)? + (1 << len) - 1)
+    let short_value = backend.read_bits(len as usize)?;
+    match short_value.checked_add(((1_u128 << len) - 1) as u64) {
+        Some(value) => Ok(value),
+        None => bail!("The gamma code does not fit in a u64."),
+    }
+}
+
+/// Common part of the M2L and L2M impl
+///
+/// # Errors
+/// Forward `read_unary` and `skip_bits` errors.
+#[inline(always)]
+fn default_skip_gamma<BO: BitOrder, B: BitRead<BO>>(backend: &mut B) -> Result<()> {
+    let len = backend.read_unary::<false>()?;
+    backend.skip_bits(len as usize)
 }
 
 impl<B: BitRead<M2L>> GammaRead<M2L> for B {
@@ -66,9 +158,63 @@ impl<B: BitRead<M2L>> GammaRead<M2L> for B {
             if let Some(res) = gamma_tables::read_table_m2l(self)? {
                 return Ok(res);
             }
+            #[cfg(feature = "two_level_tables")]
+            if let Some(res) = two_level::read_table_m2l(self, gamma_tables::READ2)? {
+                return Ok(res);
+            }
         }
         default_read_gamma(self)
     }
+
+    #[inline]
+    fn read_gamma_into<const USE_TABLE: bool>(&mut self, values: &mut [u64]) -> Result<()> {
+        if USE_TABLE {
+            return batch::read_multi_table(
+                self,
+                values,
+                gamma_multi_tables::READ_BITS,
+                gamma_multi_tables::MAX_SYMBOLS,
+                (
+                    gamma_multi_tables::READ_LEN_M2L,
+                    gamma_multi_tables::READ_COUNT_M2L,
+                    gamma_multi_tables::READ_VALUES_M2L,
+                ),
+                |backend| backend.read_gamma::<true>(),
+            );
+        }
+        for value in values.iter_mut() {
+            *value = default_read_gamma(self)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn skip_gamma<const USE_TABLE: bool>(&mut self) -> Result<()> {
+        if USE_TABLE && gamma_tables::skip_table_m2l(self)? {
+            return Ok(());
+        }
+        default_skip_gamma(self)
+    }
+
+    #[inline]
+    fn skip_gamma_n<const USE_TABLE: bool>(&mut self, n: usize) -> Result<()> {
+        if USE_TABLE {
+            return batch::skip_multi_table(
+                self,
+                n,
+                gamma_multi_tables::READ_BITS,
+                (
+                    gamma_multi_tables::READ_LEN_M2L,
+                    gamma_multi_tables::READ_COUNT_M2L,
+                ),
+                |backend| backend.skip_gamma::<true>(),
+            );
+        }
+        for _ in 0..n {
+            default_skip_gamma(self)?;
+        }
+        Ok(())
+    }
 }
 impl<B: BitRead<L2M>> GammaRead<L2M> for B {
     #[inline]
@@ -77,9 +223,63 @@ impl<B: BitRead<L2M>> GammaRead<L2M> for B {
             if let Some(res) = gamma_tables::read_table_l2m(self)? {
                 return Ok(res);
             }
+            #[cfg(feature = "two_level_tables")]
+            if let Some(res) = two_level::read_table_l2m(self, gamma_tables::READ2)? {
+                return Ok(res);
+            }
         }
         default_read_gamma(self)
     }
+
+    #[inline]
+    fn read_gamma_into<const USE_TABLE: bool>(&mut self, values: &mut [u64]) -> Result<()> {
+        if USE_TABLE {
+            return batch::read_multi_table(
+                self,
+                values,
+                gamma_multi_tables::READ_BITS,
+                gamma_multi_tables::MAX_SYMBOLS,
+                (
+                    gamma_multi_tables::READ_LEN_L2M,
+                    gamma_multi_tables::READ_COUNT_L2M,
+                    gamma_multi_tables::READ_VALUES_L2M,
+                ),
+                |backend| backend.read_gamma::<true>(),
+            );
+        }
+        for value in values.iter_mut() {
+            *value = default_read_gamma(self)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn skip_gamma<const USE_TABLE: bool>(&mut self) -> Result<()> {
+        if USE_TABLE && gamma_tables::skip_table_l2m(self)? {
+            return Ok(());
+        }
+        default_skip_gamma(self)
+    }
+
+    #[inline]
+    fn skip_gamma_n<const USE_TABLE: bool>(&mut self, n: usize) -> Result<()> {
+        if USE_TABLE {
+            return batch::skip_multi_table(
+                self,
+                n,
+                gamma_multi_tables::READ_BITS,
+                (
+                    gamma_multi_tables::READ_LEN_L2M,
+                    gamma_multi_tables::READ_COUNT_L2M,
+                ),
+                |backend| backend.skip_gamma::<true>(),
+            );
+        }
+        for _ in 0..n {
+            default_skip_gamma(self)?;
+        }
+        Ok(())
+    }
 }
 
 /// Trait for objects that can write Gamma codes
@@ -93,29 +293,85 @@ pub trait GammaWrite<BO: BitOrder>: BitWrite<BO> {
     /// This function fails only if the BitWrite backend has problems writing
     /// bits, as when the stream ended unexpectedly
     fn write_gamma<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()>;
+
+    /// Write a signed value on the stream, mapping it to a natural number
+    /// using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`GammaWrite::write_gamma`]
+    #[inline]
+    fn write_gamma_signed<const USE_TABLE: bool>(&mut self, value: i64) -> Result<()> {
+        self.write_gamma::<USE_TABLE>(int2nat(value))
+    }
+
+    /// Write all the `values` on the stream as γ codes.
+    ///
+    /// # Errors
+    /// The same as [`GammaWrite::write_gamma`]
+    #[inline]
+    fn write_gamma_all<const USE_TABLE: bool>(&mut self, values: &[u64]) -> Result<()> {
+        for &value in values {
+            self.write_gamma::<USE_TABLE>(value)?;
+        }
+        Ok(())
+    }
 }
 
 impl<B: BitWrite<M2L>> GammaWrite<M2L> for B {
     #[inline]
     fn write_gamma<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
-        if USE_TABLE {
-            if gamma_tables::write_table_m2l(self, value)? {
-                return Ok(());
-            }
+        if USE_TABLE && gamma_tables::write_table_m2l(self, value)? {
+            return Ok(());
         }
         default_write_gamma(self, value)
     }
+
+    #[inline]
+    fn write_gamma_all<const USE_TABLE: bool>(&mut self, values: &[u64]) -> Result<()> {
+        if USE_TABLE {
+            return batch::write_packed_m2l(
+                self,
+                values,
+                |value| {
+                    let bits = *gamma_tables::WRITE_M2L.get(value as usize)?;
+                    Some((bits, gamma_tables::WRITE_LEN_M2L[value as usize] as usize))
+                },
+                default_write_gamma,
+            );
+        }
+        for &value in values {
+            default_write_gamma(self, value)?;
+        }
+        Ok(())
+    }
 }
 impl<B: BitWrite<L2M>> GammaWrite<L2M> for B {
     #[inline]
     fn write_gamma<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
-        if USE_TABLE {
-            if gamma_tables::write_table_l2m(self, value)? {
-                return Ok(());
-            }
+        if USE_TABLE && gamma_tables::write_table_l2m(self, value)? {
+            return Ok(());
         }
         default_write_gamma(self, value)
     }
+
+    #[inline]
+    fn write_gamma_all<const USE_TABLE: bool>(&mut self, values: &[u64]) -> Result<()> {
+        if USE_TABLE {
+            return batch::write_packed_l2m(
+                self,
+                values,
+                |value| {
+                    let bits = *gamma_tables::WRITE_L2M.get(value as usize)?;
+                    Some((bits, gamma_tables::WRITE_LEN_L2M[value as usize] as usize))
+                },
+                default_write_gamma,
+            );
+        }
+        for &value in values {
+            default_write_gamma(self, value)?;
+        }
+        Ok(())
+    }
 }
 
 /// Common part of the M2L and L2M impl
@@ -123,15 +379,10 @@ impl<B: BitWrite<L2M>> GammaWrite<L2M> for B {
 /// # Errors
 /// Forward `read_unary` and `read_bits` errors.
 #[inline(always)]
-fn default_write_gamma<BO: BitOrder, B: BitWrite<BO>>(
-    backend: &mut B,
-    mut value: u64,
-) -> Result<()> {
-    value += 1;
-    let number_of_bits_to_write = fast_floor_log2(value);
-    debug_assert!(number_of_bits_to_write <= u8::MAX as _);
-    // remove the most significant 1
-    let short_value = value - (1 << number_of_bits_to_write);
+fn default_write_gamma<BO: BitOrder, B: BitWrite<BO>>(backend: &mut B, value: u64) -> Result<()> {
+    let number_of_bits_to_write = fast_floor_log2_succ(value);
+    // remove the most significant 1 of value + 1
+    let short_value = (value as u128 + 1 - (1 << number_of_bits_to_write)) as u64;
     // Write the code
     backend.write_unary::<false>(number_of_bits_to_write as _)?;
     backend.write_bits(short_value, number_of_bits_to_write as usize)?;
@@ -0,0 +1,2680 @@
+// THIS FILE HAS BEEN GENERATED WITH THE SCRIPT code_tables_generator.py
+// ~~~~~~~~~~~~~~~~~~~ DO NOT MODIFY ~~~~~~~~~~~~~~~~~~~~~~
+// Pre-computed constants used to decode several gamma codes with a single lookup
+/// How many bits are needed to read the tables in this
+pub const READ_BITS: usize = 12;
+/// Maximum number of values decoded with a single lookup
+pub const MAX_SYMBOLS: usize = 8;
+///Table containing the total length of the codes decoded with a lookup
+pub const READ_LEN_M2L: &[u8] = &[
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12,
+    11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12,
+    11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12,
+    9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9,
+    12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12,
+    12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12,
+    10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10,
+    10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12,
+    12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12,
+    7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9,
+    12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8,
+    11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10,
+    10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12,
+    12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12,
+    7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9,
+    12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8,
+    11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10,
+    10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 5, 5, 5, 5, 5, 5, 5, 5,
+    12, 12, 12, 12, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12,
+    8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12,
+    12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10,
+    10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10,
+    11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 5, 5, 5, 5, 5, 5, 5, 5, 12,
+    12, 12, 12, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8,
+    8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12,
+    12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10,
+    10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10,
+    11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 5, 5, 5, 5, 5, 5, 5, 5, 12,
+    12, 12, 12, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8,
+    8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12,
+    12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10,
+    10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10,
+    11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 5, 5, 5, 5, 5, 5, 5, 5, 12,
+    12, 12, 12, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8,
+    8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12,
+    12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10,
+    10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10,
+    11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 10, 11,
+    12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11,
+    12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11,
+    12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11,
+    12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12,
+    11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7,
+    12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10,
+    10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11,
+    12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12,
+    8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10,
+    10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10,
+    11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12,
+    9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8,
+    8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 5, 5, 5, 5, 5, 5, 5, 5, 12, 12, 12, 12, 12,
+    12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12,
+    11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12,
+    6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12,
+    12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11,
+    12, 11, 12, 9, 9, 12, 12, 10, 10, 10, 10, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10,
+    10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8,
+    8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12,
+    12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11,
+    12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11,
+    12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10,
+    10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 6, 6, 6,
+    6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10,
+    10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11,
+    12, 9, 9, 12, 12, 10, 10, 11, 12, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 11, 12, 11,
+    12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12,
+    10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12,
+    12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11,
+    12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9,
+    9, 12, 12, 10, 10, 11, 12, 5, 5, 5, 5, 5, 5, 5, 5, 12, 12, 12, 12, 12, 12, 12, 12, 10, 10, 11,
+    12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12,
+    10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6,
+    6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12,
+    7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9,
+    12, 12, 10, 10, 10, 10, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10,
+    11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10,
+    11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10,
+    11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8,
+    11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10,
+    11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12,
+    9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8,
+    8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12,
+    10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10,
+    11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11,
+    12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12,
+    11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7,
+    12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10,
+    10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11,
+    12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12,
+    8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11,
+    12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12,
+    12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10,
+    11, 12, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12,
+    11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12,
+    12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12,
+    10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12,
+    12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11,
+    12, 5, 5, 5, 5, 5, 5, 5, 5, 12, 12, 12, 12, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10,
+    10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8,
+    8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11,
+    12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12,
+    12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 10, 10,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12,
+    11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10,
+    10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10,
+    11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12,
+    10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 5, 5,
+    5, 5, 5, 5, 5, 5, 12, 12, 12, 12, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11,
+    12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11,
+    12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11,
+    12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10,
+    10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 10, 10, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 11, 12,
+    11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12,
+    11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12,
+    12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12,
+    10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12,
+    12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11,
+    12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9,
+    9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8,
+    8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12,
+    10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 5, 5, 5, 5, 5, 5, 5,
+    5, 12, 12, 12, 12, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11,
+    12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9,
+    12, 12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12,
+    10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10,
+    10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 10, 10, 5, 5, 5, 5, 5, 5, 5, 5,
+    12, 12, 12, 12, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12,
+    8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12,
+    12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10,
+    10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10,
+    11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 10, 10, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 10, 11,
+    12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11,
+    12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11,
+    12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11,
+    12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12,
+    11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7,
+    12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10,
+    10, 10, 10, 6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 11,
+    12, 9, 9, 12, 12, 10, 10, 11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12,
+    8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 10, 10, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10,
+    10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10, 11, 12, 9, 9, 12, 12, 10, 10,
+    11, 12, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12, 11, 12,
+    9, 9, 12, 12, 10, 10, 10, 10, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8,
+    8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 10, 10, 5, 5, 5, 5, 5, 5, 5, 5, 12, 12, 12, 12, 12,
+    12, 12, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 10, 10, 11, 12, 8, 8, 8, 8, 11, 12,
+    11, 12, 9, 9, 12, 12, 10, 10, 10, 10, 8, 8, 8, 8, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 10, 10,
+    6, 6, 6, 6, 6, 6, 6, 6, 11, 12, 11, 12, 11, 12, 11, 12, 9, 9, 12, 12, 10, 10, 10, 10, 9, 9, 12,
+    12, 10, 10, 10, 10, 7, 7, 7, 7, 12, 12, 12, 12, 10, 10, 10, 10, 10, 10, 10, 10, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+];
+///Table containing the number of codes decoded with a lookup
+pub const READ_COUNT_M2L: &[u8] = &[
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2,
+    1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2,
+    1, 1, 2, 2, 2, 2, 3, 4, 1, 1, 2, 2, 2, 2, 3, 4, 1, 1, 2, 2, 2, 2, 3, 4, 1, 1, 2, 2, 2, 2, 3, 4,
+    1, 1, 2, 2, 2, 2, 3, 4, 1, 1, 2, 2, 2, 2, 3, 4, 1, 1, 2, 2, 2, 2, 3, 4, 1, 1, 2, 2, 2, 2, 3, 4,
+    1, 1, 2, 2, 2, 2, 3, 4, 1, 1, 2, 2, 2, 2, 3, 4, 1, 1, 2, 2, 2, 2, 3, 4, 1, 1, 2, 2, 2, 2, 3, 4,
+    1, 1, 2, 2, 2, 2, 3, 4, 1, 1, 2, 2, 2, 2, 3, 4, 1, 1, 2, 2, 2, 2, 3, 4, 1, 1, 2, 2, 2, 2, 3, 4,
+    1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4,
+    2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4,
+    2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4,
+    2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4,
+    2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4,
+    2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4,
+    3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    3, 3, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6,
+    4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 5, 6, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 5, 5, 6, 6, 6, 6, 7, 8,
+    5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 7, 8, 6, 6, 7, 8, 6, 6, 6, 6, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4,
+    2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4,
+    3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    3, 3, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6,
+    4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 5, 6, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 5, 5, 6, 6, 6, 6, 7, 8,
+    5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 7, 8, 6, 6, 7, 8, 6, 6, 6, 6, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4,
+    2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4, 2, 2, 3, 4,
+    2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 2, 2, 2, 2, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6,
+    2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4,
+    3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    3, 3, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6,
+    4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 5, 6, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 5, 5, 6, 6, 6, 6, 7, 8,
+    5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 7, 8, 6, 6, 7, 8, 6, 6, 6, 6, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4,
+    3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    3, 3, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6,
+    4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 5, 6, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 5, 5, 6, 6, 6, 6, 7, 8,
+    5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 7, 8, 6, 6, 7, 8, 6, 6, 6, 6, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4, 3, 4,
+    3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6, 3, 3, 4, 4, 4, 4, 5, 6,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    3, 3, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6,
+    4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 5, 6, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 5, 5, 6, 6, 6, 6, 7, 8,
+    5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 7, 8, 6, 6, 7, 8, 6, 6, 6, 6, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8,
+    3, 3, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6,
+    4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 5, 6, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 5, 5, 6, 6, 6, 6, 7, 8,
+    5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 7, 8, 6, 6, 7, 8, 6, 6, 6, 6, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6, 4, 4, 5, 6,
+    4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 4, 4, 4, 4, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8,
+    4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 5, 6, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 5, 5, 6, 6, 6, 6, 7, 8,
+    5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 7, 8, 6, 6, 7, 8, 6, 6, 6, 6, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8,
+    4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 5, 6, 5, 6, 5, 6, 5, 5, 6, 6, 6, 6, 7, 8, 5, 5, 6, 6, 6, 6, 7, 8,
+    5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 7, 8, 6, 6, 7, 8, 6, 6, 6, 6, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 5, 6, 5, 6, 5, 6, 5, 6, 5, 6, 5, 6, 5, 6,
+    5, 5, 6, 6, 6, 6, 7, 8, 5, 5, 6, 6, 6, 6, 7, 8, 5, 5, 6, 6, 6, 6, 7, 8, 5, 5, 6, 6, 6, 6, 7, 8,
+    5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 7, 8, 6, 6, 7, 8, 6, 6, 6, 6, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8,
+    5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 7, 8, 6, 6, 7, 8, 6, 6, 6, 6, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8,
+    5, 5, 5, 5, 5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 7, 8, 6, 6, 7, 8, 6, 6, 7, 8, 6, 6, 7, 8,
+    6, 6, 6, 6, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8, 6, 6, 6, 6, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8,
+    6, 6, 6, 6, 6, 6, 6, 6, 7, 8, 7, 8, 7, 8, 7, 8, 7, 7, 8, 8, 8, 8, 8, 8, 7, 7, 8, 8, 8, 8, 8, 8,
+    7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+];
+///Table containing the values decoded with a lookup, MAX_SYMBOLS
+///for each entry, padded with zeros
+pub const READ_VALUES_M2L: &[u8] = &[
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    31, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0,
+    0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0,
+    0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 36, 0,
+    0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0,
+    38, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0,
+    0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0,
+    0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 44, 0,
+    0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0,
+    46, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0,
+    0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0,
+    0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 51, 0,
+    0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0,
+    53, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0,
+    0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0,
+    0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 59, 0,
+    0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0,
+    61, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0,
+    0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 15, 1, 0, 0, 0, 0, 0, 0, 15, 2, 0, 0,
+    0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 15, 0,
+    0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 16, 1, 0, 0, 0, 0, 0, 0,
+    16, 2, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0,
+    0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 17, 1, 0, 0,
+    0, 0, 0, 0, 17, 2, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 17, 0,
+    0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0,
+    18, 1, 0, 0, 0, 0, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0,
+    0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0,
+    0, 0, 0, 0, 19, 1, 0, 0, 0, 0, 0, 0, 19, 2, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 19, 0,
+    0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0,
+    20, 0, 0, 0, 0, 0, 0, 0, 20, 1, 0, 0, 0, 0, 0, 0, 20, 2, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0,
+    0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+    0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 21, 1, 0, 0, 0, 0, 0, 0, 21, 2, 0, 0, 0, 0, 0, 0, 21, 0,
+    0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0,
+    22, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 22, 1, 0, 0, 0, 0, 0, 0, 22, 2, 0, 0, 0, 0,
+    0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0,
+    0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 23, 1, 0, 0, 0, 0, 0, 0, 23, 2,
+    0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0,
+    23, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0,
+    0, 0, 24, 2, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0,
+    0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 25, 1,
+    0, 0, 0, 0, 0, 0, 25, 2, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0,
+    25, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0,
+    0, 0, 26, 1, 0, 0, 0, 0, 0, 0, 26, 2, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0,
+    0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 27, 0,
+    0, 0, 0, 0, 0, 0, 27, 1, 0, 0, 0, 0, 0, 0, 27, 2, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0,
+    27, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0,
+    0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 28, 1, 0, 0, 0, 0, 0, 0, 28, 2, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0,
+    0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 29, 0,
+    0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 29, 1, 0, 0, 0, 0, 0, 0, 29, 2, 0, 0, 0, 0, 0, 0,
+    29, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0,
+    0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 30, 1, 0, 0, 0, 0, 0, 0, 30, 2, 0, 0,
+    0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 30, 0,
+    0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0,
+    0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 0, 0, 0, 0, 7, 4, 0, 0, 0, 0, 0, 0, 7, 5, 0, 0, 0, 0, 0, 0, 7, 6,
+    0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 7, 1,
+    0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 0, 7, 2,
+    0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0,
+    0, 0, 0, 0, 0, 0, 7, 0, 1, 0, 0, 0, 0, 0, 7, 0, 1, 0, 0, 0, 0, 0, 7, 0, 2, 0, 0, 0, 0, 0, 7, 0,
+    2, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 1, 0, 0, 0, 0, 7, 0,
+    0, 2, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0,
+    0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0,
+    0, 0, 0, 0, 0, 0, 8, 3, 0, 0, 0, 0, 0, 0, 8, 4, 0, 0, 0, 0, 0, 0, 8, 5, 0, 0, 0, 0, 0, 0, 8, 6,
+    0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 8, 1,
+    0, 0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 8, 2,
+    0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0,
+    0, 0, 0, 0, 0, 0, 8, 0, 1, 0, 0, 0, 0, 0, 8, 0, 1, 0, 0, 0, 0, 0, 8, 0, 2, 0, 0, 0, 0, 0, 8, 0,
+    2, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 1, 0, 0, 0, 0, 8, 0,
+    0, 2, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0,
+    0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0,
+    0, 0, 0, 0, 0, 0, 9, 3, 0, 0, 0, 0, 0, 0, 9, 4, 0, 0, 0, 0, 0, 0, 9, 5, 0, 0, 0, 0, 0, 0, 9, 6,
+    0, 0, 0, 0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 9, 1,
+    0, 0, 0, 0, 0, 0, 9, 2, 0, 0, 0, 0, 0, 0, 9, 2, 0, 0, 0, 0, 0, 0, 9, 2, 0, 0, 0, 0, 0, 0, 9, 2,
+    0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0,
+    0, 0, 0, 0, 0, 0, 9, 0, 1, 0, 0, 0, 0, 0, 9, 0, 1, 0, 0, 0, 0, 0, 9, 0, 2, 0, 0, 0, 0, 0, 9, 0,
+    2, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 1, 0, 0, 0, 0, 9, 0,
+    0, 2, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0,
+    0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0,
+    10, 0, 0, 0, 0, 0, 0, 0, 10, 3, 0, 0, 0, 0, 0, 0, 10, 4, 0, 0, 0, 0, 0, 0, 10, 5, 0, 0, 0, 0,
+    0, 0, 10, 6, 0, 0, 0, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 0, 10, 1, 0, 0,
+    0, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 0, 10, 2, 0, 0, 0, 0, 0, 0, 10, 2, 0, 0, 0, 0, 0, 0, 10, 2,
+    0, 0, 0, 0, 0, 0, 10, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0,
+    10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 1, 0, 0, 0, 0, 0, 10, 0, 1, 0, 0, 0,
+    0, 0, 10, 0, 2, 0, 0, 0, 0, 0, 10, 0, 2, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0,
+    0, 0, 0, 0, 10, 0, 0, 1, 0, 0, 0, 0, 10, 0, 0, 2, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0,
+    0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0,
+    11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 3, 0, 0, 0, 0,
+    0, 0, 11, 4, 0, 0, 0, 0, 0, 0, 11, 5, 0, 0, 0, 0, 0, 0, 11, 6, 0, 0, 0, 0, 0, 0, 11, 1, 0, 0,
+    0, 0, 0, 0, 11, 1, 0, 0, 0, 0, 0, 0, 11, 1, 0, 0, 0, 0, 0, 0, 11, 1, 0, 0, 0, 0, 0, 0, 11, 2,
+    0, 0, 0, 0, 0, 0, 11, 2, 0, 0, 0, 0, 0, 0, 11, 2, 0, 0, 0, 0, 0, 0, 11, 2, 0, 0, 0, 0, 0, 0,
+    11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0,
+    0, 0, 11, 0, 1, 0, 0, 0, 0, 0, 11, 0, 1, 0, 0, 0, 0, 0, 11, 0, 2, 0, 0, 0, 0, 0, 11, 0, 2, 0,
+    0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 1, 0, 0, 0, 0, 11, 0,
+    0, 2, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0,
+    11, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0,
+    0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 3, 0, 0, 0, 0, 0, 0, 12, 4, 0, 0, 0, 0, 0, 0, 12, 5, 0, 0,
+    0, 0, 0, 0, 12, 6, 0, 0, 0, 0, 0, 0, 12, 1, 0, 0, 0, 0, 0, 0, 12, 1, 0, 0, 0, 0, 0, 0, 12, 1,
+    0, 0, 0, 0, 0, 0, 12, 1, 0, 0, 0, 0, 0, 0, 12, 2, 0, 0, 0, 0, 0, 0, 12, 2, 0, 0, 0, 0, 0, 0,
+    12, 2, 0, 0, 0, 0, 0, 0, 12, 2, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0,
+    0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 1, 0, 0, 0, 0, 0, 12, 0, 1, 0,
+    0, 0, 0, 0, 12, 0, 2, 0, 0, 0, 0, 0, 12, 0, 2, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0,
+    0, 0, 0, 0, 0, 0, 12, 0, 0, 1, 0, 0, 0, 0, 12, 0, 0, 2, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0,
+    0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 3, 0, 0,
+    0, 0, 0, 0, 13, 4, 0, 0, 0, 0, 0, 0, 13, 5, 0, 0, 0, 0, 0, 0, 13, 6, 0, 0, 0, 0, 0, 0, 13, 1,
+    0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0,
+    13, 2, 0, 0, 0, 0, 0, 0, 13, 2, 0, 0, 0, 0, 0, 0, 13, 2, 0, 0, 0, 0, 0, 0, 13, 2, 0, 0, 0, 0,
+    0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0,
+    0, 0, 0, 0, 13, 0, 1, 0, 0, 0, 0, 0, 13, 0, 1, 0, 0, 0, 0, 0, 13, 0, 2, 0, 0, 0, 0, 0, 13, 0,
+    2, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 1, 0, 0, 0, 0,
+    13, 0, 0, 2, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0,
+    0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0,
+    0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 3, 0, 0, 0, 0, 0, 0, 14, 4, 0, 0, 0, 0, 0, 0, 14, 5,
+    0, 0, 0, 0, 0, 0, 14, 6, 0, 0, 0, 0, 0, 0, 14, 1, 0, 0, 0, 0, 0, 0, 14, 1, 0, 0, 0, 0, 0, 0,
+    14, 1, 0, 0, 0, 0, 0, 0, 14, 1, 0, 0, 0, 0, 0, 0, 14, 2, 0, 0, 0, 0, 0, 0, 14, 2, 0, 0, 0, 0,
+    0, 0, 14, 2, 0, 0, 0, 0, 0, 0, 14, 2, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0,
+    0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 1, 0, 0, 0, 0, 0, 14, 0,
+    1, 0, 0, 0, 0, 0, 14, 0, 2, 0, 0, 0, 0, 0, 14, 0, 2, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0,
+    14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 1, 0, 0, 0, 0, 14, 0, 0, 2, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0,
+    0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 7, 0, 0, 0,
+    0, 0, 0, 3, 8, 0, 0, 0, 0, 0, 0, 3, 9, 0, 0, 0, 0, 0, 0, 3, 10, 0, 0, 0, 0, 0, 0, 3, 11, 0, 0,
+    0, 0, 0, 0, 3, 12, 0, 0, 0, 0, 0, 0, 3, 13, 0, 0, 0, 0, 0, 0, 3, 14, 0, 0, 0, 0, 0, 0, 3, 3, 0,
+    0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 4, 0,
+    0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 5, 0,
+    0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 3, 6, 0,
+    0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 0, 3, 1, 0,
+    0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 1,
+    0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 0, 3, 1, 0,
+    0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 1, 0, 0, 0, 0, 3, 1, 0, 2, 0, 0, 0, 0, 3, 1, 0,
+    0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 2, 0,
+    0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 1,
+    0, 0, 0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 0, 3, 2, 2, 0, 0, 0, 0, 0, 3, 2, 2, 0, 0, 0, 0, 0, 3, 2, 0,
+    0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 1, 0, 0, 0, 0, 3, 2, 0, 2, 0, 0, 0, 0, 3, 2, 0,
+    0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0,
+    0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0,
+    0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3,
+    0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0, 3, 0, 5,
+    0, 0, 0, 0, 0, 3, 0, 5, 0, 0, 0, 0, 0, 3, 0, 6, 0, 0, 0, 0, 0, 3, 0, 6, 0, 0, 0, 0, 0, 3, 0, 1,
+    0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 1, 0, 0, 0, 0, 3, 0, 1, 2, 0, 0, 0, 0, 3, 0, 1,
+    0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 2,
+    0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 1, 0, 0, 0, 0, 3, 0, 2, 2, 0, 0, 0, 0, 3, 0, 2,
+    0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 0,
+    0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0,
+    3, 0, 0, 0, 0, 3, 0, 0, 4, 0, 0, 0, 0, 3, 0, 0, 5, 0, 0, 0, 0, 3, 0, 0, 6, 0, 0, 0, 0, 3, 0, 0,
+    1, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0,
+    2, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0,
+    0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0,
+    0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0,
+    0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0,
+    0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 7, 0,
+    0, 0, 0, 0, 0, 4, 8, 0, 0, 0, 0, 0, 0, 4, 9, 0, 0, 0, 0, 0, 0, 4, 10, 0, 0, 0, 0, 0, 0, 4, 11,
+    0, 0, 0, 0, 0, 0, 4, 12, 0, 0, 0, 0, 0, 0, 4, 13, 0, 0, 0, 0, 0, 0, 4, 14, 0, 0, 0, 0, 0, 0, 4,
+    3, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 4,
+    4, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 4,
+    5, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 4,
+    6, 0, 0, 0, 0, 0, 0, 4, 6, 0, 0, 0, 0, 0, 0, 4, 6, 0, 0, 0, 0, 0, 0, 4, 6, 0, 0, 0, 0, 0, 0, 4,
+    1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4,
+    1, 1, 0, 0, 0, 0, 0, 4, 1, 1, 0, 0, 0, 0, 0, 4, 1, 2, 0, 0, 0, 0, 0, 4, 1, 2, 0, 0, 0, 0, 0, 4,
+    1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 1, 0, 0, 0, 0, 4, 1, 0, 2, 0, 0, 0, 0, 4,
+    1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4,
+    2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4,
+    2, 1, 0, 0, 0, 0, 0, 4, 2, 1, 0, 0, 0, 0, 0, 4, 2, 2, 0, 0, 0, 0, 0, 4, 2, 2, 0, 0, 0, 0, 0, 4,
+    2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 1, 0, 0, 0, 0, 4, 2, 0, 2, 0, 0, 0, 0, 4,
+    2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 3, 0, 0, 0, 0, 0, 4, 0, 3, 0, 0, 0, 0, 0, 4, 0, 4, 0, 0, 0, 0, 0, 4, 0, 4, 0, 0, 0, 0, 0, 4,
+    0, 5, 0, 0, 0, 0, 0, 4, 0, 5, 0, 0, 0, 0, 0, 4, 0, 6, 0, 0, 0, 0, 0, 4, 0, 6, 0, 0, 0, 0, 0, 4,
+    0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 1, 0, 0, 0, 0, 4, 0, 1, 2, 0, 0, 0, 0, 4,
+    0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4,
+    0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 1, 0, 0, 0, 0, 4, 0, 2, 2, 0, 0, 0, 0, 4,
+    0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 3, 0, 0, 0, 0, 4, 0, 0, 4, 0, 0, 0, 0, 4, 0, 0, 5, 0, 0, 0, 0, 4, 0, 0, 6, 0, 0, 0, 0, 4,
+    0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 4,
+    0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 5,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5,
+    7, 0, 0, 0, 0, 0, 0, 5, 8, 0, 0, 0, 0, 0, 0, 5, 9, 0, 0, 0, 0, 0, 0, 5, 10, 0, 0, 0, 0, 0, 0,
+    5, 11, 0, 0, 0, 0, 0, 0, 5, 12, 0, 0, 0, 0, 0, 0, 5, 13, 0, 0, 0, 0, 0, 0, 5, 14, 0, 0, 0, 0,
+    0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0,
+    0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0,
+    0, 0, 5, 5, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0, 0,
+    0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 5, 6, 0, 0, 0, 0,
+    0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0,
+    0, 0, 5, 1, 1, 0, 0, 0, 0, 0, 5, 1, 1, 0, 0, 0, 0, 0, 5, 1, 2, 0, 0, 0, 0, 0, 5, 1, 2, 0, 0, 0,
+    0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 1, 0, 0, 0, 0, 5, 1, 0, 2, 0, 0,
+    0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0,
+    0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0,
+    0, 0, 5, 2, 1, 0, 0, 0, 0, 0, 5, 2, 1, 0, 0, 0, 0, 0, 5, 2, 2, 0, 0, 0, 0, 0, 5, 2, 2, 0, 0, 0,
+    0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 1, 0, 0, 0, 0, 5, 2, 0, 2, 0, 0,
+    0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0,
+    0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0,
+    0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0,
+    0, 0, 5, 0, 3, 0, 0, 0, 0, 0, 5, 0, 3, 0, 0, 0, 0, 0, 5, 0, 4, 0, 0, 0, 0, 0, 5, 0, 4, 0, 0, 0,
+    0, 0, 5, 0, 5, 0, 0, 0, 0, 0, 5, 0, 5, 0, 0, 0, 0, 0, 5, 0, 6, 0, 0, 0, 0, 0, 5, 0, 6, 0, 0, 0,
+    0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 1, 0, 0, 0, 0, 5, 0, 1, 2, 0, 0,
+    0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0,
+    0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 5, 0, 2, 1, 0, 0, 0, 0, 5, 0, 2, 2, 0, 0,
+    0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0,
+    0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0,
+    0, 0, 5, 0, 0, 3, 0, 0, 0, 0, 5, 0, 0, 4, 0, 0, 0, 0, 5, 0, 0, 5, 0, 0, 0, 0, 5, 0, 0, 6, 0, 0,
+    0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0,
+    0, 0, 5, 0, 0, 2, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0,
+    0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0,
+    0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 2, 0, 0, 0, 5, 0, 0, 0, 2, 0,
+    0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 2,
+    0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0,
+    0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0,
+    0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0,
+    0, 0, 6, 7, 0, 0, 0, 0, 0, 0, 6, 8, 0, 0, 0, 0, 0, 0, 6, 9, 0, 0, 0, 0, 0, 0, 6, 10, 0, 0, 0,
+    0, 0, 0, 6, 11, 0, 0, 0, 0, 0, 0, 6, 12, 0, 0, 0, 0, 0, 0, 6, 13, 0, 0, 0, 0, 0, 0, 6, 14, 0,
+    0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 6, 3, 0,
+    0, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 6, 4, 0,
+    0, 0, 0, 0, 0, 6, 5, 0, 0, 0, 0, 0, 0, 6, 5, 0, 0, 0, 0, 0, 0, 6, 5, 0, 0, 0, 0, 0, 0, 6, 5, 0,
+    0, 0, 0, 0, 0, 6, 6, 0, 0, 0, 0, 0, 0, 6, 6, 0, 0, 0, 0, 0, 0, 6, 6, 0, 0, 0, 0, 0, 0, 6, 6, 0,
+    0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0,
+    0, 0, 0, 0, 0, 6, 1, 1, 0, 0, 0, 0, 0, 6, 1, 1, 0, 0, 0, 0, 0, 6, 1, 2, 0, 0, 0, 0, 0, 6, 1, 2,
+    0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 1, 0, 0, 0, 0, 6, 1, 0,
+    2, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0,
+    0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0,
+    0, 0, 0, 0, 0, 6, 2, 1, 0, 0, 0, 0, 0, 6, 2, 1, 0, 0, 0, 0, 0, 6, 2, 2, 0, 0, 0, 0, 0, 6, 2, 2,
+    0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 1, 0, 0, 0, 0, 6, 2, 0,
+    2, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0,
+    0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0,
+    0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0,
+    0, 0, 0, 0, 0, 6, 0, 3, 0, 0, 0, 0, 0, 6, 0, 3, 0, 0, 0, 0, 0, 6, 0, 4, 0, 0, 0, 0, 0, 6, 0, 4,
+    0, 0, 0, 0, 0, 6, 0, 5, 0, 0, 0, 0, 0, 6, 0, 5, 0, 0, 0, 0, 0, 6, 0, 6, 0, 0, 0, 0, 0, 6, 0, 6,
+    0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 1, 0, 0, 0, 0, 6, 0, 1,
+    2, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1,
+    0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 6, 0, 2, 1, 0, 0, 0, 0, 6, 0, 2,
+    2, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 6, 0, 2,
+    0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0,
+    0, 0, 0, 0, 0, 6, 0, 0, 3, 0, 0, 0, 0, 6, 0, 0, 4, 0, 0, 0, 0, 6, 0, 0, 5, 0, 0, 0, 0, 6, 0, 0,
+    6, 0, 0, 0, 0, 6, 0, 0, 1, 0, 0, 0, 0, 6, 0, 0, 1, 0, 0, 0, 0, 6, 0, 0, 1, 0, 0, 0, 0, 6, 0, 0,
+    1, 0, 0, 0, 0, 6, 0, 0, 2, 0, 0, 0, 0, 6, 0, 0, 2, 0, 0, 0, 0, 6, 0, 0, 2, 0, 0, 0, 0, 6, 0, 0,
+    2, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0,
+    0, 0, 0, 0, 0, 6, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 2, 0, 0, 0, 6, 0, 0,
+    0, 2, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0,
+    0, 0, 2, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 1, 15, 0, 0, 0, 0, 0, 0, 1, 16, 0, 0, 0, 0, 0, 0, 1, 17, 0, 0, 0, 0, 0, 0, 1,
+    18, 0, 0, 0, 0, 0, 0, 1, 19, 0, 0, 0, 0, 0, 0, 1, 20, 0, 0, 0, 0, 0, 0, 1, 21, 0, 0, 0, 0, 0,
+    0, 1, 22, 0, 0, 0, 0, 0, 0, 1, 23, 0, 0, 0, 0, 0, 0, 1, 24, 0, 0, 0, 0, 0, 0, 1, 25, 0, 0, 0,
+    0, 0, 0, 1, 26, 0, 0, 0, 0, 0, 0, 1, 27, 0, 0, 0, 0, 0, 0, 1, 28, 0, 0, 0, 0, 0, 0, 1, 29, 0,
+    0, 0, 0, 0, 0, 1, 30, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 1, 7,
+    0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 1, 8,
+    0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 0, 1, 9,
+    0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 0, 1, 10, 0, 0, 0, 0, 0, 0, 1, 10, 0, 0, 0, 0, 0, 0, 1,
+    10, 0, 0, 0, 0, 0, 0, 1, 10, 0, 0, 0, 0, 0, 0, 1, 11, 0, 0, 0, 0, 0, 0, 1, 11, 0, 0, 0, 0, 0,
+    0, 1, 11, 0, 0, 0, 0, 0, 0, 1, 11, 0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0,
+    0, 0, 0, 1, 12, 0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0, 0, 0, 0, 1, 13, 0, 0, 0, 0, 0, 0, 1, 13, 0,
+    0, 0, 0, 0, 0, 1, 13, 0, 0, 0, 0, 0, 0, 1, 13, 0, 0, 0, 0, 0, 0, 1, 14, 0, 0, 0, 0, 0, 0, 1,
+    14, 0, 0, 0, 0, 0, 0, 1, 14, 0, 0, 0, 0, 0, 0, 1, 14, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0,
+    1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0,
+    1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0,
+    1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 1, 3, 0, 2, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0,
+    1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0,
+    1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 0,
+    1, 4, 1, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0,
+    1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 1, 0, 0, 0, 0, 1, 4, 0, 2, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0,
+    1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0,
+    1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 0,
+    1, 5, 1, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0,
+    1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 1, 0, 0, 0, 0, 1, 5, 0, 2, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0,
+    1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0,
+    1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 1, 0, 0, 0, 0, 0,
+    1, 6, 1, 0, 0, 0, 0, 0, 1, 6, 2, 0, 0, 0, 0, 0, 1, 6, 2, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0,
+    1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 1, 0, 0, 0, 0, 1, 6, 0, 2, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0,
+    1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0,
+    1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0, 0,
+    1, 1, 5, 0, 0, 0, 0, 0, 1, 1, 6, 0, 0, 0, 0, 0, 1, 1, 6, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0,
+    1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 2, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0,
+    1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0,
+    1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 0, 1, 1, 2, 2, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0,
+    1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 0,
+    1, 1, 0, 4, 0, 0, 0, 0, 1, 1, 0, 5, 0, 0, 0, 0, 1, 1, 0, 6, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0,
+    1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0,
+    1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0,
+    1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0,
+    1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0,
+    1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0,
+    1, 2, 3, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 0, 1, 2, 5, 0, 0, 0, 0, 0,
+    1, 2, 5, 0, 0, 0, 0, 0, 1, 2, 6, 0, 0, 0, 0, 0, 1, 2, 6, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0,
+    1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 0, 1, 2, 1, 2, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0,
+    1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0,
+    1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 1, 0, 0, 0, 0, 1, 2, 2, 2, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0,
+    1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0,
+    1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 3, 0, 0, 0, 0,
+    1, 2, 0, 4, 0, 0, 0, 0, 1, 2, 0, 5, 0, 0, 0, 0, 1, 2, 0, 6, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0,
+    1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0,
+    1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0,
+    1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0,
+    1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0,
+    1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0,
+    1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 7, 0, 0, 0, 0, 0,
+    1, 0, 7, 0, 0, 0, 0, 0, 1, 0, 8, 0, 0, 0, 0, 0, 1, 0, 8, 0, 0, 0, 0, 0, 1, 0, 9, 0, 0, 0, 0, 0,
+    1, 0, 9, 0, 0, 0, 0, 0, 1, 0, 10, 0, 0, 0, 0, 0, 1, 0, 10, 0, 0, 0, 0, 0, 1, 0, 11, 0, 0, 0, 0,
+    0, 1, 0, 11, 0, 0, 0, 0, 0, 1, 0, 12, 0, 0, 0, 0, 0, 1, 0, 12, 0, 0, 0, 0, 0, 1, 0, 13, 0, 0,
+    0, 0, 0, 1, 0, 13, 0, 0, 0, 0, 0, 1, 0, 14, 0, 0, 0, 0, 0, 1, 0, 14, 0, 0, 0, 0, 0, 1, 0, 3, 0,
+    0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 0, 1, 0, 3, 2, 0, 0, 0, 0, 1, 0, 3, 0,
+    0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 4, 0,
+    0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 1, 0, 0, 0, 0, 1, 0, 4, 2, 0, 0, 0, 0, 1, 0, 4, 0,
+    0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 5, 0,
+    0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 5, 1, 0, 0, 0, 0, 1, 0, 5, 2, 0, 0, 0, 0, 1, 0, 5, 0,
+    0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 6, 0,
+    0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 0, 6, 1, 0, 0, 0, 0, 1, 0, 6, 2, 0, 0, 0, 0, 1, 0, 6, 0,
+    0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 0, 1, 0,
+    0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 3,
+    0, 0, 0, 0, 1, 0, 1, 4, 0, 0, 0, 0, 1, 0, 1, 5, 0, 0, 0, 0, 1, 0, 1, 6, 0, 0, 0, 0, 1, 0, 1, 1,
+    0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 2,
+    0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 0,
+    0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0,
+    1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0,
+    0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1, 0, 1, 0,
+    0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 2, 0,
+    0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 3,
+    0, 0, 0, 0, 1, 0, 2, 4, 0, 0, 0, 0, 1, 0, 2, 5, 0, 0, 0, 0, 1, 0, 2, 6, 0, 0, 0, 0, 1, 0, 2, 1,
+    0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 2,
+    0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 0,
+    0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0,
+    1, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 1, 0, 2, 0,
+    0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 1, 0, 2, 0,
+    0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 7,
+    0, 0, 0, 0, 1, 0, 0, 8, 0, 0, 0, 0, 1, 0, 0, 9, 0, 0, 0, 0, 1, 0, 0, 10, 0, 0, 0, 0, 1, 0, 0,
+    11, 0, 0, 0, 0, 1, 0, 0, 12, 0, 0, 0, 0, 1, 0, 0, 13, 0, 0, 0, 0, 1, 0, 0, 14, 0, 0, 0, 0, 1,
+    0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1,
+    0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 1,
+    0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 1,
+    0, 0, 6, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 1,
+    0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1,
+    0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1,
+    0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1,
+    0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1,
+    0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1,
+    0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 1,
+    0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 2, 0, 0, 1,
+    0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 1,
+    0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 1,
+    0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1,
+    0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1,
+    0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 1,
+    0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 6, 0, 0, 1,
+    0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1,
+    0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    15, 0, 0, 0, 0, 0, 0, 2, 16, 0, 0, 0, 0, 0, 0, 2, 17, 0, 0, 0, 0, 0, 0, 2, 18, 0, 0, 0, 0, 0,
+    0, 2, 19, 0, 0, 0, 0, 0, 0, 2, 20, 0, 0, 0, 0, 0, 0, 2, 21, 0, 0, 0, 0, 0, 0, 2, 22, 0, 0, 0,
+    0, 0, 0, 2, 23, 0, 0, 0, 0, 0, 0, 2, 24, 0, 0, 0, 0, 0, 0, 2, 25, 0, 0, 0, 0, 0, 0, 2, 26, 0,
+    0, 0, 0, 0, 0, 2, 27, 0, 0, 0, 0, 0, 0, 2, 28, 0, 0, 0, 0, 0, 0, 2, 29, 0, 0, 0, 0, 0, 0, 2,
+    30, 0, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 0,
+    2, 7, 0, 0, 0, 0, 0, 0, 2, 8, 0, 0, 0, 0, 0, 0, 2, 8, 0, 0, 0, 0, 0, 0, 2, 8, 0, 0, 0, 0, 0, 0,
+    2, 8, 0, 0, 0, 0, 0, 0, 2, 9, 0, 0, 0, 0, 0, 0, 2, 9, 0, 0, 0, 0, 0, 0, 2, 9, 0, 0, 0, 0, 0, 0,
+    2, 9, 0, 0, 0, 0, 0, 0, 2, 10, 0, 0, 0, 0, 0, 0, 2, 10, 0, 0, 0, 0, 0, 0, 2, 10, 0, 0, 0, 0, 0,
+    0, 2, 10, 0, 0, 0, 0, 0, 0, 2, 11, 0, 0, 0, 0, 0, 0, 2, 11, 0, 0, 0, 0, 0, 0, 2, 11, 0, 0, 0,
+    0, 0, 0, 2, 11, 0, 0, 0, 0, 0, 0, 2, 12, 0, 0, 0, 0, 0, 0, 2, 12, 0, 0, 0, 0, 0, 0, 2, 12, 0,
+    0, 0, 0, 0, 0, 2, 12, 0, 0, 0, 0, 0, 0, 2, 13, 0, 0, 0, 0, 0, 0, 2, 13, 0, 0, 0, 0, 0, 0, 2,
+    13, 0, 0, 0, 0, 0, 0, 2, 13, 0, 0, 0, 0, 0, 0, 2, 14, 0, 0, 0, 0, 0, 0, 2, 14, 0, 0, 0, 0, 0,
+    0, 2, 14, 0, 0, 0, 0, 0, 0, 2, 14, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0,
+    0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0,
+    0, 0, 2, 3, 2, 0, 0, 0, 0, 0, 2, 3, 2, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0,
+    0, 0, 2, 3, 0, 1, 0, 0, 0, 0, 2, 3, 0, 2, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0,
+    0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0,
+    0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 1, 0, 0, 0, 0, 0, 2, 4, 1, 0, 0, 0,
+    0, 0, 2, 4, 2, 0, 0, 0, 0, 0, 2, 4, 2, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0,
+    0, 0, 2, 4, 0, 1, 0, 0, 0, 0, 2, 4, 0, 2, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0,
+    0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0,
+    0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 1, 0, 0, 0, 0, 0, 2, 5, 1, 0, 0, 0,
+    0, 0, 2, 5, 2, 0, 0, 0, 0, 0, 2, 5, 2, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0,
+    0, 0, 2, 5, 0, 1, 0, 0, 0, 0, 2, 5, 0, 2, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0,
+    0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0,
+    0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 1, 0, 0, 0, 0, 0, 2, 6, 1, 0, 0, 0,
+    0, 0, 2, 6, 2, 0, 0, 0, 0, 0, 2, 6, 2, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0,
+    0, 0, 2, 6, 0, 1, 0, 0, 0, 0, 2, 6, 0, 2, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0,
+    0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+    0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+    0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 3, 0, 0, 0, 0, 0, 2, 1, 3, 0, 0, 0,
+    0, 0, 2, 1, 4, 0, 0, 0, 0, 0, 2, 1, 4, 0, 0, 0, 0, 0, 2, 1, 5, 0, 0, 0, 0, 0, 2, 1, 5, 0, 0, 0,
+    0, 0, 2, 1, 6, 0, 0, 0, 0, 0, 2, 1, 6, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0,
+    0, 0, 2, 1, 1, 1, 0, 0, 0, 0, 2, 1, 1, 2, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0,
+    0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0,
+    0, 0, 2, 1, 2, 1, 0, 0, 0, 0, 2, 1, 2, 2, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0,
+    0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+    0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 3, 0, 0, 0, 0, 2, 1, 0, 4, 0, 0,
+    0, 0, 2, 1, 0, 5, 0, 0, 0, 0, 2, 1, 0, 6, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0,
+    0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0,
+    0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+    0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 1, 0,
+    0, 0, 2, 1, 0, 0, 2, 0, 0, 0, 2, 1, 0, 0, 2, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+    0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 2, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+    0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0,
+    0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0,
+    0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 3, 0, 0, 0, 0, 0, 2, 2, 3, 0, 0, 0,
+    0, 0, 2, 2, 4, 0, 0, 0, 0, 0, 2, 2, 4, 0, 0, 0, 0, 0, 2, 2, 5, 0, 0, 0, 0, 0, 2, 2, 5, 0, 0, 0,
+    0, 0, 2, 2, 6, 0, 0, 0, 0, 0, 2, 2, 6, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0,
+    0, 0, 2, 2, 1, 1, 0, 0, 0, 0, 2, 2, 1, 2, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0,
+    0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0,
+    0, 0, 2, 2, 2, 1, 0, 0, 0, 0, 2, 2, 2, 2, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0,
+    0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0,
+    0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 3, 0, 0, 0, 0, 2, 2, 0, 4, 0, 0,
+    0, 0, 2, 2, 0, 5, 0, 0, 0, 0, 2, 2, 0, 6, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0,
+    0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0,
+    0, 0, 2, 2, 0, 2, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0,
+    0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 1, 0,
+    0, 0, 2, 2, 0, 0, 2, 0, 0, 0, 2, 2, 0, 0, 2, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0,
+    0, 0, 2, 2, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 2, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0,
+    0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 7, 0, 0, 0, 0, 0, 2, 0, 7, 0, 0, 0,
+    0, 0, 2, 0, 8, 0, 0, 0, 0, 0, 2, 0, 8, 0, 0, 0, 0, 0, 2, 0, 9, 0, 0, 0, 0, 0, 2, 0, 9, 0, 0, 0,
+    0, 0, 2, 0, 10, 0, 0, 0, 0, 0, 2, 0, 10, 0, 0, 0, 0, 0, 2, 0, 11, 0, 0, 0, 0, 0, 2, 0, 11, 0,
+    0, 0, 0, 0, 2, 0, 12, 0, 0, 0, 0, 0, 2, 0, 12, 0, 0, 0, 0, 0, 2, 0, 13, 0, 0, 0, 0, 0, 2, 0,
+    13, 0, 0, 0, 0, 0, 2, 0, 14, 0, 0, 0, 0, 0, 2, 0, 14, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2,
+    0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 1, 0, 0, 0, 0, 2, 0, 3, 2, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2,
+    0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2,
+    0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 1, 0, 0, 0, 0, 2, 0, 4, 2, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2,
+    0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 2,
+    0, 5, 0, 0, 0, 0, 0, 2, 0, 5, 1, 0, 0, 0, 0, 2, 0, 5, 2, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 2,
+    0, 5, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 2,
+    0, 6, 0, 0, 0, 0, 0, 2, 0, 6, 1, 0, 0, 0, 0, 2, 0, 6, 2, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 2,
+    0, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2,
+    0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 3, 0, 0, 0, 0, 2,
+    0, 1, 4, 0, 0, 0, 0, 2, 0, 1, 5, 0, 0, 0, 0, 2, 0, 1, 6, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2,
+    0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 2,
+    0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2,
+    0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2,
+    0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2,
+    0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 2, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2,
+    0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2,
+    0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 3, 0, 0, 0, 0, 2,
+    0, 2, 4, 0, 0, 0, 0, 2, 0, 2, 5, 0, 0, 0, 0, 2, 0, 2, 6, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 2,
+    0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 2,
+    0, 2, 2, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2,
+    0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 1, 0, 0, 0, 2,
+    0, 2, 0, 1, 0, 0, 0, 2, 0, 2, 0, 2, 0, 0, 0, 2, 0, 2, 0, 2, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2,
+    0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 1, 0, 0, 2, 0, 2, 0, 0, 2, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2,
+    0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 7, 0, 0, 0, 0, 2,
+    0, 0, 8, 0, 0, 0, 0, 2, 0, 0, 9, 0, 0, 0, 0, 2, 0, 0, 10, 0, 0, 0, 0, 2, 0, 0, 11, 0, 0, 0, 0,
+    2, 0, 0, 12, 0, 0, 0, 0, 2, 0, 0, 13, 0, 0, 0, 0, 2, 0, 0, 14, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0,
+    0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0,
+    0, 2, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0,
+    0, 2, 0, 0, 5, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 0, 2, 0, 0, 6, 0, 0, 0,
+    0, 2, 0, 0, 6, 0, 0, 0, 0, 2, 0, 0, 6, 0, 0, 0, 0, 2, 0, 0, 6, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0,
+    0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0,
+    0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0,
+    0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 1, 0, 0, 0,
+    0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0,
+    0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 1, 0, 0,
+    0, 2, 0, 0, 2, 1, 0, 0, 0, 2, 0, 0, 2, 2, 0, 0, 0, 2, 0, 0, 2, 2, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0,
+    0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 1, 0, 0, 2, 0, 0, 2, 0, 2, 0, 0, 2, 0, 0, 2, 0, 0, 0,
+    0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0,
+    0, 2, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 5, 0, 0,
+    0, 2, 0, 0, 0, 5, 0, 0, 0, 2, 0, 0, 0, 6, 0, 0, 0, 2, 0, 0, 0, 6, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0,
+    0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 1, 0, 0,
+    0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0,
+    0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 1, 0, 0, 2, 0, 0, 0, 2, 2, 0, 0, 2, 0, 0, 0, 2, 0, 0,
+    0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 3, 0,
+    0, 2, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 0, 6, 0, 0, 2, 0, 0, 0, 0, 1, 0,
+    0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 2, 0,
+    0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 1,
+    0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0,
+    0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0,
+    0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 39,
+    0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0,
+    43, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0,
+    0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0,
+    0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 54, 0,
+    0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0,
+    58, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0,
+    0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0,
+    0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 16, 0,
+    0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0,
+    17, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0,
+    0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0,
+    0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 20, 0,
+    0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0,
+    21, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0,
+    0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0,
+    0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 24, 0,
+    0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0,
+    25, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0,
+    0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0,
+    0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 27, 0,
+    0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0,
+    28, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0,
+    0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0,
+    0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0,
+    0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 7, 2, 0,
+    0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 1,
+    0, 0, 0, 0, 0, 7, 0, 2, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0,
+    0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0,
+    0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 8, 2, 0,
+    0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 1,
+    0, 0, 0, 0, 0, 8, 0, 2, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0,
+    0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0,
+    0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 9, 2, 0,
+    0, 0, 0, 0, 0, 9, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 1,
+    0, 0, 0, 0, 0, 9, 0, 2, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0,
+    0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0,
+    0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 0,
+    10, 2, 0, 0, 0, 0, 0, 0, 10, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0,
+    0, 0, 10, 0, 1, 0, 0, 0, 0, 0, 10, 0, 2, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0,
+    0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0,
+    0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 1, 0, 0, 0, 0, 0, 0,
+    11, 1, 0, 0, 0, 0, 0, 0, 11, 2, 0, 0, 0, 0, 0, 0, 11, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0,
+    0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 1, 0, 0, 0, 0, 0, 11, 0, 2, 0, 0, 0, 0, 0, 11, 0, 0, 0,
+    0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 12, 0,
+    0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0,
+    12, 1, 0, 0, 0, 0, 0, 0, 12, 1, 0, 0, 0, 0, 0, 0, 12, 2, 0, 0, 0, 0, 0, 0, 12, 2, 0, 0, 0, 0,
+    0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 1, 0, 0, 0, 0, 0, 12, 0, 2, 0,
+    0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0,
+    0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0,
+    13, 0, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 13, 2, 0, 0, 0, 0,
+    0, 0, 13, 2, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 1, 0,
+    0, 0, 0, 0, 13, 0, 2, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0,
+    0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0,
+    14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 1, 0, 0, 0, 0, 0, 0, 14, 1, 0, 0, 0, 0,
+    0, 0, 14, 2, 0, 0, 0, 0, 0, 0, 14, 2, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0,
+    0, 0, 0, 0, 14, 0, 1, 0, 0, 0, 0, 0, 14, 0, 2, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0,
+    0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3,
+    3, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 3,
+    5, 0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3,
+    1, 0, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3,
+    1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3,
+    2, 0, 0, 0, 0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 0, 3, 2, 2, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3,
+    2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 3,
+    0, 4, 0, 0, 0, 0, 0, 3, 0, 5, 0, 0, 0, 0, 0, 3, 0, 6, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3,
+    0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3,
+    0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3,
+    0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 4,
+    3, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 4,
+    5, 0, 0, 0, 0, 0, 0, 4, 6, 0, 0, 0, 0, 0, 0, 4, 6, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4,
+    1, 0, 0, 0, 0, 0, 0, 4, 1, 1, 0, 0, 0, 0, 0, 4, 1, 2, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4,
+    1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4,
+    2, 0, 0, 0, 0, 0, 0, 4, 2, 1, 0, 0, 0, 0, 0, 4, 2, 2, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4,
+    2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 3, 0, 0, 0, 0, 0, 4,
+    0, 4, 0, 0, 0, 0, 0, 4, 0, 5, 0, 0, 0, 0, 0, 4, 0, 6, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4,
+    0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4,
+    0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 4,
+    0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 5,
+    3, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 0, 5,
+    5, 0, 0, 0, 0, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5,
+    1, 0, 0, 0, 0, 0, 0, 5, 1, 1, 0, 0, 0, 0, 0, 5, 1, 2, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5,
+    1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5,
+    2, 0, 0, 0, 0, 0, 0, 5, 2, 1, 0, 0, 0, 0, 0, 5, 2, 2, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5,
+    2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 3, 0, 0, 0, 0, 0, 5,
+    0, 4, 0, 0, 0, 0, 0, 5, 0, 5, 0, 0, 0, 0, 0, 5, 0, 6, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5,
+    0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 5,
+    0, 2, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 5,
+    0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 2, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6,
+    0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6,
+    0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 6,
+    3, 0, 0, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 6, 5, 0, 0, 0, 0, 0, 0, 6,
+    5, 0, 0, 0, 0, 0, 0, 6, 6, 0, 0, 0, 0, 0, 0, 6, 6, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6,
+    1, 0, 0, 0, 0, 0, 0, 6, 1, 1, 0, 0, 0, 0, 0, 6, 1, 2, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6,
+    1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6,
+    2, 0, 0, 0, 0, 0, 0, 6, 2, 1, 0, 0, 0, 0, 0, 6, 2, 2, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6,
+    2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6,
+    0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 3, 0, 0, 0, 0, 0, 6,
+    0, 4, 0, 0, 0, 0, 0, 6, 0, 5, 0, 0, 0, 0, 0, 6, 0, 6, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6,
+    0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 6,
+    0, 2, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6,
+    0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 1, 0, 0, 0, 0, 6,
+    0, 0, 1, 0, 0, 0, 0, 6, 0, 0, 2, 0, 0, 0, 0, 6, 0, 0, 2, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6,
+    0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 2, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6,
+    0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 1,
+    7, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 0, 1,
+    9, 0, 0, 0, 0, 0, 0, 1, 10, 0, 0, 0, 0, 0, 0, 1, 10, 0, 0, 0, 0, 0, 0, 1, 11, 0, 0, 0, 0, 0, 0,
+    1, 11, 0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0, 0, 0, 0, 1, 13, 0, 0, 0, 0,
+    0, 0, 1, 13, 0, 0, 0, 0, 0, 0, 1, 14, 0, 0, 0, 0, 0, 0, 1, 14, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0,
+    0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0,
+    0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0,
+    0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0,
+    0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0,
+    0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0,
+    0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0,
+    0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 1, 0, 0, 0, 0, 0, 1, 6, 2, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0,
+    0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+    0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0,
+    0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0, 0, 1, 1, 6, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0,
+    0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0,
+    0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+    0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0,
+    0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+    0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 0,
+    0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0,
+    0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0,
+    0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 0, 1, 2, 5, 0, 0, 0, 0, 0, 1, 2, 6, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0,
+    0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0,
+    0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0,
+    0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 1, 0,
+    0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 0, 0,
+    0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 0,
+    0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 7, 0, 0,
+    0, 0, 0, 1, 0, 8, 0, 0, 0, 0, 0, 1, 0, 9, 0, 0, 0, 0, 0, 1, 0, 10, 0, 0, 0, 0, 0, 1, 0, 11, 0,
+    0, 0, 0, 0, 1, 0, 12, 0, 0, 0, 0, 0, 1, 0, 13, 0, 0, 0, 0, 0, 1, 0, 14, 0, 0, 0, 0, 0, 1, 0, 3,
+    0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 4,
+    0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 5,
+    0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 6,
+    0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 0, 1,
+    0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1,
+    1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1,
+    0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1,
+    0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 2,
+    0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2,
+    1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2,
+    0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 1, 0, 2,
+    0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0,
+    5, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 1, 0, 0,
+    1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0,
+    1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0,
+    2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 1, 0, 0,
+    2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 3, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 1, 0, 0,
+    0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0,
+    0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 7, 0,
+    0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 0, 2, 8, 0, 0, 0, 0, 0, 0, 2, 8, 0, 0, 0, 0, 0, 0, 2, 9, 0,
+    0, 0, 0, 0, 0, 2, 9, 0, 0, 0, 0, 0, 0, 2, 10, 0, 0, 0, 0, 0, 0, 2, 10, 0, 0, 0, 0, 0, 0, 2, 11,
+    0, 0, 0, 0, 0, 0, 2, 11, 0, 0, 0, 0, 0, 0, 2, 12, 0, 0, 0, 0, 0, 0, 2, 12, 0, 0, 0, 0, 0, 0, 2,
+    13, 0, 0, 0, 0, 0, 0, 2, 13, 0, 0, 0, 0, 0, 0, 2, 14, 0, 0, 0, 0, 0, 0, 2, 14, 0, 0, 0, 0, 0,
+    0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 2, 3, 2, 0, 0, 0, 0,
+    0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0,
+    0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 1, 0, 0, 0, 0, 0, 2, 4, 2, 0, 0, 0, 0,
+    0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0,
+    0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 1, 0, 0, 0, 0, 0, 2, 5, 2, 0, 0, 0, 0,
+    0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0,
+    0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 1, 0, 0, 0, 0, 0, 2, 6, 2, 0, 0, 0, 0,
+    0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0,
+    0, 2, 1, 3, 0, 0, 0, 0, 0, 2, 1, 4, 0, 0, 0, 0, 0, 2, 1, 5, 0, 0, 0, 0, 0, 2, 1, 6, 0, 0, 0, 0,
+    0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0,
+    0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0,
+    0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 2, 0, 0,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0,
+    0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0,
+    0, 2, 2, 3, 0, 0, 0, 0, 0, 2, 2, 4, 0, 0, 0, 0, 0, 2, 2, 5, 0, 0, 0, 0, 0, 2, 2, 6, 0, 0, 0, 0,
+    0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0,
+    0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0,
+    0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0,
+    0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0,
+    0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 2, 0, 0,
+    0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 7, 0, 0, 0, 0, 0, 2, 0, 8, 0, 0, 0, 0, 0, 2, 0, 9, 0, 0, 0, 0, 0, 2, 0, 10, 0, 0, 0,
+    0, 0, 2, 0, 11, 0, 0, 0, 0, 0, 2, 0, 12, 0, 0, 0, 0, 0, 2, 0, 13, 0, 0, 0, 0, 0, 2, 0, 14, 0,
+    0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0,
+    0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0,
+    0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 2, 0, 5, 0,
+    0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0,
+    0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0,
+    0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2,
+    0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0,
+    2, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0,
+    0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0,
+    0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 2, 0, 2, 2,
+    0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 1, 0, 0, 0, 2, 0, 2, 0,
+    2, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 4,
+    0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 0, 2, 0, 0, 6, 0, 0, 0, 0, 2, 0, 0, 6,
+    0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1,
+    2, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1,
+    0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 1, 0, 0, 0, 2, 0, 0, 2,
+    2, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 5, 0, 0, 0, 2, 0, 0, 0,
+    6, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0,
+    1, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0,
+    2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0,
+    0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0,
+    0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 16,
+    0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0,
+    18, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0,
+    0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0,
+    0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 24, 0,
+    0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0,
+    26, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0,
+    0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0,
+    0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0,
+    0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0,
+    0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0,
+    0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0,
+    0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0,
+    0, 0, 0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 9, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0,
+    0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0,
+    0, 0, 0, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 0, 10, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0,
+    10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0,
+    0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 1, 0, 0, 0, 0, 0, 0, 11, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0,
+    0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 12, 0,
+    0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 1, 0, 0, 0, 0, 0, 0, 12, 2, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0,
+    0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 13, 2, 0, 0,
+    0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0,
+    0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 1, 0, 0, 0, 0, 0, 0,
+    14, 2, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0,
+    0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0,
+    0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0,
+    0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0,
+    0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0,
+    0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0,
+    0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0,
+    0, 0, 0, 4, 6, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0,
+    0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0,
+    0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0,
+    0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 1, 0,
+    0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0,
+    0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0,
+    0, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0,
+    0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0,
+    0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0,
+    0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0,
+    0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 1, 0,
+    0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0,
+    0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0,
+    0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 6, 5, 0, 0, 0,
+    0, 0, 0, 6, 6, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0,
+    0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0,
+    0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0,
+    0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0,
+    0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 1, 0,
+    0, 0, 0, 6, 0, 0, 2, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0,
+    0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0,
+    0, 0, 0, 1, 10, 0, 0, 0, 0, 0, 0, 1, 11, 0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0, 0, 0, 0, 1, 13, 0,
+    0, 0, 0, 0, 0, 1, 14, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3,
+    0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4,
+    0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5,
+    0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6,
+    0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1,
+    0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1,
+    2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1,
+    0, 1, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1,
+    0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2,
+    0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2,
+    2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2,
+    0, 1, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2,
+    0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0,
+    4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0,
+    6, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0,
+    1, 1, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0,
+    1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0,
+    2, 1, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0,
+    2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0,
+    0, 5, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0,
+    0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0,
+    0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0,
+    0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 0, 2, 8, 0, 0, 0, 0, 0, 0, 2, 9,
+    0, 0, 0, 0, 0, 0, 2, 10, 0, 0, 0, 0, 0, 0, 2, 11, 0, 0, 0, 0, 0, 0, 2, 12, 0, 0, 0, 0, 0, 0, 2,
+    13, 0, 0, 0, 0, 0, 0, 2, 14, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0,
+    2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0,
+    2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0,
+    2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0,
+    2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0,
+    2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0,
+    2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0,
+    2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0,
+    2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0,
+    2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0,
+    2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0,
+    2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0,
+    2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0,
+    2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0,
+    2, 0, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0,
+    2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0,
+    2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0,
+    2, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0,
+    2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 0,
+    2, 0, 0, 5, 0, 0, 0, 0, 2, 0, 0, 6, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0,
+    2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0,
+    2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0,
+    2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0,
+    0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0,
+    0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 24, 0,
+    0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0,
+    28, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0,
+    0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0,
+    0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0,
+    0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0,
+    0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0,
+    0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 12, 0,
+    0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0,
+    13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0,
+    0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
+    0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 2, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
+    0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0,
+    0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 2, 0,
+    0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0,
+    0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0,
+    0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 2, 0,
+    0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0,
+    0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0,
+    0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0,
+    0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 2, 0,
+    0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0,
+    0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0,
+    0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 2, 0,
+    0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+    0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 2, 0,
+    0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 6, 0,
+    0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0,
+    0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0,
+    2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0,
+    0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0,
+    0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 2, 0,
+    0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0,
+    0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 2, 0,
+    0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 2, 0, 6, 0,
+    0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0,
+    0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0,
+    2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0,
+    0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0,
+    0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0,
+    0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0,
+    0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0,
+    0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0,
+    0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0,
+    0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0,
+    0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0,
+    0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0,
+    0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+    0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0,
+    0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0,
+    0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0,
+    0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0,
+    0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0,
+    0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0,
+    0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0,
+    0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0,
+    0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0,
+    0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 13, 0,
+    0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6,
+    0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1,
+    2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 1, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2,
+    2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 1, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    2, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0,
+    0, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+///Table containing the total length of the codes decoded with a lookup
+pub const READ_LEN_L2M: &[u8] = &[
+    0, 1, 3, 2, 5, 4, 3, 3, 7, 6, 4, 5, 5, 4, 4, 4, 9, 8, 6, 7, 5, 5, 6, 6, 7, 6, 5, 5, 5, 5, 5, 5,
+    11, 10, 8, 9, 6, 7, 8, 8, 7, 6, 7, 6, 6, 7, 7, 7, 9, 8, 6, 7, 6, 6, 6, 6, 7, 6, 6, 6, 6, 6, 6,
+    6, 0, 12, 10, 11, 8, 9, 10, 10, 7, 7, 9, 8, 8, 9, 9, 9, 9, 8, 7, 7, 8, 8, 7, 7, 7, 7, 8, 8, 8,
+    8, 8, 8, 11, 10, 8, 9, 7, 7, 8, 8, 7, 7, 7, 7, 7, 7, 7, 7, 9, 8, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 0, 1, 12, 2, 10, 11, 12, 12, 8, 9, 11, 10, 10, 11, 11, 11, 9, 8, 9, 8, 10, 10, 9,
+    9, 8, 9, 10, 10, 10, 10, 10, 10, 11, 10, 8, 9, 9, 8, 8, 8, 8, 9, 8, 9, 9, 8, 8, 8, 9, 8, 9, 8,
+    9, 9, 9, 9, 8, 9, 9, 9, 9, 9, 9, 9, 0, 12, 10, 11, 8, 9, 10, 10, 8, 8, 9, 8, 8, 9, 9, 9, 9, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 11, 10, 8, 9, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 9,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 1, 3, 2, 12, 4, 3, 3, 10, 11, 4, 12, 12, 4, 4,
+    4, 9, 9, 11, 10, 12, 12, 11, 11, 10, 11, 12, 12, 12, 12, 12, 12, 11, 10, 9, 9, 11, 10, 9, 9,
+    10, 11, 10, 11, 11, 10, 10, 10, 9, 9, 11, 10, 11, 11, 11, 11, 10, 11, 11, 11, 11, 11, 11, 11,
+    0, 12, 10, 11, 9, 9, 10, 10, 10, 10, 9, 9, 9, 9, 9, 9, 9, 9, 10, 10, 9, 9, 10, 10, 10, 10, 9,
+    9, 9, 9, 9, 9, 11, 10, 9, 9, 10, 10, 9, 9, 10, 10, 10, 10, 10, 10, 10, 10, 9, 9, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 1, 12, 2, 10, 11, 12, 12, 9, 9, 11, 10, 10, 11,
+    11, 11, 9, 9, 9, 9, 10, 10, 9, 9, 9, 9, 10, 10, 10, 10, 10, 10, 11, 10, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 0, 12, 10, 11, 9, 9, 10, 10,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 11, 10, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 8, 0, 1, 3, 2, 5, 4, 3,
+    3, 12, 6, 4, 5, 5, 4, 4, 4, 10, 11, 6, 12, 5, 5, 6, 6, 12, 6, 5, 5, 5, 5, 5, 5, 11, 10, 11, 10,
+    6, 12, 11, 11, 12, 6, 12, 6, 6, 12, 12, 12, 10, 11, 6, 12, 6, 6, 6, 6, 12, 6, 6, 6, 6, 6, 6, 6,
+    0, 12, 10, 11, 11, 10, 10, 10, 12, 12, 10, 11, 11, 10, 10, 10, 10, 11, 12, 12, 11, 11, 12, 12,
+    12, 12, 11, 11, 11, 11, 11, 11, 11, 10, 11, 10, 12, 12, 11, 11, 12, 12, 12, 12, 12, 12, 12, 12,
+    10, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 0, 1, 12, 2, 10, 11, 12, 12,
+    11, 10, 11, 10, 10, 11, 11, 11, 10, 11, 10, 11, 10, 10, 10, 10, 11, 10, 10, 10, 10, 10, 10, 10,
+    11, 10, 11, 10, 10, 11, 11, 11, 11, 10, 11, 10, 10, 11, 11, 11, 10, 11, 10, 11, 10, 10, 10, 10,
+    11, 10, 10, 10, 10, 10, 10, 10, 0, 12, 10, 11, 11, 10, 10, 10, 11, 11, 10, 11, 11, 10, 10, 10,
+    10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 11, 10, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 8,
+    0, 1, 3, 2, 12, 4, 3, 3, 10, 11, 4, 12, 12, 4, 4, 4, 10, 10, 11, 10, 12, 12, 11, 11, 10, 11,
+    12, 12, 12, 12, 12, 12, 11, 10, 10, 10, 11, 10, 10, 10, 10, 11, 10, 11, 11, 10, 10, 10, 10, 10,
+    11, 10, 11, 11, 11, 11, 10, 11, 11, 11, 11, 11, 11, 11, 0, 12, 10, 11, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 11, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 0, 1, 12, 2, 10, 11, 12, 12, 10, 10, 11, 10, 10, 11, 11, 11, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 11, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 12,
+    10, 11, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 11, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 8, 0, 1, 3, 2, 5, 4, 3, 3, 7, 6, 4, 5, 5,
+    4, 4, 4, 12, 8, 6, 7, 5, 5, 6, 6, 7, 6, 5, 5, 5, 5, 5, 5, 11, 11, 8, 12, 6, 7, 8, 8, 7, 6, 7,
+    6, 6, 7, 7, 7, 12, 8, 6, 7, 6, 6, 6, 6, 7, 6, 6, 6, 6, 6, 6, 6, 0, 12, 11, 11, 8, 12, 11, 11,
+    7, 7, 12, 8, 8, 12, 12, 12, 12, 8, 7, 7, 8, 8, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 11, 11, 8, 12, 7,
+    7, 8, 8, 7, 7, 7, 7, 7, 7, 7, 7, 12, 8, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 1, 12, 2,
+    11, 11, 12, 12, 8, 12, 11, 11, 11, 11, 11, 11, 12, 8, 12, 8, 11, 11, 12, 12, 8, 12, 11, 11, 11,
+    11, 11, 11, 11, 11, 8, 12, 12, 8, 8, 8, 8, 12, 8, 12, 12, 8, 8, 8, 12, 8, 12, 8, 12, 12, 12,
+    12, 8, 12, 12, 12, 12, 12, 12, 12, 0, 12, 11, 11, 8, 12, 11, 11, 8, 8, 12, 8, 8, 12, 12, 12,
+    12, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 11, 11, 8, 12, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 12, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 1, 3, 2, 12, 4, 3, 3, 11, 11, 4, 12,
+    12, 4, 4, 4, 12, 12, 11, 11, 12, 12, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 11, 11, 12, 12,
+    11, 11, 12, 12, 11, 11, 11, 11, 11, 11, 11, 11, 12, 12, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 0, 12, 11, 11, 12, 12, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 12, 12, 11, 11,
+    12, 12, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 11, 11, 12, 12, 11, 11, 12, 12, 11, 11, 11, 11,
+    11, 11, 11, 11, 12, 12, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 0, 1, 12, 2,
+    11, 11, 12, 12, 12, 12, 11, 11, 11, 11, 11, 11, 12, 12, 12, 12, 11, 11, 12, 12, 12, 12, 11, 11,
+    11, 11, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 0, 12, 11, 11, 12, 12, 11, 11, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 11, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 8, 0, 1, 3, 2, 5, 4, 3, 3, 12, 6, 4, 5, 5, 4, 4, 4, 11, 11, 6, 12, 5, 5, 6, 6, 12,
+    6, 5, 5, 5, 5, 5, 5, 11, 11, 11, 11, 6, 12, 11, 11, 12, 6, 12, 6, 6, 12, 12, 12, 11, 11, 6, 12,
+    6, 6, 6, 6, 12, 6, 6, 6, 6, 6, 6, 6, 0, 12, 11, 11, 11, 11, 11, 11, 12, 12, 11, 11, 11, 11, 11,
+    11, 11, 11, 12, 12, 11, 11, 12, 12, 12, 12, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 12, 12, 11,
+    11, 12, 12, 12, 12, 12, 12, 12, 12, 11, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 0, 1, 12, 2, 11, 11, 12, 12, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 0, 12, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 8, 0, 1, 3, 2, 12, 4, 3, 3, 11, 11, 4, 12, 12, 4, 4, 4, 11, 11,
+    11, 11, 12, 12, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 0, 12,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 0, 1, 12, 2, 11, 11, 12, 12, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 10, 0, 12, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 10, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 10, 11, 11, 11, 10, 11, 11, 11, 11, 11, 10, 11, 10, 11, 11, 10, 10, 11, 10, 10, 8, 0, 1, 3,
+    2, 5, 4, 3, 3, 7, 6, 4, 5, 5, 4, 4, 4, 9, 8, 6, 7, 5, 5, 6, 6, 7, 6, 5, 5, 5, 5, 5, 5, 12, 10,
+    8, 9, 6, 7, 8, 8, 7, 6, 7, 6, 6, 7, 7, 7, 9, 8, 6, 7, 6, 6, 6, 6, 7, 6, 6, 6, 6, 6, 6, 6, 0,
+    12, 10, 12, 8, 9, 10, 10, 7, 7, 9, 8, 8, 9, 9, 9, 9, 8, 7, 7, 8, 8, 7, 7, 7, 7, 8, 8, 8, 8, 8,
+    8, 12, 10, 8, 9, 7, 7, 8, 8, 7, 7, 7, 7, 7, 7, 7, 7, 9, 8, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 0, 1, 12, 2, 10, 12, 12, 12, 8, 9, 12, 10, 10, 12, 12, 12, 9, 8, 9, 8, 10, 10, 9, 9, 8,
+    9, 10, 10, 10, 10, 10, 10, 12, 10, 8, 9, 9, 8, 8, 8, 8, 9, 8, 9, 9, 8, 8, 8, 9, 8, 9, 8, 9, 9,
+    9, 9, 8, 9, 9, 9, 9, 9, 9, 9, 0, 12, 10, 12, 8, 9, 10, 10, 8, 8, 9, 8, 8, 9, 9, 9, 9, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 12, 10, 8, 9, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 9, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 1, 3, 2, 12, 4, 3, 3, 10, 12, 4, 12, 12, 4, 4, 4, 9,
+    9, 12, 10, 12, 12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 12, 10, 9, 9, 12, 10, 9, 9, 10, 12,
+    10, 12, 12, 10, 10, 10, 9, 9, 12, 10, 12, 12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 0, 12,
+    10, 12, 9, 9, 10, 10, 10, 10, 9, 9, 9, 9, 9, 9, 9, 9, 10, 10, 9, 9, 10, 10, 10, 10, 9, 9, 9, 9,
+    9, 9, 12, 10, 9, 9, 10, 10, 9, 9, 10, 10, 10, 10, 10, 10, 10, 10, 9, 9, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 0, 1, 12, 2, 10, 12, 12, 12, 9, 9, 12, 10, 10, 12, 12, 12, 9,
+    9, 9, 9, 10, 10, 9, 9, 9, 9, 10, 10, 10, 10, 10, 10, 12, 10, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 0, 12, 10, 12, 9, 9, 10, 10, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 10, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 8, 0, 1, 3, 2, 5, 4, 3, 3, 12,
+    6, 4, 5, 5, 4, 4, 4, 10, 12, 6, 12, 5, 5, 6, 6, 12, 6, 5, 5, 5, 5, 5, 5, 12, 10, 12, 10, 6, 12,
+    12, 12, 12, 6, 12, 6, 6, 12, 12, 12, 10, 12, 6, 12, 6, 6, 6, 6, 12, 6, 6, 6, 6, 6, 6, 6, 0, 12,
+    10, 12, 12, 10, 10, 10, 12, 12, 10, 12, 12, 10, 10, 10, 10, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 10, 12, 10, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 0, 1, 12, 2, 10, 12, 12, 12, 12, 10,
+    12, 10, 10, 12, 12, 12, 10, 12, 10, 12, 10, 10, 10, 10, 12, 10, 10, 10, 10, 10, 10, 10, 12, 10,
+    12, 10, 10, 12, 12, 12, 12, 10, 12, 10, 10, 12, 12, 12, 10, 12, 10, 12, 10, 10, 10, 10, 12, 10,
+    10, 10, 10, 10, 10, 10, 0, 12, 10, 12, 12, 10, 10, 10, 12, 12, 10, 12, 12, 10, 10, 10, 10, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 12, 10, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 8, 0, 1, 3,
+    2, 12, 4, 3, 3, 10, 12, 4, 12, 12, 4, 4, 4, 10, 10, 12, 10, 12, 12, 12, 12, 10, 12, 12, 12, 12,
+    12, 12, 12, 12, 10, 10, 10, 12, 10, 10, 10, 10, 12, 10, 12, 12, 10, 10, 10, 10, 10, 12, 10, 12,
+    12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 0, 12, 10, 12, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 12, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 0, 1, 12, 2, 10, 12, 12, 12, 10, 10, 12, 10, 10, 12, 12, 12, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 12, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 12, 10, 12, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 12, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 8, 0, 1, 3, 2, 5, 4, 3, 3, 7, 6, 4, 5, 5, 4, 4, 4, 12,
+    8, 6, 7, 5, 5, 6, 6, 7, 6, 5, 5, 5, 5, 5, 5, 12, 12, 8, 12, 6, 7, 8, 8, 7, 6, 7, 6, 6, 7, 7, 7,
+    12, 8, 6, 7, 6, 6, 6, 6, 7, 6, 6, 6, 6, 6, 6, 6, 0, 12, 12, 12, 8, 12, 12, 12, 7, 7, 12, 8, 8,
+    12, 12, 12, 12, 8, 7, 7, 8, 8, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 12, 12, 8, 12, 7, 7, 8, 8, 7, 7,
+    7, 7, 7, 7, 7, 7, 12, 8, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 1, 12, 2, 12, 12, 12, 12,
+    8, 12, 12, 12, 12, 12, 12, 12, 12, 8, 12, 8, 12, 12, 12, 12, 8, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 8, 12, 12, 8, 8, 8, 8, 12, 8, 12, 12, 8, 8, 8, 12, 8, 12, 8, 12, 12, 12, 12, 8, 12, 12, 12,
+    12, 12, 12, 12, 0, 12, 12, 12, 8, 12, 12, 12, 8, 8, 12, 8, 8, 12, 12, 12, 12, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 12, 12, 8, 12, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 12, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 1, 3, 2, 12, 4, 3, 3, 12, 12, 4, 12, 12, 4, 4, 4, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 0, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 0, 1, 12, 2, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 8, 0, 1, 3,
+    2, 5, 4, 3, 3, 12, 6, 4, 5, 5, 4, 4, 4, 12, 12, 6, 12, 5, 5, 6, 6, 12, 6, 5, 5, 5, 5, 5, 5, 12,
+    12, 12, 12, 6, 12, 12, 12, 12, 6, 12, 6, 6, 12, 12, 12, 12, 12, 6, 12, 6, 6, 6, 6, 12, 6, 6, 6,
+    6, 6, 6, 6, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 0, 1, 12, 2, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 8, 0, 1, 3, 2, 12, 4, 3, 3, 12, 12, 4, 12, 12, 4, 4, 4, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 0, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 10, 0, 1, 12, 2, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10,
+    0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10,
+    12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 10,
+    12, 12, 12, 12, 12, 10, 12, 10, 12, 12, 10, 10, 12, 10, 10, 8,
+];
+///Table containing the number of codes decoded with a lookup
+pub const READ_COUNT_L2M: &[u8] = &[
+    0, 1, 1, 2, 1, 2, 1, 3, 1, 2, 2, 3, 1, 2, 2, 4, 1, 2, 2, 3, 1, 3, 2, 4, 1, 2, 3, 3, 1, 3, 3, 5,
+    1, 2, 2, 3, 2, 3, 2, 4, 1, 2, 3, 4, 2, 3, 3, 5, 1, 2, 2, 3, 2, 4, 2, 4, 1, 2, 4, 4, 2, 4, 4, 6,
+    0, 2, 2, 3, 2, 3, 2, 4, 1, 3, 3, 4, 2, 3, 3, 5, 1, 2, 3, 3, 2, 4, 3, 5, 1, 3, 4, 4, 2, 4, 4, 6,
+    1, 2, 2, 3, 3, 3, 2, 4, 1, 3, 3, 5, 3, 3, 3, 5, 1, 2, 3, 3, 3, 5, 3, 5, 1, 3, 5, 5, 3, 5, 5, 7,
+    0, 1, 2, 2, 2, 3, 2, 4, 2, 3, 3, 4, 2, 3, 3, 5, 1, 2, 3, 4, 2, 4, 3, 5, 2, 3, 4, 4, 2, 4, 4, 6,
+    1, 2, 2, 3, 3, 4, 2, 4, 2, 3, 4, 5, 3, 4, 4, 6, 1, 2, 3, 4, 3, 5, 3, 5, 2, 3, 5, 5, 3, 5, 5, 7,
+    0, 2, 2, 3, 2, 3, 2, 4, 2, 4, 3, 4, 2, 3, 3, 5, 1, 2, 4, 4, 2, 4, 4, 6, 2, 4, 4, 4, 2, 4, 4, 6,
+    1, 2, 2, 3, 4, 4, 2, 4, 2, 4, 4, 6, 4, 4, 4, 6, 1, 2, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 1, 1, 2, 2, 2, 1, 3, 2, 3, 2, 4, 2, 2, 2, 4, 1, 3, 3, 4, 2, 4, 3, 5, 2, 3, 4, 4, 2, 4, 4, 6,
+    1, 2, 3, 3, 3, 4, 3, 5, 2, 3, 4, 5, 3, 4, 4, 6, 1, 3, 3, 4, 3, 5, 3, 5, 2, 3, 5, 5, 3, 5, 5, 7,
+    0, 2, 2, 3, 3, 3, 2, 4, 2, 4, 3, 5, 3, 3, 3, 5, 1, 3, 4, 4, 3, 5, 4, 6, 2, 4, 5, 5, 3, 5, 5, 7,
+    1, 2, 3, 3, 4, 4, 3, 5, 2, 4, 4, 6, 4, 4, 4, 6, 1, 3, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 1, 2, 2, 2, 3, 2, 4, 3, 3, 3, 4, 2, 3, 3, 5, 1, 3, 3, 5, 2, 4, 3, 5, 3, 3, 4, 4, 2, 4, 4, 6,
+    1, 2, 3, 3, 3, 5, 3, 5, 3, 3, 5, 5, 3, 5, 5, 7, 1, 3, 3, 5, 3, 5, 3, 5, 3, 3, 5, 5, 3, 5, 5, 7,
+    0, 2, 2, 3, 3, 3, 2, 4, 3, 5, 3, 5, 3, 3, 3, 5, 1, 3, 5, 5, 3, 5, 5, 7, 3, 5, 5, 5, 3, 5, 5, 7,
+    1, 2, 3, 3, 5, 5, 3, 5, 3, 5, 5, 7, 5, 5, 5, 7, 1, 3, 5, 5, 5, 7, 5, 7, 3, 5, 7, 7, 5, 7, 7, 8,
+    0, 1, 1, 2, 1, 2, 1, 3, 2, 2, 2, 3, 1, 2, 2, 4, 2, 3, 2, 4, 1, 3, 2, 4, 2, 2, 3, 3, 1, 3, 3, 5,
+    1, 2, 3, 4, 2, 4, 3, 5, 2, 2, 4, 4, 2, 4, 4, 6, 2, 3, 2, 4, 2, 4, 2, 4, 2, 2, 4, 4, 2, 4, 4, 6,
+    0, 2, 2, 3, 3, 4, 2, 4, 2, 4, 4, 5, 3, 4, 4, 6, 2, 3, 4, 4, 3, 5, 4, 6, 2, 4, 5, 5, 3, 5, 5, 7,
+    1, 2, 3, 4, 4, 4, 3, 5, 2, 4, 4, 6, 4, 4, 4, 6, 2, 3, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 1, 2, 2, 2, 3, 2, 4, 3, 4, 3, 4, 2, 3, 3, 5, 2, 3, 4, 5, 2, 4, 4, 6, 3, 4, 4, 4, 2, 4, 4, 6,
+    1, 2, 3, 4, 4, 5, 3, 5, 3, 4, 5, 6, 4, 5, 5, 7, 2, 3, 4, 5, 4, 6, 4, 6, 3, 4, 6, 6, 4, 6, 6, 8,
+    0, 2, 2, 3, 3, 4, 2, 4, 3, 5, 4, 5, 3, 4, 4, 6, 2, 3, 5, 5, 3, 5, 5, 7, 3, 5, 5, 5, 3, 5, 5, 7,
+    1, 2, 3, 4, 5, 5, 3, 5, 3, 5, 5, 7, 5, 5, 5, 7, 2, 3, 5, 5, 5, 7, 5, 7, 3, 5, 7, 7, 5, 7, 7, 8,
+    0, 1, 1, 2, 2, 2, 1, 3, 2, 3, 2, 4, 2, 2, 2, 4, 2, 4, 3, 4, 2, 4, 3, 5, 2, 3, 4, 4, 2, 4, 4, 6,
+    1, 2, 4, 4, 3, 4, 4, 6, 2, 3, 4, 5, 3, 4, 4, 6, 2, 4, 3, 4, 3, 5, 3, 5, 2, 3, 5, 5, 3, 5, 5, 7,
+    0, 2, 2, 3, 4, 4, 2, 4, 2, 4, 4, 6, 4, 4, 4, 6, 2, 4, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    1, 2, 4, 4, 4, 4, 4, 6, 2, 4, 4, 6, 4, 4, 4, 6, 2, 4, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 1, 2, 2, 2, 3, 2, 4, 4, 4, 3, 4, 2, 3, 3, 5, 2, 4, 4, 6, 2, 4, 4, 6, 4, 4, 4, 4, 2, 4, 4, 6,
+    1, 2, 4, 4, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8, 2, 4, 4, 6, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8,
+    0, 2, 2, 3, 4, 4, 2, 4, 4, 6, 4, 6, 4, 4, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8, 4, 6, 6, 6, 4, 6, 6, 8,
+    1, 2, 4, 4, 6, 6, 4, 6, 4, 6, 6, 8, 6, 6, 6, 8, 2, 4, 6, 6, 6, 8, 6, 8, 4, 6, 8, 8, 6, 8, 8, 8,
+    0, 1, 1, 2, 1, 2, 1, 3, 1, 2, 2, 3, 1, 2, 2, 4, 2, 2, 2, 3, 1, 3, 2, 4, 1, 2, 3, 3, 1, 3, 3, 5,
+    1, 3, 2, 4, 2, 3, 2, 4, 1, 2, 3, 4, 2, 3, 3, 5, 2, 2, 2, 3, 2, 4, 2, 4, 1, 2, 4, 4, 2, 4, 4, 6,
+    0, 2, 3, 3, 2, 4, 3, 5, 1, 3, 4, 4, 2, 4, 4, 6, 2, 2, 3, 3, 2, 4, 3, 5, 1, 3, 4, 4, 2, 4, 4, 6,
+    1, 3, 2, 4, 3, 3, 2, 4, 1, 3, 3, 5, 3, 3, 3, 5, 2, 2, 3, 3, 3, 5, 3, 5, 1, 3, 5, 5, 3, 5, 5, 7,
+    0, 1, 2, 2, 3, 3, 2, 4, 2, 4, 3, 5, 3, 3, 3, 5, 2, 2, 4, 4, 3, 5, 4, 6, 2, 4, 5, 5, 3, 5, 5, 7,
+    1, 3, 2, 4, 4, 4, 2, 4, 2, 4, 4, 6, 4, 4, 4, 6, 2, 2, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 2, 3, 3, 2, 4, 3, 5, 2, 4, 4, 4, 2, 4, 4, 6, 2, 2, 4, 4, 2, 4, 4, 6, 2, 4, 4, 4, 2, 4, 4, 6,
+    1, 3, 2, 4, 4, 4, 2, 4, 2, 4, 4, 6, 4, 4, 4, 6, 2, 2, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 1, 1, 2, 2, 2, 1, 3, 3, 3, 2, 4, 2, 2, 2, 4, 2, 4, 3, 5, 2, 4, 3, 5, 3, 3, 4, 4, 2, 4, 4, 6,
+    1, 3, 4, 4, 3, 5, 4, 6, 3, 3, 5, 5, 3, 5, 5, 7, 2, 4, 3, 5, 3, 5, 3, 5, 3, 3, 5, 5, 3, 5, 5, 7,
+    0, 2, 3, 3, 4, 4, 3, 5, 3, 5, 4, 6, 4, 4, 4, 6, 2, 4, 5, 5, 4, 6, 5, 7, 3, 5, 6, 6, 4, 6, 6, 8,
+    1, 3, 4, 4, 5, 5, 4, 6, 3, 5, 5, 7, 5, 5, 5, 7, 2, 4, 5, 5, 5, 7, 5, 7, 3, 5, 7, 7, 5, 7, 7, 8,
+    0, 1, 2, 2, 3, 3, 2, 4, 4, 4, 3, 5, 3, 3, 3, 5, 2, 4, 4, 6, 3, 5, 4, 6, 4, 4, 5, 5, 3, 5, 5, 7,
+    1, 3, 4, 4, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8, 2, 4, 4, 6, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8,
+    0, 2, 3, 3, 4, 4, 3, 5, 4, 6, 4, 6, 4, 4, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8, 4, 6, 6, 6, 4, 6, 6, 8,
+    1, 3, 4, 4, 6, 6, 4, 6, 4, 6, 6, 8, 6, 6, 6, 8, 2, 4, 6, 6, 6, 8, 6, 8, 4, 6, 8, 8, 6, 8, 8, 8,
+    0, 1, 1, 2, 1, 2, 1, 3, 2, 2, 2, 3, 1, 2, 2, 4, 3, 3, 2, 4, 1, 3, 2, 4, 2, 2, 3, 3, 1, 3, 3, 5,
+    1, 3, 3, 5, 2, 4, 3, 5, 2, 2, 4, 4, 2, 4, 4, 6, 3, 3, 2, 4, 2, 4, 2, 4, 2, 2, 4, 4, 2, 4, 4, 6,
+    0, 2, 3, 3, 3, 5, 3, 5, 2, 4, 5, 5, 3, 5, 5, 7, 3, 3, 4, 4, 3, 5, 4, 6, 2, 4, 5, 5, 3, 5, 5, 7,
+    1, 3, 3, 5, 4, 4, 3, 5, 2, 4, 4, 6, 4, 4, 4, 6, 3, 3, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 1, 2, 2, 3, 3, 2, 4, 3, 5, 3, 5, 3, 3, 3, 5, 3, 3, 5, 5, 3, 5, 5, 7, 3, 5, 5, 5, 3, 5, 5, 7,
+    1, 3, 3, 5, 5, 5, 3, 5, 3, 5, 5, 7, 5, 5, 5, 7, 3, 3, 5, 5, 5, 7, 5, 7, 3, 5, 7, 7, 5, 7, 7, 8,
+    0, 2, 3, 3, 3, 5, 3, 5, 3, 5, 5, 5, 3, 5, 5, 7, 3, 3, 5, 5, 3, 5, 5, 7, 3, 5, 5, 5, 3, 5, 5, 7,
+    1, 3, 3, 5, 5, 5, 3, 5, 3, 5, 5, 7, 5, 5, 5, 7, 3, 3, 5, 5, 5, 7, 5, 7, 3, 5, 7, 7, 5, 7, 7, 8,
+    0, 1, 1, 2, 2, 2, 1, 3, 3, 3, 2, 4, 2, 2, 2, 4, 3, 5, 3, 5, 2, 4, 3, 5, 3, 3, 4, 4, 2, 4, 4, 6,
+    1, 3, 5, 5, 3, 5, 5, 7, 3, 3, 5, 5, 3, 5, 5, 7, 3, 5, 3, 5, 3, 5, 3, 5, 3, 3, 5, 5, 3, 5, 5, 7,
+    0, 2, 3, 3, 5, 5, 3, 5, 3, 5, 5, 7, 5, 5, 5, 7, 3, 5, 5, 5, 5, 7, 5, 7, 3, 5, 7, 7, 5, 7, 7, 8,
+    1, 3, 5, 5, 5, 5, 5, 7, 3, 5, 5, 7, 5, 5, 5, 7, 3, 5, 5, 5, 5, 7, 5, 7, 3, 5, 7, 7, 5, 7, 7, 8,
+    0, 1, 2, 2, 3, 3, 2, 4, 5, 5, 3, 5, 3, 3, 3, 5, 3, 5, 5, 7, 3, 5, 5, 7, 5, 5, 5, 5, 3, 5, 5, 7,
+    1, 3, 5, 5, 5, 7, 5, 7, 5, 5, 7, 7, 5, 7, 7, 8, 3, 5, 5, 7, 5, 7, 5, 7, 5, 5, 7, 7, 5, 7, 7, 8,
+    0, 2, 3, 3, 5, 5, 3, 5, 5, 7, 5, 7, 5, 5, 5, 7, 3, 5, 7, 7, 5, 7, 7, 8, 5, 7, 7, 7, 5, 7, 7, 8,
+    1, 3, 5, 5, 7, 7, 5, 7, 5, 7, 7, 8, 7, 7, 7, 8, 3, 5, 7, 7, 7, 8, 7, 8, 5, 7, 8, 8, 7, 8, 8, 8,
+    0, 1, 1, 2, 1, 2, 1, 3, 1, 2, 2, 3, 1, 2, 2, 4, 1, 2, 2, 3, 1, 3, 2, 4, 1, 2, 3, 3, 1, 3, 3, 5,
+    2, 2, 2, 3, 2, 3, 2, 4, 1, 2, 3, 4, 2, 3, 3, 5, 1, 2, 2, 3, 2, 4, 2, 4, 1, 2, 4, 4, 2, 4, 4, 6,
+    0, 2, 2, 4, 2, 3, 2, 4, 1, 3, 3, 4, 2, 3, 3, 5, 1, 2, 3, 3, 2, 4, 3, 5, 1, 3, 4, 4, 2, 4, 4, 6,
+    2, 2, 2, 3, 3, 3, 2, 4, 1, 3, 3, 5, 3, 3, 3, 5, 1, 2, 3, 3, 3, 5, 3, 5, 1, 3, 5, 5, 3, 5, 5, 7,
+    0, 1, 2, 2, 2, 4, 2, 4, 2, 3, 4, 4, 2, 4, 4, 6, 1, 2, 3, 4, 2, 4, 3, 5, 2, 3, 4, 4, 2, 4, 4, 6,
+    2, 2, 2, 3, 3, 4, 2, 4, 2, 3, 4, 5, 3, 4, 4, 6, 1, 2, 3, 4, 3, 5, 3, 5, 2, 3, 5, 5, 3, 5, 5, 7,
+    0, 2, 2, 4, 2, 3, 2, 4, 2, 4, 3, 4, 2, 3, 3, 5, 1, 2, 4, 4, 2, 4, 4, 6, 2, 4, 4, 4, 2, 4, 4, 6,
+    2, 2, 2, 3, 4, 4, 2, 4, 2, 4, 4, 6, 4, 4, 4, 6, 1, 2, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 1, 1, 2, 2, 2, 1, 3, 2, 4, 2, 4, 2, 2, 2, 4, 1, 3, 4, 4, 2, 4, 4, 6, 2, 4, 4, 4, 2, 4, 4, 6,
+    2, 2, 3, 3, 4, 4, 3, 5, 2, 4, 4, 6, 4, 4, 4, 6, 1, 3, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 2, 2, 4, 3, 3, 2, 4, 2, 4, 3, 5, 3, 3, 3, 5, 1, 3, 4, 4, 3, 5, 4, 6, 2, 4, 5, 5, 3, 5, 5, 7,
+    2, 2, 3, 3, 4, 4, 3, 5, 2, 4, 4, 6, 4, 4, 4, 6, 1, 3, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 1, 2, 2, 2, 4, 2, 4, 3, 3, 4, 4, 2, 4, 4, 6, 1, 3, 3, 5, 2, 4, 3, 5, 3, 3, 4, 4, 2, 4, 4, 6,
+    2, 2, 3, 3, 3, 5, 3, 5, 3, 3, 5, 5, 3, 5, 5, 7, 1, 3, 3, 5, 3, 5, 3, 5, 3, 3, 5, 5, 3, 5, 5, 7,
+    0, 2, 2, 4, 3, 3, 2, 4, 3, 5, 3, 5, 3, 3, 3, 5, 1, 3, 5, 5, 3, 5, 5, 7, 3, 5, 5, 5, 3, 5, 5, 7,
+    2, 2, 3, 3, 5, 5, 3, 5, 3, 5, 5, 7, 5, 5, 5, 7, 1, 3, 5, 5, 5, 7, 5, 7, 3, 5, 7, 7, 5, 7, 7, 8,
+    0, 1, 1, 2, 1, 2, 1, 3, 2, 2, 2, 3, 1, 2, 2, 4, 2, 4, 2, 4, 1, 3, 2, 4, 2, 2, 3, 3, 1, 3, 3, 5,
+    2, 2, 4, 4, 2, 4, 4, 6, 2, 2, 4, 4, 2, 4, 4, 6, 2, 4, 2, 4, 2, 4, 2, 4, 2, 2, 4, 4, 2, 4, 4, 6,
+    0, 2, 2, 4, 4, 4, 2, 4, 2, 4, 4, 6, 4, 4, 4, 6, 2, 4, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    2, 2, 4, 4, 4, 4, 4, 6, 2, 4, 4, 6, 4, 4, 4, 6, 2, 4, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 1, 2, 2, 2, 4, 2, 4, 4, 4, 4, 4, 2, 4, 4, 6, 2, 4, 4, 6, 2, 4, 4, 6, 4, 4, 4, 4, 2, 4, 4, 6,
+    2, 2, 4, 4, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8, 2, 4, 4, 6, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8,
+    0, 2, 2, 4, 4, 4, 2, 4, 4, 6, 4, 6, 4, 4, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8, 4, 6, 6, 6, 4, 6, 6, 8,
+    2, 2, 4, 4, 6, 6, 4, 6, 4, 6, 6, 8, 6, 6, 6, 8, 2, 4, 6, 6, 6, 8, 6, 8, 4, 6, 8, 8, 6, 8, 8, 8,
+    0, 1, 1, 2, 2, 2, 1, 3, 2, 4, 2, 4, 2, 2, 2, 4, 2, 4, 4, 4, 2, 4, 4, 6, 2, 4, 4, 4, 2, 4, 4, 6,
+    2, 2, 4, 4, 4, 4, 4, 6, 2, 4, 4, 6, 4, 4, 4, 6, 2, 4, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 2, 2, 4, 4, 4, 2, 4, 2, 4, 4, 6, 4, 4, 4, 6, 2, 4, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    2, 2, 4, 4, 4, 4, 4, 6, 2, 4, 4, 6, 4, 4, 4, 6, 2, 4, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 1, 2, 2, 2, 4, 2, 4, 4, 4, 4, 4, 2, 4, 4, 6, 2, 4, 4, 6, 2, 4, 4, 6, 4, 4, 4, 4, 2, 4, 4, 6,
+    2, 2, 4, 4, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8, 2, 4, 4, 6, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8,
+    0, 2, 2, 4, 4, 4, 2, 4, 4, 6, 4, 6, 4, 4, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8, 4, 6, 6, 6, 4, 6, 6, 8,
+    2, 2, 4, 4, 6, 6, 4, 6, 4, 6, 6, 8, 6, 6, 6, 8, 2, 4, 6, 6, 6, 8, 6, 8, 4, 6, 8, 8, 6, 8, 8, 8,
+    0, 1, 1, 2, 1, 2, 1, 3, 1, 2, 2, 3, 1, 2, 2, 4, 2, 2, 2, 3, 1, 3, 2, 4, 1, 2, 3, 3, 1, 3, 3, 5,
+    2, 4, 2, 4, 2, 3, 2, 4, 1, 2, 3, 4, 2, 3, 3, 5, 2, 2, 2, 3, 2, 4, 2, 4, 1, 2, 4, 4, 2, 4, 4, 6,
+    0, 2, 4, 4, 2, 4, 4, 6, 1, 3, 4, 4, 2, 4, 4, 6, 2, 2, 3, 3, 2, 4, 3, 5, 1, 3, 4, 4, 2, 4, 4, 6,
+    2, 4, 2, 4, 3, 3, 2, 4, 1, 3, 3, 5, 3, 3, 3, 5, 2, 2, 3, 3, 3, 5, 3, 5, 1, 3, 5, 5, 3, 5, 5, 7,
+    0, 1, 2, 2, 4, 4, 2, 4, 2, 4, 4, 6, 4, 4, 4, 6, 2, 2, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    2, 4, 2, 4, 4, 4, 2, 4, 2, 4, 4, 6, 4, 4, 4, 6, 2, 2, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 2, 4, 4, 2, 4, 4, 6, 2, 4, 4, 4, 2, 4, 4, 6, 2, 2, 4, 4, 2, 4, 4, 6, 2, 4, 4, 4, 2, 4, 4, 6,
+    2, 4, 2, 4, 4, 4, 2, 4, 2, 4, 4, 6, 4, 4, 4, 6, 2, 2, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 1, 1, 2, 2, 2, 1, 3, 4, 4, 2, 4, 2, 2, 2, 4, 2, 4, 4, 6, 2, 4, 4, 6, 4, 4, 4, 4, 2, 4, 4, 6,
+    2, 4, 4, 4, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8, 2, 4, 4, 6, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8,
+    0, 2, 4, 4, 4, 4, 4, 6, 4, 6, 4, 6, 4, 4, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8, 4, 6, 6, 6, 4, 6, 6, 8,
+    2, 4, 4, 4, 6, 6, 4, 6, 4, 6, 6, 8, 6, 6, 6, 8, 2, 4, 6, 6, 6, 8, 6, 8, 4, 6, 8, 8, 6, 8, 8, 8,
+    0, 1, 2, 2, 4, 4, 2, 4, 4, 4, 4, 6, 4, 4, 4, 6, 2, 4, 4, 6, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8,
+    2, 4, 4, 4, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8, 2, 4, 4, 6, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8,
+    0, 2, 4, 4, 4, 4, 4, 6, 4, 6, 4, 6, 4, 4, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8, 4, 6, 6, 6, 4, 6, 6, 8,
+    2, 4, 4, 4, 6, 6, 4, 6, 4, 6, 6, 8, 6, 6, 6, 8, 2, 4, 6, 6, 6, 8, 6, 8, 4, 6, 8, 8, 6, 8, 8, 8,
+    0, 1, 1, 2, 1, 2, 1, 3, 2, 2, 2, 3, 1, 2, 2, 4, 4, 4, 2, 4, 1, 3, 2, 4, 2, 2, 3, 3, 1, 3, 3, 5,
+    2, 4, 4, 6, 2, 4, 4, 6, 2, 2, 4, 4, 2, 4, 4, 6, 4, 4, 2, 4, 2, 4, 2, 4, 2, 2, 4, 4, 2, 4, 4, 6,
+    0, 2, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8, 4, 4, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    2, 4, 4, 6, 4, 4, 4, 6, 2, 4, 4, 6, 4, 4, 4, 6, 4, 4, 4, 4, 4, 6, 4, 6, 2, 4, 6, 6, 4, 6, 6, 8,
+    0, 1, 2, 2, 4, 4, 2, 4, 4, 6, 4, 6, 4, 4, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8, 4, 6, 6, 6, 4, 6, 6, 8,
+    2, 4, 4, 6, 6, 6, 4, 6, 4, 6, 6, 8, 6, 6, 6, 8, 4, 4, 6, 6, 6, 8, 6, 8, 4, 6, 8, 8, 6, 8, 8, 8,
+    0, 2, 4, 4, 4, 6, 4, 6, 4, 6, 6, 6, 4, 6, 6, 8, 4, 4, 6, 6, 4, 6, 6, 8, 4, 6, 6, 6, 4, 6, 6, 8,
+    2, 4, 4, 6, 6, 6, 4, 6, 4, 6, 6, 8, 6, 6, 6, 8, 4, 4, 6, 6, 6, 8, 6, 8, 4, 6, 8, 8, 6, 8, 8, 8,
+    0, 1, 1, 2, 2, 2, 1, 3, 4, 4, 2, 4, 2, 2, 2, 4, 4, 6, 4, 6, 2, 4, 4, 6, 4, 4, 4, 4, 2, 4, 4, 6,
+    2, 4, 6, 6, 4, 6, 6, 8, 4, 4, 6, 6, 4, 6, 6, 8, 4, 6, 4, 6, 4, 6, 4, 6, 4, 4, 6, 6, 4, 6, 6, 8,
+    0, 2, 4, 4, 6, 6, 4, 6, 4, 6, 6, 8, 6, 6, 6, 8, 4, 6, 6, 6, 6, 8, 6, 8, 4, 6, 8, 8, 6, 8, 8, 8,
+    2, 4, 6, 6, 6, 6, 6, 8, 4, 6, 6, 8, 6, 6, 6, 8, 4, 6, 6, 6, 6, 8, 6, 8, 4, 6, 8, 8, 6, 8, 8, 8,
+    0, 1, 2, 2, 4, 4, 2, 4, 6, 6, 4, 6, 4, 4, 4, 6, 4, 6, 6, 8, 4, 6, 6, 8, 6, 6, 6, 6, 4, 6, 6, 8,
+    2, 4, 6, 6, 6, 8, 6, 8, 6, 6, 8, 8, 6, 8, 8, 8, 4, 6, 6, 8, 6, 8, 6, 8, 6, 6, 8, 8, 6, 8, 8, 8,
+    0, 2, 4, 4, 6, 6, 4, 6, 6, 8, 6, 8, 6, 6, 6, 8, 4, 6, 8, 8, 6, 8, 8, 8, 6, 8, 8, 8, 6, 8, 8, 8,
+    2, 4, 6, 6, 8, 8, 6, 8, 6, 8, 8, 8, 8, 8, 8, 8, 4, 6, 8, 8, 8, 8, 8, 8, 6, 8, 8, 8, 8, 8, 8, 8,
+];
+///Table containing the values decoded with a lookup, MAX_SYMBOLS
+///for each entry, padded with zeros
+pub const READ_VALUES_L2M: &[u8] = &[
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    7, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    15, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0,
+    0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0,
+    0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 0, 0, 15,
+    0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    7, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 3, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 5, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 2, 1, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0,
+    0, 0, 0, 8, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 4, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0,
+    0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 15, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 2, 15,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 1,
+    0, 7, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 2,
+    0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0,
+    1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0,
+    2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0,
+    1, 0, 0, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0,
+    2, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0,
+    0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
+    0, 2, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0,
+    0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+    0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0,
+    0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0,
+    0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 1,
+    0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0,
+    0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 1, 4,
+    0, 0, 0, 0, 0, 2, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0,
+    13, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0,
+    0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0,
+    0, 0, 2, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 34, 0, 0, 0, 0, 0,
+    0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 13, 0, 0, 0,
+    0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 22, 0, 0,
+    0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 5, 0,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 14,
+    0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6,
+    0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
+    7, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7,
+    1, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 4,
+    7, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    23, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0,
+    0, 5, 7, 0, 0, 0, 0, 0, 0, 0, 1, 0, 7, 0, 0, 0, 0, 2, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0,
+    0, 8, 1, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 1, 0, 0, 7, 0, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0,
+    0, 6, 7, 0, 0, 0, 0, 0, 0, 0, 2, 0, 7, 0, 0, 0, 0, 2, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0,
+    0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0,
+    0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0,
+    0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0,
+    0, 0, 4, 0, 3, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1,
+    0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0,
+    0, 0, 0, 5, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 2, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3,
+    0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 2, 0,
+    3, 0, 0, 0, 6, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 0, 0, 0, 17,
+    0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 2, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    9, 0, 0, 0, 0, 11, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 5, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0,
+    0, 5, 1, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 1, 0, 0, 12, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 2, 1, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0,
+    0, 0, 0, 12, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 1, 2, 0, 1,
+    0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 2, 2, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 14, 1, 0, 0, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 6, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 2, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 16,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 2,
+    16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0,
+    1, 0, 8, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 0, 2, 8, 0, 0, 0, 0, 0,
+    2, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0,
+    0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0,
+    0, 2, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0,
+    0, 1, 0, 0, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0,
+    0, 2, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0,
+    0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+    0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0,
+    0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0,
+    0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0,
+    0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    2, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 6, 2,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0,
+    0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34,
+    0, 0, 0, 0, 0, 0, 1, 10, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0,
+    1, 6, 0, 0, 0, 0, 0, 2, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0,
+    0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0,
+    0, 2, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0,
+    0, 0, 13, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 12, 0, 0, 0, 0,
+    0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 6, 2, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 38, 0, 0, 0,
+    0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 3, 0, 0,
+    0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 13, 0,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0,
+    0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 30,
+    0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0,
+    5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    14, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 7, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0,
+    0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 8, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 0, 3, 1,
+    0, 0, 0, 9, 3, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 3, 0, 0, 0, 0, 2, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    3, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 4, 3,
+    0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 10, 3, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 1, 11, 0, 0, 0, 0, 0, 0, 0, 0,
+    19, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 2, 11, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 11, 0, 0, 0, 0, 11, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0,
+    0, 0, 1, 1, 1, 0, 0, 0, 4, 1, 1, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0,
+    0, 0, 0, 5, 3, 0, 0, 0, 0, 5, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 2, 1, 0, 3, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 3, 0, 0, 12, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 3, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0,
+    0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 6, 1, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0,
+    0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 3, 0, 0, 3, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 2, 4, 1, 0, 0,
+    0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 13, 3, 0, 0, 0, 0, 0, 0, 0, 5, 0, 3, 0, 0, 0, 0, 1, 0, 2, 3,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 4, 0, 0, 3, 0, 0, 0, 0, 0, 2, 2, 3, 0, 0, 0, 0, 2, 0, 2, 3,
+    0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 1, 2, 0,
+    3, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 5, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 2, 2, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 14, 3, 0, 0, 0, 0, 0, 0, 0, 6, 0, 3, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 6, 0, 0, 3, 0, 0, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 2, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0,
+    2, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 7, 0, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0,
+    0, 1, 0, 9, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 0, 2, 9, 0, 0, 0, 0,
+    0, 2, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 11, 1, 0, 0, 0,
+    0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0,
+    0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 8, 0, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0,
+    0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 6, 5, 0, 0, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0,
+    0, 0, 2, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0,
+    0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1,
+    0, 0, 0, 2, 5, 1, 0, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 9, 0, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0,
+    0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 1,
+    0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 12, 1, 0,
+    0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    1, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 10, 0, 1, 0, 0, 0, 0, 0, 0, 6, 1,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0,
+    0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36,
+    0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 0, 0,
+    1, 4, 0, 0, 0, 0, 0, 2, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 11, 0, 1, 0, 0, 0, 0, 0,
+    0, 3, 0, 0, 1, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 4, 2, 1, 0, 0, 0, 0, 0,
+    0, 2, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0,
+    0, 0, 13, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 5, 2, 1, 0, 0, 0,
+    0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 12, 0, 1, 0, 0,
+    0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 6, 2, 1, 0, 0,
+    0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 2, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 42, 0, 0, 0,
+    0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 3, 0, 0,
+    0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 2, 6, 1, 0, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 13, 0,
+    1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 4, 0,
+    0, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 2, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 22,
+    0, 0, 0, 0, 0, 0, 0, 0, 14, 1, 0, 0, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0,
+    5, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0,
+    14, 0, 1, 0, 0, 0, 0, 0, 0, 6, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1,
+    0, 6, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 3, 8, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 7, 2, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0,
+    0, 4, 8, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0,
+    0, 0, 5, 8, 0, 0, 0, 0, 0, 0, 0, 1, 0, 8, 0, 0, 0, 0, 2, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0,
+    0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 1, 0, 0, 8, 0, 0, 0, 0, 0, 0, 2, 8, 0, 0,
+    0, 0, 6, 8, 0, 0, 0, 0, 0, 0, 0, 2, 0, 8, 0, 0, 0, 0, 2, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8,
+    0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0,
+    0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0,
+    0, 0, 0, 9, 2, 0, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 4,
+    0, 0, 0, 4, 0, 4, 0, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    2, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 0, 0, 0, 4, 2,
+    0, 0, 0, 0, 5, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 2, 2, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+    4, 0, 0, 0, 10, 2, 0, 0, 0, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 0, 0, 2,
+    0, 4, 0, 0, 0, 6, 0, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 1, 13, 0, 0, 0, 0, 0, 0, 0, 0,
+    21, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 2, 13, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 13, 0, 0, 0, 0, 11, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0,
+    0, 0, 1, 1, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 5, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0,
+    0, 0, 0, 5, 2, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 2, 0, 0, 12, 2, 0, 0, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0,
+    0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 2, 4, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 13, 2, 0, 0, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 1, 0, 2,
+    2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 2, 0, 2,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 1, 2,
+    0, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 2, 2,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 14, 2, 0, 0, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 6, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 2,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 0, 0, 1, 10, 0, 0, 0, 0, 0,
+    2, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0,
+    0, 1, 0, 10, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 4, 6, 0, 0, 0, 0, 0, 0, 0, 2, 10, 0, 0, 0,
+    0, 0, 2, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0,
+    0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 6,
+    0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0,
+    0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 6, 6, 0, 0, 0, 0, 0, 0, 0, 2, 0, 6,
+    0, 0, 0, 0, 2, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0,
+    0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 0, 1,
+    1, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5,
+    2, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 0, 2,
+    1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 2, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0,
+    0, 0, 6, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0,
+    0, 0, 2, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 38, 0, 0, 0, 0, 0, 0, 1, 14, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0,
+    0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 2, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 11, 0, 0, 0, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 4, 2, 0, 0, 0,
+    0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 29, 0, 0, 0,
+    0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 5, 2, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 12, 0,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 6, 2,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 46,
+    0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0,
+    13, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0,
+    0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 15, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0,
+    0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 7,
+    1, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 16, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0,
+    0, 0, 23, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 7, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0,
+    0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 2, 0, 3, 1, 0, 0,
+    0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 17, 1, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+    0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 1, 4, 0,
+    0, 0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 2, 4, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 18, 1, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 1,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 1, 11, 0, 0, 0, 0,
+    0, 2, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0,
+    0, 0, 1, 0, 11, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 0, 2, 11, 0, 0,
+    0, 0, 0, 2, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 19, 1, 0, 0, 0, 0, 0, 0, 0, 11, 0,
+    0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    3, 0, 0, 0, 0, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 1,
+    1, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    3, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 17,
+    0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 9, 1, 0, 0, 0, 0, 3, 0, 1, 1, 0, 0, 0, 0, 0, 1,
+    1, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5,
+    1, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 4, 0, 1, 1, 0, 0, 0, 0, 0, 2,
+    1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 20, 1, 0, 0, 0, 0, 0, 0, 0,
+    12, 0, 0, 0, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 5, 0, 1, 1, 0, 0, 0, 0,
+    0, 1, 0, 0, 1, 1, 0, 0, 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 10, 0, 0, 0, 0, 0, 0,
+    0, 0, 6, 1, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 6, 0, 1, 1, 0, 0, 0,
+    0, 0, 2, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 40, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0,
+    0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 2, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 11, 0, 0, 0, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 4, 1, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 4, 2, 0, 0, 0,
+    0, 0, 0, 0, 2, 4, 1, 0, 0, 0, 0, 2, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 21, 1, 0, 0,
+    0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 5, 2, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 12, 0,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 6, 2,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 50,
+    0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 10, 1, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0,
+    13, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 22, 1, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0,
+    0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 3, 9, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 9, 0,
+    0, 0, 0, 4, 9, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 23, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 1, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0, 0, 0, 0, 3, 1,
+    0, 0, 0, 0, 5, 9, 0, 0, 0, 0, 0, 0, 0, 1, 0, 9, 0, 0, 0, 0, 2, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    5, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 1, 0, 0, 9, 0, 0, 0, 0, 0, 0, 2, 9,
+    0, 0, 0, 0, 6, 9, 0, 0, 0, 0, 0, 0, 0, 2, 0, 9, 0, 0, 0, 0, 2, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 9, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 0, 0, 11,
+    1, 0, 0, 0, 0, 3, 0, 5, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 2, 3, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    3, 0, 1, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1,
+    0, 5, 0, 0, 0, 4, 0, 5, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 1, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 1, 0, 0, 0, 0, 1, 2, 5, 0, 0, 0, 0, 0, 0, 0,
+    4, 1, 0, 0, 0, 0, 5, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 2, 2, 5, 0, 0, 0, 0, 0, 0, 0,
+    0, 2, 5, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 0, 0, 6, 5, 0, 0, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 0,
+    0, 2, 0, 5, 0, 0, 0, 6, 0, 5, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 2, 0, 0, 0, 5, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 0,
+    0, 0, 25, 0, 0, 0, 0, 0, 3, 1, 0, 1, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 2, 9, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 9, 0, 0, 0, 0, 11, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 1, 0, 5, 1, 0, 0,
+    0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 4, 1, 0, 1, 0, 0, 0, 0, 0, 2, 5, 1, 0, 0, 0, 0, 2, 0, 5, 1, 0, 0,
+    0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 25, 1, 0, 0, 0, 0, 0, 0, 0, 9, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0,
+    0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 5, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 2, 1, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 12, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1,
+    0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 6, 1, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 52, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 1, 4, 0,
+    1, 0, 0, 0, 0, 0, 0, 12, 1, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 2, 4,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 1,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 2,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 26, 1, 0, 0, 0, 0, 0, 0, 0, 10, 0, 1, 0, 0, 0, 0,
+    1, 2, 0, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0,
+    2, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 14, 1, 0, 0, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 6, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0,
+    0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0,
+    0, 0, 2, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 7, 0, 0, 1, 0, 0, 0, 0, 0, 3, 2, 1, 0,
+    0, 0, 0, 1, 0, 12, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 0, 2, 12, 0,
+    0, 0, 0, 0, 2, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 27, 1, 0, 0, 0, 0, 0, 0, 0, 11,
+    0, 1, 0, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 4, 0, 0, 0, 0, 2, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 8, 0, 0, 1, 0, 0, 0, 0, 0, 4,
+    2, 1, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 4, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0,
+    21, 0, 0, 0, 0, 0, 0, 1, 5, 0, 1, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 3, 0, 2, 1, 0, 0, 0, 0,
+    0, 1, 1, 0, 0, 1, 0, 0, 2, 5, 0, 1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 9, 0, 0, 1, 0, 0, 0, 0,
+    0, 5, 2, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 4, 0, 2, 1, 0, 0, 0, 0,
+    0, 2, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 28, 1, 0, 0, 0, 0, 0,
+    0, 0, 12, 0, 1, 0, 0, 0, 0, 1, 2, 2, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 5, 0, 2, 1, 0, 0,
+    0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 2, 2, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 10, 0, 0, 1, 0,
+    0, 0, 0, 0, 6, 2, 1, 0, 0, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 6, 0, 2, 1, 0,
+    0, 0, 0, 0, 2, 0, 0, 2, 1, 0, 0, 2, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 1, 10, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 3, 2, 0, 1,
+    0, 0, 0, 0, 0, 1, 6, 1, 0, 0, 0, 0, 2, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 11, 0, 0,
+    1, 0, 0, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 1, 0, 6, 1, 0, 0, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 4, 2, 0,
+    1, 0, 0, 0, 0, 0, 2, 6, 1, 0, 0, 0, 0, 2, 0, 6, 1, 0, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 29, 1,
+    0, 0, 0, 0, 0, 0, 0, 13, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 5,
+    2, 0, 1, 0, 0, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1,
+    12, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1, 0,
+    0, 6, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 2, 0, 1, 0, 0, 2, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    1, 54, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 1, 6, 0, 1, 0, 0, 0, 0, 0, 0, 14, 1, 0, 0,
+    0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 2, 6, 0, 1, 0, 0, 0, 0, 0, 0, 0, 6, 0, 1,
+    0, 0, 13, 0, 0, 1, 0, 0, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 1, 4, 0, 0, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 1, 0, 0, 2, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 1, 30, 1, 0, 0, 0, 0, 0, 0, 0, 14, 0, 1, 0, 0, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 0, 0, 6, 0,
+    0, 1, 0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 1, 14, 0, 0, 1, 0, 0, 0, 0, 0, 6, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2,
+    0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 7, 4, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    3, 4, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 8, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 0,
+    0, 7, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 2, 3, 2, 0, 0, 0, 0, 0, 0,
+    0, 0, 3, 2, 0, 0, 0, 9, 4, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 1, 4, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 4, 0, 0, 0, 0, 2, 0, 1, 4, 0, 0, 0, 0, 0,
+    0, 0, 0, 1, 4, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0,
+    0, 0, 4, 4, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 10, 4, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 1, 11, 0, 0, 0, 0,
+    0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 2, 11, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 11, 4, 0, 0, 0, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 1, 0, 3, 0,
+    0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 4, 1, 2, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 9, 2, 0, 0, 0, 0, 0, 1, 1, 0,
+    4, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 5, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 2, 1, 0,
+    4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 12, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 4, 0, 0, 0, 0, 1, 0,
+    0, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 6, 1, 2, 0, 0, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 2, 0,
+    0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 1,
+    4, 2, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 3, 0, 0, 4, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 2,
+    4, 2, 0, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 13, 4, 0, 0, 0, 0, 0, 0, 0, 5, 0, 4, 0, 0, 0, 0,
+    1, 0, 2, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 4, 0, 0, 4, 0, 0, 0, 0, 0, 2, 2, 4, 0, 0, 0, 0,
+    2, 0, 2, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 10, 2, 0, 0, 0, 0,
+    0, 1, 2, 0, 4, 0, 0, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 5, 0, 0, 4, 0, 0, 0, 0, 0, 1, 0, 0, 0, 4, 0,
+    0, 2, 2, 0, 4, 0, 0, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 14, 4, 0, 0, 0, 0, 0, 0, 0, 6, 0, 4, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 6, 0, 0, 4, 0, 0, 0, 0, 0, 2, 0, 0, 0, 4,
+    0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 1, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 0, 1, 13, 0, 0,
+    0, 0, 0, 2, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 7, 0, 2, 0, 0, 0, 0, 0, 0, 3, 1, 0,
+    0, 0, 0, 0, 1, 0, 13, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 0, 2, 13,
+    0, 0, 0, 0, 0, 2, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0,
+    11, 2, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 5, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 8, 0, 2, 0, 0, 0, 0, 0,
+    0, 4, 1, 0, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 6, 5, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 5, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 57, 0, 0, 0, 0, 0, 0,
+    0, 0, 25, 0, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0,
+    0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 2, 5, 2, 0, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 9, 0, 2, 0, 0, 0,
+    0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 4, 0, 1, 0, 0, 0,
+    0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 20, 0, 0, 0, 0,
+    0, 0, 0, 0, 12, 2, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 5, 0, 1, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 10, 0, 2,
+    0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 6, 0, 1,
+    0, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 3, 2,
+    2, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 2, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 11,
+    0, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 4,
+    2, 2, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    21, 0, 0, 0, 0, 0, 0, 0, 0, 13, 2, 0, 0, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0,
+    0, 5, 2, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 2, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2,
+    0, 12, 0, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0,
+    0, 0, 6, 2, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 2, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+    2, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 1, 6, 2, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 2, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 6, 2,
+    0, 0, 0, 13, 0, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 2, 0, 4, 0, 0, 0, 2, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 2, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 2, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 14, 2, 0, 0, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 0, 0, 6,
+    0, 2, 0, 0, 0, 5, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 2, 0, 14, 0, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    2, 0, 0, 0, 2, 0, 6, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 3, 10, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 10, 0, 0, 0, 0, 4, 10, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 1, 6, 0, 0, 0, 0,
+    0, 0, 0, 3, 2, 0, 0, 0, 0, 5, 10, 0, 0, 0, 0, 0, 0, 0, 1, 0, 10, 0, 0, 0, 0, 2, 1, 6, 0, 0, 0,
+    0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 0, 4, 6, 0, 0, 0, 0, 0, 1, 0, 0, 10, 0,
+    0, 0, 0, 0, 0, 2, 10, 0, 0, 0, 0, 6, 10, 0, 0, 0, 0, 0, 0, 0, 2, 0, 10, 0, 0, 0, 0, 2, 0, 0,
+    10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 1,
+    3, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 3, 0, 6, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0,
+    2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 9, 2, 0, 0, 0, 0, 0, 0, 0, 5, 6, 0, 0, 0, 0, 0,
+    1, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 4, 0, 6, 0, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0,
+    2, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0,
+    0, 1, 2, 6, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0,
+    0, 2, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 10, 2, 0, 0, 0, 0, 0, 0, 0, 6, 6, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 6, 0, 6, 0, 0, 0, 0, 0, 0, 2, 0, 0, 6, 0,
+    0, 0, 2, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0,
+    0, 0, 0, 1, 13, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0,
+    0, 0, 0, 0, 2, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 11, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0,
+    2, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 2, 5,
+    0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 9,
+    0, 0, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 1, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 12, 2, 0, 0, 0, 0, 0, 0, 0,
+    4, 0, 2, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0,
+    0, 28, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0,
+    0, 0, 1, 2, 2, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 13, 2, 0, 0, 0, 0,
+    0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 4, 0, 0, 2, 0, 0,
+    0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 26, 0, 0, 0, 0,
+    0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 5, 0, 0, 2,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 2, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 14, 2, 0,
+    0, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 6, 0, 0,
+    2, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 6,
+    0, 0, 0, 0, 0, 0, 0, 1, 14, 0, 0, 0, 0, 0, 2, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 7,
+    0, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 1, 0, 14, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0,
+    4, 6, 0, 0, 0, 0, 0, 0, 0, 2, 14, 0, 0, 0, 0, 0, 2, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0,
+    0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
+    0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0,
+    0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0,
+    0, 0, 6, 6, 0, 0, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 2, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6,
+    0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0,
+    0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0,
+    0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2,
+    0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 0, 0, 4, 0,
+    0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+    2, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 2,
+    0, 2, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 1, 14, 0, 0, 0, 0, 0, 0, 0, 0,
+    30, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 2, 14, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 14, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0,
+    0, 0, 1, 2, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 6, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
+    0, 0, 0, 5, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0,
+    0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0,
+    0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 2, 6, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 1, 2,
+    0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+    2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0,
+    0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
+    0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0,
+    0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+    0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0,
+    0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0,
+    0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 3,
+    0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 2,
+    3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0,
+    9, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0,
+    0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 32, 0, 0, 0, 0, 0, 0,
+    0, 0, 16, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0,
+    0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 13, 0, 0, 0, 0,
+    0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+    0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 18, 0, 0, 0,
+    0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 5, 0, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 14, 0,
+    0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
+    3, 0, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 2, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0,
+    7, 0, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 1, 0, 7, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0,
+    4, 3, 0, 0, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 2, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0,
+    19, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 5, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+    0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0,
+    0, 6, 3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0,
+    0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0,
+    0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0,
+    0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0,
+    0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1,
+    0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0,
+    1, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 0, 0, 16,
+    0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 2, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    8, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+    1, 2, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 4, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 5, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0,
+    0, 0, 2, 2, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 2, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0,
+    0, 0, 0, 10, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0,
+    0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 11, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 11, 0, 0, 0, 0, 4, 11, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0,
+    1, 1, 3, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 5, 11, 0, 0, 0, 0, 0, 0, 0, 1, 0, 11, 0, 0, 0,
+    0, 2, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0,
+    0, 1, 0, 0, 11, 0, 0, 0, 0, 0, 0, 2, 11, 0, 0, 0, 0, 6, 11, 0, 0, 0, 0, 0, 0, 0, 2, 0, 11, 0,
+    0, 0, 0, 2, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0,
+    0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 1,
+    1, 1, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 0, 5,
+    3, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 4, 0, 3, 0, 0, 0, 0, 0, 0, 2,
+    1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0,
+    8, 0, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 5, 0, 3, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 3, 0, 0, 0, 2, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 0,
+    0, 6, 3, 0, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 6, 0, 3, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 49, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0,
+    0, 0, 1, 5, 0, 0, 0, 0, 0, 2, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 11, 1, 0, 0, 0, 0,
+    0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0,
+    0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 25, 0, 0, 0, 0,
+    0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 5, 1, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 12, 1, 0, 0,
+    0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 6, 1, 0, 0,
+    0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 36, 0, 0,
+    0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 3, 0,
+    0, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 13,
+    1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 4,
+    0, 0, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0,
+    26, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0,
+    0, 5, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0,
+    0, 14, 1, 0, 0, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1,
+    0, 0, 6, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 2, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24,
+    0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 1, 0, 8, 0, 0, 0, 0, 0, 0, 0, 1, 4,
+    0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 0, 2, 8, 0, 0, 0, 0, 0, 2, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    8, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 2, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 0, 0, 2,
+    4, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 4, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0,
+    13, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 5, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 2, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0,
+    0, 0, 4, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0,
+    0, 0, 0, 2, 2, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 2, 0, 2, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 1, 10, 0, 0, 0, 0,
+    0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 2, 10, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0,
+    0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 1, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 2, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 1,
+    6, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0,
+    2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0,
+    0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0,
+    0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0,
+    0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 5, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0,
+    0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 8, 5, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0,
+    0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1,
+    5, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 9, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0,
+    0, 0, 0, 0, 0, 1, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1,
+    5, 0, 0, 0, 0, 2, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 8,
+    1, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 10, 5, 0, 0, 0, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    51, 0, 0, 0, 0, 0, 0, 1, 11, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0,
+    0, 1, 3, 0, 0, 0, 0, 0, 2, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 11, 5, 0, 0, 0, 0, 0,
+    0, 0, 3, 0, 5, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 4, 1, 1, 0, 0, 0, 0,
+    0, 0, 2, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 17, 0, 0, 0, 0, 0,
+    0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 1, 1, 0, 5, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0, 0, 5, 1, 1, 0, 0, 0,
+    0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 2, 1, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 12, 5, 0, 0, 0,
+    0, 0, 0, 0, 4, 0, 5, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 6, 1, 1, 0, 0,
+    0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 40, 0, 0, 0,
+    0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 3, 0, 0,
+    5, 0, 0, 0, 0, 0, 1, 2, 5, 0, 0, 0, 0, 2, 4, 1, 0, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 13, 5,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 5, 0, 0, 0, 0, 1, 0, 2, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 4, 0,
+    0, 5, 0, 0, 0, 0, 0, 2, 2, 5, 0, 0, 0, 0, 2, 0, 2, 5, 0, 0, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 18,
+    0, 0, 0, 0, 0, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 1, 2, 0, 5, 0, 0, 0, 0, 0, 0, 6, 5, 0, 0, 0, 0,
+    5, 0, 0, 5, 0, 0, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 2, 2, 0, 5, 0, 0, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0,
+    14, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 5, 0, 0, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 0, 0, 2, 0, 0, 5, 0,
+    0, 6, 0, 0, 5, 0, 0, 0, 0, 0, 2, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 2, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0,
+    0, 0, 0, 7, 0, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 1, 0, 9, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0,
+    0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 0, 2, 9, 0, 0, 0, 0, 0, 2, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9,
+    0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 11, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0,
+    1, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    1, 0, 0, 0, 8, 0, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 0, 0, 2, 5,
+    0, 0, 0, 0, 6, 5, 0, 0, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 5, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 0, 0, 0, 9,
+    0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 2, 5, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    5, 1, 0, 0, 0, 9, 0, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1,
+    0, 1, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 1, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 12, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0,
+    4, 0, 1, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 2, 1, 0, 0, 0, 10, 0, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 2, 0, 1, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0, 0, 0, 0,
+    0, 0, 20, 0, 0, 0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 2, 12, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 12, 0, 0, 0, 0, 11, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 1, 0, 4, 0, 0, 0,
+    0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 4, 2, 1, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1,
+    0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 5, 2, 1, 0, 0, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 2, 1, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 12, 0, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 1, 0, 0, 2,
+    1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 6, 2, 1, 0, 0, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 2, 0, 0, 2,
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 1, 6, 1,
+    0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 2, 6,
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 13, 0, 1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 1,
+    0, 2, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 4, 0, 0, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 2,
+    0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 14, 1, 0, 0, 0, 0, 0,
+    1, 2, 0, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0,
+    2, 2, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 14, 0, 1, 0, 0, 0, 0, 0, 0, 6, 0, 0, 1, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 6, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 1,
+    0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 12, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0, 0, 4, 12, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0,
+    0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 5, 12, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    12, 0, 0, 0, 0, 2, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 0, 4,
+    4, 0, 0, 0, 0, 0, 1, 0, 0, 12, 0, 0, 0, 0, 0, 0, 2, 12, 0, 0, 0, 0, 6, 12, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 12, 0, 0, 0, 0, 2, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 43, 0, 0, 0, 0, 0, 0,
+    0, 0, 27, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0,
+    0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 9, 2, 0, 0, 0, 0,
+    0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 4, 0, 4, 0, 0, 0,
+    0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 24, 0, 0, 0, 0,
+    0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 5, 0, 4, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 2, 2, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 10, 2, 0, 0,
+    0, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 6, 0, 4, 0,
+    0, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 1, 13, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 3, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 2, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 11, 2,
+    0, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 4, 1,
+    0, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 25,
+    0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 5,
+    1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0,
+    12, 2, 0, 0, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0,
+    0, 6, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0,
+    0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    0, 0, 13, 2, 0, 0, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    2, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+    2, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 0, 0, 6, 2,
+    0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 2, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 2, 0, 0, 14, 2, 0, 0, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 2,
+    0, 0, 2, 0, 0, 6, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 26, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 0, 0, 1, 10, 0, 0, 0, 0, 0, 2, 26, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 26, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 1, 0, 10, 0, 0, 0, 0, 0,
+    0, 0, 1, 6, 0, 0, 0, 0, 4, 6, 0, 0, 0, 0, 0, 0, 0, 2, 10, 0, 0, 0, 0, 0, 2, 0, 10, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 10, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0,
+    0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0,
+    0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 6, 6, 0, 0, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 2, 0, 0, 6, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0,
+    0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 5, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 1, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 1, 2, 2,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 2, 2, 2,
+    0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 2, 0,
+    0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 1,
+    14, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0,
+    2, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0,
+    0, 1, 0, 6, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0,
+    0, 2, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0,
+    0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0,
+    0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0,
+    0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0,
+    0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2,
+    0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 5,
+    0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+    2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0,
+    14, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0,
+    0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0,
+    0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0,
+    0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0,
+    0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 2, 0, 0, 0, 0,
+    0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0,
+    0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 3, 0, 0, 0,
+    0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 9, 0, 0, 0,
+    0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 16, 2, 0,
+    0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 5, 0, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 10, 0,
+    0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 3,
+    1, 0, 0, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
+    11, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+    0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 2, 0, 0, 0, 0, 2, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0,
+    0, 17, 2, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0,
+    0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0,
+    0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 8, 2,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 27, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 1, 11, 0, 0, 0, 0, 0, 2, 27, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 27, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 1, 0, 11, 0, 0, 0,
+    0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 0, 2, 11, 0, 0, 0, 0, 0, 2, 0, 11, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 19, 2, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 1, 1, 1, 2,
+    0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 2, 1, 1, 2,
+    0, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 1, 2, 0, 0, 0, 0, 1, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 1, 5, 0,
+    0, 0, 0, 0, 0, 0, 0, 9, 2, 0, 0, 0, 0, 3, 0, 1, 2, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 5, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5, 1, 2, 0, 0, 0, 0, 1, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 4, 0, 1, 2, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 20, 2, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 1, 2,
+    1, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 5, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 2, 2,
+    1, 2, 0, 0, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 6, 1, 2, 0, 0, 0, 0, 1,
+    0, 0, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 6, 0, 1, 2, 0, 0, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 2,
+    0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0,
+    1, 8, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0,
+    0, 2, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 0, 1, 0, 4, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 2, 4, 2, 0, 0,
+    0, 0, 2, 0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 21, 2, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0,
+    0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0,
+    0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2,
+    0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0,
+    0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 10, 2, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    2, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0,
+    5, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0,
+    2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 22, 2, 0, 0, 0, 0, 0, 0,
+    0, 14, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0,
+    0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 13, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0,
+    0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 13, 0, 0, 0, 0, 4, 13, 0, 0,
+    0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 2, 0,
+    0, 0, 0, 0, 0, 0, 7, 0, 2, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 5, 13,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 13, 0, 0, 0, 0, 2, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 8,
+    1, 0, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 1, 0, 0, 13, 0, 0, 0, 0, 0, 0, 2, 13, 0, 0, 0, 0,
+    6, 13, 0, 0, 0, 0, 0, 0, 0, 2, 0, 13, 0, 0, 0, 0, 2, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13,
+    0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 1, 3, 0, 2, 0, 0, 0, 0, 0, 0, 11, 2, 0,
+    0, 0, 0, 3, 0, 5, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 2, 3, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0,
+    2, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 5,
+    0, 0, 0, 4, 0, 5, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    1, 0, 0, 24, 2, 0, 0, 0, 0, 0, 0, 0, 8, 0, 2, 0, 0, 0, 0, 1, 2, 5, 0, 0, 0, 0, 0, 0, 0, 4, 1,
+    0, 0, 0, 0, 5, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 2, 2, 5, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+    5, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 0, 0, 6, 5, 0, 0, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 0, 0, 2,
+    0, 5, 0, 0, 0, 6, 0, 5, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 2, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 0, 0, 0,
+    25, 0, 0, 0, 0, 0, 3, 1, 0, 2, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0, 0, 0, 2, 9, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 9, 0, 0, 0, 0, 11, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 1, 0, 5, 2, 0, 0, 0, 0,
+    0, 0, 1, 1, 0, 2, 0, 0, 4, 1, 0, 2, 0, 0, 0, 0, 0, 2, 5, 2, 0, 0, 0, 0, 2, 0, 5, 2, 0, 0, 0, 0,
+    0, 0, 0, 0, 5, 2, 0, 0, 25, 2, 0, 0, 0, 0, 0, 0, 0, 9, 0, 2, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0,
+    0, 0, 0, 5, 1, 0, 0, 0, 0, 5, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 2, 1, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 1, 0, 0, 12, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 2,
+    0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 6, 1, 0, 2, 0, 0, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0, 2, 0, 0, 1, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 52, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 1, 4, 0, 2, 0,
+    0, 0, 0, 0, 0, 12, 2, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 2, 4, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 1, 0, 2,
+    1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 2, 0, 2,
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 26, 2, 0, 0, 0, 0, 0, 0, 0, 10, 0, 2, 0, 0, 0, 0, 1, 2,
+    0, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 2, 2,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 14, 1, 0, 0, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 6, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 2,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0, 0, 0,
+    2, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 7, 0, 0, 2, 0, 0, 0, 0, 0, 3, 2, 2, 0, 0, 0,
+    0, 1, 0, 12, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 0, 2, 12, 0, 0, 0,
+    0, 0, 2, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 27, 2, 0, 0, 0, 0, 0, 0, 0, 11, 0, 2,
+    0, 0, 0, 0, 1, 1, 2, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4,
+    0, 0, 0, 0, 2, 1, 2, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 8, 0, 0, 2, 0, 0, 0, 0, 0, 4, 2, 2,
+    0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 0, 2, 0, 4,
+    0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0,
+    0, 0, 0, 0, 0, 1, 5, 0, 2, 0, 0, 0, 0, 0, 0, 13, 2, 0, 0, 0, 0, 3, 0, 2, 2, 0, 0, 0, 0, 0, 1,
+    1, 0, 0, 2, 0, 0, 2, 5, 0, 2, 0, 0, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 9, 0, 0, 2, 0, 0, 0, 0, 0, 5,
+    2, 2, 0, 0, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 4, 0, 2, 2, 0, 0, 0, 0, 0, 2,
+    1, 0, 0, 2, 0, 0, 2, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 28, 2, 0, 0, 0, 0, 0, 0, 0,
+    12, 0, 2, 0, 0, 0, 0, 1, 2, 2, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 5, 0, 2, 2, 0, 0, 0, 0,
+    0, 1, 0, 0, 2, 2, 0, 0, 2, 2, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 10, 0, 0, 2, 0, 0, 0,
+    0, 0, 6, 2, 2, 0, 0, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 6, 0, 2, 2, 0, 0, 0,
+    0, 0, 2, 0, 0, 2, 2, 0, 0, 2, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 58, 0, 0, 0, 0, 0, 0, 1, 10, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 3, 2, 0, 2, 0, 0,
+    0, 0, 0, 1, 6, 2, 0, 0, 0, 0, 2, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 11, 0, 0, 2, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 1, 0, 6, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 4, 2, 0, 2, 0,
+    0, 0, 0, 0, 2, 6, 2, 0, 0, 0, 0, 2, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 29, 2, 0, 0,
+    0, 0, 0, 0, 0, 13, 0, 2, 0, 0, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 5, 2, 0,
+    2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 2, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 12, 0,
+    0, 2, 0, 0, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 1, 0, 0, 2, 0, 2, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 6, 2,
+    0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 2, 0, 0, 2, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 54,
+    0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 1, 6, 0, 2, 0, 0, 0, 0, 0, 0, 14, 2, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 2, 6, 0, 2, 0, 0, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0,
+    13, 0, 0, 2, 0, 0, 0, 0, 0, 5, 0, 0, 0, 2, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    2, 4, 0, 0, 0, 0, 2, 0, 0, 0, 2, 2, 0, 0, 2, 0, 0, 2, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    2, 30, 2, 0, 0, 0, 0, 0, 0, 0, 14, 0, 2, 0, 0, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 0, 0, 6, 0, 0, 2,
+    0, 0, 5, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 2, 14, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0,
+    0, 0, 2, 6, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 7, 6, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 3, 6,
+    0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 8, 6, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 0, 0, 7,
+    0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 6, 0, 0, 0, 0, 2, 3, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    3, 2, 0, 0, 0, 9, 6, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 6, 0, 0, 0, 0, 2, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 6, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    4, 6, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 10, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 1, 11, 0, 0, 0, 0, 0, 0,
+    0, 0, 27, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 2, 11, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 11, 0, 0, 0, 0, 11, 6, 0, 0, 0, 0, 0, 0, 0, 3, 0, 6, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0,
+    0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 4, 1, 2, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 9, 2, 0, 0, 0, 0, 0, 1, 1, 0, 6, 0,
+    0, 0, 0, 0, 0, 5, 6, 0, 0, 0, 0, 5, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 2, 1, 0, 6, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 12, 6, 0, 0, 0, 0, 0, 0, 0, 4, 0, 6, 0, 0, 0, 0, 1, 0, 0, 1,
+    2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 6, 1, 2, 0, 0, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 2, 0, 0, 1,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 1, 4, 2,
+    0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 3, 0, 0, 6, 0, 0, 0, 0, 0, 1, 2, 6, 0, 0, 0, 0, 2, 4, 2,
+    0, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 13, 6, 0, 0, 0, 0, 0, 0, 0, 5, 0, 6, 0, 0, 0, 0, 1, 0,
+    2, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 4, 0, 0, 6, 0, 0, 0, 0, 0, 2, 2, 6, 0, 0, 0, 0, 2, 0,
+    2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 10, 2, 0, 0, 0, 0, 0, 1,
+    2, 0, 6, 0, 0, 0, 0, 0, 0, 6, 6, 0, 0, 0, 0, 5, 0, 0, 6, 0, 0, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 2,
+    2, 0, 6, 0, 0, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 14, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 6, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 6, 0, 0, 0, 0, 2, 0, 0, 6, 0, 0, 6, 0, 0, 6, 0, 0, 0, 0, 0, 2, 0, 0, 0, 6, 0, 0,
+    2, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 0, 1, 13, 0, 0, 0, 0,
+    0, 2, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 7, 0, 2, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0,
+    0, 0, 1, 0, 13, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 0, 2, 13, 0, 0,
+    0, 0, 0, 2, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 11, 2,
+    0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    5, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 8, 0, 2, 0, 0, 0, 0, 0, 0, 4, 1,
+    0, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 6, 5, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    5, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 25,
+    0, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 1,
+    1, 0, 2, 0, 0, 0, 2, 5, 2, 0, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 9, 0, 2, 0, 0, 0, 0, 0, 0, 5,
+    1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 0, 2,
+    1, 0, 2, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0,
+    12, 2, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 1, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 10, 0, 2, 0, 0, 0, 0,
+    0, 0, 6, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0,
+    0, 0, 2, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 60, 0, 0, 0, 0, 0, 0, 1, 12, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 3, 2, 2, 0, 0, 0,
+    0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 2, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 11, 0, 2, 0, 0,
+    0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 4, 2, 2, 0, 0,
+    0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 21, 0, 0, 0,
+    0, 0, 0, 0, 0, 13, 2, 0, 0, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 5, 2, 2,
+    0, 0, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 2, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 12, 0,
+    2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 6, 2,
+    2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 2, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 58,
+    0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 1, 6, 2, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0,
+    3, 0, 0, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 2, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0,
+    13, 0, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2,
+    0, 4, 0, 0, 0, 2, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 2, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2,
+    0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 14, 2, 0, 0, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 2, 0,
+    0, 0, 5, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    2, 0, 14, 0, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0,
+    0, 2, 0, 6, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 3, 14, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    14, 0, 0, 0, 0, 4, 14, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 1, 6, 0, 0, 0, 0, 0, 0,
+    0, 3, 2, 0, 0, 0, 0, 5, 14, 0, 0, 0, 0, 0, 0, 0, 1, 0, 14, 0, 0, 0, 0, 2, 1, 6, 0, 0, 0, 0, 0,
+    0, 0, 0, 1, 6, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 0, 4, 6, 0, 0, 0, 0, 0, 1, 0, 0, 14, 0, 0, 0,
+    0, 0, 0, 2, 14, 0, 0, 0, 0, 6, 14, 0, 0, 0, 0, 0, 0, 0, 2, 0, 14, 0, 0, 0, 0, 2, 0, 0, 14, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0,
+    0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 3, 0, 6, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 2, 3, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 9, 2, 0, 0, 0, 0, 0, 0, 0, 5, 6, 0, 0, 0, 0, 0, 1, 0, 1,
+    2, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 4, 0, 6, 0, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 2, 0, 1,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1, 2,
+    6, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 2, 2,
+    6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 10, 2, 0, 0, 0, 0, 0, 0, 0, 6, 6, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 6, 0, 6, 0, 0, 0, 0, 0, 0, 2, 0, 0, 6, 0, 0, 0, 2,
+    0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0,
+    1, 13, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0,
+    0, 2, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 11, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0,
+    0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0,
+    0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0,
+    0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0,
+    0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 12, 2, 0, 0, 0, 0, 0, 0, 0, 4, 0, 2,
+    0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1,
+    0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0,
+    0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 0, 1,
+    2, 2, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 13, 2, 0, 0, 0, 0, 0, 0, 0,
+    5, 0, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 0,
+    2, 2, 2, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0,
+    0, 10, 0, 0, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 2, 0, 0, 2, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 14, 2, 0, 0, 0, 0,
+    0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 6, 0, 0, 2, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0,
+    0, 0, 0, 0, 1, 14, 0, 0, 0, 0, 0, 2, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 7, 0, 0, 0,
+    0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 1, 0, 14, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 4, 6, 0,
+    0, 0, 0, 0, 0, 0, 2, 14, 0, 0, 0, 0, 0, 2, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 27,
+    0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0,
+    5, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0,
+    8, 0, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0,
+    6, 6, 0, 0, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 2, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0,
+    61, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0,
+    0, 3, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0,
+    0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0,
+    0, 4, 0, 2, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0,
+    0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2,
+    0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 1, 14, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0,
+    0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 2, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    14, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 0, 0,
+    1, 2, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 6, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 5, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 1, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0,
+    0, 0, 2, 2, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 2, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0,
+    0, 0, 0, 14, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0,
+    0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
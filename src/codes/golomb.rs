@@ -0,0 +1,167 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Golomb
+//! Optimal for geometric distributions.
+//! The Golomb code of x ∈ N with modulus b > 0 is obtained by writing
+//! floor(x / b) in unary, followed by x mod b in minimal binary with
+//! maximum b.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in 0..100 {
+//!         writer.write_golomb(value, 7).unwrap();
+//!     }
+//!     // the quotient u64::MAX cannot be written in unary
+//!     assert!(writer.write_golomb(u64::MAX, 1).is_err());
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut unbuffered = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..100 {
+//!     assert_eq!(reader.read_golomb(7).unwrap(), value);
+//!     assert_eq!(unbuffered.read_golomb(7).unwrap(), value);
+//! }
+//! assert_eq!(
+//!     unbuffered.get_position(),
+//!     (0..100).map(|value| len_golomb(value, 7)).sum::<usize>()
+//! );
+//!
+//! // the same holds for the L2M bit order
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in 0..100 {
+//!         writer.write_golomb(value, 7).unwrap();
+//!     }
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<L2M, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut unbuffered = <UnbufferedBitStreamRead<L2M, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..100 {
+//!     assert_eq!(reader.read_golomb(7).unwrap(), value);
+//!     assert_eq!(unbuffered.read_golomb(7).unwrap(), value);
+//! }
+//! ```
+
+use super::{int2nat, len_minimal_binary, nat2int, MinimalBinaryRead, MinimalBinaryWrite};
+use crate::traits::*;
+use anyhow::{bail, Result};
+
+/// Returns how long the Golomb code for `value` will be for a given
+/// modulus `b`, which must be nonzero.
+#[must_use]
+#[inline]
+pub fn len_golomb(value: u64, b: u64) -> usize {
+    debug_assert!(b > 0, "the modulus of a Golomb code can't be zero");
+    (value / b) as usize + 1 + len_minimal_binary(value % b, b)
+}
+
+/// Returns how long the Golomb code for the signed `value` will be for a
+/// given modulus `b`, which must be nonzero.
+#[must_use]
+#[inline]
+pub fn len_golomb_signed(value: i64, b: u64) -> usize {
+    len_golomb(int2nat(value), b)
+}
+
+/// Trait for objects that can read Golomb codes
+pub trait GolombRead<BO: BitOrder>: MinimalBinaryRead<BO> {
+    /// Read a Golomb code with modulus `b` from the stream.
+    ///
+    /// # Errors
+    /// This function fails if `b` is zero or if the BitRead backend has
+    /// problems reading bits, as when the stream ended unexpectedly
+    #[inline]
+    fn read_golomb(&mut self, b: u64) -> Result<u64> {
+        if b == 0 {
+            bail!("The modulus of a Golomb code can't be zero.");
+        }
+        let quotient = self.read_unary::<false>()?;
+        Ok(quotient * b + self.read_minimal_binary(b)?)
+    }
+
+    /// Read a Golomb code with modulus `b` from the stream and map it to a
+    /// signed value using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`GolombRead::read_golomb`]
+    #[inline]
+    fn read_golomb_signed(&mut self, b: u64) -> Result<i64> {
+        self.read_golomb(b).map(nat2int)
+    }
+
+    /// Read `values.len()` Golomb codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`GolombRead::read_golomb`]
+    #[inline]
+    fn read_golomb_into(&mut self, values: &mut [u64], b: u64) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_golomb(b)?;
+        }
+        Ok(())
+    }
+}
+
+/// Trait for objects that can write Golomb codes
+pub trait GolombWrite<BO: BitOrder>: MinimalBinaryWrite<BO> {
+    /// Write a value on the stream as a Golomb code with modulus `b`.
+    ///
+    /// The quotient `value / b` is written in unary, so it must be smaller
+    /// than [`u64::MAX`]: with `b` equal to one, [`u64::MAX`] cannot be
+    /// written.
+    ///
+    /// # Errors
+    /// This function fails if `b` is zero, if the quotient is [`u64::MAX`],
+    /// or if the BitWrite backend has problems writing bits, as when the
+    /// stream ended unexpectedly
+    #[inline]
+    fn write_golomb(&mut self, value: u64, b: u64) -> Result<()> {
+        if b == 0 {
+            bail!("The modulus of a Golomb code can't be zero.");
+        }
+        let quotient = value / b;
+        if quotient == u64::MAX {
+            bail!("The quotient of a Golomb code must be smaller than u64::MAX.");
+        }
+        self.write_unary::<false>(quotient)?;
+        self.write_minimal_binary(value % b, b)
+    }
+
+    /// Write a signed value on the stream as a Golomb code with modulus `b`,
+    /// mapping it to a natural number using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`GolombWrite::write_golomb`]
+    #[inline]
+    fn write_golomb_signed(&mut self, value: i64, b: u64) -> Result<()> {
+        self.write_golomb(int2nat(value), b)
+    }
+
+    /// Write all the `values` on the stream as Golomb codes.
+    ///
+    /// # Errors
+    /// The same as [`GolombWrite::write_golomb`]
+    #[inline]
+    fn write_golomb_all(&mut self, values: &[u64], b: u64) -> Result<()> {
+        for &value in values {
+            self.write_golomb(value, b)?;
+        }
+        Ok(())
+    }
+}
+
+impl<BO: BitOrder, B: BitRead<BO>> GolombRead<BO> for B {}
+impl<BO: BitOrder, B: BitWrite<BO>> GolombWrite<BO> for B {}
@@ -0,0 +1,347 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Binary Interpolative
+//! Binary interpolative coding, as defined by Moffat and Stuiver, of a
+//! strictly increasing sequence of n values in the interval [lo, hi]. The
+//! middle element, of rank m = floor(n / 2), is written in minimal binary as
+//! an offset in the interval of the values it can assume, that is,
+//! [lo + m, hi - (n - m - 1)]. Then, the elements before it are coded
+//! recursively in [lo, x - 1], followed by the elements after it, coded
+//! recursively in [x + 1, hi].
+//!
+//! The length n and the bounds lo and hi are not written, so they must be
+//! known when decoding. Since an interval containing a single value needs no
+//! bits, runs of consecutive values are coded very compactly, which makes
+//! this code well suited for clustered data such as posting lists.
+//!
+//! A prefix of the sequence, or a single element given its rank, can be
+//! decoded without decoding the whole sequence, but afterwards the stream is
+//! positioned in the middle of the code. To move past the code, seek back to
+//! its start and skip it with [`InterpolativeRead::skip_interpolative`],
+//! which needs only n, lo and hi.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let values = [3, 4, 5, 6, 10, 11, 23, 100, 101, 102, 511];
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     writer.write_interpolative(&values, 0, 511).unwrap();
+//!     writer.write_interpolative(&values, 0, u64::MAX).unwrap();
+//!     // values must be strictly increasing and within the bounds
+//!     assert!(writer.write_interpolative(&[1, 1], 0, 511).is_err());
+//!     assert!(writer.write_interpolative(&[1, 2], 0, 1).is_err());
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut decoded = [0; 11];
+//! reader.read_interpolative(&mut decoded, 0, 511).unwrap();
+//! assert_eq!(decoded, values);
+//! reader.read_interpolative(&mut decoded, 0, u64::MAX).unwrap();
+//! assert_eq!(decoded, values);
+//! assert_eq!(
+//!     reader.get_position(),
+//!     len_interpolative(&values, 0, 511) + len_interpolative(&values, 0, u64::MAX)
+//! );
+//!
+//! // decode a prefix
+//! let mut reader = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+//! let mut prefix = [0; 4];
+//! reader.read_interpolative_prefix(values.len(), 0, 511, &mut prefix).unwrap();
+//! assert_eq!(prefix, values[..4]);
+//!
+//! // decode single elements
+//! for (rank, value) in values.iter().enumerate() {
+//!     reader.seek_bit(0).unwrap();
+//!     assert_eq!(
+//!         reader.read_interpolative_rank(values.len(), 0, 511, rank).unwrap(),
+//!         *value
+//!     );
+//! }
+//! assert!(reader.read_interpolative_rank(values.len(), 0, 511, 11).is_err());
+//!
+//! // move past the first code
+//! reader.seek_bit(0).unwrap();
+//! reader.skip_interpolative(values.len(), 0, 511).unwrap();
+//! assert_eq!(reader.get_position(), len_interpolative(&values, 0, 511));
+//! reader.read_interpolative(&mut decoded, 0, u64::MAX).unwrap();
+//! assert_eq!(decoded, values);
+//! ```
+
+use super::{len_minimal_binary, MinimalBinaryRead, MinimalBinaryWrite};
+use crate::traits::*;
+use anyhow::{bail, Result};
+
+/// Returns how long the binary interpolative code of the strictly increasing
+/// sequence `values`, with all elements between `lo` and `hi`, will be
+#[must_use]
+#[inline]
+pub fn len_interpolative(values: &[u64], lo: u64, hi: u64) -> usize {
+    if values.is_empty() {
+        return 0;
+    }
+    let mid = values.len() / 2;
+    let value = values[mid];
+    let min = lo as u128 + mid as u128;
+    let max = hi as u128 - (values.len() - 1 - mid) as u128;
+    debug_assert!(min <= value as u128 && value as u128 <= max);
+    let size = max - min + 1;
+    let len = match u64::try_from(size) {
+        Ok(size) => len_minimal_binary((value as u128 - min) as u64, size),
+        Err(_) => 64,
+    };
+    len + len_interpolative(&values[..mid], lo, value.saturating_sub(1))
+        + len_interpolative(&values[mid + 1..], value.saturating_add(1), hi)
+}
+
+/// Trait for objects that can read binary interpolative codes
+pub trait InterpolativeRead<BO: BitOrder>: MinimalBinaryRead<BO> {
+    /// Read the binary interpolative code of a strictly increasing sequence
+    /// of `values.len()` elements between `lo` and `hi` from the stream,
+    /// storing it in `values`.
+    ///
+    /// # Errors
+    /// This function fails if `values.len()` elements do not fit between `lo`
+    /// and `hi` or if the BitRead backend has problems reading bits, as when
+    /// the stream ended unexpectedly
+    #[inline]
+    fn read_interpolative(&mut self, values: &mut [u64], lo: u64, hi: u64) -> Result<()> {
+        read_interpolative_prefix(self, values.len(), lo, hi, values)
+    }
+
+    /// Read the first `prefix.len()` elements of the binary interpolative code
+    /// of a strictly increasing sequence of `n` elements between `lo` and
+    /// `hi`, storing them in `prefix`.
+    ///
+    /// The stream is not moved past the end of the code, unless the whole
+    /// sequence is read.
+    ///
+    /// # Errors
+    /// This function fails if `prefix` is longer than `n`, if `n` elements do
+    /// not fit between `lo` and `hi`, or if the BitRead backend has problems
+    /// reading bits, as when the stream ended unexpectedly
+    #[inline]
+    fn read_interpolative_prefix(
+        &mut self,
+        n: usize,
+        lo: u64,
+        hi: u64,
+        prefix: &mut [u64],
+    ) -> Result<()> {
+        if prefix.len() > n {
+            bail!(
+                "The prefix length {} is larger than the sequence length {}.",
+                prefix.len(),
+                n
+            );
+        }
+        read_interpolative_prefix(self, n, lo, hi, prefix)
+    }
+
+    /// Read the element of rank `rank` of the binary interpolative code of a
+    /// strictly increasing sequence of `n` elements between `lo` and `hi`.
+    ///
+    /// The elements coded before the requested one must be decoded anyway,
+    /// and the stream is not moved past the end of the code.
+    ///
+    /// # Errors
+    /// This function fails if `rank` is not smaller than `n`, if `n` elements
+    /// do not fit between `lo` and `hi`, or if the BitRead backend has
+    /// problems reading bits, as when the stream ended unexpectedly
+    #[inline]
+    fn read_interpolative_rank(&mut self, n: usize, lo: u64, hi: u64, rank: usize) -> Result<u64> {
+        if rank >= n {
+            bail!(
+                "The rank {} is not smaller than the sequence length {}.",
+                rank,
+                n
+            );
+        }
+        read_interpolative_rank(self, n, lo, hi, rank)
+    }
+
+    /// Skip the binary interpolative code of a strictly increasing sequence
+    /// of `n` elements between `lo` and `hi`. The elements are decoded
+    /// anyway, as they determine the intervals of the following ones.
+    ///
+    /// # Errors
+    /// This function fails if `n` elements do not fit between `lo` and `hi`
+    /// or if the BitRead backend has problems reading bits, as when the
+    /// stream ended unexpectedly
+    #[inline]
+    fn skip_interpolative(&mut self, n: usize, lo: u64, hi: u64) -> Result<()> {
+        skip_interpolative(self, n, lo, hi)
+    }
+}
+
+/// Trait for objects that can write binary interpolative codes
+pub trait InterpolativeWrite<BO: BitOrder>: MinimalBinaryWrite<BO> {
+    /// Write the strictly increasing sequence `values`, with all elements
+    /// between `lo` and `hi`, on the stream
+    ///
+    /// # Errors
+    /// This function fails if `values` is not strictly increasing, if some
+    /// element is not between `lo` and `hi`, or if the BitWrite backend has
+    /// problems writing bits, as when the stream ended unexpectedly
+    #[inline]
+    fn write_interpolative(&mut self, values: &[u64], lo: u64, hi: u64) -> Result<()> {
+        if values.is_empty() {
+            return Ok(());
+        }
+        let mid = values.len() / 2;
+        let value = values[mid];
+        let (min, size) = middle_interval(values.len(), lo, hi)?;
+        if value < min || (value - min) as u128 >= size {
+            bail!(
+                "The sequence is not strictly increasing or not within [{}, {}].",
+                lo,
+                hi
+            );
+        }
+        if size > u64::MAX as u128 {
+            self.write_bits(value - min, 64)?;
+        } else {
+            self.write_minimal_binary(value - min, size as u64)?;
+        }
+        self.write_interpolative(&values[..mid], lo, value.saturating_sub(1))?;
+        self.write_interpolative(&values[mid + 1..], value.saturating_add(1), hi)
+    }
+}
+
+/// Return the smallest value and the number of values that the middle
+/// element of a strictly increasing sequence of `n` elements between `lo`
+/// and `hi` can assume. The number of values is 2^64 only if `n` is one,
+/// `lo` is zero and `hi` is [`u64::MAX`].
+///
+/// # Errors
+/// Fails if `n` elements do not fit between `lo` and `hi`.
+#[inline(always)]
+fn middle_interval(n: usize, lo: u64, hi: u64) -> Result<(u64, u128)> {
+    debug_assert!(n > 0);
+    let mid = n / 2;
+    let min = lo as u128 + mid as u128;
+    match (hi as u128).checked_sub((n - 1 - mid) as u128) {
+        Some(max) if max >= min => Ok((min as u64, max - min + 1)),
+        _ => bail!(
+            "{} strictly increasing values do not fit in [{}, {}].",
+            n,
+            lo,
+            hi
+        ),
+    }
+}
+
+/// Read the middle element of a strictly increasing sequence of `n` elements
+/// between `lo` and `hi`.
+///
+/// # Errors
+/// Forward `read_minimal_binary` and `read_bits` errors, and fails if `n`
+/// elements do not fit between `lo` and `hi`.
+#[inline(always)]
+fn read_middle<BO: BitOrder, B: MinimalBinaryRead<BO> + ?Sized>(
+    backend: &mut B,
+    n: usize,
+    lo: u64,
+    hi: u64,
+) -> Result<u64> {
+    let (min, size) = middle_interval(n, lo, hi)?;
+    let offset = if size > u64::MAX as u128 {
+        backend.read_bits(64)?
+    } else {
+        backend.read_minimal_binary(size as u64)?
+    };
+    Ok(min + offset)
+}
+
+/// Read the first `prefix.len()` elements of a sequence of `n` elements,
+/// stopping as soon as the remaining elements are not needed.
+///
+/// # Errors
+/// Forward `read_middle` errors.
+fn read_interpolative_prefix<BO: BitOrder, B: MinimalBinaryRead<BO> + ?Sized>(
+    backend: &mut B,
+    n: usize,
+    lo: u64,
+    hi: u64,
+    prefix: &mut [u64],
+) -> Result<()> {
+    if prefix.is_empty() {
+        return Ok(());
+    }
+    let mid = n / 2;
+    let value = read_middle(backend, n, lo, hi)?;
+    let left = prefix.len().min(mid);
+    read_interpolative_prefix(
+        backend,
+        mid,
+        lo,
+        value.saturating_sub(1),
+        &mut prefix[..left],
+    )?;
+    if mid < prefix.len() {
+        prefix[mid] = value;
+        read_interpolative_prefix(
+            backend,
+            n - mid - 1,
+            value.saturating_add(1),
+            hi,
+            &mut prefix[mid + 1..],
+        )?;
+    }
+    Ok(())
+}
+
+/// Read the element of rank `rank` of a sequence of `n` elements.
+///
+/// # Errors
+/// Forward `read_middle` errors.
+fn read_interpolative_rank<BO: BitOrder, B: MinimalBinaryRead<BO> + ?Sized>(
+    backend: &mut B,
+    n: usize,
+    lo: u64,
+    hi: u64,
+    rank: usize,
+) -> Result<u64> {
+    let mid = n / 2;
+    let value = read_middle(backend, n, lo, hi)?;
+    if rank < mid {
+        read_interpolative_rank(backend, mid, lo, value - 1, rank)
+    } else if rank > mid {
+        // the elements before the middle one are coded first
+        skip_interpolative(backend, mid, lo, value.saturating_sub(1))?;
+        read_interpolative_rank(backend, n - mid - 1, value + 1, hi, rank - mid - 1)
+    } else {
+        Ok(value)
+    }
+}
+
+/// Skip the code of a sequence of `n` elements. The elements must be decoded
+/// anyway, as they determine the intervals of the following ones.
+///
+/// # Errors
+/// Forward `read_middle` errors.
+fn skip_interpolative<BO: BitOrder, B: MinimalBinaryRead<BO> + ?Sized>(
+    backend: &mut B,
+    n: usize,
+    lo: u64,
+    hi: u64,
+) -> Result<()> {
+    if n == 0 {
+        return Ok(());
+    }
+    let mid = n / 2;
+    let value = read_middle(backend, n, lo, hi)?;
+    skip_interpolative(backend, mid, lo, value.saturating_sub(1))?;
+    skip_interpolative(backend, n - mid - 1, value.saturating_add(1), hi)
+}
+
+impl<BO: BitOrder, B: BitRead<BO>> InterpolativeRead<BO> for B {}
+impl<BO: BitOrder, B: BitWrite<BO>> InterpolativeWrite<BO> for B {}
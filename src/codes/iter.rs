@@ -0,0 +1,197 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Decoding iterators
+//! [`CodeIter`] wraps a reader and yields the values coded in the stream with
+//! a [`StaticCode`], so that decoding loops can use iterator adapters and
+//! collect errors instead of handling them at each call. [`GammaIter`],
+//! [`DeltaIter`], [`UnaryIter`] and [`ZetaIter`] are shorthands for the
+//! corresponding codes.
+//!
+//! The iterator either yields values until the stream ends, or, if it was
+//! created with [`CodeIter::with_len`], exactly the given number of values.
+//! In both cases it stops after the first error, and it is fused. Since a
+//! stream does not record where its last code ends, an iterator created with
+//! [`CodeIter::new`] always ends with an error, which comes from decoding the
+//! zero padding of the last word or from reading past the end of the stream:
+//! use [`CodeIter::with_len`] when the number of values is known. As it never
+//! reads ahead, it can also be limited with [`Iterator::take`].
+//! [`Iterator::nth`] (and thus [`Iterator::skip`]) skips the intermediate
+//! values without decoding them. The reader can be recovered with
+//! [`CodeIter::into_inner`], and, if it implements [`BitSeek`], its position
+//! is available on the iterator too.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     writer.write_gamma_all::<true>(&(0..1000).collect::<Vec<_>>()).unwrap();
+//!     writer.write_delta::<true, true>(1_000_000).unwrap();
+//! }
+//!
+//! let reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut iter = GammaIter::with_len(reader, 1000);
+//! assert_eq!(iter.size_hint(), (1000, Some(1000)));
+//! let first = iter.by_ref().take(10).collect::<anyhow::Result<Vec<_>>>().unwrap();
+//! assert_eq!(first, (0..10).collect::<Vec<_>>());
+//! // skips values 10..=509 without decoding them
+//! assert_eq!(iter.nth(500).unwrap().unwrap(), 510);
+//! assert_eq!(iter.map(|value| value.unwrap()).sum::<u64>(), (511..1000).sum());
+//!
+//! // the iterator has been consumed: iterate again with a new reader, skip
+//! // all the γ codes, then recover the reader and read the δ code after them
+//! let reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut iter = GammaIter::with_len(reader, 1000);
+//! assert!(iter.nth(1000).is_none());
+//! assert_eq!(
+//!     iter.get_position(),
+//!     (0..1000).map(len_gamma::<true>).sum::<usize>()
+//! );
+//! let mut reader = iter.into_inner();
+//! assert_eq!(reader.read_delta::<true, true>().unwrap(), 1_000_000);
+//!
+//! // without a length, the iterator decodes as γ codes also the δ code and
+//! // the padding, and ends with an error
+//! let reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut iter = GammaIter::new(reader);
+//! let values = iter.by_ref().collect::<Vec<_>>();
+//! assert!(values[..1000].iter().all(Result::is_ok));
+//! assert!(values.last().unwrap().is_err());
+//! assert!(iter.next().is_none());
+//! ```
+
+use super::*;
+use crate::traits::*;
+use anyhow::Result;
+use core::marker::PhantomData;
+
+/// An iterator over the values coded with the code `C` in the stream of a
+/// reader `R`
+#[derive(Debug, Clone)]
+pub struct CodeIter<BO: BitOrder, R, C: StaticCode> {
+    /// The reader we decode values from
+    reader: R,
+    /// The number of values still to be read, or `None` if we read values
+    /// until the stream ends
+    remaining: Option<usize>,
+    _marker: PhantomData<(BO, C)>,
+}
+
+/// An iterator over unary codes
+pub type UnaryIter<BO, R> = CodeIter<BO, R, Unary>;
+/// An iterator over γ codes
+pub type GammaIter<BO, R> = CodeIter<BO, R, Gamma>;
+/// An iterator over δ codes
+pub type DeltaIter<BO, R> = CodeIter<BO, R, Delta>;
+/// An iterator over ζ codes with index `K`
+pub type ZetaIter<BO, R, const K: u64> = CodeIter<BO, R, Zeta<K>>;
+
+impl<BO: BitOrder, R, C: StaticCode> CodeIter<BO, R, C> {
+    /// Create an iterator yielding the values in the stream of `reader` until
+    /// an error occurs
+    ///
+    /// The iteration always ends with an error, as the zero padding at the end
+    /// of the stream is decoded too: use [`CodeIter::with_len`] to stop
+    /// cleanly after a known number of values.
+    #[must_use]
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            remaining: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Create an iterator yielding the next `len` values in the stream of
+    /// `reader`
+    #[must_use]
+    pub fn with_len(reader: R, len: usize) -> Self {
+        Self {
+            reader,
+            remaining: Some(len),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Return the underlying reader, positioned after the last value read
+    /// or skipped
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>, C: StaticCode> Iterator for CodeIter<BO, R, C> {
+    type Item = Result<u64>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.remaining {
+            Some(0) => return None,
+            Some(remaining) => *remaining -= 1,
+            None => {}
+        }
+        let result = C::read(&mut self.reader);
+        if result.is_err() {
+            self.remaining = Some(0);
+        }
+        Some(result)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let to_skip = match self.remaining {
+            // skip what is left, so that the reader ends up after the last value
+            Some(remaining) if n >= remaining => remaining,
+            _ => n,
+        };
+        if to_skip > 0 {
+            if let Err(error) = C::skip_n(&mut self.reader, to_skip) {
+                self.remaining = Some(0);
+                return Some(Err(error));
+            }
+            if let Some(remaining) = &mut self.remaining {
+                *remaining -= to_skip;
+            }
+        }
+        if to_skip < n {
+            return None;
+        }
+        self.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (0, None),
+        }
+    }
+}
+
+impl<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>, C: StaticCode> core::iter::FusedIterator
+    for CodeIter<BO, R, C>
+{
+}
+
+impl<BO: BitOrder, R: BitSeek, C: StaticCode> BitSeek for CodeIter<BO, R, C> {
+    /// Move the underlying reader to `bit_index`; the number of values still
+    /// to be read, if known, is not changed
+    #[inline]
+    fn seek_bit(&mut self, bit_index: usize) -> Result<()> {
+        self.reader.seek_bit(bit_index)
+    }
+
+    #[inline]
+    fn get_position(&self) -> usize {
+        self.reader.get_position()
+    }
+}
@@ -0,0 +1,192 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Levenshtein
+//! Levenshtein’s universal coding of x ∈ N: zero is written as a single zero.
+//! Otherwise, x is written in binary without its most significant bit,
+//! preceded by the same representation of the number of bits just written,
+//! recursively, until the number of bits is zero. The whole code is preceded
+//! by the number of steps c written as c ones followed by a zero.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     writer.write_levenshtein(4).unwrap();
+//!     for value in 0..1000 {
+//!         writer.write_levenshtein(value).unwrap();
+//!     }
+//!     writer.write_levenshtein(u64::MAX).unwrap();
+//! }
+//!
+//! let mut reader = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+//! assert_eq!(reader.read_bits(7).unwrap(), 0b1110_000);
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_levenshtein().unwrap(), value);
+//! }
+//! assert_eq!(reader.read_levenshtein().unwrap(), u64::MAX);
+//! assert_eq!(
+//!     reader.get_position(),
+//!     (0..1000).map(len_levenshtein).sum::<usize>()
+//!         + len_levenshtein(4)
+//!         + len_levenshtein(u64::MAX)
+//! );
+//! ```
+
+use super::{fast_floor_log2, int2nat, nat2int};
+use crate::traits::*;
+use anyhow::{bail, Result};
+
+/// Returns how long the Levenshtein code for `value` will be
+#[must_use]
+#[inline]
+pub fn len_levenshtein(value: u64) -> usize {
+    if value == 0 {
+        return 1;
+    }
+    let mut steps = 1;
+    let mut res = 0;
+    let mut n = fast_floor_log2(value) as u64;
+    res += n as usize;
+    while n > 0 {
+        steps += 1;
+        n = fast_floor_log2(n) as u64;
+        res += n as usize;
+    }
+    res + steps + 1
+}
+
+/// Returns how long the Levenshtein code for the signed `value` will be
+#[must_use]
+#[inline]
+pub fn len_levenshtein_signed(value: i64) -> usize {
+    len_levenshtein(int2nat(value))
+}
+
+/// Trait for objects that can read Levenshtein codes
+pub trait LevenshteinRead<BO: BitOrder>: BitRead<BO> {
+    /// Read a Levenshtein code from the stream.
+    ///
+    /// # Errors
+    /// This function fails only if the BitRead backend has problems reading
+    /// bits, as when the stream ended unexpectedly, or if the code does not
+    /// fit in a [`u64`]
+    #[inline]
+    fn read_levenshtein(&mut self) -> Result<u64> {
+        let mut steps = 0;
+        while self.read_bits(1)? != 0 {
+            steps += 1;
+        }
+        if steps == 0 {
+            return Ok(0);
+        }
+        let mut n: u64 = 1;
+        for _ in 1..steps {
+            if n >= 64 {
+                bail!("The Levenshtein code does not fit in a u64.");
+            }
+            n = (1 << n) | self.read_bits(n as usize)?;
+        }
+        Ok(n)
+    }
+
+    /// Read a Levenshtein code from the stream and map it to a signed value
+    /// using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`LevenshteinRead::read_levenshtein`]
+    #[inline]
+    fn read_levenshtein_signed(&mut self) -> Result<i64> {
+        self.read_levenshtein().map(nat2int)
+    }
+
+    /// Read `values.len()` Levenshtein codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`LevenshteinRead::read_levenshtein`]
+    #[inline]
+    fn read_levenshtein_into(&mut self, values: &mut [u64]) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_levenshtein()?;
+        }
+        Ok(())
+    }
+}
+
+/// Trait for objects that can write Levenshtein codes
+pub trait LevenshteinWrite<BO: BitOrder>: BitWrite<BO> {
+    /// Write a value on the stream
+    ///
+    /// # Errors
+    /// This function fails only if the BitWrite backend has problems writing
+    /// bits, as when the stream ended unexpectedly
+    #[inline]
+    fn write_levenshtein(&mut self, value: u64) -> Result<()> {
+        if value == 0 {
+            return self.write_bits(0, 1);
+        }
+        let mut steps = 1;
+        let mut n = value;
+        while n > 1 {
+            steps += 1;
+            n = fast_floor_log2(n) as u64;
+        }
+        self.write_bits((1 << steps) - 1, steps)?;
+        self.write_bits(0, 1)?;
+        write_levenshtein_groups(self, value)
+    }
+
+    /// Write a signed value on the stream as a Levenshtein code, mapping it
+    /// to a natural number using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`LevenshteinWrite::write_levenshtein`]
+    #[inline]
+    fn write_levenshtein_signed(&mut self, value: i64) -> Result<()> {
+        self.write_levenshtein(int2nat(value))
+    }
+
+    /// Write all the `values` on the stream as Levenshtein codes.
+    ///
+    /// # Errors
+    /// The same as [`LevenshteinWrite::write_levenshtein`]
+    #[inline]
+    fn write_levenshtein_all(&mut self, values: &[u64]) -> Result<()> {
+        for &value in values {
+            self.write_levenshtein(value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Write the binary representations without the most significant bit of a
+/// Levenshtein code for `n`, starting from the innermost one. The recursion
+/// depth is at most five for a [`u64`].
+///
+/// # Errors
+/// Forward `write_bits` errors.
+#[inline(always)]
+fn write_levenshtein_groups<BO: BitOrder, B: BitWrite<BO> + ?Sized>(
+    backend: &mut B,
+    n: u64,
+) -> Result<()> {
+    if n <= 1 {
+        return Ok(());
+    }
+    let l = fast_floor_log2(n);
+    write_levenshtein_groups(backend, l as u64)?;
+    // remove the most significant 1
+    backend.write_bits(n - (1 << l), l as usize)
+}
+
+impl<BO: BitOrder, B: BitRead<BO>> LevenshteinRead<BO> for B {}
+impl<BO: BitOrder, B: BitWrite<BO>> LevenshteinWrite<BO> for B {}
@@ -13,7 +13,7 @@
 //! When the size of the alphabet is a power of two, this is equivalent to
 //! the classical binary encoding.
 
-use super::fast_floor_log2;
+use super::{fast_floor_log2, int2nat, nat2int};
 use crate::traits::*;
 use anyhow::{bail, Result};
 
@@ -26,7 +26,8 @@ pub fn len_minimal_binary(value: u64, max: u64) -> usize {
         return 0;
     }
     let l = fast_floor_log2(max);
-    let limit = (1 << (l + 1)) - max;
+    // wrapping, so that we get the correct limit also when l is 63
+    let limit = (2_u64 << l).wrapping_sub(max);
     let mut result = l as usize;
     if value >= limit {
         result += 1;
@@ -34,6 +35,14 @@ pub fn len_minimal_binary(value: u64, max: u64) -> usize {
     result
 }
 
+/// Returns how long the minimal binary code for the signed `value` will be
+/// for a given `max`
+#[must_use]
+#[inline]
+pub fn len_minimal_binary_signed(value: i64, max: u64) -> usize {
+    len_minimal_binary(int2nat(value), max)
+}
+
 /// Trait for objects that can read Minimal Binary codes
 pub trait MinimalBinaryRead<BO: BitOrder>: BitRead<BO> {
     /// Read a minimal binary code from the stream.
@@ -48,7 +57,7 @@ pub trait MinimalBinaryRead<BO: BitOrder>: BitRead<BO> {
         }
         let l = fast_floor_log2(max);
         let mut value = self.read_bits(l as _)?;
-        let limit = (1 << (l + 1)) - max;
+        let limit = (2_u64 << l).wrapping_sub(max);
 
         Ok(if value < limit {
             value
@@ -58,6 +67,59 @@ pub trait MinimalBinaryRead<BO: BitOrder>: BitRead<BO> {
             value - limit
         })
     }
+
+    /// Read a minimal binary code from the stream and map it to a signed value
+    /// using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`MinimalBinaryRead::read_minimal_binary`]
+    #[inline]
+    fn read_minimal_binary_signed(&mut self, max: u64) -> Result<i64> {
+        self.read_minimal_binary(max).map(nat2int)
+    }
+
+    /// Read `values.len()` minimal binary codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`MinimalBinaryRead::read_minimal_binary`]
+    #[inline]
+    fn read_minimal_binary_into(&mut self, values: &mut [u64], max: u64) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_minimal_binary(max)?;
+        }
+        Ok(())
+    }
+
+    /// Skip a minimal binary code from the stream without decoding it.
+    ///
+    /// # Errors
+    /// The same as [`MinimalBinaryRead::read_minimal_binary`]
+    #[inline]
+    fn skip_minimal_binary(&mut self, max: u64) -> Result<()> {
+        self.skip_minimal_binary_n(1, max)
+    }
+
+    /// Skip `n` minimal binary codes from the stream without decoding them.
+    ///
+    /// # Errors
+    /// The same as [`MinimalBinaryRead::read_minimal_binary`]
+    #[inline]
+    fn skip_minimal_binary_n(&mut self, n: usize, max: u64) -> Result<()> {
+        if max == 0 {
+            bail!("The max of a minimal binary value can't be zero.");
+        }
+        let l = fast_floor_log2(max);
+        let limit = (2_u64 << l).wrapping_sub(max);
+        for _ in 0..n {
+            // the code is one bit longer if its first l bits are not
+            // smaller than the limit
+            if self.read_bits(l as _)? >= limit {
+                self.skip_bits(1)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Trait for objects that can write Minimal Binary codes
@@ -73,7 +135,7 @@ pub trait MinimalBinaryWrite<BO: BitOrder>: BitWrite<BO> {
             bail!("The max of a minimal binary value can't be zero.");
         }
         let l = fast_floor_log2(max);
-        let limit = (1 << (l + 1)) - max;
+        let limit = (2_u64 << l).wrapping_sub(max);
 
         if value < limit {
             self.write_bits(value, l as _)
@@ -83,6 +145,29 @@ pub trait MinimalBinaryWrite<BO: BitOrder>: BitWrite<BO> {
             self.write_bits(to_write & 1, 1)
         }
     }
+
+    /// Write a signed value on the stream as a minimal binary code, mapping
+    /// it to a natural number, which must be smaller than `max`, using
+    /// [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`MinimalBinaryWrite::write_minimal_binary`]
+    #[inline]
+    fn write_minimal_binary_signed(&mut self, value: i64, max: u64) -> Result<()> {
+        self.write_minimal_binary(int2nat(value), max)
+    }
+
+    /// Write all the `values` on the stream as minimal binary codes.
+    ///
+    /// # Errors
+    /// The same as [`MinimalBinaryWrite::write_minimal_binary`]
+    #[inline]
+    fn write_minimal_binary_all(&mut self, values: &[u64], max: u64) -> Result<()> {
+        for &value in values {
+            self.write_minimal_binary(value, max)?;
+        }
+        Ok(())
+    }
 }
 
 impl<BO: BitOrder, B: BitRead<BO>> MinimalBinaryRead<BO> for B {}
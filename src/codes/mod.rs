@@ -70,37 +70,125 @@
 
 mod gamma;
 
-pub use gamma::{len_gamma, GammaRead, GammaWrite};
+pub use gamma::{len_gamma, len_gamma_signed, GammaRead, GammaWrite};
 
 mod delta;
-pub use delta::{len_delta, DeltaRead, DeltaWrite};
+pub use delta::{len_delta, len_delta_signed, DeltaRead, DeltaWrite};
+
+mod omega;
+pub use omega::{len_omega, len_omega_signed, OmegaRead, OmegaWrite};
 
 mod minimal_binary;
-pub use minimal_binary::{len_minimal_binary, MinimalBinaryRead, MinimalBinaryWrite};
+pub use minimal_binary::{
+    len_minimal_binary, len_minimal_binary_signed, MinimalBinaryRead, MinimalBinaryWrite,
+};
 
 mod zeta;
-pub use zeta::{len_zeta, ZetaRead, ZetaWrite};
+pub use zeta::{
+    len_zeta, len_zeta_const, len_zeta_const_signed, len_zeta_signed, ZetaRead, ZetaWrite,
+};
+
+mod golomb;
+pub use golomb::{len_golomb, len_golomb_signed, GolombRead, GolombWrite};
+
+mod rice;
+pub use rice::{len_rice, len_rice_signed, RiceRead, RiceWrite};
+
+mod exp_golomb;
+pub use exp_golomb::{len_exp_golomb, len_exp_golomb_signed, ExpGolombRead, ExpGolombWrite};
+
+mod fibonacci;
+pub use fibonacci::{len_fibonacci, len_fibonacci_signed, FibonacciRead, FibonacciWrite};
+
+mod nibble;
+pub use nibble::{len_nibble, len_nibble_signed, NibbleRead, NibbleWrite};
+
+mod pi;
+pub use pi::{len_pi, len_pi_signed, PiRead, PiWrite};
+
+mod skewed_golomb;
+pub use skewed_golomb::{
+    len_skewed_golomb, len_skewed_golomb_signed, SkewedGolombRead, SkewedGolombWrite,
+};
+
+mod levenshtein;
+pub use levenshtein::{len_levenshtein, len_levenshtein_signed, LevenshteinRead, LevenshteinWrite};
+
+mod even_rodeh;
+pub use even_rodeh::{len_even_rodeh, len_even_rodeh_signed, EvenRodehRead, EvenRodehWrite};
+
+mod interpolative;
+pub use interpolative::{len_interpolative, InterpolativeRead, InterpolativeWrite};
+
+mod code;
+pub use code::Code;
+
+mod static_code;
+pub use static_code::{Delta, Gamma, StaticCode, Unary, Zeta};
+
+mod iter;
+pub use iter::{CodeIter, DeltaIter, GammaIter, UnaryIter, ZetaIter};
+
+#[cfg(feature = "alloc")]
+mod dynamic_table;
+#[cfg(feature = "alloc")]
+pub use dynamic_table::{DynamicTable, DynamicTableRead, DynamicTableWrite};
+
+mod stats;
+pub use stats::CodesStats;
+
+pub(crate) mod batch;
+
+mod tables;
+
+#[cfg(feature = "two_level_tables")]
+mod two_level;
 
 pub mod delta_tables;
+pub mod fibonacci_tables;
+pub mod gamma_multi_tables;
 pub mod gamma_tables;
+pub mod omega_tables;
+pub mod pi_tables;
+pub mod rice_tables;
+pub mod unary_multi_tables;
 pub mod unary_tables;
+#[cfg(feature = "zeta2_tables")]
+pub mod zeta2_tables;
+#[cfg(feature = "zeta4_tables")]
+pub mod zeta4_tables;
+#[cfg(feature = "zeta5_tables")]
+pub mod zeta5_tables;
+#[cfg(feature = "zeta6_tables")]
+pub mod zeta6_tables;
+#[cfg(feature = "zeta7_tables")]
+pub mod zeta7_tables;
 pub mod zeta_tables;
 
 #[must_use]
 #[inline]
-/// Return how long the unary code for `value` will be
+/// Return how long the unary code for `value` will be; `value` must be
+/// smaller than [`u64::MAX`], whose code would be 2⁶⁴ bits long
 ///
-/// `USE_TABLE` enables or disables the use of pre-computed tables
-/// for decoding
+/// `USE_TABLE` enables or disables the use of pre-computed tables for decoding
 pub fn len_unary<const USE_TABLE: bool>(value: u64) -> usize {
-    // we can use the table but it's not useful at all
-    // I implemented if for consistency with all the other codes
+    debug_assert_ne!(value, u64::MAX, "the unary code of u64::MAX is too long");
     if USE_TABLE {
-        if let Some(idx) = unary_tables::LEN.get(value as usize) {
-            return *idx as usize;
+        if let Some(len) = unary_tables::LEN.get(value as usize) {
+            return *len as usize;
         }
     }
-    (value + 1) as usize
+    value as usize + 1
+}
+
+#[must_use]
+#[inline]
+/// Return how long the unary code for the signed `value` will be; `value`
+/// must not be [`i64::MIN`], which is mapped to [`u64::MAX`]
+///
+/// `USE_TABLE` enables or disables the use of pre-computed tables for decoding
+pub fn len_unary_signed<const USE_TABLE: bool>(value: i64) -> usize {
+    len_unary::<USE_TABLE>(int2nat(value))
 }
 
 #[inline(always)]
@@ -110,3 +198,56 @@ pub fn fast_floor_log2(value: u64) -> u32 {
     debug_assert!(value > 0, "log2(0) is undefined");
     63 - value.leading_zeros()
 }
+
+#[inline(always)]
+/// Return the floor of the base 2 logarithm of `value + 1`, without
+/// overflowing when `value` is [`u64::MAX`].
+pub(crate) fn fast_floor_log2_succ(value: u64) -> u32 {
+    127 - (value as u128 + 1).leading_zeros()
+}
+
+#[must_use]
+#[inline(always)]
+/// Map a signed integer to a natural number using the zigzag mapping
+/// 0, -1, 1, -2, 2, ... → 0, 1, 2, 3, 4, ..., which is defined on the whole
+/// [`i64`] range.
+///
+/// This is the mapping used by all the `_signed` methods of the codes.
+///
+/// #### Example:
+/// ```
+/// use dsi_bitstream::prelude::*;
+///
+/// assert_eq!(int2nat(0), 0);
+/// assert_eq!(int2nat(-1), 1);
+/// assert_eq!(int2nat(1), 2);
+/// assert_eq!(int2nat(i64::MAX), u64::MAX - 1);
+/// assert_eq!(int2nat(i64::MIN), u64::MAX);
+///
+/// let mut buffer: Vec<u64> = vec![];
+/// {
+///     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+///     for value in [0, -1, 1, i64::MIN, i64::MAX] {
+///         writer.write_gamma_signed::<true>(value).unwrap();
+///         writer.write_delta_signed::<true, true>(value).unwrap();
+///         writer.write_zeta_signed::<true>(value, 3).unwrap();
+///     }
+/// }
+///
+/// let mut reader = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+/// for value in [0, -1, 1, i64::MIN, i64::MAX] {
+///     assert_eq!(reader.read_gamma_signed::<true>().unwrap(), value);
+///     assert_eq!(reader.read_delta_signed::<true, true>().unwrap(), value);
+///     assert_eq!(reader.read_zeta_signed::<true>(3).unwrap(), value);
+/// }
+/// ```
+pub fn int2nat(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+#[must_use]
+#[inline(always)]
+/// Map back a natural number to a signed integer, inverting [`int2nat`].
+pub fn nat2int(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
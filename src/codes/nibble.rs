@@ -0,0 +1,163 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Nibble
+//! Variable-length nibble coding, as in `OutputBitStream::writeNibble` of the
+//! [DSI Utilities](https://dsiutils.di.unimi.it/). The binary representation
+//! of x ∈ N is split in groups of three bits, starting from the most
+//! significant one, and each group is preceded by a bit that is one only for
+//! the last group.
+//!
+//! Note that, as in the DSI Utilities, zero is a valid value and it is not
+//! shifted by one.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     writer.write_nibble(0).unwrap();
+//!     writer.write_nibble(9).unwrap();
+//!     for value in 0..1000 {
+//!         writer.write_nibble(value).unwrap();
+//!     }
+//!     writer.write_nibble(u64::MAX).unwrap();
+//! }
+//!
+//! let mut reader = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+//! // the same bits written by the DSI Utilities
+//! assert_eq!(reader.read_bits(12).unwrap(), 0b1000_0001_1001);
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_nibble().unwrap(), value);
+//! }
+//! assert_eq!(reader.read_nibble().unwrap(), u64::MAX);
+//! assert_eq!(
+//!     reader.get_position(),
+//!     (0..1000).map(len_nibble).sum::<usize>() + len_nibble(0) + len_nibble(9) + len_nibble(u64::MAX)
+//! );
+//! ```
+
+use super::{fast_floor_log2, int2nat, nat2int};
+use crate::traits::*;
+use anyhow::{bail, Result};
+
+/// Returns how long the nibble code for `value` will be
+#[must_use]
+#[inline]
+pub fn len_nibble(value: u64) -> usize {
+    if value == 0 {
+        return 4;
+    }
+    4 * (fast_floor_log2(value) as usize / 3 + 1)
+}
+
+/// Returns how long the nibble code for the signed `value` will be
+#[must_use]
+#[inline]
+pub fn len_nibble_signed(value: i64) -> usize {
+    len_nibble(int2nat(value))
+}
+
+/// Trait for objects that can read nibble codes
+pub trait NibbleRead<BO: BitOrder>: BitRead<BO> {
+    /// Read a nibble code from the stream.
+    ///
+    /// # Errors
+    /// This function fails only if the BitRead backend has problems reading
+    /// bits, as when the stream ended unexpectedly, or if the code does not
+    /// fit in a [`u64`]
+    #[inline]
+    fn read_nibble(&mut self) -> Result<u64> {
+        let mut value: u64 = 0;
+        loop {
+            if value >> 61 != 0 {
+                bail!("The nibble code does not fit in a u64.");
+            }
+            let last = self.read_bits(1)?;
+            value = (value << 3) | self.read_bits(3)?;
+            if last != 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Read a nibble code from the stream and map it to a signed value
+    /// using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`NibbleRead::read_nibble`]
+    #[inline]
+    fn read_nibble_signed(&mut self) -> Result<i64> {
+        self.read_nibble().map(nat2int)
+    }
+
+    /// Read `values.len()` nibble codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`NibbleRead::read_nibble`]
+    #[inline]
+    fn read_nibble_into(&mut self, values: &mut [u64]) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_nibble()?;
+        }
+        Ok(())
+    }
+}
+
+/// Trait for objects that can write nibble codes
+pub trait NibbleWrite<BO: BitOrder>: BitWrite<BO> {
+    /// Write a value on the stream
+    ///
+    /// # Errors
+    /// This function fails only if the BitWrite backend has problems writing
+    /// bits, as when the stream ended unexpectedly
+    #[inline]
+    fn write_nibble(&mut self, value: u64) -> Result<()> {
+        if value == 0 {
+            self.write_bits(1, 1)?;
+            return self.write_bits(0, 3);
+        }
+        let mut groups = fast_floor_log2(value) / 3;
+        loop {
+            self.write_bits((groups == 0) as u64, 1)?;
+            self.write_bits((value >> (groups * 3)) & 7, 3)?;
+            if groups == 0 {
+                return Ok(());
+            }
+            groups -= 1;
+        }
+    }
+
+    /// Write a signed value on the stream as a nibble code, mapping it to a
+    /// natural number using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`NibbleWrite::write_nibble`]
+    #[inline]
+    fn write_nibble_signed(&mut self, value: i64) -> Result<()> {
+        self.write_nibble(int2nat(value))
+    }
+
+    /// Write all the `values` on the stream as nibble codes.
+    ///
+    /// # Errors
+    /// The same as [`NibbleWrite::write_nibble`]
+    #[inline]
+    fn write_nibble_all(&mut self, values: &[u64]) -> Result<()> {
+        for &value in values {
+            self.write_nibble(value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<BO: BitOrder, B: BitRead<BO>> NibbleRead<BO> for B {}
+impl<BO: BitOrder, B: BitWrite<BO>> NibbleWrite<BO> for B {}
@@ -0,0 +1,240 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Elias’ ω
+//! universal coding of x ∈ N+ is obtained by recursively writing the length
+//! of the binary representation of x, and then x itself, followed by a zero.
+//! More precisely, each group is a one followed by the binary representation
+//! of n without its most significant bit, where n starts from x and is
+//! replaced by floor(log(n)) until it becomes one. To code x ∈ N, as the
+//! other codes of this crate, we write the code of x + 1.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in 0..1000 {
+//!         writer.write_omega::<true>(value).unwrap();
+//!     }
+//!     writer.write_omega::<false>(u64::MAX).unwrap();
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut unbuffered = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_omega::<true>().unwrap(), value);
+//!     assert_eq!(unbuffered.read_omega::<false>().unwrap(), value);
+//! }
+//! assert_eq!(reader.read_omega::<true>().unwrap(), u64::MAX);
+//! assert_eq!(
+//!     unbuffered.get_position(),
+//!     (0..1000).map(len_omega::<false>).sum::<usize>()
+//! );
+//! ```
+
+use super::{int2nat, nat2int, omega_tables};
+use crate::traits::*;
+use anyhow::{bail, Result};
+
+/// Returns how long the omega code for `value` will be
+///
+/// `USE_TABLE` enables or disables the use of pre-computed tables
+/// for decoding
+#[must_use]
+#[inline]
+pub fn len_omega<const USE_TABLE: bool>(value: u64) -> usize {
+    if USE_TABLE {
+        if let Some(idx) = omega_tables::LEN.get(value as usize) {
+            return *idx as usize;
+        }
+    }
+    let mut n = value as u128 + 1;
+    let mut res = 1;
+    while n > 1 {
+        let l = 127 - n.leading_zeros();
+        res += l as usize + 1;
+        n = l as u128;
+    }
+    res
+}
+
+/// Returns how long the omega code for the signed `value` will be
+///
+/// `USE_TABLE` enables or disables the use of pre-computed tables
+/// for decoding
+#[must_use]
+#[inline]
+pub fn len_omega_signed<const USE_TABLE: bool>(value: i64) -> usize {
+    len_omega::<USE_TABLE>(int2nat(value))
+}
+
+/// Trait for objects that can read omega codes
+pub trait OmegaRead<BO: BitOrder>: BitRead<BO> {
+    /// Read an omega code from the stream.
+    ///
+    /// `USE_TABLE` enables or disables the use of pre-computed tables
+    /// for decoding
+    ///
+    /// # Errors
+    /// This function fails only if the BitRead backend has problems reading
+    /// bits, as when the stream ended unexpectedly, or if the code does not
+    /// fit in a [`u64`]
+    fn read_omega<const USE_TABLE: bool>(&mut self) -> Result<u64>;
+
+    /// Read an omega code from the stream and map it to a signed value
+    /// using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`OmegaRead::read_omega`]
+    #[inline]
+    fn read_omega_signed<const USE_TABLE: bool>(&mut self) -> Result<i64> {
+        self.read_omega::<USE_TABLE>().map(nat2int)
+    }
+
+    /// Read `values.len()` ω codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`OmegaRead::read_omega`]
+    #[inline]
+    fn read_omega_into<const USE_TABLE: bool>(&mut self, values: &mut [u64]) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_omega::<USE_TABLE>()?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: BitRead<M2L>> OmegaRead<M2L> for B {
+    #[inline]
+    fn read_omega<const USE_TABLE: bool>(&mut self) -> Result<u64> {
+        if USE_TABLE {
+            if let Some(res) = omega_tables::read_table_m2l(self)? {
+                return Ok(res);
+            }
+        }
+        default_read_omega(self)
+    }
+}
+impl<B: BitRead<L2M>> OmegaRead<L2M> for B {
+    #[inline]
+    fn read_omega<const USE_TABLE: bool>(&mut self) -> Result<u64> {
+        if USE_TABLE {
+            if let Some(res) = omega_tables::read_table_l2m(self)? {
+                return Ok(res);
+            }
+        }
+        default_read_omega(self)
+    }
+}
+
+/// Common part of the M2L and L2M impl
+///
+/// # Errors
+/// Forward `read_bits` errors.
+#[inline(always)]
+fn default_read_omega<BO: BitOrder, B: BitRead<BO>>(backend: &mut B) -> Result<u64> {
+    let mut n: u128 = 1;
+    loop {
+        if backend.read_bits(1)? == 0 {
+            match u64::try_from(n - 1) {
+                Ok(value) => return Ok(value),
+                Err(_) => bail!("The omega code does not fit in a u64."),
+            }
+        }
+        if n > 64 {
+            bail!("The omega code does not fit in a u64.");
+        }
+        n = (1 << n) | backend.read_bits(n as usize)? as u128;
+    }
+}
+
+/// Trait for objects that can write omega codes
+pub trait OmegaWrite<BO: BitOrder>: BitWrite<BO> {
+    /// Write a value on the stream
+    ///
+    /// `USE_TABLE` enables or disables the use of pre-computed tables
+    /// for decoding
+    ///
+    /// # Errors
+    /// This function fails only if the BitWrite backend has problems writing
+    /// bits, as when the stream ended unexpectedly
+    fn write_omega<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()>;
+
+    /// Write a signed value on the stream, mapping it to a natural number
+    /// using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`OmegaWrite::write_omega`]
+    #[inline]
+    fn write_omega_signed<const USE_TABLE: bool>(&mut self, value: i64) -> Result<()> {
+        self.write_omega::<USE_TABLE>(int2nat(value))
+    }
+
+    /// Write all the `values` on the stream as ω codes.
+    ///
+    /// # Errors
+    /// The same as [`OmegaWrite::write_omega`]
+    #[inline]
+    fn write_omega_all<const USE_TABLE: bool>(&mut self, values: &[u64]) -> Result<()> {
+        for &value in values {
+            self.write_omega::<USE_TABLE>(value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: BitWrite<M2L>> OmegaWrite<M2L> for B {
+    #[inline]
+    fn write_omega<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
+        if USE_TABLE && omega_tables::write_table_m2l(self, value)? {
+            return Ok(());
+        }
+        default_write_omega(self, value)
+    }
+}
+impl<B: BitWrite<L2M>> OmegaWrite<L2M> for B {
+    #[inline]
+    fn write_omega<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
+        if USE_TABLE && omega_tables::write_table_l2m(self, value)? {
+            return Ok(());
+        }
+        default_write_omega(self, value)
+    }
+}
+
+/// Common part of the M2L and L2M impl
+///
+/// # Errors
+/// Forward `write_bits` errors.
+#[inline(always)]
+fn default_write_omega<BO: BitOrder, B: BitWrite<BO>>(backend: &mut B, value: u64) -> Result<()> {
+    write_omega_groups(backend, value as u128 + 1)?;
+    backend.write_bits(0, 1)
+}
+
+/// Write the groups of an omega code for `n`, starting from the innermost
+/// one. The recursion depth is at most five for a [`u64`], and `n` is a
+/// [`u128`] as it might be 2^64.
+///
+/// # Errors
+/// Forward `write_bits` errors.
+#[inline(always)]
+fn write_omega_groups<BO: BitOrder, B: BitWrite<BO>>(backend: &mut B, n: u128) -> Result<()> {
+    if n <= 1 {
+        return Ok(());
+    }
+    let l = 127 - n.leading_zeros();
+    write_omega_groups(backend, l as u128)?;
+    backend.write_bits(1, 1)?;
+    // remove the most significant 1
+    backend.write_bits((n - (1 << l)) as u64, l as usize)
+}
@@ -0,0 +1,344 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Pi
+//! The π code of index k of x ∈ N+, as defined by Apostolico and Drovandi,
+//! is obtained by splitting the length h of the binary representation of x in
+//! blocks of 2^k bits: the number of blocks l = ceil(h / 2^k) is written in
+//! unary, then l·2^k - h is written in minimal binary (that is, on k bits),
+//! and finally x is written in binary without its most significant bit.
+//!
+//! π codes fit heavy-tailed distributions that lie between the ones for which
+//! γ and ζ are optimal.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in 0..1000 {
+//!         writer.write_pi2::<true>(value).unwrap();
+//!         writer.write_pi::<false>(value, 3).unwrap();
+//!     }
+//!     writer.write_pi::<false>(u64::MAX, 1).unwrap();
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_pi2::<true>().unwrap(), value);
+//!     assert_eq!(reader.read_pi::<true>(3).unwrap(), value);
+//! }
+//! assert_eq!(reader.read_pi::<false>(1).unwrap(), u64::MAX);
+//!
+//! let mut reader = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_pi::<false>(2).unwrap(), value);
+//!     assert_eq!(reader.read_pi::<false>(3).unwrap(), value);
+//! }
+//! assert_eq!(
+//!     reader.get_position(),
+//!     (0..1000)
+//!         .map(|value| len_pi::<true>(value, 2) + len_pi::<false>(value, 3))
+//!         .sum::<usize>()
+//! );
+//! ```
+
+use super::{
+    fast_floor_log2_succ, int2nat, nat2int, pi_tables, MinimalBinaryRead, MinimalBinaryWrite,
+};
+use crate::traits::*;
+use anyhow::{bail, Result};
+
+/// Returns how long the π code of index `k` for `value` will be; as when
+/// reading and writing, `k` must be at most 6
+///
+/// `USE_TABLE` enables or disables the use of pre-computed tables
+/// for decoding
+#[must_use]
+#[inline]
+pub fn len_pi<const USE_TABLE: bool>(value: u64, k: u64) -> usize {
+    debug_assert!(k <= 6, "the index of a π code must be at most 6");
+    if USE_TABLE && k == pi_tables::K {
+        if let Some(idx) = pi_tables::LEN.get(value as usize) {
+            return *idx as usize;
+        }
+    }
+    let r = fast_floor_log2_succ(value) as u64;
+    let l = (r >> k) + 1;
+    (l + k + r) as usize
+}
+
+/// Returns how long the π code of index `k` for the signed `value` will be;
+/// `k` must be at most 6
+///
+/// `USE_TABLE` enables or disables the use of pre-computed tables
+/// for decoding
+#[must_use]
+#[inline]
+pub fn len_pi_signed<const USE_TABLE: bool>(value: i64, k: u64) -> usize {
+    len_pi::<USE_TABLE>(int2nat(value), k)
+}
+
+/// Trait for objects that can read π codes
+pub trait PiRead<BO: BitOrder>: MinimalBinaryRead<BO> {
+    /// Generic π code reader. The tables are used only when `k` is equal to
+    /// [`pi_tables::K`].
+    ///
+    /// # Errors
+    /// This function fails only if the BitRead backend has problems reading
+    /// bits, as when the stream ended unexpectedly, or if the code does not
+    /// fit in a [`u64`]
+    fn read_pi<const USE_TABLE: bool>(&mut self, k: u64) -> Result<u64>;
+    /// Specialized π code reader for k = 2
+    ///
+    /// # Errors
+    /// This function fails only if the BitRead backend has problems reading
+    /// bits, as when the stream ended unexpectedly
+    fn read_pi2<const USE_TABLE: bool>(&mut self) -> Result<u64>;
+
+    /// Generic π code reader mapping the value to a signed one using
+    /// [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`PiRead::read_pi`]
+    #[inline]
+    fn read_pi_signed<const USE_TABLE: bool>(&mut self, k: u64) -> Result<i64> {
+        self.read_pi::<USE_TABLE>(k).map(nat2int)
+    }
+    /// Specialized π code reader for k = 2 mapping the value to a signed one
+    /// using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`PiRead::read_pi2`]
+    #[inline]
+    fn read_pi2_signed<const USE_TABLE: bool>(&mut self) -> Result<i64> {
+        self.read_pi2::<USE_TABLE>().map(nat2int)
+    }
+
+    /// Read `values.len()` π codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`PiRead::read_pi`]
+    #[inline]
+    fn read_pi_into<const USE_TABLE: bool>(&mut self, values: &mut [u64], k: u64) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_pi::<USE_TABLE>(k)?;
+        }
+        Ok(())
+    }
+
+    /// Read `values.len()` π2 codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`PiRead::read_pi2`]
+    #[inline]
+    fn read_pi2_into<const USE_TABLE: bool>(&mut self, values: &mut [u64]) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_pi2::<USE_TABLE>()?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: BitRead<M2L>> PiRead<M2L> for B {
+    #[inline]
+    fn read_pi<const USE_TABLE: bool>(&mut self, k: u64) -> Result<u64> {
+        if USE_TABLE && k == pi_tables::K {
+            if let Some(res) = pi_tables::read_table_m2l(self)? {
+                return Ok(res);
+            }
+        }
+        default_read_pi(self, k)
+    }
+
+    #[inline]
+    fn read_pi2<const USE_TABLE: bool>(&mut self) -> Result<u64> {
+        if USE_TABLE {
+            if let Some(res) = pi_tables::read_table_m2l(self)? {
+                return Ok(res);
+            }
+        }
+        default_read_pi(self, 2)
+    }
+}
+impl<B: BitRead<L2M>> PiRead<L2M> for B {
+    #[inline]
+    fn read_pi<const USE_TABLE: bool>(&mut self, k: u64) -> Result<u64> {
+        if USE_TABLE && k == pi_tables::K {
+            if let Some(res) = pi_tables::read_table_l2m(self)? {
+                return Ok(res);
+            }
+        }
+        default_read_pi(self, k)
+    }
+
+    #[inline]
+    fn read_pi2<const USE_TABLE: bool>(&mut self) -> Result<u64> {
+        if USE_TABLE {
+            if let Some(res) = pi_tables::read_table_l2m(self)? {
+                return Ok(res);
+            }
+        }
+        default_read_pi(self, 2)
+    }
+}
+
+/// Common part of the M2L and L2M impl
+///
+/// # Errors
+/// Forward `read_unary`, `read_minimal_binary` and `read_bits` errors.
+#[inline(always)]
+fn default_read_pi<BO: BitOrder, B: BitRead<BO>>(backend: &mut B, k: u64) -> Result<u64> {
+    if k > 6 {
+        bail!(
+            "The index of a π code has to be in [0, 6] and {} is not.",
+            k
+        );
+    }
+    let l = backend.read_unary::<false>()? + 1;
+    let v = backend.read_minimal_binary(1 << k)?;
+    let h = l
+        .checked_mul(1 << k)
+        .and_then(|blocks| blocks.checked_sub(v))
+        .unwrap_or(u64::MAX);
+    if h > 65 {
+        bail!("The π code does not fit in a u64.");
+    }
+    let r = h - 1;
+    let short_value = backend.read_bits(r as usize)?;
+    match short_value.checked_add(((1_u128 << r) - 1) as u64) {
+        Some(value) => Ok(value),
+        None => bail!("The π code does not fit in a u64."),
+    }
+}
+
+/// Trait for objects that can write π codes
+pub trait PiWrite<BO: BitOrder>: MinimalBinaryWrite<BO> {
+    /// Generic π code writer. The tables are used only when `k` is equal to
+    /// [`pi_tables::K`].
+    ///
+    /// # Errors
+    /// This function fails only if the BitWrite backend has problems writing
+    /// bits, as when the stream ended unexpectedly
+    fn write_pi<const USE_TABLE: bool>(&mut self, value: u64, k: u64) -> Result<()>;
+    /// Specialized π code writer for k = 2
+    ///
+    /// # Errors
+    /// This function fails only if the BitWrite backend has problems writing
+    /// bits, as when the stream ended unexpectedly
+    fn write_pi2<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()>;
+
+    /// Generic π code writer for a signed value, which is mapped to a natural
+    /// number using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`PiWrite::write_pi`]
+    #[inline]
+    fn write_pi_signed<const USE_TABLE: bool>(&mut self, value: i64, k: u64) -> Result<()> {
+        self.write_pi::<USE_TABLE>(int2nat(value), k)
+    }
+    /// Specialized π code writer for k = 2 for a signed value, which is
+    /// mapped to a natural number using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`PiWrite::write_pi2`]
+    #[inline]
+    fn write_pi2_signed<const USE_TABLE: bool>(&mut self, value: i64) -> Result<()> {
+        self.write_pi2::<USE_TABLE>(int2nat(value))
+    }
+
+    /// Write all the `values` on the stream as π codes.
+    ///
+    /// # Errors
+    /// The same as [`PiWrite::write_pi`]
+    #[inline]
+    fn write_pi_all<const USE_TABLE: bool>(&mut self, values: &[u64], k: u64) -> Result<()> {
+        for &value in values {
+            self.write_pi::<USE_TABLE>(value, k)?;
+        }
+        Ok(())
+    }
+
+    /// Write all the `values` on the stream as π2 codes.
+    ///
+    /// # Errors
+    /// The same as [`PiWrite::write_pi2`]
+    #[inline]
+    fn write_pi2_all<const USE_TABLE: bool>(&mut self, values: &[u64]) -> Result<()> {
+        for &value in values {
+            self.write_pi2::<USE_TABLE>(value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: BitWrite<M2L>> PiWrite<M2L> for B {
+    #[inline]
+    fn write_pi<const USE_TABLE: bool>(&mut self, value: u64, k: u64) -> Result<()> {
+        if USE_TABLE && k == pi_tables::K && pi_tables::write_table_m2l(self, value)? {
+            return Ok(());
+        }
+        default_write_pi(self, value, k)
+    }
+
+    #[inline]
+    fn write_pi2<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
+        if USE_TABLE && pi_tables::write_table_m2l(self, value)? {
+            return Ok(());
+        }
+        default_write_pi(self, value, 2)
+    }
+}
+impl<B: BitWrite<L2M>> PiWrite<L2M> for B {
+    #[inline]
+    fn write_pi<const USE_TABLE: bool>(&mut self, value: u64, k: u64) -> Result<()> {
+        if USE_TABLE && k == pi_tables::K && pi_tables::write_table_l2m(self, value)? {
+            return Ok(());
+        }
+        default_write_pi(self, value, k)
+    }
+
+    #[inline]
+    fn write_pi2<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
+        if USE_TABLE && pi_tables::write_table_l2m(self, value)? {
+            return Ok(());
+        }
+        default_write_pi(self, value, 2)
+    }
+}
+
+/// Common part of the M2L and L2M impl
+///
+/// # Errors
+/// Forward `write_unary`, `write_minimal_binary` and `write_bits` errors.
+#[inline(always)]
+fn default_write_pi<BO: BitOrder, B: BitWrite<BO>>(
+    backend: &mut B,
+    value: u64,
+    k: u64,
+) -> Result<()> {
+    if k > 6 {
+        bail!(
+            "The index of a π code has to be in [0, 6] and {} is not.",
+            k
+        );
+    }
+    let r = fast_floor_log2_succ(value) as u64;
+    let h = r + 1;
+    let l = (r >> k) + 1;
+
+    // Write the code
+    backend.write_unary::<false>(l - 1)?;
+    backend.write_minimal_binary((l << k) - h, 1 << k)?;
+    // remove the most significant 1 of value + 1
+    backend.write_bits((value as u128 + 1 - (1 << r)) as u64, r as usize)
+}
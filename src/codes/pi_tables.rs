@@ -0,0 +1,819 @@
+// THIS FILE HAS BEEN GENERATED WITH THE SCRIPT code_tables_generator.py
+// ~~~~~~~~~~~~~~~~~~~ DO NOT MODIFY ~~~~~~~~~~~~~~~~~~~~~~
+// Pre-computed constants used to speedup the reading and writing of pi codes
+#![allow(clippy::unnecessary_cast)]
+use crate::traits::{BitRead, BitWrite, UpcastableInto, L2M, M2L};
+use anyhow::Result;
+/// How many bits are needed to read the tables in this
+pub const READ_BITS: usize = 12;
+/// The len we assign to a code that cannot be decoded through the table
+pub const MISSING_VALUE_LEN: u8 = 255;
+/// Maximum value writable using the table(s)
+pub const WRITE_MAX: u64 = 255;
+
+#[inline(always)]
+/// Autogenerated function to lookup a read table, if the result is `Some` the
+/// value was found, otherwise we were not able to decode the value and you
+/// should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn read_table_l2m<B: BitRead<L2M>>(backend: &mut B) -> Result<Option<u64>> {
+    if let Ok(idx) = backend.peek_bits(READ_BITS) {
+        let idx: u64 = idx.upcast();
+        let len = READ_LEN_L2M[idx as usize];
+        if len != MISSING_VALUE_LEN {
+            backend.skip_bits_after_table_lookup(len as usize)?;
+            return Ok(Some(READ_L2M[idx as usize] as u64));
+        }
+    }
+    Ok(None)
+}
+
+#[inline(always)]
+/// Autogenerated function to skip a code using the read table, if the result
+/// is `true` the code was skipped, otherwise we were not able to find its
+/// length and you should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn skip_table_l2m<B: BitRead<L2M>>(backend: &mut B) -> Result<bool> {
+    if let Ok(idx) = backend.peek_bits(READ_BITS) {
+        let idx: u64 = idx.upcast();
+        let len = READ_LEN_L2M[idx as usize];
+        if len != MISSING_VALUE_LEN {
+            backend.skip_bits_after_table_lookup(len as usize)?;
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+#[inline(always)]
+/// Autogenerated function to lookup a read table, if the result is `Some` the
+/// value was found, otherwise we were not able to decode the value and you
+/// should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn write_table_l2m<B: BitWrite<L2M>>(backend: &mut B, value: u64) -> Result<bool> {
+    Ok(if let Some(bits) = WRITE_L2M.get(value as usize) {
+        backend.write_bits(*bits as u64, WRITE_LEN_L2M[value as usize] as usize)?;
+        true
+    } else {
+        false
+    })
+}
+
+#[inline(always)]
+/// Autogenerated function to lookup a read table, if the result is `Some` the
+/// value was found, otherwise we were not able to decode the value and you
+/// should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn read_table_m2l<B: BitRead<M2L>>(backend: &mut B) -> Result<Option<u64>> {
+    if let Ok(idx) = backend.peek_bits(READ_BITS) {
+        let idx: u64 = idx.upcast();
+        let len = READ_LEN_M2L[idx as usize];
+        if len != MISSING_VALUE_LEN {
+            backend.skip_bits_after_table_lookup(len as usize)?;
+            return Ok(Some(READ_M2L[idx as usize] as u64));
+        }
+    }
+    Ok(None)
+}
+
+#[inline(always)]
+/// Autogenerated function to skip a code using the read table, if the result
+/// is `true` the code was skipped, otherwise we were not able to find its
+/// length and you should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn skip_table_m2l<B: BitRead<M2L>>(backend: &mut B) -> Result<bool> {
+    if let Ok(idx) = backend.peek_bits(READ_BITS) {
+        let idx: u64 = idx.upcast();
+        let len = READ_LEN_M2L[idx as usize];
+        if len != MISSING_VALUE_LEN {
+            backend.skip_bits_after_table_lookup(len as usize)?;
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+#[inline(always)]
+/// Autogenerated function to lookup a read table, if the result is `Some` the
+/// value was found, otherwise we were not able to decode the value and you
+/// should fallback to the default implementation
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+pub fn write_table_m2l<B: BitWrite<M2L>>(backend: &mut B, value: u64) -> Result<bool> {
+    Ok(if let Some(bits) = WRITE_M2L.get(value as usize) {
+        backend.write_bits(*bits as u64, WRITE_LEN_M2L[value as usize] as usize)?;
+        true
+    } else {
+        false
+    })
+}
+///Table containing the values used to speed up the reading of pi codes
+pub const READ_M2L: &[u16] = &[
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    127, 127, 128, 128, 129, 129, 130, 130, 131, 131, 132, 132, 133, 133, 134, 134, 135, 135, 136,
+    136, 137, 137, 138, 138, 139, 139, 140, 140, 141, 141, 142, 142, 143, 143, 144, 144, 145, 145,
+    146, 146, 147, 147, 148, 148, 149, 149, 150, 150, 151, 151, 152, 152, 153, 153, 154, 154, 155,
+    155, 156, 156, 157, 157, 158, 158, 159, 159, 160, 160, 161, 161, 162, 162, 163, 163, 164, 164,
+    165, 165, 166, 166, 167, 167, 168, 168, 169, 169, 170, 170, 171, 171, 172, 172, 173, 173, 174,
+    174, 175, 175, 176, 176, 177, 177, 178, 178, 179, 179, 180, 180, 181, 181, 182, 182, 183, 183,
+    184, 184, 185, 185, 186, 186, 187, 187, 188, 188, 189, 189, 190, 190, 191, 191, 192, 192, 193,
+    193, 194, 194, 195, 195, 196, 196, 197, 197, 198, 198, 199, 199, 200, 200, 201, 201, 202, 202,
+    203, 203, 204, 204, 205, 205, 206, 206, 207, 207, 208, 208, 209, 209, 210, 210, 211, 211, 212,
+    212, 213, 213, 214, 214, 215, 215, 216, 216, 217, 217, 218, 218, 219, 219, 220, 220, 221, 221,
+    222, 222, 223, 223, 224, 224, 225, 225, 226, 226, 227, 227, 228, 228, 229, 229, 230, 230, 231,
+    231, 232, 232, 233, 233, 234, 234, 235, 235, 236, 236, 237, 237, 238, 238, 239, 239, 240, 240,
+    241, 241, 242, 242, 243, 243, 244, 244, 245, 245, 246, 246, 247, 247, 248, 248, 249, 249, 250,
+    250, 251, 251, 252, 252, 253, 253, 254, 254, 63, 63, 63, 63, 64, 64, 64, 64, 65, 65, 65, 65,
+    66, 66, 66, 66, 67, 67, 67, 67, 68, 68, 68, 68, 69, 69, 69, 69, 70, 70, 70, 70, 71, 71, 71, 71,
+    72, 72, 72, 72, 73, 73, 73, 73, 74, 74, 74, 74, 75, 75, 75, 75, 76, 76, 76, 76, 77, 77, 77, 77,
+    78, 78, 78, 78, 79, 79, 79, 79, 80, 80, 80, 80, 81, 81, 81, 81, 82, 82, 82, 82, 83, 83, 83, 83,
+    84, 84, 84, 84, 85, 85, 85, 85, 86, 86, 86, 86, 87, 87, 87, 87, 88, 88, 88, 88, 89, 89, 89, 89,
+    90, 90, 90, 90, 91, 91, 91, 91, 92, 92, 92, 92, 93, 93, 93, 93, 94, 94, 94, 94, 95, 95, 95, 95,
+    96, 96, 96, 96, 97, 97, 97, 97, 98, 98, 98, 98, 99, 99, 99, 99, 100, 100, 100, 100, 101, 101,
+    101, 101, 102, 102, 102, 102, 103, 103, 103, 103, 104, 104, 104, 104, 105, 105, 105, 105, 106,
+    106, 106, 106, 107, 107, 107, 107, 108, 108, 108, 108, 109, 109, 109, 109, 110, 110, 110, 110,
+    111, 111, 111, 111, 112, 112, 112, 112, 113, 113, 113, 113, 114, 114, 114, 114, 115, 115, 115,
+    115, 116, 116, 116, 116, 117, 117, 117, 117, 118, 118, 118, 118, 119, 119, 119, 119, 120, 120,
+    120, 120, 121, 121, 121, 121, 122, 122, 122, 122, 123, 123, 123, 123, 124, 124, 124, 124, 125,
+    125, 125, 125, 126, 126, 126, 126, 31, 31, 31, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32,
+    32, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35,
+    35, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38,
+    38, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41,
+    41, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 44, 44, 44, 44, 44, 44, 44,
+    44, 45, 45, 45, 45, 45, 45, 45, 45, 46, 46, 46, 46, 46, 46, 46, 46, 47, 47, 47, 47, 47, 47, 47,
+    47, 48, 48, 48, 48, 48, 48, 48, 48, 49, 49, 49, 49, 49, 49, 49, 49, 50, 50, 50, 50, 50, 50, 50,
+    50, 51, 51, 51, 51, 51, 51, 51, 51, 52, 52, 52, 52, 52, 52, 52, 52, 53, 53, 53, 53, 53, 53, 53,
+    53, 54, 54, 54, 54, 54, 54, 54, 54, 55, 55, 55, 55, 55, 55, 55, 55, 56, 56, 56, 56, 56, 56, 56,
+    56, 57, 57, 57, 57, 57, 57, 57, 57, 58, 58, 58, 58, 58, 58, 58, 58, 59, 59, 59, 59, 59, 59, 59,
+    59, 60, 60, 60, 60, 60, 60, 60, 60, 61, 61, 61, 61, 61, 61, 61, 61, 62, 62, 62, 62, 62, 62, 62,
+    62, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 16, 16, 16, 16, 16, 16, 16,
+    16, 16, 16, 16, 16, 16, 16, 16, 16, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+    17, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 19, 19, 19, 19, 19, 19, 19,
+    19, 19, 19, 19, 19, 19, 19, 19, 19, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+    20, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 22, 22, 22, 22, 22, 22, 22,
+    22, 22, 22, 22, 22, 22, 22, 22, 22, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23,
+    23, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 25, 25, 25, 25, 25, 25, 25,
+    25, 25, 25, 25, 25, 25, 25, 25, 25, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+    26, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 28, 28, 28, 28, 28, 28, 28,
+    28, 28, 28, 28, 28, 28, 28, 28, 28, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+    29, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 13, 13, 13, 13, 13, 13,
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13,
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13,
+    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14,
+    14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14,
+    14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14,
+    14, 14, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0,
+];
+///Table contaings the lens used to speed up the reading of pi codes
+pub const READ_LEN_M2L: &[u8] = &[
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3,
+];
+///Table containing the values used to speed up the reading of pi codes
+pub const READ_L2M: &[u16] = &[
+    0, 7, 127, 3, 0, 1, 63, 0, 0, 8, 31, 4, 0, 2, 15, 0, 0, 9, 128, 5, 0, 1, 64, 0, 0, 10, 32, 6,
+    0, 2, 16, 0, 0, 11, 129, 3, 0, 1, 65, 0, 0, 12, 33, 4, 0, 2, 17, 0, 0, 13, 130, 5, 0, 1, 66, 0,
+    0, 14, 34, 6, 0, 2, 18, 0, 0, 7, 131, 3, 0, 1, 67, 0, 0, 8, 35, 4, 0, 2, 19, 0, 0, 9, 132, 5,
+    0, 1, 68, 0, 0, 10, 36, 6, 0, 2, 20, 0, 0, 11, 133, 3, 0, 1, 69, 0, 0, 12, 37, 4, 0, 2, 21, 0,
+    0, 13, 134, 5, 0, 1, 70, 0, 0, 14, 38, 6, 0, 2, 22, 0, 0, 7, 135, 3, 0, 1, 71, 0, 0, 8, 39, 4,
+    0, 2, 23, 0, 0, 9, 136, 5, 0, 1, 72, 0, 0, 10, 40, 6, 0, 2, 24, 0, 0, 11, 137, 3, 0, 1, 73, 0,
+    0, 12, 41, 4, 0, 2, 25, 0, 0, 13, 138, 5, 0, 1, 74, 0, 0, 14, 42, 6, 0, 2, 26, 0, 0, 7, 139, 3,
+    0, 1, 75, 0, 0, 8, 43, 4, 0, 2, 27, 0, 0, 9, 140, 5, 0, 1, 76, 0, 0, 10, 44, 6, 0, 2, 28, 0, 0,
+    11, 141, 3, 0, 1, 77, 0, 0, 12, 45, 4, 0, 2, 29, 0, 0, 13, 142, 5, 0, 1, 78, 0, 0, 14, 46, 6,
+    0, 2, 30, 0, 0, 7, 143, 3, 0, 1, 79, 0, 0, 8, 47, 4, 0, 2, 15, 0, 0, 9, 144, 5, 0, 1, 80, 0, 0,
+    10, 48, 6, 0, 2, 16, 0, 0, 11, 145, 3, 0, 1, 81, 0, 0, 12, 49, 4, 0, 2, 17, 0, 0, 13, 146, 5,
+    0, 1, 82, 0, 0, 14, 50, 6, 0, 2, 18, 0, 0, 7, 147, 3, 0, 1, 83, 0, 0, 8, 51, 4, 0, 2, 19, 0, 0,
+    9, 148, 5, 0, 1, 84, 0, 0, 10, 52, 6, 0, 2, 20, 0, 0, 11, 149, 3, 0, 1, 85, 0, 0, 12, 53, 4, 0,
+    2, 21, 0, 0, 13, 150, 5, 0, 1, 86, 0, 0, 14, 54, 6, 0, 2, 22, 0, 0, 7, 151, 3, 0, 1, 87, 0, 0,
+    8, 55, 4, 0, 2, 23, 0, 0, 9, 152, 5, 0, 1, 88, 0, 0, 10, 56, 6, 0, 2, 24, 0, 0, 11, 153, 3, 0,
+    1, 89, 0, 0, 12, 57, 4, 0, 2, 25, 0, 0, 13, 154, 5, 0, 1, 90, 0, 0, 14, 58, 6, 0, 2, 26, 0, 0,
+    7, 155, 3, 0, 1, 91, 0, 0, 8, 59, 4, 0, 2, 27, 0, 0, 9, 156, 5, 0, 1, 92, 0, 0, 10, 60, 6, 0,
+    2, 28, 0, 0, 11, 157, 3, 0, 1, 93, 0, 0, 12, 61, 4, 0, 2, 29, 0, 0, 13, 158, 5, 0, 1, 94, 0, 0,
+    14, 62, 6, 0, 2, 30, 0, 0, 7, 159, 3, 0, 1, 95, 0, 0, 8, 31, 4, 0, 2, 15, 0, 0, 9, 160, 5, 0,
+    1, 96, 0, 0, 10, 32, 6, 0, 2, 16, 0, 0, 11, 161, 3, 0, 1, 97, 0, 0, 12, 33, 4, 0, 2, 17, 0, 0,
+    13, 162, 5, 0, 1, 98, 0, 0, 14, 34, 6, 0, 2, 18, 0, 0, 7, 163, 3, 0, 1, 99, 0, 0, 8, 35, 4, 0,
+    2, 19, 0, 0, 9, 164, 5, 0, 1, 100, 0, 0, 10, 36, 6, 0, 2, 20, 0, 0, 11, 165, 3, 0, 1, 101, 0,
+    0, 12, 37, 4, 0, 2, 21, 0, 0, 13, 166, 5, 0, 1, 102, 0, 0, 14, 38, 6, 0, 2, 22, 0, 0, 7, 167,
+    3, 0, 1, 103, 0, 0, 8, 39, 4, 0, 2, 23, 0, 0, 9, 168, 5, 0, 1, 104, 0, 0, 10, 40, 6, 0, 2, 24,
+    0, 0, 11, 169, 3, 0, 1, 105, 0, 0, 12, 41, 4, 0, 2, 25, 0, 0, 13, 170, 5, 0, 1, 106, 0, 0, 14,
+    42, 6, 0, 2, 26, 0, 0, 7, 171, 3, 0, 1, 107, 0, 0, 8, 43, 4, 0, 2, 27, 0, 0, 9, 172, 5, 0, 1,
+    108, 0, 0, 10, 44, 6, 0, 2, 28, 0, 0, 11, 173, 3, 0, 1, 109, 0, 0, 12, 45, 4, 0, 2, 29, 0, 0,
+    13, 174, 5, 0, 1, 110, 0, 0, 14, 46, 6, 0, 2, 30, 0, 0, 7, 175, 3, 0, 1, 111, 0, 0, 8, 47, 4,
+    0, 2, 15, 0, 0, 9, 176, 5, 0, 1, 112, 0, 0, 10, 48, 6, 0, 2, 16, 0, 0, 11, 177, 3, 0, 1, 113,
+    0, 0, 12, 49, 4, 0, 2, 17, 0, 0, 13, 178, 5, 0, 1, 114, 0, 0, 14, 50, 6, 0, 2, 18, 0, 0, 7,
+    179, 3, 0, 1, 115, 0, 0, 8, 51, 4, 0, 2, 19, 0, 0, 9, 180, 5, 0, 1, 116, 0, 0, 10, 52, 6, 0, 2,
+    20, 0, 0, 11, 181, 3, 0, 1, 117, 0, 0, 12, 53, 4, 0, 2, 21, 0, 0, 13, 182, 5, 0, 1, 118, 0, 0,
+    14, 54, 6, 0, 2, 22, 0, 0, 7, 183, 3, 0, 1, 119, 0, 0, 8, 55, 4, 0, 2, 23, 0, 0, 9, 184, 5, 0,
+    1, 120, 0, 0, 10, 56, 6, 0, 2, 24, 0, 0, 11, 185, 3, 0, 1, 121, 0, 0, 12, 57, 4, 0, 2, 25, 0,
+    0, 13, 186, 5, 0, 1, 122, 0, 0, 14, 58, 6, 0, 2, 26, 0, 0, 7, 187, 3, 0, 1, 123, 0, 0, 8, 59,
+    4, 0, 2, 27, 0, 0, 9, 188, 5, 0, 1, 124, 0, 0, 10, 60, 6, 0, 2, 28, 0, 0, 11, 189, 3, 0, 1,
+    125, 0, 0, 12, 61, 4, 0, 2, 29, 0, 0, 13, 190, 5, 0, 1, 126, 0, 0, 14, 62, 6, 0, 2, 30, 0, 0,
+    7, 191, 3, 0, 1, 63, 0, 0, 8, 31, 4, 0, 2, 15, 0, 0, 9, 192, 5, 0, 1, 64, 0, 0, 10, 32, 6, 0,
+    2, 16, 0, 0, 11, 193, 3, 0, 1, 65, 0, 0, 12, 33, 4, 0, 2, 17, 0, 0, 13, 194, 5, 0, 1, 66, 0, 0,
+    14, 34, 6, 0, 2, 18, 0, 0, 7, 195, 3, 0, 1, 67, 0, 0, 8, 35, 4, 0, 2, 19, 0, 0, 9, 196, 5, 0,
+    1, 68, 0, 0, 10, 36, 6, 0, 2, 20, 0, 0, 11, 197, 3, 0, 1, 69, 0, 0, 12, 37, 4, 0, 2, 21, 0, 0,
+    13, 198, 5, 0, 1, 70, 0, 0, 14, 38, 6, 0, 2, 22, 0, 0, 7, 199, 3, 0, 1, 71, 0, 0, 8, 39, 4, 0,
+    2, 23, 0, 0, 9, 200, 5, 0, 1, 72, 0, 0, 10, 40, 6, 0, 2, 24, 0, 0, 11, 201, 3, 0, 1, 73, 0, 0,
+    12, 41, 4, 0, 2, 25, 0, 0, 13, 202, 5, 0, 1, 74, 0, 0, 14, 42, 6, 0, 2, 26, 0, 0, 7, 203, 3, 0,
+    1, 75, 0, 0, 8, 43, 4, 0, 2, 27, 0, 0, 9, 204, 5, 0, 1, 76, 0, 0, 10, 44, 6, 0, 2, 28, 0, 0,
+    11, 205, 3, 0, 1, 77, 0, 0, 12, 45, 4, 0, 2, 29, 0, 0, 13, 206, 5, 0, 1, 78, 0, 0, 14, 46, 6,
+    0, 2, 30, 0, 0, 7, 207, 3, 0, 1, 79, 0, 0, 8, 47, 4, 0, 2, 15, 0, 0, 9, 208, 5, 0, 1, 80, 0, 0,
+    10, 48, 6, 0, 2, 16, 0, 0, 11, 209, 3, 0, 1, 81, 0, 0, 12, 49, 4, 0, 2, 17, 0, 0, 13, 210, 5,
+    0, 1, 82, 0, 0, 14, 50, 6, 0, 2, 18, 0, 0, 7, 211, 3, 0, 1, 83, 0, 0, 8, 51, 4, 0, 2, 19, 0, 0,
+    9, 212, 5, 0, 1, 84, 0, 0, 10, 52, 6, 0, 2, 20, 0, 0, 11, 213, 3, 0, 1, 85, 0, 0, 12, 53, 4, 0,
+    2, 21, 0, 0, 13, 214, 5, 0, 1, 86, 0, 0, 14, 54, 6, 0, 2, 22, 0, 0, 7, 215, 3, 0, 1, 87, 0, 0,
+    8, 55, 4, 0, 2, 23, 0, 0, 9, 216, 5, 0, 1, 88, 0, 0, 10, 56, 6, 0, 2, 24, 0, 0, 11, 217, 3, 0,
+    1, 89, 0, 0, 12, 57, 4, 0, 2, 25, 0, 0, 13, 218, 5, 0, 1, 90, 0, 0, 14, 58, 6, 0, 2, 26, 0, 0,
+    7, 219, 3, 0, 1, 91, 0, 0, 8, 59, 4, 0, 2, 27, 0, 0, 9, 220, 5, 0, 1, 92, 0, 0, 10, 60, 6, 0,
+    2, 28, 0, 0, 11, 221, 3, 0, 1, 93, 0, 0, 12, 61, 4, 0, 2, 29, 0, 0, 13, 222, 5, 0, 1, 94, 0, 0,
+    14, 62, 6, 0, 2, 30, 0, 0, 7, 223, 3, 0, 1, 95, 0, 0, 8, 31, 4, 0, 2, 15, 0, 0, 9, 224, 5, 0,
+    1, 96, 0, 0, 10, 32, 6, 0, 2, 16, 0, 0, 11, 225, 3, 0, 1, 97, 0, 0, 12, 33, 4, 0, 2, 17, 0, 0,
+    13, 226, 5, 0, 1, 98, 0, 0, 14, 34, 6, 0, 2, 18, 0, 0, 7, 227, 3, 0, 1, 99, 0, 0, 8, 35, 4, 0,
+    2, 19, 0, 0, 9, 228, 5, 0, 1, 100, 0, 0, 10, 36, 6, 0, 2, 20, 0, 0, 11, 229, 3, 0, 1, 101, 0,
+    0, 12, 37, 4, 0, 2, 21, 0, 0, 13, 230, 5, 0, 1, 102, 0, 0, 14, 38, 6, 0, 2, 22, 0, 0, 7, 231,
+    3, 0, 1, 103, 0, 0, 8, 39, 4, 0, 2, 23, 0, 0, 9, 232, 5, 0, 1, 104, 0, 0, 10, 40, 6, 0, 2, 24,
+    0, 0, 11, 233, 3, 0, 1, 105, 0, 0, 12, 41, 4, 0, 2, 25, 0, 0, 13, 234, 5, 0, 1, 106, 0, 0, 14,
+    42, 6, 0, 2, 26, 0, 0, 7, 235, 3, 0, 1, 107, 0, 0, 8, 43, 4, 0, 2, 27, 0, 0, 9, 236, 5, 0, 1,
+    108, 0, 0, 10, 44, 6, 0, 2, 28, 0, 0, 11, 237, 3, 0, 1, 109, 0, 0, 12, 45, 4, 0, 2, 29, 0, 0,
+    13, 238, 5, 0, 1, 110, 0, 0, 14, 46, 6, 0, 2, 30, 0, 0, 7, 239, 3, 0, 1, 111, 0, 0, 8, 47, 4,
+    0, 2, 15, 0, 0, 9, 240, 5, 0, 1, 112, 0, 0, 10, 48, 6, 0, 2, 16, 0, 0, 11, 241, 3, 0, 1, 113,
+    0, 0, 12, 49, 4, 0, 2, 17, 0, 0, 13, 242, 5, 0, 1, 114, 0, 0, 14, 50, 6, 0, 2, 18, 0, 0, 7,
+    243, 3, 0, 1, 115, 0, 0, 8, 51, 4, 0, 2, 19, 0, 0, 9, 244, 5, 0, 1, 116, 0, 0, 10, 52, 6, 0, 2,
+    20, 0, 0, 11, 245, 3, 0, 1, 117, 0, 0, 12, 53, 4, 0, 2, 21, 0, 0, 13, 246, 5, 0, 1, 118, 0, 0,
+    14, 54, 6, 0, 2, 22, 0, 0, 7, 247, 3, 0, 1, 119, 0, 0, 8, 55, 4, 0, 2, 23, 0, 0, 9, 248, 5, 0,
+    1, 120, 0, 0, 10, 56, 6, 0, 2, 24, 0, 0, 11, 249, 3, 0, 1, 121, 0, 0, 12, 57, 4, 0, 2, 25, 0,
+    0, 13, 250, 5, 0, 1, 122, 0, 0, 14, 58, 6, 0, 2, 26, 0, 0, 7, 251, 3, 0, 1, 123, 0, 0, 8, 59,
+    4, 0, 2, 27, 0, 0, 9, 252, 5, 0, 1, 124, 0, 0, 10, 60, 6, 0, 2, 28, 0, 0, 11, 253, 3, 0, 1,
+    125, 0, 0, 12, 61, 4, 0, 2, 29, 0, 0, 13, 254, 5, 0, 1, 126, 0, 0, 14, 62, 6, 0, 2, 30, 0, 0,
+    7, 127, 3, 0, 1, 63, 0, 0, 8, 31, 4, 0, 2, 15, 0, 0, 9, 128, 5, 0, 1, 64, 0, 0, 10, 32, 6, 0,
+    2, 16, 0, 0, 11, 129, 3, 0, 1, 65, 0, 0, 12, 33, 4, 0, 2, 17, 0, 0, 13, 130, 5, 0, 1, 66, 0, 0,
+    14, 34, 6, 0, 2, 18, 0, 0, 7, 131, 3, 0, 1, 67, 0, 0, 8, 35, 4, 0, 2, 19, 0, 0, 9, 132, 5, 0,
+    1, 68, 0, 0, 10, 36, 6, 0, 2, 20, 0, 0, 11, 133, 3, 0, 1, 69, 0, 0, 12, 37, 4, 0, 2, 21, 0, 0,
+    13, 134, 5, 0, 1, 70, 0, 0, 14, 38, 6, 0, 2, 22, 0, 0, 7, 135, 3, 0, 1, 71, 0, 0, 8, 39, 4, 0,
+    2, 23, 0, 0, 9, 136, 5, 0, 1, 72, 0, 0, 10, 40, 6, 0, 2, 24, 0, 0, 11, 137, 3, 0, 1, 73, 0, 0,
+    12, 41, 4, 0, 2, 25, 0, 0, 13, 138, 5, 0, 1, 74, 0, 0, 14, 42, 6, 0, 2, 26, 0, 0, 7, 139, 3, 0,
+    1, 75, 0, 0, 8, 43, 4, 0, 2, 27, 0, 0, 9, 140, 5, 0, 1, 76, 0, 0, 10, 44, 6, 0, 2, 28, 0, 0,
+    11, 141, 3, 0, 1, 77, 0, 0, 12, 45, 4, 0, 2, 29, 0, 0, 13, 142, 5, 0, 1, 78, 0, 0, 14, 46, 6,
+    0, 2, 30, 0, 0, 7, 143, 3, 0, 1, 79, 0, 0, 8, 47, 4, 0, 2, 15, 0, 0, 9, 144, 5, 0, 1, 80, 0, 0,
+    10, 48, 6, 0, 2, 16, 0, 0, 11, 145, 3, 0, 1, 81, 0, 0, 12, 49, 4, 0, 2, 17, 0, 0, 13, 146, 5,
+    0, 1, 82, 0, 0, 14, 50, 6, 0, 2, 18, 0, 0, 7, 147, 3, 0, 1, 83, 0, 0, 8, 51, 4, 0, 2, 19, 0, 0,
+    9, 148, 5, 0, 1, 84, 0, 0, 10, 52, 6, 0, 2, 20, 0, 0, 11, 149, 3, 0, 1, 85, 0, 0, 12, 53, 4, 0,
+    2, 21, 0, 0, 13, 150, 5, 0, 1, 86, 0, 0, 14, 54, 6, 0, 2, 22, 0, 0, 7, 151, 3, 0, 1, 87, 0, 0,
+    8, 55, 4, 0, 2, 23, 0, 0, 9, 152, 5, 0, 1, 88, 0, 0, 10, 56, 6, 0, 2, 24, 0, 0, 11, 153, 3, 0,
+    1, 89, 0, 0, 12, 57, 4, 0, 2, 25, 0, 0, 13, 154, 5, 0, 1, 90, 0, 0, 14, 58, 6, 0, 2, 26, 0, 0,
+    7, 155, 3, 0, 1, 91, 0, 0, 8, 59, 4, 0, 2, 27, 0, 0, 9, 156, 5, 0, 1, 92, 0, 0, 10, 60, 6, 0,
+    2, 28, 0, 0, 11, 157, 3, 0, 1, 93, 0, 0, 12, 61, 4, 0, 2, 29, 0, 0, 13, 158, 5, 0, 1, 94, 0, 0,
+    14, 62, 6, 0, 2, 30, 0, 0, 7, 159, 3, 0, 1, 95, 0, 0, 8, 31, 4, 0, 2, 15, 0, 0, 9, 160, 5, 0,
+    1, 96, 0, 0, 10, 32, 6, 0, 2, 16, 0, 0, 11, 161, 3, 0, 1, 97, 0, 0, 12, 33, 4, 0, 2, 17, 0, 0,
+    13, 162, 5, 0, 1, 98, 0, 0, 14, 34, 6, 0, 2, 18, 0, 0, 7, 163, 3, 0, 1, 99, 0, 0, 8, 35, 4, 0,
+    2, 19, 0, 0, 9, 164, 5, 0, 1, 100, 0, 0, 10, 36, 6, 0, 2, 20, 0, 0, 11, 165, 3, 0, 1, 101, 0,
+    0, 12, 37, 4, 0, 2, 21, 0, 0, 13, 166, 5, 0, 1, 102, 0, 0, 14, 38, 6, 0, 2, 22, 0, 0, 7, 167,
+    3, 0, 1, 103, 0, 0, 8, 39, 4, 0, 2, 23, 0, 0, 9, 168, 5, 0, 1, 104, 0, 0, 10, 40, 6, 0, 2, 24,
+    0, 0, 11, 169, 3, 0, 1, 105, 0, 0, 12, 41, 4, 0, 2, 25, 0, 0, 13, 170, 5, 0, 1, 106, 0, 0, 14,
+    42, 6, 0, 2, 26, 0, 0, 7, 171, 3, 0, 1, 107, 0, 0, 8, 43, 4, 0, 2, 27, 0, 0, 9, 172, 5, 0, 1,
+    108, 0, 0, 10, 44, 6, 0, 2, 28, 0, 0, 11, 173, 3, 0, 1, 109, 0, 0, 12, 45, 4, 0, 2, 29, 0, 0,
+    13, 174, 5, 0, 1, 110, 0, 0, 14, 46, 6, 0, 2, 30, 0, 0, 7, 175, 3, 0, 1, 111, 0, 0, 8, 47, 4,
+    0, 2, 15, 0, 0, 9, 176, 5, 0, 1, 112, 0, 0, 10, 48, 6, 0, 2, 16, 0, 0, 11, 177, 3, 0, 1, 113,
+    0, 0, 12, 49, 4, 0, 2, 17, 0, 0, 13, 178, 5, 0, 1, 114, 0, 0, 14, 50, 6, 0, 2, 18, 0, 0, 7,
+    179, 3, 0, 1, 115, 0, 0, 8, 51, 4, 0, 2, 19, 0, 0, 9, 180, 5, 0, 1, 116, 0, 0, 10, 52, 6, 0, 2,
+    20, 0, 0, 11, 181, 3, 0, 1, 117, 0, 0, 12, 53, 4, 0, 2, 21, 0, 0, 13, 182, 5, 0, 1, 118, 0, 0,
+    14, 54, 6, 0, 2, 22, 0, 0, 7, 183, 3, 0, 1, 119, 0, 0, 8, 55, 4, 0, 2, 23, 0, 0, 9, 184, 5, 0,
+    1, 120, 0, 0, 10, 56, 6, 0, 2, 24, 0, 0, 11, 185, 3, 0, 1, 121, 0, 0, 12, 57, 4, 0, 2, 25, 0,
+    0, 13, 186, 5, 0, 1, 122, 0, 0, 14, 58, 6, 0, 2, 26, 0, 0, 7, 187, 3, 0, 1, 123, 0, 0, 8, 59,
+    4, 0, 2, 27, 0, 0, 9, 188, 5, 0, 1, 124, 0, 0, 10, 60, 6, 0, 2, 28, 0, 0, 11, 189, 3, 0, 1,
+    125, 0, 0, 12, 61, 4, 0, 2, 29, 0, 0, 13, 190, 5, 0, 1, 126, 0, 0, 14, 62, 6, 0, 2, 30, 0, 0,
+    7, 191, 3, 0, 1, 63, 0, 0, 8, 31, 4, 0, 2, 15, 0, 0, 9, 192, 5, 0, 1, 64, 0, 0, 10, 32, 6, 0,
+    2, 16, 0, 0, 11, 193, 3, 0, 1, 65, 0, 0, 12, 33, 4, 0, 2, 17, 0, 0, 13, 194, 5, 0, 1, 66, 0, 0,
+    14, 34, 6, 0, 2, 18, 0, 0, 7, 195, 3, 0, 1, 67, 0, 0, 8, 35, 4, 0, 2, 19, 0, 0, 9, 196, 5, 0,
+    1, 68, 0, 0, 10, 36, 6, 0, 2, 20, 0, 0, 11, 197, 3, 0, 1, 69, 0, 0, 12, 37, 4, 0, 2, 21, 0, 0,
+    13, 198, 5, 0, 1, 70, 0, 0, 14, 38, 6, 0, 2, 22, 0, 0, 7, 199, 3, 0, 1, 71, 0, 0, 8, 39, 4, 0,
+    2, 23, 0, 0, 9, 200, 5, 0, 1, 72, 0, 0, 10, 40, 6, 0, 2, 24, 0, 0, 11, 201, 3, 0, 1, 73, 0, 0,
+    12, 41, 4, 0, 2, 25, 0, 0, 13, 202, 5, 0, 1, 74, 0, 0, 14, 42, 6, 0, 2, 26, 0, 0, 7, 203, 3, 0,
+    1, 75, 0, 0, 8, 43, 4, 0, 2, 27, 0, 0, 9, 204, 5, 0, 1, 76, 0, 0, 10, 44, 6, 0, 2, 28, 0, 0,
+    11, 205, 3, 0, 1, 77, 0, 0, 12, 45, 4, 0, 2, 29, 0, 0, 13, 206, 5, 0, 1, 78, 0, 0, 14, 46, 6,
+    0, 2, 30, 0, 0, 7, 207, 3, 0, 1, 79, 0, 0, 8, 47, 4, 0, 2, 15, 0, 0, 9, 208, 5, 0, 1, 80, 0, 0,
+    10, 48, 6, 0, 2, 16, 0, 0, 11, 209, 3, 0, 1, 81, 0, 0, 12, 49, 4, 0, 2, 17, 0, 0, 13, 210, 5,
+    0, 1, 82, 0, 0, 14, 50, 6, 0, 2, 18, 0, 0, 7, 211, 3, 0, 1, 83, 0, 0, 8, 51, 4, 0, 2, 19, 0, 0,
+    9, 212, 5, 0, 1, 84, 0, 0, 10, 52, 6, 0, 2, 20, 0, 0, 11, 213, 3, 0, 1, 85, 0, 0, 12, 53, 4, 0,
+    2, 21, 0, 0, 13, 214, 5, 0, 1, 86, 0, 0, 14, 54, 6, 0, 2, 22, 0, 0, 7, 215, 3, 0, 1, 87, 0, 0,
+    8, 55, 4, 0, 2, 23, 0, 0, 9, 216, 5, 0, 1, 88, 0, 0, 10, 56, 6, 0, 2, 24, 0, 0, 11, 217, 3, 0,
+    1, 89, 0, 0, 12, 57, 4, 0, 2, 25, 0, 0, 13, 218, 5, 0, 1, 90, 0, 0, 14, 58, 6, 0, 2, 26, 0, 0,
+    7, 219, 3, 0, 1, 91, 0, 0, 8, 59, 4, 0, 2, 27, 0, 0, 9, 220, 5, 0, 1, 92, 0, 0, 10, 60, 6, 0,
+    2, 28, 0, 0, 11, 221, 3, 0, 1, 93, 0, 0, 12, 61, 4, 0, 2, 29, 0, 0, 13, 222, 5, 0, 1, 94, 0, 0,
+    14, 62, 6, 0, 2, 30, 0, 0, 7, 223, 3, 0, 1, 95, 0, 0, 8, 31, 4, 0, 2, 15, 0, 0, 9, 224, 5, 0,
+    1, 96, 0, 0, 10, 32, 6, 0, 2, 16, 0, 0, 11, 225, 3, 0, 1, 97, 0, 0, 12, 33, 4, 0, 2, 17, 0, 0,
+    13, 226, 5, 0, 1, 98, 0, 0, 14, 34, 6, 0, 2, 18, 0, 0, 7, 227, 3, 0, 1, 99, 0, 0, 8, 35, 4, 0,
+    2, 19, 0, 0, 9, 228, 5, 0, 1, 100, 0, 0, 10, 36, 6, 0, 2, 20, 0, 0, 11, 229, 3, 0, 1, 101, 0,
+    0, 12, 37, 4, 0, 2, 21, 0, 0, 13, 230, 5, 0, 1, 102, 0, 0, 14, 38, 6, 0, 2, 22, 0, 0, 7, 231,
+    3, 0, 1, 103, 0, 0, 8, 39, 4, 0, 2, 23, 0, 0, 9, 232, 5, 0, 1, 104, 0, 0, 10, 40, 6, 0, 2, 24,
+    0, 0, 11, 233, 3, 0, 1, 105, 0, 0, 12, 41, 4, 0, 2, 25, 0, 0, 13, 234, 5, 0, 1, 106, 0, 0, 14,
+    42, 6, 0, 2, 26, 0, 0, 7, 235, 3, 0, 1, 107, 0, 0, 8, 43, 4, 0, 2, 27, 0, 0, 9, 236, 5, 0, 1,
+    108, 0, 0, 10, 44, 6, 0, 2, 28, 0, 0, 11, 237, 3, 0, 1, 109, 0, 0, 12, 45, 4, 0, 2, 29, 0, 0,
+    13, 238, 5, 0, 1, 110, 0, 0, 14, 46, 6, 0, 2, 30, 0, 0, 7, 239, 3, 0, 1, 111, 0, 0, 8, 47, 4,
+    0, 2, 15, 0, 0, 9, 240, 5, 0, 1, 112, 0, 0, 10, 48, 6, 0, 2, 16, 0, 0, 11, 241, 3, 0, 1, 113,
+    0, 0, 12, 49, 4, 0, 2, 17, 0, 0, 13, 242, 5, 0, 1, 114, 0, 0, 14, 50, 6, 0, 2, 18, 0, 0, 7,
+    243, 3, 0, 1, 115, 0, 0, 8, 51, 4, 0, 2, 19, 0, 0, 9, 244, 5, 0, 1, 116, 0, 0, 10, 52, 6, 0, 2,
+    20, 0, 0, 11, 245, 3, 0, 1, 117, 0, 0, 12, 53, 4, 0, 2, 21, 0, 0, 13, 246, 5, 0, 1, 118, 0, 0,
+    14, 54, 6, 0, 2, 22, 0, 0, 7, 247, 3, 0, 1, 119, 0, 0, 8, 55, 4, 0, 2, 23, 0, 0, 9, 248, 5, 0,
+    1, 120, 0, 0, 10, 56, 6, 0, 2, 24, 0, 0, 11, 249, 3, 0, 1, 121, 0, 0, 12, 57, 4, 0, 2, 25, 0,
+    0, 13, 250, 5, 0, 1, 122, 0, 0, 14, 58, 6, 0, 2, 26, 0, 0, 7, 251, 3, 0, 1, 123, 0, 0, 8, 59,
+    4, 0, 2, 27, 0, 0, 9, 252, 5, 0, 1, 124, 0, 0, 10, 60, 6, 0, 2, 28, 0, 0, 11, 253, 3, 0, 1,
+    125, 0, 0, 12, 61, 4, 0, 2, 29, 0, 0, 13, 254, 5, 0, 1, 126, 0, 0, 14, 62, 6, 0, 2, 30, 0,
+];
+///Table contaings the lens used to speed up the reading of pi codes
+pub const READ_LEN_L2M: &[u8] = &[
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5,
+    255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4,
+    10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+    255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6,
+    9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5,
+    255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4,
+    8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3,
+    255, 6, 9, 5, 255, 4, 8, 3, 255, 6, 11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3, 255, 6,
+    11, 5, 255, 4, 10, 3, 255, 6, 9, 5, 255, 4, 8, 3,
+];
+///Table used to speed up the writing of pi codes
+pub const WRITE_M2L: &[u16] = &[
+    7, 12, 13, 20, 21, 22, 23, 32, 33, 34, 35, 36, 37, 38, 39, 112, 113, 114, 115, 116, 117, 118,
+    119, 120, 121, 122, 123, 124, 125, 126, 127, 192, 193, 194, 195, 196, 197, 198, 199, 200, 201,
+    202, 203, 204, 205, 206, 207, 208, 209, 210, 211, 212, 213, 214, 215, 216, 217, 218, 219, 220,
+    221, 222, 223, 320, 321, 322, 323, 324, 325, 326, 327, 328, 329, 330, 331, 332, 333, 334, 335,
+    336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346, 347, 348, 349, 350, 351, 352, 353, 354,
+    355, 356, 357, 358, 359, 360, 361, 362, 363, 364, 365, 366, 367, 368, 369, 370, 371, 372, 373,
+    374, 375, 376, 377, 378, 379, 380, 381, 382, 383, 512, 513, 514, 515, 516, 517, 518, 519, 520,
+    521, 522, 523, 524, 525, 526, 527, 528, 529, 530, 531, 532, 533, 534, 535, 536, 537, 538, 539,
+    540, 541, 542, 543, 544, 545, 546, 547, 548, 549, 550, 551, 552, 553, 554, 555, 556, 557, 558,
+    559, 560, 561, 562, 563, 564, 565, 566, 567, 568, 569, 570, 571, 572, 573, 574, 575, 576, 577,
+    578, 579, 580, 581, 582, 583, 584, 585, 586, 587, 588, 589, 590, 591, 592, 593, 594, 595, 596,
+    597, 598, 599, 600, 601, 602, 603, 604, 605, 606, 607, 608, 609, 610, 611, 612, 613, 614, 615,
+    616, 617, 618, 619, 620, 621, 622, 623, 624, 625, 626, 627, 628, 629, 630, 631, 632, 633, 634,
+    635, 636, 637, 638, 639, 1792,
+];
+///Table used to speed up the writing of pi codes
+pub const WRITE_LEN_M2L: &[u16] = &[
+    3, 4, 4, 5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 6, 6, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    13,
+];
+///Table used to speed up the writing of pi codes
+pub const WRITE_L2M: &[u16] = &[
+    7, 5, 13, 3, 11, 19, 27, 1, 9, 17, 25, 33, 41, 49, 57, 14, 30, 46, 62, 78, 94, 110, 126, 142,
+    158, 174, 190, 206, 222, 238, 254, 10, 26, 42, 58, 74, 90, 106, 122, 138, 154, 170, 186, 202,
+    218, 234, 250, 266, 282, 298, 314, 330, 346, 362, 378, 394, 410, 426, 442, 458, 474, 490, 506,
+    6, 22, 38, 54, 70, 86, 102, 118, 134, 150, 166, 182, 198, 214, 230, 246, 262, 278, 294, 310,
+    326, 342, 358, 374, 390, 406, 422, 438, 454, 470, 486, 502, 518, 534, 550, 566, 582, 598, 614,
+    630, 646, 662, 678, 694, 710, 726, 742, 758, 774, 790, 806, 822, 838, 854, 870, 886, 902, 918,
+    934, 950, 966, 982, 998, 1014, 2, 18, 34, 50, 66, 82, 98, 114, 130, 146, 162, 178, 194, 210,
+    226, 242, 258, 274, 290, 306, 322, 338, 354, 370, 386, 402, 418, 434, 450, 466, 482, 498, 514,
+    530, 546, 562, 578, 594, 610, 626, 642, 658, 674, 690, 706, 722, 738, 754, 770, 786, 802, 818,
+    834, 850, 866, 882, 898, 914, 930, 946, 962, 978, 994, 1010, 1026, 1042, 1058, 1074, 1090,
+    1106, 1122, 1138, 1154, 1170, 1186, 1202, 1218, 1234, 1250, 1266, 1282, 1298, 1314, 1330, 1346,
+    1362, 1378, 1394, 1410, 1426, 1442, 1458, 1474, 1490, 1506, 1522, 1538, 1554, 1570, 1586, 1602,
+    1618, 1634, 1650, 1666, 1682, 1698, 1714, 1730, 1746, 1762, 1778, 1794, 1810, 1826, 1842, 1858,
+    1874, 1890, 1906, 1922, 1938, 1954, 1970, 1986, 2002, 2018, 2034, 28,
+];
+///Table used to speed up the writing of pi codes
+pub const WRITE_LEN_L2M: &[u16] = &[
+    3, 4, 4, 5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 6, 6, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    13,
+];
+///Table used to speed up the skipping of pi codes
+pub const LEN: &[u8] = &[
+    3, 4, 4, 5, 5, 5, 5, 6, 6, 6, 6, 6, 6, 6, 6, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    13,
+];
+/// The K of the pi codes for these tables
+pub const K: u64 = 2;
@@ -0,0 +1,219 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Rice
+//! Rice codes are Golomb codes whose modulus b is a power of two: the
+//! Rice code of x ∈ N with parameter log2(b) is obtained by writing
+//! floor(x / b) in unary followed by the lowest log2(b) bits of x.
+//!
+//! Since the parameter is known only at runtime, the decoding tables in
+//! [`rice_tables`](super::rice_tables) resolve the unary prefix, and the low
+//! bits are taken from the same peeked window.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in 0..1000 {
+//!         writer.write_rice::<true>(value, 3).unwrap();
+//!     }
+//!     // the quotient u64::MAX cannot be written in unary
+//!     assert!(writer.write_rice::<true>(u64::MAX, 0).is_err());
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<L2M, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut unbuffered = <UnbufferedBitStreamRead<L2M, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_rice::<true>(3).unwrap(), value);
+//!     assert_eq!(unbuffered.read_rice::<false>(3).unwrap(), value);
+//! }
+//! assert_eq!(
+//!     unbuffered.get_position(),
+//!     (0..1000).map(|value| len_rice(value, 3)).sum::<usize>()
+//! );
+//!
+//! // the same holds for the M2L bit order
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in 0..1000 {
+//!         writer.write_rice::<false>(value, 3).unwrap();
+//!     }
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut unbuffered = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_rice::<false>(3).unwrap(), value);
+//!     assert_eq!(unbuffered.read_rice::<true>(3).unwrap(), value);
+//! }
+//! assert!(reader.read_rice::<true>(u64::MAX).is_err());
+//! ```
+
+use super::{int2nat, nat2int, rice_tables};
+use crate::traits::*;
+use anyhow::{bail, Result};
+
+/// Returns how long the Rice code for `value` will be for a given
+/// `log2_b`, which must be smaller than 64.
+#[must_use]
+#[inline]
+pub fn len_rice(value: u64, log2_b: u64) -> usize {
+    debug_assert!(log2_b < 64);
+    (value >> log2_b) as usize + 1 + log2_b as usize
+}
+
+/// Returns how long the Rice code for the signed `value` will be for a
+/// given `log2_b`, which must be smaller than 64.
+#[must_use]
+#[inline]
+pub fn len_rice_signed(value: i64, log2_b: u64) -> usize {
+    len_rice(int2nat(value), log2_b)
+}
+
+/// Trait for objects that can read Rice codes
+pub trait RiceRead<BO: BitOrder>: BitRead<BO> {
+    /// Read a Rice code with parameter `log2_b` from the stream.
+    ///
+    /// `USE_TABLE` enables or disables the use of pre-computed tables
+    /// for decoding
+    ///
+    /// # Errors
+    /// This function fails if `log2_b` is not smaller than 64 or if the
+    /// BitRead backend has problems reading bits, as when the stream ended
+    /// unexpectedly
+    fn read_rice<const USE_TABLE: bool>(&mut self, log2_b: u64) -> Result<u64>;
+
+    /// Read a Rice code with parameter `log2_b` from the stream and map it to
+    /// a signed value using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`RiceRead::read_rice`]
+    #[inline]
+    fn read_rice_signed<const USE_TABLE: bool>(&mut self, log2_b: u64) -> Result<i64> {
+        self.read_rice::<USE_TABLE>(log2_b).map(nat2int)
+    }
+
+    /// Read `values.len()` Rice codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`RiceRead::read_rice`]
+    #[inline]
+    fn read_rice_into<const USE_TABLE: bool>(
+        &mut self,
+        values: &mut [u64],
+        log2_b: u64,
+    ) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_rice::<USE_TABLE>(log2_b)?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: BitRead<M2L>> RiceRead<M2L> for B {
+    #[inline]
+    fn read_rice<const USE_TABLE: bool>(&mut self, log2_b: u64) -> Result<u64> {
+        check_log2_b(log2_b)?;
+        if USE_TABLE {
+            if let Some(res) = rice_tables::read_table_m2l(self, log2_b)? {
+                return Ok(res);
+            }
+        }
+        default_read_rice(self, log2_b)
+    }
+}
+impl<B: BitRead<L2M>> RiceRead<L2M> for B {
+    #[inline]
+    fn read_rice<const USE_TABLE: bool>(&mut self, log2_b: u64) -> Result<u64> {
+        check_log2_b(log2_b)?;
+        if USE_TABLE {
+            if let Some(res) = rice_tables::read_table_l2m(self, log2_b)? {
+                return Ok(res);
+            }
+        }
+        default_read_rice(self, log2_b)
+    }
+}
+
+/// Check that the parameter `log2_b` of a Rice code is smaller than 64
+#[inline(always)]
+fn check_log2_b(log2_b: u64) -> Result<()> {
+    if log2_b >= 64 {
+        bail!(
+            "The log2_b of a Rice code has to be in [0, 63] and {} is not.",
+            log2_b
+        );
+    }
+    Ok(())
+}
+
+/// Common part of the M2L and L2M impl, which expects a valid `log2_b`
+///
+/// # Errors
+/// Forward `read_unary` and `read_bits` errors.
+#[inline(always)]
+fn default_read_rice<BO: BitOrder, B: BitRead<BO>>(backend: &mut B, log2_b: u64) -> Result<u64> {
+    let quotient = backend.read_unary::<false>()?;
+    Ok((quotient << log2_b) | backend.read_bits(log2_b as usize)?)
+}
+
+/// Trait for objects that can write Rice codes
+pub trait RiceWrite<BO: BitOrder>: BitWrite<BO> {
+    /// Write a value on the stream as a Rice code with parameter `log2_b`.
+    ///
+    /// `USE_TABLE` enables or disables the use of pre-computed tables
+    /// for encoding the unary prefix
+    ///
+    /// The quotient `value >> log2_b` is written in unary, so it must be
+    /// smaller than [`u64::MAX`]: with `log2_b` equal to zero, [`u64::MAX`]
+    /// cannot be written.
+    ///
+    /// # Errors
+    /// This function fails if `log2_b` is not smaller than 64, if the
+    /// quotient is [`u64::MAX`], or if the BitWrite backend has problems
+    /// writing bits, as when the stream ended unexpectedly
+    #[inline]
+    fn write_rice<const USE_TABLE: bool>(&mut self, value: u64, log2_b: u64) -> Result<()> {
+        check_log2_b(log2_b)?;
+        let quotient = value >> log2_b;
+        if quotient == u64::MAX {
+            bail!("The quotient of a Rice code must be smaller than u64::MAX.");
+        }
+        self.write_unary::<USE_TABLE>(quotient)?;
+        self.write_bits(value & ((1 << log2_b) - 1), log2_b as usize)
+    }
+
+    /// Write a signed value on the stream as a Rice code with parameter
+    /// `log2_b`, mapping it to a natural number using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`RiceWrite::write_rice`]
+    #[inline]
+    fn write_rice_signed<const USE_TABLE: bool>(&mut self, value: i64, log2_b: u64) -> Result<()> {
+        self.write_rice::<USE_TABLE>(int2nat(value), log2_b)
+    }
+
+    /// Write all the `values` on the stream as Rice codes.
+    ///
+    /// # Errors
+    /// The same as [`RiceWrite::write_rice`]
+    #[inline]
+    fn write_rice_all<const USE_TABLE: bool>(&mut self, values: &[u64], log2_b: u64) -> Result<()> {
+        for &value in values {
+            self.write_rice::<USE_TABLE>(value, log2_b)?;
+        }
+        Ok(())
+    }
+}
+
+impl<BO: BitOrder, B: BitWrite<BO>> RiceWrite<BO> for B {}
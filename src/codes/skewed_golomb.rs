@@ -0,0 +1,180 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Skewed Golomb
+//! Skewed Golomb coding, as in `OutputBitStream::writeSkewedGolomb` of the
+//! [DSI Utilities](https://dsiutils.di.unimi.it/). Values are split in
+//! intervals whose size grows exponentially, starting with b: the index i of
+//! the interval of x ∈ N, that is, floor(log(x / b + 1)), is written in unary,
+//! followed by the offset of x in the interval, written in minimal binary with
+//! maximum b·2^i.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in 0..1000 {
+//!         writer.write_skewed_golomb(value, 5).unwrap();
+//!     }
+//!     assert!(writer.write_skewed_golomb(1, 0).is_err());
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_skewed_golomb(5).unwrap(), value);
+//! }
+//! assert!(reader.read_skewed_golomb(0).is_err());
+//!
+//! let mut reader = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..1000 {
+//!     assert_eq!(reader.read_skewed_golomb(5).unwrap(), value);
+//! }
+//! assert_eq!(
+//!     reader.get_position(),
+//!     (0..1000).map(|value| len_skewed_golomb(value, 5)).sum::<usize>()
+//! );
+//! ```
+
+use super::{
+    fast_floor_log2_succ, int2nat, len_minimal_binary, len_unary, nat2int, MinimalBinaryRead,
+    MinimalBinaryWrite,
+};
+use crate::traits::*;
+use anyhow::{bail, Result};
+
+/// Returns how long the skewed Golomb code for `value` will be for a given
+/// modulus `b`, which must be nonzero.
+#[must_use]
+#[inline]
+pub fn len_skewed_golomb(value: u64, b: u64) -> usize {
+    debug_assert!(b > 0, "the modulus of a skewed Golomb code can't be zero");
+    let i = fast_floor_log2_succ(value / b) as u64;
+    let (lower, size) = skewed_golomb_interval(i, b);
+    len_unary::<false>(i) + len_minimal_binary((value as u128 - lower) as u64, size)
+}
+
+/// Returns how long the skewed Golomb code for the signed `value` will be for
+/// a given modulus `b`, which must be nonzero.
+#[must_use]
+#[inline]
+pub fn len_skewed_golomb_signed(value: i64, b: u64) -> usize {
+    len_skewed_golomb(int2nat(value), b)
+}
+
+/// Trait for objects that can read skewed Golomb codes
+pub trait SkewedGolombRead<BO: BitOrder>: MinimalBinaryRead<BO> {
+    /// Read a skewed Golomb code with modulus `b` from the stream.
+    ///
+    /// # Errors
+    /// This function fails if `b` is zero, if the code does not fit in a
+    /// [`u64`], or if the BitRead backend has problems reading bits, as when
+    /// the stream ended unexpectedly
+    #[inline]
+    fn read_skewed_golomb(&mut self, b: u64) -> Result<u64> {
+        if b == 0 {
+            bail!("The modulus of a skewed Golomb code can't be zero.");
+        }
+        let i = self.read_unary::<false>()?;
+        if i > 64 {
+            bail!("The skewed Golomb code does not fit in a u64.");
+        }
+        let (lower, size) = skewed_golomb_interval(i, b);
+        if lower > u64::MAX as u128 {
+            bail!("The skewed Golomb code does not fit in a u64.");
+        }
+        match u64::try_from(lower + self.read_minimal_binary(size)? as u128) {
+            Ok(value) => Ok(value),
+            Err(_) => bail!("The skewed Golomb code does not fit in a u64."),
+        }
+    }
+
+    /// Read a skewed Golomb code with modulus `b` from the stream and map it
+    /// to a signed value using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`SkewedGolombRead::read_skewed_golomb`]
+    #[inline]
+    fn read_skewed_golomb_signed(&mut self, b: u64) -> Result<i64> {
+        self.read_skewed_golomb(b).map(nat2int)
+    }
+
+    /// Read `values.len()` skewed Golomb codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`SkewedGolombRead::read_skewed_golomb`]
+    #[inline]
+    fn read_skewed_golomb_into(&mut self, values: &mut [u64], b: u64) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_skewed_golomb(b)?;
+        }
+        Ok(())
+    }
+}
+
+/// Trait for objects that can write skewed Golomb codes
+pub trait SkewedGolombWrite<BO: BitOrder>: MinimalBinaryWrite<BO> {
+    /// Write a value on the stream as a skewed Golomb code with modulus `b`.
+    ///
+    /// # Errors
+    /// This function fails if `b` is zero or if the BitWrite backend has
+    /// problems writing bits, as when the stream ended unexpectedly
+    #[inline]
+    fn write_skewed_golomb(&mut self, value: u64, b: u64) -> Result<()> {
+        if b == 0 {
+            bail!("The modulus of a skewed Golomb code can't be zero.");
+        }
+        let i = fast_floor_log2_succ(value / b) as u64;
+        let (lower, size) = skewed_golomb_interval(i, b);
+        self.write_unary::<false>(i)?;
+        self.write_minimal_binary((value as u128 - lower) as u64, size)
+    }
+
+    /// Write a signed value on the stream as a skewed Golomb code with
+    /// modulus `b`, mapping it to a natural number using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`SkewedGolombWrite::write_skewed_golomb`]
+    #[inline]
+    fn write_skewed_golomb_signed(&mut self, value: i64, b: u64) -> Result<()> {
+        self.write_skewed_golomb(int2nat(value), b)
+    }
+
+    /// Write all the `values` on the stream as skewed Golomb codes.
+    ///
+    /// # Errors
+    /// The same as [`SkewedGolombWrite::write_skewed_golomb`]
+    #[inline]
+    fn write_skewed_golomb_all(&mut self, values: &[u64], b: u64) -> Result<()> {
+        for &value in values {
+            self.write_skewed_golomb(value, b)?;
+        }
+        Ok(())
+    }
+}
+
+/// Return the first value and the size of the `i`-th interval of a skewed
+/// Golomb code with modulus `b`, where `i` is at most 64. If the size does
+/// not fit in a [`u64`], the interval is truncated to the values that fit in
+/// a [`u64`]. Note that the first value might not fit in a [`u64`], either.
+#[inline(always)]
+fn skewed_golomb_interval(i: u64, b: u64) -> (u128, u64) {
+    debug_assert!(i <= 64);
+    let lower = ((1_u128 << i) - 1) * b as u128;
+    let size = match u64::try_from((b as u128) << i) {
+        Ok(size) => size,
+        Err(_) => ((1_u128 << 64).saturating_sub(lower)) as u64,
+    };
+    (lower, size)
+}
+
+impl<BO: BitOrder, B: BitRead<BO>> SkewedGolombRead<BO> for B {}
+impl<BO: BitOrder, B: BitWrite<BO>> SkewedGolombWrite<BO> for B {}
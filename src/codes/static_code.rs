@@ -0,0 +1,244 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Static codes
+//! Zero-sized types representing a code at the type level, so that generic
+//! structures can be parameterized by the code they use and calls are
+//! monomorphized, as opposed to [`Code`], which selects the code at runtime.
+//! Reads, skips, writes and lengths use the decoding tables.
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! fn round_trip<C: StaticCode>(values: &[u64]) -> usize {
+//!     let mut buffer: Vec<u64> = vec![];
+//!     {
+//!         let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!         for &value in values {
+//!             C::write(&mut writer, value).unwrap();
+//!         }
+//!     }
+//!     let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+//!     for &value in values {
+//!         assert_eq!(C::read(&mut reader).unwrap(), value);
+//!     }
+//!     assert_eq!(reader.get_position(), values.iter().map(|&value| C::len(value)).sum::<usize>());
+//!     reader.get_position()
+//! }
+//!
+//! let values = (0..1000).collect::<Vec<_>>();
+//! round_trip::<Unary>(&values);
+//! assert!(round_trip::<Gamma>(&values) > round_trip::<Delta>(&values));
+//! round_trip::<Zeta<3>>(&values);
+//! round_trip::<Zeta<5>>(&values);
+//! assert_eq!(Zeta::<3>::CODE, Code::Zeta { k: 3 });
+//! ```
+
+use super::*;
+use crate::traits::*;
+use anyhow::Result;
+
+/// A code known at compile time
+pub trait StaticCode {
+    /// The corresponding runtime code
+    const CODE: Code;
+
+    /// Read a value coded with this code from `reader`
+    ///
+    /// # Errors
+    /// This function fails only if the BitRead backend has problems reading
+    /// bits, as when the stream ended unexpectedly
+    fn read<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(reader: &mut R) -> Result<u64>;
+
+    /// Skip a value coded with this code from `reader` without decoding it
+    ///
+    /// # Errors
+    /// The same as [`StaticCode::read`]
+    fn skip<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(reader: &mut R) -> Result<()>;
+
+    /// Skip `n` values coded with this code from `reader` without decoding
+    /// them
+    ///
+    /// # Errors
+    /// The same as [`StaticCode::read`]
+    fn skip_n<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(
+        reader: &mut R,
+        n: usize,
+    ) -> Result<()>;
+
+    /// Write `value` with this code on `writer`
+    ///
+    /// # Errors
+    /// This function fails only if the BitWrite backend has problems writing
+    /// bits, as when the stream ended unexpectedly
+    fn write<BO: BitOrder, W: DeltaWrite<BO> + ZetaWrite<BO>>(
+        writer: &mut W,
+        value: u64,
+    ) -> Result<()>;
+
+    /// Returns how long the code for `value` will be
+    fn len(value: u64) -> usize;
+}
+
+/// Unary code
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Unary;
+
+impl StaticCode for Unary {
+    const CODE: Code = Code::Unary;
+
+    #[inline(always)]
+    fn read<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(reader: &mut R) -> Result<u64> {
+        reader.read_unary::<true>()
+    }
+
+    #[inline(always)]
+    fn skip<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(reader: &mut R) -> Result<()> {
+        reader.skip_unary::<true>()
+    }
+
+    #[inline(always)]
+    fn skip_n<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(
+        reader: &mut R,
+        n: usize,
+    ) -> Result<()> {
+        reader.skip_unary_n::<true>(n)
+    }
+
+    #[inline(always)]
+    fn write<BO: BitOrder, W: DeltaWrite<BO> + ZetaWrite<BO>>(
+        writer: &mut W,
+        value: u64,
+    ) -> Result<()> {
+        writer.write_unary::<true>(value)
+    }
+
+    #[inline(always)]
+    fn len(value: u64) -> usize {
+        len_unary::<true>(value)
+    }
+}
+
+/// Elias γ code
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Gamma;
+
+impl StaticCode for Gamma {
+    const CODE: Code = Code::Gamma;
+
+    #[inline(always)]
+    fn read<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(reader: &mut R) -> Result<u64> {
+        reader.read_gamma::<true>()
+    }
+
+    #[inline(always)]
+    fn skip<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(reader: &mut R) -> Result<()> {
+        reader.skip_gamma::<true>()
+    }
+
+    #[inline(always)]
+    fn skip_n<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(
+        reader: &mut R,
+        n: usize,
+    ) -> Result<()> {
+        reader.skip_gamma_n::<true>(n)
+    }
+
+    #[inline(always)]
+    fn write<BO: BitOrder, W: DeltaWrite<BO> + ZetaWrite<BO>>(
+        writer: &mut W,
+        value: u64,
+    ) -> Result<()> {
+        writer.write_gamma::<true>(value)
+    }
+
+    #[inline(always)]
+    fn len(value: u64) -> usize {
+        len_gamma::<true>(value)
+    }
+}
+
+/// Elias δ code
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Delta;
+
+impl StaticCode for Delta {
+    const CODE: Code = Code::Delta;
+
+    #[inline(always)]
+    fn read<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(reader: &mut R) -> Result<u64> {
+        reader.read_delta::<true, true>()
+    }
+
+    #[inline(always)]
+    fn skip<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(reader: &mut R) -> Result<()> {
+        reader.skip_delta::<true, true>()
+    }
+
+    #[inline(always)]
+    fn skip_n<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(
+        reader: &mut R,
+        n: usize,
+    ) -> Result<()> {
+        reader.skip_delta_n::<true, true>(n)
+    }
+
+    #[inline(always)]
+    fn write<BO: BitOrder, W: DeltaWrite<BO> + ZetaWrite<BO>>(
+        writer: &mut W,
+        value: u64,
+    ) -> Result<()> {
+        writer.write_delta::<true, true>(value)
+    }
+
+    #[inline(always)]
+    fn len(value: u64) -> usize {
+        len_delta::<true>(value)
+    }
+}
+
+/// ζ code with index `K`, which must be in [1, 64]. Decoding tables are used
+/// whenever they are available for `K`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Zeta<const K: u64>;
+
+impl<const K: u64> StaticCode for Zeta<K> {
+    const CODE: Code = Code::Zeta { k: K };
+
+    #[inline(always)]
+    fn read<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(reader: &mut R) -> Result<u64> {
+        reader.read_zeta_const::<K, true>()
+    }
+
+    #[inline(always)]
+    fn skip<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(reader: &mut R) -> Result<()> {
+        reader.skip_zeta::<true>(K)
+    }
+
+    #[inline(always)]
+    fn skip_n<BO: BitOrder, R: DeltaRead<BO> + ZetaRead<BO>>(
+        reader: &mut R,
+        n: usize,
+    ) -> Result<()> {
+        reader.skip_zeta_n::<true>(n, K)
+    }
+
+    #[inline(always)]
+    fn write<BO: BitOrder, W: DeltaWrite<BO> + ZetaWrite<BO>>(
+        writer: &mut W,
+        value: u64,
+    ) -> Result<()> {
+        writer.write_zeta_const::<K, true>(value)
+    }
+
+    #[inline(always)]
+    fn len(value: u64) -> usize {
+        len_zeta_const::<K, true>(value)
+    }
+}
@@ -0,0 +1,193 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Codes statistics
+//! An accumulator that computes how many bits the values it is fed with
+//! would take using unary, γ, δ, ζ codes with index from 1 to `ZETA_K`, and a
+//! minimal binary code with a fixed maximum, so that the best code for some
+//! actual data can be chosen before freezing a format.
+//!
+//! Statistics computed on different parts of the data, for example by
+//! different threads, can be combined with [`CodesStats::merge`].
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let mut first = CodesStats::<10>::new(1 << 20);
+//! let mut second = CodesStats::<10>::new(1 << 20);
+//! for value in 0..1000 {
+//!     first.update(value % 3);
+//!     second.update(value % 17);
+//! }
+//! assert_eq!(first.best_code(), (Code::Unary, 1 * 334 + 2 * 333 + 3 * 333));
+//! assert_eq!(first.total_bits(Code::Gamma), Some(1 * 334 + 3 * 666));
+//! assert_eq!(first.total_bits(Code::Zeta { k: 11 }), None);
+//! assert_eq!(second.best_code().0, Code::Zeta { k: 4 });
+//!
+//! let mut all = first;
+//! all.merge(&second);
+//! assert_eq!(all.count, 2000);
+//! for (code, bits) in all.costs() {
+//!     assert_eq!(
+//!         bits,
+//!         first.total_bits(code).unwrap() + second.total_bits(code).unwrap()
+//!     );
+//! }
+//!
+//! // the minimal binary code can't be used if a value is not smaller than
+//! // the maximum
+//! all.update(1 << 20);
+//! assert_eq!(all.total_bits(Code::MinimalBinary { max: 1 << 20 }), None);
+//! ```
+
+use super::*;
+
+/// Accumulator of the number of bits needed to write a sequence of values
+/// with different codes
+///
+/// The ζ codes considered have index from 1 to `ZETA_K`, which must be at
+/// most 64, the largest index of a ζ code; larger values do not compile:
+/// ```compile_fail
+/// use dsi_bitstream::prelude::*;
+///
+/// let mut stats = CodesStats::<65>::new(10);
+/// stats.update(1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodesStats<const ZETA_K: usize = 10> {
+    /// The number of values seen
+    pub count: u64,
+    /// The number of bits needed using unary codes
+    pub unary: u64,
+    /// The number of bits needed using γ codes
+    pub gamma: u64,
+    /// The number of bits needed using δ codes
+    pub delta: u64,
+    /// The number of bits needed using ζ codes, where `zeta[i]` is for
+    /// index i + 1
+    pub zeta: [u64; ZETA_K],
+    /// The maximum of the minimal binary code
+    pub minimal_binary_max: u64,
+    /// The number of bits needed using minimal binary codes, or `None` if
+    /// some value was not smaller than the maximum
+    pub minimal_binary: Option<u64>,
+}
+
+impl<const ZETA_K: usize> CodesStats<ZETA_K> {
+    /// Create empty statistics, using `minimal_binary_max` as the maximum of
+    /// the minimal binary code
+    #[must_use]
+    pub fn new(minimal_binary_max: u64) -> Self {
+        const { assert!(ZETA_K <= 64, "the index of a ζ code must be at most 64") };
+        Self {
+            count: 0,
+            unary: 0,
+            gamma: 0,
+            delta: 0,
+            zeta: [0; ZETA_K],
+            minimal_binary_max,
+            minimal_binary: if minimal_binary_max == 0 {
+                None
+            } else {
+                Some(0)
+            },
+        }
+    }
+
+    /// Add the cost of writing `value` with each code
+    #[inline]
+    pub fn update(&mut self, value: u64) {
+        // the fields are public, so the statistics might not come from new
+        const { assert!(ZETA_K <= 64, "the index of a ζ code must be at most 64") };
+        self.count += 1;
+        // the length of the unary code, without overflowing
+        self.unary = self.unary.saturating_add(value).saturating_add(1);
+        self.gamma += len_gamma::<true>(value) as u64;
+        self.delta += len_delta::<true>(value) as u64;
+        for (k, bits) in self.zeta.iter_mut().enumerate() {
+            *bits += len_zeta::<true>(value, k as u64 + 1) as u64;
+        }
+        self.minimal_binary = match self.minimal_binary {
+            Some(bits) if value < self.minimal_binary_max => {
+                Some(bits + len_minimal_binary(value, self.minimal_binary_max) as u64)
+            }
+            _ => None,
+        };
+    }
+
+    /// Combine these statistics with `other`, as if all the values seen by
+    /// `other` had been seen by these statistics. If the maxima of the
+    /// minimal binary codes are different, the minimal binary code becomes
+    /// unavailable.
+    pub fn merge(&mut self, other: &Self) {
+        self.count += other.count;
+        self.unary = self.unary.saturating_add(other.unary);
+        self.gamma += other.gamma;
+        self.delta += other.delta;
+        for (bits, other_bits) in self.zeta.iter_mut().zip(other.zeta.iter()) {
+            *bits += other_bits;
+        }
+        self.minimal_binary = match (self.minimal_binary, other.minimal_binary) {
+            (Some(bits), Some(other_bits))
+                if self.minimal_binary_max == other.minimal_binary_max =>
+            {
+                Some(bits + other_bits)
+            }
+            _ => None,
+        };
+    }
+
+    /// Return an iterator over the codes that can be used and the
+    /// corresponding total number of bits
+    pub fn costs(&self) -> impl Iterator<Item = (Code, u64)> + '_ {
+        [
+            (Code::Unary, self.unary),
+            (Code::Gamma, self.gamma),
+            (Code::Delta, self.delta),
+        ]
+        .into_iter()
+        .chain(
+            self.zeta
+                .iter()
+                .enumerate()
+                .map(|(k, bits)| (Code::Zeta { k: k as u64 + 1 }, *bits)),
+        )
+        .chain(self.minimal_binary.map(|bits| {
+            (
+                Code::MinimalBinary {
+                    max: self.minimal_binary_max,
+                },
+                bits,
+            )
+        }))
+    }
+
+    /// Return the total number of bits needed using `code`, or `None` if the
+    /// code is not tracked by these statistics or can't be used
+    #[must_use]
+    pub fn total_bits(&self, code: Code) -> Option<u64> {
+        self.costs()
+            .find(|(candidate, _)| *candidate == code)
+            .map(|(_, bits)| bits)
+    }
+
+    /// Return the code needing the smallest number of bits, and the number
+    /// of bits. Ties are broken in favor of the code that comes first among
+    /// unary, γ, δ, ζ by increasing index, and minimal binary.
+    #[must_use]
+    pub fn best_code(&self) -> (Code, u64) {
+        let mut best = (Code::Unary, self.unary);
+        for (code, bits) in self.costs() {
+            if bits < best.1 {
+                best = (code, bits);
+            }
+        }
+        best
+    }
+}
@@ -0,0 +1,453 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Compile-time tables
+//! Constant functions computing the decoding and encoding tables of unary,
+//! γ, δ and ζ codes, and the [`code_tables`] macro that defines the tables of
+//! a code in its module, so that their size can be chosen at build time.
+//!
+//! The number of bits used for decoding and the maximum value written using
+//! the tables are read when compiling from the environment variables passed
+//! to the macro (for example, `GAMMA_CODE_TABLE_BITS` and
+//! `GAMMA_CODE_TABLE_MAX`), falling back to the defaults passed to the macro.
+//! Cargo tracks these variables, so changing them rebuilds the crate.
+//!
+//! Codewords are handled as integers containing their bits: in M2L order
+//! the first bit is the most significant one, in L2M order it is the least
+//! significant one, as in the peeked windows and in the arguments of
+//! `write_bits`.
+
+/// The len we assign to a code that cannot be decoded through the table
+pub(crate) const MISSING_VALUE_LEN: u8 = 255;
+
+/// The maximum number of bits of the decoding tables, so that the decoded
+/// values fit in a [`u16`]
+pub(crate) const MAX_READ_BITS: usize = 16;
+
+/// Maximum length of the codes decoded through the second-level tables,
+/// which is the maximum number of bits that all the backends can peek
+#[cfg(feature = "two_level_tables")]
+pub(crate) const READ2_BITS: usize = 32;
+
+/// A code whose tables can be computed at compile time
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum TableCode {
+    Unary,
+    Gamma,
+    Delta,
+    Zeta(u64),
+}
+
+/// Parse the decimal value of an environment variable, or return `default`
+/// if it is not set. Invalid values stop the compilation.
+pub(crate) const fn env_or(value: Option<&str>, default: usize) -> usize {
+    let bytes = match value {
+        Some(value) => value.as_bytes(),
+        None => return default,
+    };
+    assert!(!bytes.is_empty(), "The size of a table can't be empty.");
+    let mut result = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        assert!(
+            bytes[i].is_ascii_digit(),
+            "The size of a table must be a decimal number."
+        );
+        result = result * 10 + (bytes[i] - b'0') as usize;
+        i += 1;
+    }
+    result
+}
+
+/// Return the `n_bits` bits at `pos` in a window of `len` bits, or `None` if
+/// there are not enough bits
+const fn read_bits(window: u64, len: usize, pos: usize, n_bits: usize, m2l: bool) -> Option<u64> {
+    if pos + n_bits > len {
+        return None;
+    }
+    if n_bits == 0 {
+        return Some(0);
+    }
+    let mask = u64::MAX >> (64 - n_bits);
+    Some(if m2l {
+        (window >> (len - pos - n_bits)) & mask
+    } else {
+        (window >> pos) & mask
+    })
+}
+
+/// Decode an unary code at `pos` in a window of `len` bits, returning the
+/// value and the position after the code
+const fn read_unary(window: u64, len: usize, pos: usize, m2l: bool) -> Option<(u64, usize)> {
+    let mut value = 0;
+    loop {
+        match read_bits(window, len, pos + value, 1, m2l) {
+            Some(0) => value += 1,
+            Some(_) => return Some((value as u64, pos + value + 1)),
+            None => return None,
+        }
+    }
+}
+
+/// Decode a ζ code of index `k` (a γ code if `k` is one) at the start of a
+/// window of `len` bits, returning the value and the position after the code
+const fn read_zeta(window: u64, len: usize, k: u64, m2l: bool) -> Option<(u64, usize)> {
+    let (h, pos) = match read_unary(window, len, 0, m2l) {
+        Some(res) => res,
+        None => return None,
+    };
+    if (h + 1) * k > 64 {
+        return None;
+    }
+    let (lo, max) = zeta_interval(h, k);
+    let l = floor_log2(max);
+    let limit = (2 << l) - max;
+    let (mut payload, mut pos) = match read_bits(window, len, pos, l, m2l) {
+        Some(payload) => (payload as u128, pos + l),
+        None => return None,
+    };
+    if payload >= limit {
+        payload = match read_bits(window, len, pos, 1, m2l) {
+            Some(bit) => ((payload << 1) | bit as u128) - limit,
+            None => return None,
+        };
+        pos += 1;
+    }
+    Some(((lo + payload - 1) as u64, pos))
+}
+
+/// Decode `code` at the start of a window of `len` bits, returning the value
+/// and the length of the code
+pub(crate) const fn read_code(
+    code: TableCode,
+    window: u64,
+    len: usize,
+    m2l: bool,
+) -> Option<(u64, usize)> {
+    match code {
+        TableCode::Unary => read_unary(window, len, 0, m2l),
+        TableCode::Gamma => read_zeta(window, len, 1, m2l),
+        TableCode::Zeta(k) => read_zeta(window, len, k, m2l),
+        TableCode::Delta => {
+            let (n_bits, pos) = match read_zeta(window, len, 1, m2l) {
+                Some(res) => res,
+                None => return None,
+            };
+            if n_bits >= 64 {
+                return None;
+            }
+            match read_bits(window, len, pos, n_bits as usize, m2l) {
+                Some(bits) => Some(((1 << n_bits) + bits - 1, pos + n_bits as usize)),
+                None => None,
+            }
+        }
+    }
+}
+
+/// Append the lowest `n_bits` bits of `bits` to the codeword `word` of
+/// length `len`, as `write_bits` would do
+const fn append(word: u128, len: usize, bits: u128, n_bits: usize, m2l: bool) -> (u128, usize) {
+    if m2l {
+        ((word << n_bits) | bits, len + n_bits)
+    } else {
+        (word | (bits << len), len + n_bits)
+    }
+}
+
+/// Return the codeword of `value` in a ζ code of index `k` appended to the
+/// codeword `word` of length `len`
+const fn write_zeta(value: u64, k: u64, word: u128, len: usize, m2l: bool) -> (u128, usize) {
+    let h = floor_log2(value as u128 + 1) as u64 / k;
+    let (lo, max) = zeta_interval(h, k);
+    let offset = value as u128 + 1 - lo;
+    // unary code of h
+    let (word, len) = append(word, len, 0, h as usize, m2l);
+    let (word, len) = append(word, len, 1, 1, m2l);
+    // minimal binary code of offset
+    let l = floor_log2(max);
+    let limit = (2 << l) - max;
+    if offset < limit {
+        append(word, len, offset, l, m2l)
+    } else {
+        let to_write = offset + limit;
+        let (word, len) = append(word, len, to_write >> 1, l, m2l);
+        append(word, len, to_write & 1, 1, m2l)
+    }
+}
+
+/// Return the codeword of `value` in `code` and its length
+pub(crate) const fn write_code(code: TableCode, value: u64, m2l: bool) -> (u128, usize) {
+    match code {
+        TableCode::Unary => {
+            let (word, len) = append(0, 0, 0, value as usize, m2l);
+            append(word, len, 1, 1, m2l)
+        }
+        TableCode::Gamma => write_zeta(value, 1, 0, 0, m2l),
+        TableCode::Zeta(k) => write_zeta(value, k, 0, 0, m2l),
+        TableCode::Delta => {
+            let n_bits = floor_log2(value as u128 + 1);
+            let (word, len) = write_zeta(n_bits as u64, 1, 0, 0, m2l);
+            append(word, len, value as u128 + 1 - (1 << n_bits), n_bits, m2l)
+        }
+    }
+}
+
+/// Return the floor of the base-2 logarithm of `value`, which must be
+/// positive
+const fn floor_log2(value: u128) -> usize {
+    127 - value.leading_zeros() as usize
+}
+
+/// Return the first value and the size of the interval of the values of
+/// x + 1 whose ζ code of index `k` has unary part `h`
+const fn zeta_interval(h: u64, k: u64) -> (u128, u128) {
+    let lo = 1 << (h * k);
+    (lo, (1 << ((h + 1) * k)) - lo)
+}
+
+/// Compute the decoding tables of `code` with `N` entries, containing the
+/// values and the lengths of the codes at the start of each window of
+/// log₂`N` bits, or [`MISSING_VALUE_LEN`] if there is no complete code.
+pub(crate) const fn read_tables<const N: usize>(code: TableCode, m2l: bool) -> ([u16; N], [u8; N]) {
+    assert!(
+        N.is_power_of_two(),
+        "The size of a table must be a power of two."
+    );
+    let read_bits = N.trailing_zeros() as usize;
+    assert!(
+        read_bits <= MAX_READ_BITS,
+        "Decoding tables can use at most 16 bits."
+    );
+    let mut values = [0; N];
+    let mut lens = [MISSING_VALUE_LEN; N];
+    let mut idx = 0;
+    while idx < N {
+        if let Some((value, len)) = read_code(code, idx as u64, read_bits, m2l) {
+            values[idx] = value as u16;
+            lens[idx] = len as u8;
+        }
+        idx += 1;
+    }
+    (values, lens)
+}
+
+/// Compute the encoding tables of `code` with `N` entries, containing the
+/// codewords and the lengths of the codes of the values smaller than `N`
+pub(crate) const fn write_tables<const N: usize>(
+    code: TableCode,
+    m2l: bool,
+) -> ([u64; N], [u8; N]) {
+    let mut words = [0; N];
+    let mut lens = [0; N];
+    let mut value = 0;
+    while value < N {
+        let (word, len) = write_code(code, value as u64, m2l);
+        assert!(
+            len <= 64,
+            "Encoding tables can contain codes of at most 64 bits."
+        );
+        words[value] = word as u64;
+        lens[value] = len as u8;
+        value += 1;
+    }
+    (words, lens)
+}
+
+/// Compute the lengths of the codes of the values smaller than `N`
+pub(crate) const fn len_table<const N: usize>(code: TableCode) -> [u8; N] {
+    let mut lens = [0; N];
+    let mut value = 0;
+    while value < N {
+        lens[value] = write_code(code, value as u64, true).1 as u8;
+        value += 1;
+    }
+    lens
+}
+
+/// Return the number of entries of the second-level table of ζ codes of
+/// index `k`, that is, the number of unary prefixes of the codes not longer
+/// than [`READ2_BITS`]
+#[cfg(feature = "two_level_tables")]
+pub(crate) const fn second_level_len(k: u64) -> usize {
+    let mut h = 0;
+    loop {
+        let (_, max) = zeta_interval(h as u64, k);
+        if h + 1 + floor_log2(max) > READ2_BITS {
+            return h;
+        }
+        h += 1;
+    }
+}
+
+/// Compute the second-level table of ζ codes of index `k` (see the
+/// [`second_level_tables`] macro), which must have [`second_level_len`]
+/// entries
+#[cfg(feature = "two_level_tables")]
+pub(crate) const fn second_level_table<const N: usize>(k: u64) -> [(u8, u32, u32); N] {
+    let mut table = [(0, 0, 0); N];
+    let mut h = 0;
+    while h < N {
+        let (lo, max) = zeta_interval(h as u64, k);
+        let l = floor_log2(max);
+        table[h] = (l as u8, ((2 << l) - max) as u32, (lo - 1) as u32);
+        h += 1;
+    }
+    table
+}
+
+/// Define in the calling module the tables of a code, and the functions
+/// reading, skipping and writing codes with them.
+///
+/// The arguments are the name of the code in the documentation, its
+/// [`TableCode`], the environment variable setting `READ_BITS` with its
+/// default, and the environment variable setting `WRITE_MAX` with its
+/// default.
+macro_rules! code_tables {
+    (
+        $name:literal,
+        $code:expr,
+        $read_bits_var:literal = $read_bits:literal,
+        $write_max_var:literal = $write_max:literal $(,)?
+    ) => {
+        use crate::codes::tables::{env_or, len_table, read_tables, write_tables, TableCode};
+        use crate::traits::{BitRead, BitWrite, UpcastableInto, L2M, M2L};
+        use anyhow::Result;
+
+        #[doc = concat!("How many bits are needed to read the tables in this module, set by `", $read_bits_var, "`")]
+        pub const READ_BITS: usize = env_or(option_env!($read_bits_var), $read_bits);
+        /// The len we assign to a code that cannot be decoded through the table
+        pub const MISSING_VALUE_LEN: u8 = crate::codes::tables::MISSING_VALUE_LEN;
+        #[doc = concat!("Maximum value writable using the table(s), set by `", $write_max_var, "`")]
+        pub const WRITE_MAX: u64 = env_or(option_env!($write_max_var), $write_max) as u64;
+
+        const CODE: TableCode = $code;
+        const READ_LEN: usize = 1 << READ_BITS;
+        const WRITE_LEN: usize = WRITE_MAX as usize + 1;
+        const READ_TABLES_M2L: ([u16; READ_LEN], [u8; READ_LEN]) = read_tables(CODE, true);
+        const READ_TABLES_L2M: ([u16; READ_LEN], [u8; READ_LEN]) = read_tables(CODE, false);
+        const WRITE_TABLES_M2L: ([u64; WRITE_LEN], [u8; WRITE_LEN]) = write_tables(CODE, true);
+        const WRITE_TABLES_L2M: ([u64; WRITE_LEN], [u8; WRITE_LEN]) = write_tables(CODE, false);
+
+        #[doc = concat!("Table containing the values used to speed up the reading of ", $name, " codes")]
+        pub const READ_M2L: &[u16] = &READ_TABLES_M2L.0;
+        #[doc = concat!("Table containing the lens used to speed up the reading of ", $name, " codes")]
+        pub const READ_LEN_M2L: &[u8] = &READ_TABLES_M2L.1;
+        #[doc = concat!("Table containing the values used to speed up the reading of ", $name, " codes")]
+        pub const READ_L2M: &[u16] = &READ_TABLES_L2M.0;
+        #[doc = concat!("Table containing the lens used to speed up the reading of ", $name, " codes")]
+        pub const READ_LEN_L2M: &[u8] = &READ_TABLES_L2M.1;
+        #[doc = concat!("Table used to speed up the writing of ", $name, " codes")]
+        pub const WRITE_M2L: &[u64] = &WRITE_TABLES_M2L.0;
+        #[doc = concat!("Table used to speed up the writing of ", $name, " codes")]
+        pub const WRITE_LEN_M2L: &[u8] = &WRITE_TABLES_M2L.1;
+        #[doc = concat!("Table used to speed up the writing of ", $name, " codes")]
+        pub const WRITE_L2M: &[u64] = &WRITE_TABLES_L2M.0;
+        #[doc = concat!("Table used to speed up the writing of ", $name, " codes")]
+        pub const WRITE_LEN_L2M: &[u8] = &WRITE_TABLES_L2M.1;
+        #[doc = concat!("Table used to speed up the computation of the length of ", $name, " codes")]
+        pub const LEN: &[u8] = &len_table::<WRITE_LEN>(CODE);
+
+        crate::codes::tables::code_tables!(
+            @functions L2M, read_table_l2m, skip_table_l2m, write_table_l2m,
+            READ_L2M, READ_LEN_L2M, WRITE_L2M, WRITE_LEN_L2M
+        );
+        crate::codes::tables::code_tables!(
+            @functions M2L, read_table_m2l, skip_table_m2l, write_table_m2l,
+            READ_M2L, READ_LEN_M2L, WRITE_M2L, WRITE_LEN_M2L
+        );
+    };
+    (
+        @functions $BO:ident, $read:ident, $skip:ident, $write:ident,
+        $READ:ident, $READ_LEN:ident, $WRITE:ident, $WRITE_LEN:ident
+    ) => {
+        #[inline(always)]
+        /// Lookup a read table, if the result is `Some` the value was found,
+        /// otherwise we were not able to decode the value and you should
+        /// fallback to the default implementation
+        ///
+        /// # Errors
+        /// This function errors if it wasn't able to skip_bits
+        pub fn $read<B: BitRead<$BO>>(backend: &mut B) -> Result<Option<u64>> {
+            // without bits there is nothing to look up
+            if READ_BITS == 0 {
+                return Ok(None);
+            }
+            if let Ok(idx) = backend.peek_bits(READ_BITS) {
+                let idx: u64 = idx.upcast();
+                let len = $READ_LEN[idx as usize];
+                if len != MISSING_VALUE_LEN {
+                    backend.skip_bits_after_table_lookup(len as usize)?;
+                    return Ok(Some($READ[idx as usize] as u64));
+                }
+            }
+            Ok(None)
+        }
+
+        #[inline(always)]
+        /// Skip a code using the read table, if the result is `true` the code
+        /// was skipped, otherwise we were not able to find its length and you
+        /// should fallback to the default implementation
+        ///
+        /// # Errors
+        /// This function errors if it wasn't able to skip_bits
+        pub fn $skip<B: BitRead<$BO>>(backend: &mut B) -> Result<bool> {
+            // without bits there is nothing to look up
+            if READ_BITS == 0 {
+                return Ok(false);
+            }
+            if let Ok(idx) = backend.peek_bits(READ_BITS) {
+                let idx: u64 = idx.upcast();
+                let len = $READ_LEN[idx as usize];
+                if len != MISSING_VALUE_LEN {
+                    backend.skip_bits_after_table_lookup(len as usize)?;
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+
+        #[inline(always)]
+        /// Write a value using the write table, if the result is `true` the
+        /// value was written, otherwise it is larger than [`WRITE_MAX`] and
+        /// you should fallback to the default implementation
+        ///
+        /// # Errors
+        /// This function errors if it wasn't able to write_bits
+        pub fn $write<B: BitWrite<$BO>>(backend: &mut B, value: u64) -> Result<bool> {
+            Ok(if let Some(bits) = $WRITE.get(value as usize) {
+                backend.write_bits(*bits, $WRITE_LEN[value as usize] as usize)?;
+                true
+            } else {
+                false
+            })
+        }
+    };
+}
+
+pub(crate) use code_tables;
+
+/// Define in the calling module the second-level table `READ2` of ζ codes of
+/// index `k` (γ codes are ζ codes of index 1), if the `two_level_tables`
+/// feature is enabled.
+///
+/// The arguments are the name of the code in the documentation and `k`.
+macro_rules! second_level_tables {
+    ($name:literal, $k:expr) => {
+        #[cfg(feature = "two_level_tables")]
+        #[doc = concat!("Second-level table used to speed up the reading of ", $name, " codes")]
+        /// longer than [`READ_BITS`]. It is indexed by the length of the unary
+        /// prefix, and contains the number of bits of the short minimal binary
+        /// payloads, the limit above which the payload is one bit longer, and
+        /// the value to add to the payload
+        pub const READ2: &[(u8, u32, u32)] = &crate::codes::tables::second_level_table::<
+            { crate::codes::tables::second_level_len($k) },
+        >($k);
+    };
+}
+
+pub(crate) use second_level_tables;
@@ -0,0 +1,179 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! # Two-level decoding tables
+//! Second-level decoding of the codes that are too long for the first-level
+//! tables, but not longer than [`READ_BITS`].
+//!
+//! γ and ζ codewords are made by a unary prefix h followed by a minimal binary
+//! payload whose parameters depend only on h, so we peek [`READ_BITS`] bits,
+//! find h by counting zeros, and look up the parameters of the payload in the
+//! `READ2` table of the code, which is indexed by h. A δ code is a γ code of
+//! the length n followed by n bits, so it needs a single lookup in the γ
+//! second-level table.
+//!
+//! This module is enabled by the `two_level_tables` feature.
+
+use crate::traits::*;
+use anyhow::Result;
+
+/// Maximum length of the codes decoded through the second-level tables
+pub(crate) const READ_BITS: usize = 32;
+
+/// Return the `n_bits` bits of `window` starting at bit `start`, where the
+/// first bit is the most significant one
+#[inline(always)]
+fn extract_m2l(window: u64, start: usize, n_bits: usize) -> u64 {
+    // valid also when n_bits is zero
+    ((window << start) >> 1) >> (63 - n_bits)
+}
+
+/// Return the `n_bits` bits of `window` starting at bit `start`, where the
+/// first bit is the least significant one
+#[inline(always)]
+fn extract_l2m(window: u64, start: usize, n_bits: usize) -> u64 {
+    (window >> start) & ((1 << n_bits) - 1)
+}
+
+/// Decode a codeword made by a unary prefix followed by a minimal binary
+/// payload at the start of a window of [`READ_BITS`] bits using the
+/// second-level table `table`, returning the value and the length of the
+/// codeword, or `None` if the codeword is too long.
+#[inline(always)]
+fn decode(
+    window: u64,
+    table: &[(u8, u32, u32)],
+    zeros: impl Fn(u64) -> usize,
+    extract: impl Fn(u64, usize, usize) -> u64,
+) -> Option<(u64, usize)> {
+    let h = zeros(window);
+    let &(n_bits, limit, base) = table.get(h)?;
+    let mut pos = h + 1 + n_bits as usize;
+    if pos > READ_BITS {
+        return None;
+    }
+    let mut payload = extract(window, pos - n_bits as usize, n_bits as usize);
+    if payload >= limit as u64 {
+        if pos == READ_BITS {
+            return None;
+        }
+        payload = ((payload << 1) | extract(window, pos, 1)) - limit as u64;
+        pos += 1;
+    }
+    Some((payload + base as u64, pos))
+}
+
+/// Read a γ or ζ code using the second-level table `table`, returning `None`
+/// if it is longer than [`READ_BITS`]
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+#[inline(always)]
+pub(crate) fn read_table_m2l<B: BitRead<M2L>>(
+    backend: &mut B,
+    table: &[(u8, u32, u32)],
+) -> Result<Option<u64>> {
+    if let Ok(window) = backend.peek_bits(READ_BITS) {
+        // left-align the window
+        let window: u64 = window.upcast() << (64 - READ_BITS);
+        if let Some((value, len)) = decode(
+            window,
+            table,
+            |window| window.leading_zeros() as usize,
+            extract_m2l,
+        ) {
+            backend.skip_bits_after_table_lookup(len)?;
+            return Ok(Some(value));
+        }
+    }
+    Ok(None)
+}
+
+/// Read a γ or ζ code using the second-level table `table`, returning `None`
+/// if it is longer than [`READ_BITS`]
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+#[inline(always)]
+pub(crate) fn read_table_l2m<B: BitRead<L2M>>(
+    backend: &mut B,
+    table: &[(u8, u32, u32)],
+) -> Result<Option<u64>> {
+    if let Ok(window) = backend.peek_bits(READ_BITS) {
+        let window: u64 = window.upcast();
+        if let Some((value, len)) = decode(
+            window,
+            table,
+            |window| window.trailing_zeros() as usize,
+            extract_l2m,
+        ) {
+            backend.skip_bits_after_table_lookup(len)?;
+            return Ok(Some(value));
+        }
+    }
+    Ok(None)
+}
+
+/// Read a δ code using the second-level table of γ codes `gamma_table`,
+/// returning `None` if it is longer than [`READ_BITS`]
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+#[inline(always)]
+pub(crate) fn read_delta_table_m2l<B: BitRead<M2L>>(
+    backend: &mut B,
+    gamma_table: &[(u8, u32, u32)],
+) -> Result<Option<u64>> {
+    if let Ok(window) = backend.peek_bits(READ_BITS) {
+        // left-align the window
+        let window: u64 = window.upcast() << (64 - READ_BITS);
+        if let Some((n_bits, pos)) = decode(
+            window,
+            gamma_table,
+            |window| window.leading_zeros() as usize,
+            extract_m2l,
+        ) {
+            let len = pos + n_bits as usize;
+            if len <= READ_BITS {
+                let value = extract_m2l(window, pos, n_bits as usize) + (1 << n_bits) - 1;
+                backend.skip_bits_after_table_lookup(len)?;
+                return Ok(Some(value));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Read a δ code using the second-level table of γ codes `gamma_table`,
+/// returning `None` if it is longer than [`READ_BITS`]
+///
+/// # Errors
+/// This function errors if it wasn't able to skip_bits
+#[inline(always)]
+pub(crate) fn read_delta_table_l2m<B: BitRead<L2M>>(
+    backend: &mut B,
+    gamma_table: &[(u8, u32, u32)],
+) -> Result<Option<u64>> {
+    if let Ok(window) = backend.peek_bits(READ_BITS) {
+        let window: u64 = window.upcast();
+        if let Some((n_bits, pos)) = decode(
+            window,
+            gamma_table,
+            |window| window.trailing_zeros() as usize,
+            extract_l2m,
+        ) {
+            let len = pos + n_bits as usize;
+            if len <= READ_BITS {
+                let value = extract_l2m(window, pos, n_bits as usize) + (1 << n_bits) - 1;
+                backend.skip_bits_after_table_lookup(len)?;
+                return Ok(Some(value));
+            }
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,2669 @@
+// THIS FILE HAS BEEN GENERATED WITH THE SCRIPT code_tables_generator.py
+// ~~~~~~~~~~~~~~~~~~~ DO NOT MODIFY ~~~~~~~~~~~~~~~~~~~~~~
+// Pre-computed constants used to decode several unary codes with a single lookup
+/// How many bits are needed to read the tables in this
+pub const READ_BITS: usize = 12;
+/// Maximum number of values decoded with a single lookup
+pub const MAX_SYMBOLS: usize = 8;
+///Table containing the total length of the codes decoded with a lookup
+pub const READ_LEN_M2L: &[u8] = &[
+    0, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 6, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 5, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 6,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 4, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 5,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 6, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 3, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 6,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 5, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 4,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 6, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 5, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 6,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8,
+    12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 2, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 5,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 6, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 4, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 6,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 5, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 7, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 3,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 6, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 5, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 6,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8,
+    12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 4, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 7, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 5,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8,
+    12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 6, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 7,
+    12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 8, 12, 11, 11, 10, 10, 10, 10, 9, 9,
+    9, 9, 9, 9, 9, 9, 1, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 6, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 5, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 4, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 11, 5, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 6, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 11, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12,
+    10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 3, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 5, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 7, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11,
+    10, 10, 10, 10, 4, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 6, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 11, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12,
+    10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 5, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12,
+    10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 7, 12, 11, 12,
+    10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11,
+    10, 10, 10, 10, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12,
+    10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 7, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11,
+    10, 10, 10, 10, 8, 12, 11, 11, 10, 10, 10, 10, 9, 9, 9, 9, 9, 9, 9, 9, 2, 12, 11, 12, 10, 12,
+    11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12,
+    11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12,
+    11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12,
+    11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12,
+    11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12,
+    11, 11, 5, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12,
+    11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12,
+    11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 6, 12, 11, 12, 10, 12,
+    11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12,
+    11, 11, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12, 10, 12,
+    11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 4, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12,
+    11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12,
+    11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12,
+    11, 11, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12,
+    11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12,
+    11, 11, 8, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 5, 12, 11, 12, 10, 12,
+    11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12,
+    11, 11, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12, 10, 12,
+    11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12,
+    11, 11, 8, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 7, 12, 11, 12, 10, 12,
+    11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 8, 12, 11, 11, 10, 10, 10, 10, 9, 9, 9, 9, 9, 9, 9, 9,
+    3, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 7, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 6, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 7,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12, 10, 12, 11, 11, 9,
+    12, 11, 11, 10, 10, 10, 10, 5, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 12, 8,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 7, 12, 11, 12, 10, 12, 11, 12, 9,
+    12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 6,
+    12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12, 10, 12, 11, 11, 9,
+    12, 11, 11, 10, 10, 10, 10, 7, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 8,
+    12, 11, 11, 10, 10, 10, 10, 9, 9, 9, 9, 9, 9, 9, 9, 4, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11,
+    12, 10, 12, 11, 12, 8, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 7, 12, 11,
+    12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11,
+    11, 10, 10, 10, 10, 6, 12, 11, 12, 10, 12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8, 12, 11,
+    12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 7, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11,
+    11, 10, 10, 10, 10, 8, 12, 11, 11, 10, 10, 10, 10, 9, 9, 9, 9, 9, 9, 9, 9, 5, 12, 11, 12, 10,
+    12, 11, 12, 9, 12, 11, 12, 10, 12, 11, 11, 8, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10,
+    10, 10, 10, 7, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10, 10, 8, 12, 11, 11, 10,
+    10, 10, 10, 9, 9, 9, 9, 9, 9, 9, 9, 6, 12, 11, 12, 10, 12, 11, 11, 9, 12, 11, 11, 10, 10, 10,
+    10, 8, 12, 11, 11, 10, 10, 10, 10, 9, 9, 9, 9, 9, 9, 9, 9, 7, 12, 11, 11, 10, 10, 10, 10, 9, 9,
+    9, 9, 9, 9, 9, 9, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+];
+///Table containing the number of codes decoded with a lookup
+pub const READ_COUNT_M2L: &[u8] = &[
+    0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+];
+///Table containing the values decoded with a lookup, MAX_SYMBOLS
+///for each entry, padded with zeros
+pub const READ_VALUES_M2L: &[u8] = &[
+    0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0,
+    0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0,
+    0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0,
+    0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 1, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0,
+    0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0,
+    0, 7, 1, 0, 0, 0, 0, 0, 0, 7, 1, 1, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0,
+    0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 2, 0, 0, 0, 0, 0, 7, 0, 1, 0, 0, 0, 0, 0, 7, 0, 1, 0, 0, 0, 0,
+    0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 1, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0,
+    0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0,
+    0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 1, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0,
+    0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 2, 0, 0, 0, 0, 0, 6, 1, 1, 0, 0, 0, 0, 0, 6, 1, 1, 0, 0, 0, 0,
+    0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 1, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0,
+    0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 3, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0,
+    0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 1, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0,
+    0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 2, 0, 0, 0, 0, 6, 0, 0, 1, 0, 0, 0, 0, 6, 0, 0, 1, 0, 0, 0,
+    0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0,
+    0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0,
+    0, 5, 3, 0, 0, 0, 0, 0, 0, 5, 3, 1, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0,
+    0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 2, 0, 0, 0, 0, 0, 5, 2, 1, 0, 0, 0, 0, 0, 5, 2, 1, 0, 0, 0, 0,
+    0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 1, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0,
+    0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 3, 0, 0, 0, 0, 0, 5, 1, 2, 0, 0, 0, 0, 0, 5, 1, 2, 0, 0, 0, 0,
+    0, 5, 1, 1, 0, 0, 0, 0, 0, 5, 1, 1, 1, 0, 0, 0, 0, 5, 1, 1, 0, 0, 0, 0, 0, 5, 1, 1, 0, 0, 0, 0,
+    0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 2, 0, 0, 0, 0, 5, 1, 0, 1, 0, 0, 0, 0, 5, 1, 0, 1, 0, 0, 0,
+    0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 1, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0,
+    0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 4, 0, 0, 0, 0, 0, 5, 0, 3, 0, 0, 0, 0, 0, 5, 0, 3, 0, 0, 0, 0,
+    0, 5, 0, 2, 0, 0, 0, 0, 0, 5, 0, 2, 1, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0,
+    0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 2, 0, 0, 0, 0, 5, 0, 1, 1, 0, 0, 0, 0, 5, 0, 1, 1, 0, 0, 0,
+    0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 0, 1, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0,
+    0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 3, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0,
+    0, 5, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 1, 1, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0,
+    0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 2, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0,
+    0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0,
+    0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 6, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0,
+    0, 4, 4, 0, 0, 0, 0, 0, 0, 4, 4, 1, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0,
+    0, 4, 3, 0, 0, 0, 0, 0, 0, 4, 3, 2, 0, 0, 0, 0, 0, 4, 3, 1, 0, 0, 0, 0, 0, 4, 3, 1, 0, 0, 0, 0,
+    0, 4, 3, 0, 0, 0, 0, 0, 0, 4, 3, 0, 1, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0,
+    0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 3, 0, 0, 0, 0, 0, 4, 2, 2, 0, 0, 0, 0, 0, 4, 2, 2, 0, 0, 0, 0,
+    0, 4, 2, 1, 0, 0, 0, 0, 0, 4, 2, 1, 1, 0, 0, 0, 0, 4, 2, 1, 0, 0, 0, 0, 0, 4, 2, 1, 0, 0, 0, 0,
+    0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 2, 0, 0, 0, 0, 4, 2, 0, 1, 0, 0, 0, 0, 4, 2, 0, 1, 0, 0, 0,
+    0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 1, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0,
+    0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 4, 0, 0, 0, 0, 0, 4, 1, 3, 0, 0, 0, 0, 0, 4, 1, 3, 0, 0, 0, 0,
+    0, 4, 1, 2, 0, 0, 0, 0, 0, 4, 1, 2, 1, 0, 0, 0, 0, 4, 1, 2, 0, 0, 0, 0, 0, 4, 1, 2, 0, 0, 0, 0,
+    0, 4, 1, 1, 0, 0, 0, 0, 0, 4, 1, 1, 2, 0, 0, 0, 0, 4, 1, 1, 1, 0, 0, 0, 0, 4, 1, 1, 1, 0, 0, 0,
+    0, 4, 1, 1, 0, 0, 0, 0, 0, 4, 1, 1, 0, 1, 0, 0, 0, 4, 1, 1, 0, 0, 0, 0, 0, 4, 1, 1, 0, 0, 0, 0,
+    0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 3, 0, 0, 0, 0, 4, 1, 0, 2, 0, 0, 0, 0, 4, 1, 0, 2, 0, 0, 0,
+    0, 4, 1, 0, 1, 0, 0, 0, 0, 4, 1, 0, 1, 1, 0, 0, 0, 4, 1, 0, 1, 0, 0, 0, 0, 4, 1, 0, 1, 0, 0, 0,
+    0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 2, 0, 0, 0, 4, 1, 0, 0, 1, 0, 0, 0, 4, 1, 0, 0, 1, 0, 0,
+    0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 1, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0,
+    0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 5, 0, 0, 0, 0, 0, 4, 0, 4, 0, 0, 0, 0, 0, 4, 0, 4, 0, 0, 0, 0,
+    0, 4, 0, 3, 0, 0, 0, 0, 0, 4, 0, 3, 1, 0, 0, 0, 0, 4, 0, 3, 0, 0, 0, 0, 0, 4, 0, 3, 0, 0, 0, 0,
+    0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 2, 0, 0, 0, 0, 4, 0, 2, 1, 0, 0, 0, 0, 4, 0, 2, 1, 0, 0, 0,
+    0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 0, 1, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0,
+    0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 3, 0, 0, 0, 0, 4, 0, 1, 2, 0, 0, 0, 0, 4, 0, 1, 2, 0, 0, 0,
+    0, 4, 0, 1, 1, 0, 0, 0, 0, 4, 0, 1, 1, 1, 0, 0, 0, 4, 0, 1, 1, 0, 0, 0, 0, 4, 0, 1, 1, 0, 0, 0,
+    0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 2, 0, 0, 0, 4, 0, 1, 0, 1, 0, 0, 0, 4, 0, 1, 0, 1, 0, 0,
+    0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 1, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0,
+    0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 0, 0, 0, 0, 4, 0, 0, 3, 0, 0, 0, 0, 4, 0, 0, 3, 0, 0, 0,
+    0, 4, 0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 2, 1, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0,
+    0, 4, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 1, 2, 0, 0, 0, 4, 0, 0, 1, 1, 0, 0, 0, 4, 0, 0, 1, 1, 0, 0,
+    0, 4, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 1, 0, 1, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0,
+    0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0,
+    0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 1, 1, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0,
+    0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0,
+    0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0,
+    0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 7, 0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0,
+    0, 3, 5, 0, 0, 0, 0, 0, 0, 3, 5, 1, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0,
+    0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 4, 2, 0, 0, 0, 0, 0, 3, 4, 1, 0, 0, 0, 0, 0, 3, 4, 1, 0, 0, 0, 0,
+    0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 4, 0, 1, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0,
+    0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 3, 3, 0, 0, 0, 0, 0, 3, 3, 2, 0, 0, 0, 0, 0, 3, 3, 2, 0, 0, 0, 0,
+    0, 3, 3, 1, 0, 0, 0, 0, 0, 3, 3, 1, 1, 0, 0, 0, 0, 3, 3, 1, 0, 0, 0, 0, 0, 3, 3, 1, 0, 0, 0, 0,
+    0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 3, 0, 2, 0, 0, 0, 0, 3, 3, 0, 1, 0, 0, 0, 0, 3, 3, 0, 1, 0, 0, 0,
+    0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 1, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0,
+    0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 4, 0, 0, 0, 0, 0, 3, 2, 3, 0, 0, 0, 0, 0, 3, 2, 3, 0, 0, 0, 0,
+    0, 3, 2, 2, 0, 0, 0, 0, 0, 3, 2, 2, 1, 0, 0, 0, 0, 3, 2, 2, 0, 0, 0, 0, 0, 3, 2, 2, 0, 0, 0, 0,
+    0, 3, 2, 1, 0, 0, 0, 0, 0, 3, 2, 1, 2, 0, 0, 0, 0, 3, 2, 1, 1, 0, 0, 0, 0, 3, 2, 1, 1, 0, 0, 0,
+    0, 3, 2, 1, 0, 0, 0, 0, 0, 3, 2, 1, 0, 1, 0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 0, 3, 2, 1, 0, 0, 0, 0,
+    0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 3, 0, 0, 0, 0, 3, 2, 0, 2, 0, 0, 0, 0, 3, 2, 0, 2, 0, 0, 0,
+    0, 3, 2, 0, 1, 0, 0, 0, 0, 3, 2, 0, 1, 1, 0, 0, 0, 3, 2, 0, 1, 0, 0, 0, 0, 3, 2, 0, 1, 0, 0, 0,
+    0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 2, 0, 0, 0, 3, 2, 0, 0, 1, 0, 0, 0, 3, 2, 0, 0, 1, 0, 0,
+    0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 1, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0,
+    0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 5, 0, 0, 0, 0, 0, 3, 1, 4, 0, 0, 0, 0, 0, 3, 1, 4, 0, 0, 0, 0,
+    0, 3, 1, 3, 0, 0, 0, 0, 0, 3, 1, 3, 1, 0, 0, 0, 0, 3, 1, 3, 0, 0, 0, 0, 0, 3, 1, 3, 0, 0, 0, 0,
+    0, 3, 1, 2, 0, 0, 0, 0, 0, 3, 1, 2, 2, 0, 0, 0, 0, 3, 1, 2, 1, 0, 0, 0, 0, 3, 1, 2, 1, 0, 0, 0,
+    0, 3, 1, 2, 0, 0, 0, 0, 0, 3, 1, 2, 0, 1, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0,
+    0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1, 3, 0, 0, 0, 0, 3, 1, 1, 2, 0, 0, 0, 0, 3, 1, 1, 2, 0, 0, 0,
+    0, 3, 1, 1, 1, 0, 0, 0, 0, 3, 1, 1, 1, 1, 0, 0, 0, 3, 1, 1, 1, 0, 0, 0, 0, 3, 1, 1, 1, 0, 0, 0,
+    0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1, 0, 2, 0, 0, 0, 3, 1, 1, 0, 1, 0, 0, 0, 3, 1, 1, 0, 1, 0, 0,
+    0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 1, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0,
+    0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 4, 0, 0, 0, 0, 3, 1, 0, 3, 0, 0, 0, 0, 3, 1, 0, 3, 0, 0, 0,
+    0, 3, 1, 0, 2, 0, 0, 0, 0, 3, 1, 0, 2, 1, 0, 0, 0, 3, 1, 0, 2, 0, 0, 0, 0, 3, 1, 0, 2, 0, 0, 0,
+    0, 3, 1, 0, 1, 0, 0, 0, 0, 3, 1, 0, 1, 2, 0, 0, 0, 3, 1, 0, 1, 1, 0, 0, 0, 3, 1, 0, 1, 1, 0, 0,
+    0, 3, 1, 0, 1, 0, 0, 0, 0, 3, 1, 0, 1, 0, 1, 0, 0, 3, 1, 0, 1, 0, 0, 0, 0, 3, 1, 0, 1, 0, 0, 0,
+    0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 3, 0, 0, 0, 3, 1, 0, 0, 2, 0, 0, 0, 3, 1, 0, 0, 2, 0, 0,
+    0, 3, 1, 0, 0, 1, 0, 0, 0, 3, 1, 0, 0, 1, 1, 0, 0, 3, 1, 0, 0, 1, 0, 0, 0, 3, 1, 0, 0, 1, 0, 0,
+    0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 2, 0, 0, 3, 1, 0, 0, 0, 1, 0, 0, 3, 1, 0, 0, 0, 1, 0,
+    0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0,
+    0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 6, 0, 0, 0, 0, 0, 3, 0, 5, 0, 0, 0, 0, 0, 3, 0, 5, 0, 0, 0, 0,
+    0, 3, 0, 4, 0, 0, 0, 0, 0, 3, 0, 4, 1, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0,
+    0, 3, 0, 3, 0, 0, 0, 0, 0, 3, 0, 3, 2, 0, 0, 0, 0, 3, 0, 3, 1, 0, 0, 0, 0, 3, 0, 3, 1, 0, 0, 0,
+    0, 3, 0, 3, 0, 0, 0, 0, 0, 3, 0, 3, 0, 1, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0,
+    0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 3, 0, 0, 0, 0, 3, 0, 2, 2, 0, 0, 0, 0, 3, 0, 2, 2, 0, 0, 0,
+    0, 3, 0, 2, 1, 0, 0, 0, 0, 3, 0, 2, 1, 1, 0, 0, 0, 3, 0, 2, 1, 0, 0, 0, 0, 3, 0, 2, 1, 0, 0, 0,
+    0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 0, 2, 0, 0, 0, 3, 0, 2, 0, 1, 0, 0, 0, 3, 0, 2, 0, 1, 0, 0,
+    0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 1, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0,
+    0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 4, 0, 0, 0, 0, 3, 0, 1, 3, 0, 0, 0, 0, 3, 0, 1, 3, 0, 0, 0,
+    0, 3, 0, 1, 2, 0, 0, 0, 0, 3, 0, 1, 2, 1, 0, 0, 0, 3, 0, 1, 2, 0, 0, 0, 0, 3, 0, 1, 2, 0, 0, 0,
+    0, 3, 0, 1, 1, 0, 0, 0, 0, 3, 0, 1, 1, 2, 0, 0, 0, 3, 0, 1, 1, 1, 0, 0, 0, 3, 0, 1, 1, 1, 0, 0,
+    0, 3, 0, 1, 1, 0, 0, 0, 0, 3, 0, 1, 1, 0, 1, 0, 0, 3, 0, 1, 1, 0, 0, 0, 0, 3, 0, 1, 1, 0, 0, 0,
+    0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 3, 0, 0, 0, 3, 0, 1, 0, 2, 0, 0, 0, 3, 0, 1, 0, 2, 0, 0,
+    0, 3, 0, 1, 0, 1, 0, 0, 0, 3, 0, 1, 0, 1, 1, 0, 0, 3, 0, 1, 0, 1, 0, 0, 0, 3, 0, 1, 0, 1, 0, 0,
+    0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 2, 0, 0, 3, 0, 1, 0, 0, 1, 0, 0, 3, 0, 1, 0, 0, 1, 0,
+    0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0,
+    0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 5, 0, 0, 0, 0, 3, 0, 0, 4, 0, 0, 0, 0, 3, 0, 0, 4, 0, 0, 0,
+    0, 3, 0, 0, 3, 0, 0, 0, 0, 3, 0, 0, 3, 1, 0, 0, 0, 3, 0, 0, 3, 0, 0, 0, 0, 3, 0, 0, 3, 0, 0, 0,
+    0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 2, 2, 0, 0, 0, 3, 0, 0, 2, 1, 0, 0, 0, 3, 0, 0, 2, 1, 0, 0,
+    0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 2, 0, 1, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0,
+    0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 3, 0, 0, 0, 3, 0, 0, 1, 2, 0, 0, 0, 3, 0, 0, 1, 2, 0, 0,
+    0, 3, 0, 0, 1, 1, 0, 0, 0, 3, 0, 0, 1, 1, 1, 0, 0, 3, 0, 0, 1, 1, 0, 0, 0, 3, 0, 0, 1, 1, 0, 0,
+    0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 0, 2, 0, 0, 3, 0, 0, 1, 0, 1, 0, 0, 3, 0, 0, 1, 0, 1, 0,
+    0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 1, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0,
+    0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0, 3, 0, 0, 0, 3, 0, 0, 0, 3, 0, 0, 0, 3, 0, 0,
+    0, 3, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 2, 1, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0,
+    0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 2, 0, 0, 3, 0, 0, 0, 1, 1, 0, 0, 3, 0, 0, 0, 1, 1, 0,
+    0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 0, 1, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0,
+    0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 3, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0,
+    0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0,
+    0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1,
+    0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 8, 0, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0,
+    0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 1, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0,
+    0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 2, 0, 0, 0, 0, 0, 2, 5, 1, 0, 0, 0, 0, 0, 2, 5, 1, 0, 0, 0, 0,
+    0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 1, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0,
+    0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 3, 0, 0, 0, 0, 0, 2, 4, 2, 0, 0, 0, 0, 0, 2, 4, 2, 0, 0, 0, 0,
+    0, 2, 4, 1, 0, 0, 0, 0, 0, 2, 4, 1, 1, 0, 0, 0, 0, 2, 4, 1, 0, 0, 0, 0, 0, 2, 4, 1, 0, 0, 0, 0,
+    0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 2, 0, 0, 0, 0, 2, 4, 0, 1, 0, 0, 0, 0, 2, 4, 0, 1, 0, 0, 0,
+    0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 1, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0,
+    0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 4, 0, 0, 0, 0, 0, 2, 3, 3, 0, 0, 0, 0, 0, 2, 3, 3, 0, 0, 0, 0,
+    0, 2, 3, 2, 0, 0, 0, 0, 0, 2, 3, 2, 1, 0, 0, 0, 0, 2, 3, 2, 0, 0, 0, 0, 0, 2, 3, 2, 0, 0, 0, 0,
+    0, 2, 3, 1, 0, 0, 0, 0, 0, 2, 3, 1, 2, 0, 0, 0, 0, 2, 3, 1, 1, 0, 0, 0, 0, 2, 3, 1, 1, 0, 0, 0,
+    0, 2, 3, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 1, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0,
+    0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 3, 0, 0, 0, 0, 2, 3, 0, 2, 0, 0, 0, 0, 2, 3, 0, 2, 0, 0, 0,
+    0, 2, 3, 0, 1, 0, 0, 0, 0, 2, 3, 0, 1, 1, 0, 0, 0, 2, 3, 0, 1, 0, 0, 0, 0, 2, 3, 0, 1, 0, 0, 0,
+    0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 2, 0, 0, 0, 2, 3, 0, 0, 1, 0, 0, 0, 2, 3, 0, 0, 1, 0, 0,
+    0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 1, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0,
+    0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 5, 0, 0, 0, 0, 0, 2, 2, 4, 0, 0, 0, 0, 0, 2, 2, 4, 0, 0, 0, 0,
+    0, 2, 2, 3, 0, 0, 0, 0, 0, 2, 2, 3, 1, 0, 0, 0, 0, 2, 2, 3, 0, 0, 0, 0, 0, 2, 2, 3, 0, 0, 0, 0,
+    0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 2, 0, 0, 0, 0, 2, 2, 2, 1, 0, 0, 0, 0, 2, 2, 2, 1, 0, 0, 0,
+    0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 1, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0,
+    0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 3, 0, 0, 0, 0, 2, 2, 1, 2, 0, 0, 0, 0, 2, 2, 1, 2, 0, 0, 0,
+    0, 2, 2, 1, 1, 0, 0, 0, 0, 2, 2, 1, 1, 1, 0, 0, 0, 2, 2, 1, 1, 0, 0, 0, 0, 2, 2, 1, 1, 0, 0, 0,
+    0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 2, 0, 0, 0, 2, 2, 1, 0, 1, 0, 0, 0, 2, 2, 1, 0, 1, 0, 0,
+    0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 1, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0,
+    0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 4, 0, 0, 0, 0, 2, 2, 0, 3, 0, 0, 0, 0, 2, 2, 0, 3, 0, 0, 0,
+    0, 2, 2, 0, 2, 0, 0, 0, 0, 2, 2, 0, 2, 1, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0,
+    0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1, 2, 0, 0, 0, 2, 2, 0, 1, 1, 0, 0, 0, 2, 2, 0, 1, 1, 0, 0,
+    0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1, 0, 1, 0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0,
+    0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 3, 0, 0, 0, 2, 2, 0, 0, 2, 0, 0, 0, 2, 2, 0, 0, 2, 0, 0,
+    0, 2, 2, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 1, 1, 0, 0, 2, 2, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 1, 0, 0,
+    0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 2, 0, 0, 2, 2, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 1, 0,
+    0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 6, 0, 0, 0, 0, 0, 2, 1, 5, 0, 0, 0, 0, 0, 2, 1, 5, 0, 0, 0, 0,
+    0, 2, 1, 4, 0, 0, 0, 0, 0, 2, 1, 4, 1, 0, 0, 0, 0, 2, 1, 4, 0, 0, 0, 0, 0, 2, 1, 4, 0, 0, 0, 0,
+    0, 2, 1, 3, 0, 0, 0, 0, 0, 2, 1, 3, 2, 0, 0, 0, 0, 2, 1, 3, 1, 0, 0, 0, 0, 2, 1, 3, 1, 0, 0, 0,
+    0, 2, 1, 3, 0, 0, 0, 0, 0, 2, 1, 3, 0, 1, 0, 0, 0, 2, 1, 3, 0, 0, 0, 0, 0, 2, 1, 3, 0, 0, 0, 0,
+    0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 3, 0, 0, 0, 0, 2, 1, 2, 2, 0, 0, 0, 0, 2, 1, 2, 2, 0, 0, 0,
+    0, 2, 1, 2, 1, 0, 0, 0, 0, 2, 1, 2, 1, 1, 0, 0, 0, 2, 1, 2, 1, 0, 0, 0, 0, 2, 1, 2, 1, 0, 0, 0,
+    0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 2, 0, 0, 0, 2, 1, 2, 0, 1, 0, 0, 0, 2, 1, 2, 0, 1, 0, 0,
+    0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 1, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0,
+    0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 4, 0, 0, 0, 0, 2, 1, 1, 3, 0, 0, 0, 0, 2, 1, 1, 3, 0, 0, 0,
+    0, 2, 1, 1, 2, 0, 0, 0, 0, 2, 1, 1, 2, 1, 0, 0, 0, 2, 1, 1, 2, 0, 0, 0, 0, 2, 1, 1, 2, 0, 0, 0,
+    0, 2, 1, 1, 1, 0, 0, 0, 0, 2, 1, 1, 1, 2, 0, 0, 0, 2, 1, 1, 1, 1, 0, 0, 0, 2, 1, 1, 1, 1, 0, 0,
+    0, 2, 1, 1, 1, 0, 0, 0, 0, 2, 1, 1, 1, 0, 1, 0, 0, 2, 1, 1, 1, 0, 0, 0, 0, 2, 1, 1, 1, 0, 0, 0,
+    0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 3, 0, 0, 0, 2, 1, 1, 0, 2, 0, 0, 0, 2, 1, 1, 0, 2, 0, 0,
+    0, 2, 1, 1, 0, 1, 0, 0, 0, 2, 1, 1, 0, 1, 1, 0, 0, 2, 1, 1, 0, 1, 0, 0, 0, 2, 1, 1, 0, 1, 0, 0,
+    0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 2, 0, 0, 2, 1, 1, 0, 0, 1, 0, 0, 2, 1, 1, 0, 0, 1, 0,
+    0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 1, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 5, 0, 0, 0, 0, 2, 1, 0, 4, 0, 0, 0, 0, 2, 1, 0, 4, 0, 0, 0,
+    0, 2, 1, 0, 3, 0, 0, 0, 0, 2, 1, 0, 3, 1, 0, 0, 0, 2, 1, 0, 3, 0, 0, 0, 0, 2, 1, 0, 3, 0, 0, 0,
+    0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 2, 2, 0, 0, 0, 2, 1, 0, 2, 1, 0, 0, 0, 2, 1, 0, 2, 1, 0, 0,
+    0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 2, 0, 1, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0,
+    0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 3, 0, 0, 0, 2, 1, 0, 1, 2, 0, 0, 0, 2, 1, 0, 1, 2, 0, 0,
+    0, 2, 1, 0, 1, 1, 0, 0, 0, 2, 1, 0, 1, 1, 1, 0, 0, 2, 1, 0, 1, 1, 0, 0, 0, 2, 1, 0, 1, 1, 0, 0,
+    0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 2, 0, 0, 2, 1, 0, 1, 0, 1, 0, 0, 2, 1, 0, 1, 0, 1, 0,
+    0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 1, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 4, 0, 0, 0, 2, 1, 0, 0, 3, 0, 0, 0, 2, 1, 0, 0, 3, 0, 0,
+    0, 2, 1, 0, 0, 2, 0, 0, 0, 2, 1, 0, 0, 2, 1, 0, 0, 2, 1, 0, 0, 2, 0, 0, 0, 2, 1, 0, 0, 2, 0, 0,
+    0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 1, 2, 0, 0, 2, 1, 0, 0, 1, 1, 0, 0, 2, 1, 0, 0, 1, 1, 0,
+    0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 1, 0, 1, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 3, 0, 0, 2, 1, 0, 0, 0, 2, 0, 0, 2, 1, 0, 0, 0, 2, 0,
+    0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 1, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 7, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0,
+    0, 2, 0, 5, 0, 0, 0, 0, 0, 2, 0, 5, 1, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0,
+    0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 2, 0, 0, 0, 0, 2, 0, 4, 1, 0, 0, 0, 0, 2, 0, 4, 1, 0, 0, 0,
+    0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 1, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0,
+    0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 3, 0, 0, 0, 0, 2, 0, 3, 2, 0, 0, 0, 0, 2, 0, 3, 2, 0, 0, 0,
+    0, 2, 0, 3, 1, 0, 0, 0, 0, 2, 0, 3, 1, 1, 0, 0, 0, 2, 0, 3, 1, 0, 0, 0, 0, 2, 0, 3, 1, 0, 0, 0,
+    0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 2, 0, 0, 0, 2, 0, 3, 0, 1, 0, 0, 0, 2, 0, 3, 0, 1, 0, 0,
+    0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 1, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0,
+    0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 4, 0, 0, 0, 0, 2, 0, 2, 3, 0, 0, 0, 0, 2, 0, 2, 3, 0, 0, 0,
+    0, 2, 0, 2, 2, 0, 0, 0, 0, 2, 0, 2, 2, 1, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0,
+    0, 2, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1, 2, 0, 0, 0, 2, 0, 2, 1, 1, 0, 0, 0, 2, 0, 2, 1, 1, 0, 0,
+    0, 2, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1, 0, 1, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0,
+    0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 3, 0, 0, 0, 2, 0, 2, 0, 2, 0, 0, 0, 2, 0, 2, 0, 2, 0, 0,
+    0, 2, 0, 2, 0, 1, 0, 0, 0, 2, 0, 2, 0, 1, 1, 0, 0, 2, 0, 2, 0, 1, 0, 0, 0, 2, 0, 2, 0, 1, 0, 0,
+    0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 2, 0, 0, 2, 0, 2, 0, 0, 1, 0, 0, 2, 0, 2, 0, 0, 1, 0,
+    0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0,
+    0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 5, 0, 0, 0, 0, 2, 0, 1, 4, 0, 0, 0, 0, 2, 0, 1, 4, 0, 0, 0,
+    0, 2, 0, 1, 3, 0, 0, 0, 0, 2, 0, 1, 3, 1, 0, 0, 0, 2, 0, 1, 3, 0, 0, 0, 0, 2, 0, 1, 3, 0, 0, 0,
+    0, 2, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2, 2, 0, 0, 0, 2, 0, 1, 2, 1, 0, 0, 0, 2, 0, 1, 2, 1, 0, 0,
+    0, 2, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2, 0, 1, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0,
+    0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 3, 0, 0, 0, 2, 0, 1, 1, 2, 0, 0, 0, 2, 0, 1, 1, 2, 0, 0,
+    0, 2, 0, 1, 1, 1, 0, 0, 0, 2, 0, 1, 1, 1, 1, 0, 0, 2, 0, 1, 1, 1, 0, 0, 0, 2, 0, 1, 1, 1, 0, 0,
+    0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 2, 0, 0, 2, 0, 1, 1, 0, 1, 0, 0, 2, 0, 1, 1, 0, 1, 0,
+    0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 1, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0,
+    0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 4, 0, 0, 0, 2, 0, 1, 0, 3, 0, 0, 0, 2, 0, 1, 0, 3, 0, 0,
+    0, 2, 0, 1, 0, 2, 0, 0, 0, 2, 0, 1, 0, 2, 1, 0, 0, 2, 0, 1, 0, 2, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0,
+    0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0, 1, 2, 0, 0, 2, 0, 1, 0, 1, 1, 0, 0, 2, 0, 1, 0, 1, 1, 0,
+    0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0, 1, 0, 1, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0,
+    0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 3, 0, 0, 2, 0, 1, 0, 0, 2, 0, 0, 2, 0, 1, 0, 0, 2, 0,
+    0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 1, 1, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 1, 0,
+    0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 2, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 1,
+    0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 6, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0,
+    0, 2, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 4, 1, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0,
+    0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 2, 0, 0, 0, 2, 0, 0, 3, 1, 0, 0, 0, 2, 0, 0, 3, 1, 0, 0,
+    0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 0, 1, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0,
+    0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 3, 0, 0, 0, 2, 0, 0, 2, 2, 0, 0, 0, 2, 0, 0, 2, 2, 0, 0,
+    0, 2, 0, 0, 2, 1, 0, 0, 0, 2, 0, 0, 2, 1, 1, 0, 0, 2, 0, 0, 2, 1, 0, 0, 0, 2, 0, 0, 2, 1, 0, 0,
+    0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 2, 0, 0, 2, 0, 0, 2, 0, 1, 0, 0, 2, 0, 0, 2, 0, 1, 0,
+    0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0,
+    0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 4, 0, 0, 0, 2, 0, 0, 1, 3, 0, 0, 0, 2, 0, 0, 1, 3, 0, 0,
+    0, 2, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 1, 2, 1, 0, 0, 2, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0,
+    0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1, 2, 0, 0, 2, 0, 0, 1, 1, 1, 0, 0, 2, 0, 0, 1, 1, 1, 0,
+    0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1, 0, 1, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0,
+    0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 3, 0, 0, 2, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 1, 0, 2, 0,
+    0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1, 0, 1, 1, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1, 0, 1, 0,
+    0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 2, 0, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 1,
+    0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 5, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0,
+    0, 2, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 3, 1, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0,
+    0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 2, 0, 0, 2, 0, 0, 0, 2, 1, 0, 0, 2, 0, 0, 0, 2, 1, 0,
+    0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0,
+    0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 3, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 1, 2, 0,
+    0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 1, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0,
+    0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1,
+    0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0,
+    0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0,
+    0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1,
+    0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2,
+    0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0,
+    1, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0,
+    0, 1, 7, 0, 0, 0, 0, 0, 0, 1, 7, 1, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0,
+    0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 2, 0, 0, 0, 0, 0, 1, 6, 1, 0, 0, 0, 0, 0, 1, 6, 1, 0, 0, 0, 0,
+    0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 1, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0,
+    0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 3, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0, 0, 0,
+    0, 1, 5, 1, 0, 0, 0, 0, 0, 1, 5, 1, 1, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0,
+    0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 2, 0, 0, 0, 0, 1, 5, 0, 1, 0, 0, 0, 0, 1, 5, 0, 1, 0, 0, 0,
+    0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 1, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0,
+    0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 4, 0, 0, 0, 0, 0, 1, 4, 3, 0, 0, 0, 0, 0, 1, 4, 3, 0, 0, 0, 0,
+    0, 1, 4, 2, 0, 0, 0, 0, 0, 1, 4, 2, 1, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0, 0,
+    0, 1, 4, 1, 0, 0, 0, 0, 0, 1, 4, 1, 2, 0, 0, 0, 0, 1, 4, 1, 1, 0, 0, 0, 0, 1, 4, 1, 1, 0, 0, 0,
+    0, 1, 4, 1, 0, 0, 0, 0, 0, 1, 4, 1, 0, 1, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0,
+    0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 3, 0, 0, 0, 0, 1, 4, 0, 2, 0, 0, 0, 0, 1, 4, 0, 2, 0, 0, 0,
+    0, 1, 4, 0, 1, 0, 0, 0, 0, 1, 4, 0, 1, 1, 0, 0, 0, 1, 4, 0, 1, 0, 0, 0, 0, 1, 4, 0, 1, 0, 0, 0,
+    0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 2, 0, 0, 0, 1, 4, 0, 0, 1, 0, 0, 0, 1, 4, 0, 0, 1, 0, 0,
+    0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 1, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0,
+    0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 5, 0, 0, 0, 0, 0, 1, 3, 4, 0, 0, 0, 0, 0, 1, 3, 4, 0, 0, 0, 0,
+    0, 1, 3, 3, 0, 0, 0, 0, 0, 1, 3, 3, 1, 0, 0, 0, 0, 1, 3, 3, 0, 0, 0, 0, 0, 1, 3, 3, 0, 0, 0, 0,
+    0, 1, 3, 2, 0, 0, 0, 0, 0, 1, 3, 2, 2, 0, 0, 0, 0, 1, 3, 2, 1, 0, 0, 0, 0, 1, 3, 2, 1, 0, 0, 0,
+    0, 1, 3, 2, 0, 0, 0, 0, 0, 1, 3, 2, 0, 1, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0,
+    0, 1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 1, 3, 0, 0, 0, 0, 1, 3, 1, 2, 0, 0, 0, 0, 1, 3, 1, 2, 0, 0, 0,
+    0, 1, 3, 1, 1, 0, 0, 0, 0, 1, 3, 1, 1, 1, 0, 0, 0, 1, 3, 1, 1, 0, 0, 0, 0, 1, 3, 1, 1, 0, 0, 0,
+    0, 1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 1, 0, 2, 0, 0, 0, 1, 3, 1, 0, 1, 0, 0, 0, 1, 3, 1, 0, 1, 0, 0,
+    0, 1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 1, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0,
+    0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 4, 0, 0, 0, 0, 1, 3, 0, 3, 0, 0, 0, 0, 1, 3, 0, 3, 0, 0, 0,
+    0, 1, 3, 0, 2, 0, 0, 0, 0, 1, 3, 0, 2, 1, 0, 0, 0, 1, 3, 0, 2, 0, 0, 0, 0, 1, 3, 0, 2, 0, 0, 0,
+    0, 1, 3, 0, 1, 0, 0, 0, 0, 1, 3, 0, 1, 2, 0, 0, 0, 1, 3, 0, 1, 1, 0, 0, 0, 1, 3, 0, 1, 1, 0, 0,
+    0, 1, 3, 0, 1, 0, 0, 0, 0, 1, 3, 0, 1, 0, 1, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0,
+    0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 3, 0, 0, 0, 1, 3, 0, 0, 2, 0, 0, 0, 1, 3, 0, 0, 2, 0, 0,
+    0, 1, 3, 0, 0, 1, 0, 0, 0, 1, 3, 0, 0, 1, 1, 0, 0, 1, 3, 0, 0, 1, 0, 0, 0, 1, 3, 0, 0, 1, 0, 0,
+    0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 2, 0, 0, 1, 3, 0, 0, 0, 1, 0, 0, 1, 3, 0, 0, 0, 1, 0,
+    0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0,
+    0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 6, 0, 0, 0, 0, 0, 1, 2, 5, 0, 0, 0, 0, 0, 1, 2, 5, 0, 0, 0, 0,
+    0, 1, 2, 4, 0, 0, 0, 0, 0, 1, 2, 4, 1, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0,
+    0, 1, 2, 3, 0, 0, 0, 0, 0, 1, 2, 3, 2, 0, 0, 0, 0, 1, 2, 3, 1, 0, 0, 0, 0, 1, 2, 3, 1, 0, 0, 0,
+    0, 1, 2, 3, 0, 0, 0, 0, 0, 1, 2, 3, 0, 1, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0,
+    0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 3, 0, 0, 0, 0, 1, 2, 2, 2, 0, 0, 0, 0, 1, 2, 2, 2, 0, 0, 0,
+    0, 1, 2, 2, 1, 0, 0, 0, 0, 1, 2, 2, 1, 1, 0, 0, 0, 1, 2, 2, 1, 0, 0, 0, 0, 1, 2, 2, 1, 0, 0, 0,
+    0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 2, 0, 0, 0, 1, 2, 2, 0, 1, 0, 0, 0, 1, 2, 2, 0, 1, 0, 0,
+    0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 1, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0,
+    0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 4, 0, 0, 0, 0, 1, 2, 1, 3, 0, 0, 0, 0, 1, 2, 1, 3, 0, 0, 0,
+    0, 1, 2, 1, 2, 0, 0, 0, 0, 1, 2, 1, 2, 1, 0, 0, 0, 1, 2, 1, 2, 0, 0, 0, 0, 1, 2, 1, 2, 0, 0, 0,
+    0, 1, 2, 1, 1, 0, 0, 0, 0, 1, 2, 1, 1, 2, 0, 0, 0, 1, 2, 1, 1, 1, 0, 0, 0, 1, 2, 1, 1, 1, 0, 0,
+    0, 1, 2, 1, 1, 0, 0, 0, 0, 1, 2, 1, 1, 0, 1, 0, 0, 1, 2, 1, 1, 0, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0,
+    0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 3, 0, 0, 0, 1, 2, 1, 0, 2, 0, 0, 0, 1, 2, 1, 0, 2, 0, 0,
+    0, 1, 2, 1, 0, 1, 0, 0, 0, 1, 2, 1, 0, 1, 1, 0, 0, 1, 2, 1, 0, 1, 0, 0, 0, 1, 2, 1, 0, 1, 0, 0,
+    0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 2, 0, 0, 1, 2, 1, 0, 0, 1, 0, 0, 1, 2, 1, 0, 0, 1, 0,
+    0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 1, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0,
+    0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 5, 0, 0, 0, 0, 1, 2, 0, 4, 0, 0, 0, 0, 1, 2, 0, 4, 0, 0, 0,
+    0, 1, 2, 0, 3, 0, 0, 0, 0, 1, 2, 0, 3, 1, 0, 0, 0, 1, 2, 0, 3, 0, 0, 0, 0, 1, 2, 0, 3, 0, 0, 0,
+    0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 2, 0, 0, 0, 1, 2, 0, 2, 1, 0, 0, 0, 1, 2, 0, 2, 1, 0, 0,
+    0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0, 1, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0,
+    0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 3, 0, 0, 0, 1, 2, 0, 1, 2, 0, 0, 0, 1, 2, 0, 1, 2, 0, 0,
+    0, 1, 2, 0, 1, 1, 0, 0, 0, 1, 2, 0, 1, 1, 1, 0, 0, 1, 2, 0, 1, 1, 0, 0, 0, 1, 2, 0, 1, 1, 0, 0,
+    0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 2, 0, 0, 1, 2, 0, 1, 0, 1, 0, 0, 1, 2, 0, 1, 0, 1, 0,
+    0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 1, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0,
+    0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 4, 0, 0, 0, 1, 2, 0, 0, 3, 0, 0, 0, 1, 2, 0, 0, 3, 0, 0,
+    0, 1, 2, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 2, 1, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0,
+    0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 2, 0, 0, 1, 2, 0, 0, 1, 1, 0, 0, 1, 2, 0, 0, 1, 1, 0,
+    0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 1, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0,
+    0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 3, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 0, 2, 0,
+    0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 1, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0,
+    0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1,
+    0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0,
+    0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 7, 0, 0, 0, 0, 0, 1, 1, 6, 0, 0, 0, 0, 0, 1, 1, 6, 0, 0, 0, 0,
+    0, 1, 1, 5, 0, 0, 0, 0, 0, 1, 1, 5, 1, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0,
+    0, 1, 1, 4, 0, 0, 0, 0, 0, 1, 1, 4, 2, 0, 0, 0, 0, 1, 1, 4, 1, 0, 0, 0, 0, 1, 1, 4, 1, 0, 0, 0,
+    0, 1, 1, 4, 0, 0, 0, 0, 0, 1, 1, 4, 0, 1, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0,
+    0, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 3, 3, 0, 0, 0, 0, 1, 1, 3, 2, 0, 0, 0, 0, 1, 1, 3, 2, 0, 0, 0,
+    0, 1, 1, 3, 1, 0, 0, 0, 0, 1, 1, 3, 1, 1, 0, 0, 0, 1, 1, 3, 1, 0, 0, 0, 0, 1, 1, 3, 1, 0, 0, 0,
+    0, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 3, 0, 2, 0, 0, 0, 1, 1, 3, 0, 1, 0, 0, 0, 1, 1, 3, 0, 1, 0, 0,
+    0, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 1, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0,
+    0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 4, 0, 0, 0, 0, 1, 1, 2, 3, 0, 0, 0, 0, 1, 1, 2, 3, 0, 0, 0,
+    0, 1, 1, 2, 2, 0, 0, 0, 0, 1, 1, 2, 2, 1, 0, 0, 0, 1, 1, 2, 2, 0, 0, 0, 0, 1, 1, 2, 2, 0, 0, 0,
+    0, 1, 1, 2, 1, 0, 0, 0, 0, 1, 1, 2, 1, 2, 0, 0, 0, 1, 1, 2, 1, 1, 0, 0, 0, 1, 1, 2, 1, 1, 0, 0,
+    0, 1, 1, 2, 1, 0, 0, 0, 0, 1, 1, 2, 1, 0, 1, 0, 0, 1, 1, 2, 1, 0, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0,
+    0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 3, 0, 0, 0, 1, 1, 2, 0, 2, 0, 0, 0, 1, 1, 2, 0, 2, 0, 0,
+    0, 1, 1, 2, 0, 1, 0, 0, 0, 1, 1, 2, 0, 1, 1, 0, 0, 1, 1, 2, 0, 1, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0,
+    0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 2, 0, 0, 1, 1, 2, 0, 0, 1, 0, 0, 1, 1, 2, 0, 0, 1, 0,
+    0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 1, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0,
+    0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 5, 0, 0, 0, 0, 1, 1, 1, 4, 0, 0, 0, 0, 1, 1, 1, 4, 0, 0, 0,
+    0, 1, 1, 1, 3, 0, 0, 0, 0, 1, 1, 1, 3, 1, 0, 0, 0, 1, 1, 1, 3, 0, 0, 0, 0, 1, 1, 1, 3, 0, 0, 0,
+    0, 1, 1, 1, 2, 0, 0, 0, 0, 1, 1, 1, 2, 2, 0, 0, 0, 1, 1, 1, 2, 1, 0, 0, 0, 1, 1, 1, 2, 1, 0, 0,
+    0, 1, 1, 1, 2, 0, 0, 0, 0, 1, 1, 1, 2, 0, 1, 0, 0, 1, 1, 1, 2, 0, 0, 0, 0, 1, 1, 1, 2, 0, 0, 0,
+    0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 3, 0, 0, 0, 1, 1, 1, 1, 2, 0, 0, 0, 1, 1, 1, 1, 2, 0, 0,
+    0, 1, 1, 1, 1, 1, 0, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0,
+    0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 2, 0, 0, 1, 1, 1, 1, 0, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0,
+    0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0,
+    0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 4, 0, 0, 0, 1, 1, 1, 0, 3, 0, 0, 0, 1, 1, 1, 0, 3, 0, 0,
+    0, 1, 1, 1, 0, 2, 0, 0, 0, 1, 1, 1, 0, 2, 1, 0, 0, 1, 1, 1, 0, 2, 0, 0, 0, 1, 1, 1, 0, 2, 0, 0,
+    0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 2, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0, 1, 1, 0,
+    0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 1, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0,
+    0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 3, 0, 0, 1, 1, 1, 0, 0, 2, 0, 0, 1, 1, 1, 0, 0, 2, 0,
+    0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 1, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0,
+    0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 2, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1,
+    0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0,
+    0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 6, 0, 0, 0, 0, 1, 1, 0, 5, 0, 0, 0, 0, 1, 1, 0, 5, 0, 0, 0,
+    0, 1, 1, 0, 4, 0, 0, 0, 0, 1, 1, 0, 4, 1, 0, 0, 0, 1, 1, 0, 4, 0, 0, 0, 0, 1, 1, 0, 4, 0, 0, 0,
+    0, 1, 1, 0, 3, 0, 0, 0, 0, 1, 1, 0, 3, 2, 0, 0, 0, 1, 1, 0, 3, 1, 0, 0, 0, 1, 1, 0, 3, 1, 0, 0,
+    0, 1, 1, 0, 3, 0, 0, 0, 0, 1, 1, 0, 3, 0, 1, 0, 0, 1, 1, 0, 3, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0,
+    0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 3, 0, 0, 0, 1, 1, 0, 2, 2, 0, 0, 0, 1, 1, 0, 2, 2, 0, 0,
+    0, 1, 1, 0, 2, 1, 0, 0, 0, 1, 1, 0, 2, 1, 1, 0, 0, 1, 1, 0, 2, 1, 0, 0, 0, 1, 1, 0, 2, 1, 0, 0,
+    0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 2, 0, 0, 1, 1, 0, 2, 0, 1, 0, 0, 1, 1, 0, 2, 0, 1, 0,
+    0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 1, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0,
+    0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 4, 0, 0, 0, 1, 1, 0, 1, 3, 0, 0, 0, 1, 1, 0, 1, 3, 0, 0,
+    0, 1, 1, 0, 1, 2, 0, 0, 0, 1, 1, 0, 1, 2, 1, 0, 0, 1, 1, 0, 1, 2, 0, 0, 0, 1, 1, 0, 1, 2, 0, 0,
+    0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 2, 0, 0, 1, 1, 0, 1, 1, 1, 0, 0, 1, 1, 0, 1, 1, 1, 0,
+    0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 1, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0,
+    0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 3, 0, 0, 1, 1, 0, 1, 0, 2, 0, 0, 1, 1, 0, 1, 0, 2, 0,
+    0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 1, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0,
+    0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 2, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1,
+    0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0,
+    0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 5, 0, 0, 0, 1, 1, 0, 0, 4, 0, 0, 0, 1, 1, 0, 0, 4, 0, 0,
+    0, 1, 1, 0, 0, 3, 0, 0, 0, 1, 1, 0, 0, 3, 1, 0, 0, 1, 1, 0, 0, 3, 0, 0, 0, 1, 1, 0, 0, 3, 0, 0,
+    0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 2, 0, 0, 1, 1, 0, 0, 2, 1, 0, 0, 1, 1, 0, 0, 2, 1, 0,
+    0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0,
+    0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 3, 0, 0, 1, 1, 0, 0, 1, 2, 0, 0, 1, 1, 0, 0, 1, 2, 0,
+    0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0,
+    0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 2, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1,
+    0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0,
+    0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 4, 0, 0, 1, 1, 0, 0, 0, 3, 0, 0, 1, 1, 0, 0, 0, 3, 0,
+    0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 1, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0,
+    0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 2, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1,
+    0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0,
+    0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 3, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2,
+    0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1,
+    0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0,
+    1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 8, 0, 0, 0, 0, 0, 1, 0, 7, 0, 0, 0, 0, 0, 1, 0, 7, 0, 0, 0, 0,
+    0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 0, 6, 1, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0,
+    0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 5, 2, 0, 0, 0, 0, 1, 0, 5, 1, 0, 0, 0, 0, 1, 0, 5, 1, 0, 0, 0,
+    0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0, 1, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0,
+    0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 3, 0, 0, 0, 0, 1, 0, 4, 2, 0, 0, 0, 0, 1, 0, 4, 2, 0, 0, 0,
+    0, 1, 0, 4, 1, 0, 0, 0, 0, 1, 0, 4, 1, 1, 0, 0, 0, 1, 0, 4, 1, 0, 0, 0, 0, 1, 0, 4, 1, 0, 0, 0,
+    0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 2, 0, 0, 0, 1, 0, 4, 0, 1, 0, 0, 0, 1, 0, 4, 0, 1, 0, 0,
+    0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 1, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0,
+    0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 4, 0, 0, 0, 0, 1, 0, 3, 3, 0, 0, 0, 0, 1, 0, 3, 3, 0, 0, 0,
+    0, 1, 0, 3, 2, 0, 0, 0, 0, 1, 0, 3, 2, 1, 0, 0, 0, 1, 0, 3, 2, 0, 0, 0, 0, 1, 0, 3, 2, 0, 0, 0,
+    0, 1, 0, 3, 1, 0, 0, 0, 0, 1, 0, 3, 1, 2, 0, 0, 0, 1, 0, 3, 1, 1, 0, 0, 0, 1, 0, 3, 1, 1, 0, 0,
+    0, 1, 0, 3, 1, 0, 0, 0, 0, 1, 0, 3, 1, 0, 1, 0, 0, 1, 0, 3, 1, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0,
+    0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 3, 0, 0, 0, 1, 0, 3, 0, 2, 0, 0, 0, 1, 0, 3, 0, 2, 0, 0,
+    0, 1, 0, 3, 0, 1, 0, 0, 0, 1, 0, 3, 0, 1, 1, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 1, 0, 3, 0, 1, 0, 0,
+    0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 2, 0, 0, 1, 0, 3, 0, 0, 1, 0, 0, 1, 0, 3, 0, 0, 1, 0,
+    0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 1, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0,
+    0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 5, 0, 0, 0, 0, 1, 0, 2, 4, 0, 0, 0, 0, 1, 0, 2, 4, 0, 0, 0,
+    0, 1, 0, 2, 3, 0, 0, 0, 0, 1, 0, 2, 3, 1, 0, 0, 0, 1, 0, 2, 3, 0, 0, 0, 0, 1, 0, 2, 3, 0, 0, 0,
+    0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 2, 0, 0, 0, 1, 0, 2, 2, 1, 0, 0, 0, 1, 0, 2, 2, 1, 0, 0,
+    0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 1, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0,
+    0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 3, 0, 0, 0, 1, 0, 2, 1, 2, 0, 0, 0, 1, 0, 2, 1, 2, 0, 0,
+    0, 1, 0, 2, 1, 1, 0, 0, 0, 1, 0, 2, 1, 1, 1, 0, 0, 1, 0, 2, 1, 1, 0, 0, 0, 1, 0, 2, 1, 1, 0, 0,
+    0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 2, 0, 0, 1, 0, 2, 1, 0, 1, 0, 0, 1, 0, 2, 1, 0, 1, 0,
+    0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 1, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0,
+    0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 4, 0, 0, 0, 1, 0, 2, 0, 3, 0, 0, 0, 1, 0, 2, 0, 3, 0, 0,
+    0, 1, 0, 2, 0, 2, 0, 0, 0, 1, 0, 2, 0, 2, 1, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0,
+    0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 2, 0, 0, 1, 0, 2, 0, 1, 1, 0, 0, 1, 0, 2, 0, 1, 1, 0,
+    0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 0, 1, 0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0,
+    0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 3, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 1, 0, 2, 0, 0, 2, 0,
+    0, 1, 0, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 1, 0, 1, 0, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 0,
+    0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1,
+    0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0,
+    0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 6, 0, 0, 0, 0, 1, 0, 1, 5, 0, 0, 0, 0, 1, 0, 1, 5, 0, 0, 0,
+    0, 1, 0, 1, 4, 0, 0, 0, 0, 1, 0, 1, 4, 1, 0, 0, 0, 1, 0, 1, 4, 0, 0, 0, 0, 1, 0, 1, 4, 0, 0, 0,
+    0, 1, 0, 1, 3, 0, 0, 0, 0, 1, 0, 1, 3, 2, 0, 0, 0, 1, 0, 1, 3, 1, 0, 0, 0, 1, 0, 1, 3, 1, 0, 0,
+    0, 1, 0, 1, 3, 0, 0, 0, 0, 1, 0, 1, 3, 0, 1, 0, 0, 1, 0, 1, 3, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0,
+    0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 3, 0, 0, 0, 1, 0, 1, 2, 2, 0, 0, 0, 1, 0, 1, 2, 2, 0, 0,
+    0, 1, 0, 1, 2, 1, 0, 0, 0, 1, 0, 1, 2, 1, 1, 0, 0, 1, 0, 1, 2, 1, 0, 0, 0, 1, 0, 1, 2, 1, 0, 0,
+    0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 2, 0, 0, 1, 0, 1, 2, 0, 1, 0, 0, 1, 0, 1, 2, 0, 1, 0,
+    0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 1, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0,
+    0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 4, 0, 0, 0, 1, 0, 1, 1, 3, 0, 0, 0, 1, 0, 1, 1, 3, 0, 0,
+    0, 1, 0, 1, 1, 2, 0, 0, 0, 1, 0, 1, 1, 2, 1, 0, 0, 1, 0, 1, 1, 2, 0, 0, 0, 1, 0, 1, 1, 2, 0, 0,
+    0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 2, 0, 0, 1, 0, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1, 0,
+    0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 1, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0,
+    0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 3, 0, 0, 1, 0, 1, 1, 0, 2, 0, 0, 1, 0, 1, 1, 0, 2, 0,
+    0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 1, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0,
+    0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 2, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1,
+    0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0,
+    0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 5, 0, 0, 0, 1, 0, 1, 0, 4, 0, 0, 0, 1, 0, 1, 0, 4, 0, 0,
+    0, 1, 0, 1, 0, 3, 0, 0, 0, 1, 0, 1, 0, 3, 1, 0, 0, 1, 0, 1, 0, 3, 0, 0, 0, 1, 0, 1, 0, 3, 0, 0,
+    0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 2, 0, 0, 1, 0, 1, 0, 2, 1, 0, 0, 1, 0, 1, 0, 2, 1, 0,
+    0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 1, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0,
+    0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 3, 0, 0, 1, 0, 1, 0, 1, 2, 0, 0, 1, 0, 1, 0, 1, 2, 0,
+    0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 1, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0,
+    0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 2, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1,
+    0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0,
+    0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 4, 0, 0, 1, 0, 1, 0, 0, 3, 0, 0, 1, 0, 1, 0, 0, 3, 0,
+    0, 1, 0, 1, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 1, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0,
+    0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 2, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1,
+    0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0,
+    0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 3, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2,
+    0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1,
+    0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0,
+    1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 7, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0,
+    0, 1, 0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 5, 1, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0,
+    0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 2, 0, 0, 0, 1, 0, 0, 4, 1, 0, 0, 0, 1, 0, 0, 4, 1, 0, 0,
+    0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 1, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0,
+    0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 3, 0, 0, 0, 1, 0, 0, 3, 2, 0, 0, 0, 1, 0, 0, 3, 2, 0, 0,
+    0, 1, 0, 0, 3, 1, 0, 0, 0, 1, 0, 0, 3, 1, 1, 0, 0, 1, 0, 0, 3, 1, 0, 0, 0, 1, 0, 0, 3, 1, 0, 0,
+    0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 2, 0, 0, 1, 0, 0, 3, 0, 1, 0, 0, 1, 0, 0, 3, 0, 1, 0,
+    0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 1, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0,
+    0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 4, 0, 0, 0, 1, 0, 0, 2, 3, 0, 0, 0, 1, 0, 0, 2, 3, 0, 0,
+    0, 1, 0, 0, 2, 2, 0, 0, 0, 1, 0, 0, 2, 2, 1, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0,
+    0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 2, 0, 0, 1, 0, 0, 2, 1, 1, 0, 0, 1, 0, 0, 2, 1, 1, 0,
+    0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 1, 0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0,
+    0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 3, 0, 0, 1, 0, 0, 2, 0, 2, 0, 0, 1, 0, 0, 2, 0, 2, 0,
+    0, 1, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 1, 0, 1, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0,
+    0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 2, 0, 1, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1,
+    0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0,
+    0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 5, 0, 0, 0, 1, 0, 0, 1, 4, 0, 0, 0, 1, 0, 0, 1, 4, 0, 0,
+    0, 1, 0, 0, 1, 3, 0, 0, 0, 1, 0, 0, 1, 3, 1, 0, 0, 1, 0, 0, 1, 3, 0, 0, 0, 1, 0, 0, 1, 3, 0, 0,
+    0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 2, 0, 0, 1, 0, 0, 1, 2, 1, 0, 0, 1, 0, 0, 1, 2, 1, 0,
+    0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 1, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0,
+    0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 3, 0, 0, 1, 0, 0, 1, 1, 2, 0, 0, 1, 0, 0, 1, 1, 2, 0,
+    0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0,
+    0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 2, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1,
+    0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0,
+    0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 4, 0, 0, 1, 0, 0, 1, 0, 3, 0, 0, 1, 0, 0, 1, 0, 3, 0,
+    0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 1, 0, 2, 1, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0,
+    0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 2, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1,
+    0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0,
+    0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 3, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2,
+    0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1,
+    0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0,
+    1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0,
+    0, 1, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 1, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0,
+    0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 2, 0, 0, 1, 0, 0, 0, 3, 1, 0, 0, 1, 0, 0, 0, 3, 1, 0,
+    0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 1, 0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0,
+    0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 3, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 1, 0, 0, 0, 2, 2, 0,
+    0, 1, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 1, 0, 1, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0,
+    0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 2, 0, 1, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1,
+    0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0,
+    0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 4, 0, 0, 1, 0, 0, 0, 1, 3, 0, 0, 1, 0, 0, 0, 1, 3, 0,
+    0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 1, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0,
+    0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1,
+    0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0,
+    0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2,
+    0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1,
+    0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0,
+    1, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 4, 0,
+    0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 1, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0,
+    0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1,
+    0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0,
+    0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2,
+    0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1,
+    0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0,
+    1, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3,
+    0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2,
+    0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1,
+    1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0,
+    2, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0,
+    0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0,
+    0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0,
+    0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 1, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
+    0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0,
+    0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 1, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0,
+    0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0,
+    0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 1, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0,
+    0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0,
+    0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 1, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0,
+    0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 2, 0, 0, 0, 0, 0, 5, 1, 1, 0, 0, 0, 0, 0, 5, 1, 1, 0, 0,
+    0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 1, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0,
+    0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 3, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0,
+    0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 1, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0,
+    0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 1, 0,
+    0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0,
+    0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 4, 3, 1, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0,
+    0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 2, 0, 0, 0, 0, 0, 4, 2, 1, 0, 0, 0, 0, 0, 4, 2, 1, 0, 0,
+    0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 1, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0,
+    0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 3, 0, 0, 0, 0, 0, 4, 1, 2, 0, 0, 0, 0, 0, 4, 1, 2, 0, 0,
+    0, 0, 0, 4, 1, 1, 0, 0, 0, 0, 0, 4, 1, 1, 1, 0, 0, 0, 0, 4, 1, 1, 0, 0, 0, 0, 0, 4, 1, 1, 0, 0,
+    0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 2, 0, 0, 0, 0, 4, 1, 0, 1, 0, 0, 0, 0, 4, 1, 0, 1, 0,
+    0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 1, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 4, 0, 0, 0, 0, 0, 4, 0, 3, 0, 0, 0, 0, 0, 4, 0, 3, 0, 0,
+    0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 1, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0,
+    0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 2, 0, 0, 0, 0, 4, 0, 1, 1, 0, 0, 0, 0, 4, 0, 1, 1, 0,
+    0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 1, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 3, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 2, 0,
+    0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 1, 1, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 1, 0,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 1,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0,
+    0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 4, 1, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0,
+    0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 3, 2, 0, 0, 0, 0, 0, 3, 3, 1, 0, 0, 0, 0, 0, 3, 3, 1, 0, 0,
+    0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 3, 0, 1, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0,
+    0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 3, 0, 0, 0, 0, 0, 3, 2, 2, 0, 0, 0, 0, 0, 3, 2, 2, 0, 0,
+    0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 0, 3, 2, 1, 1, 0, 0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 0, 3, 2, 1, 0, 0,
+    0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 2, 0, 0, 0, 0, 3, 2, 0, 1, 0, 0, 0, 0, 3, 2, 0, 1, 0,
+    0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 1, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0,
+    0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 4, 0, 0, 0, 0, 0, 3, 1, 3, 0, 0, 0, 0, 0, 3, 1, 3, 0, 0,
+    0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 0, 3, 1, 2, 1, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0,
+    0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1, 2, 0, 0, 0, 0, 3, 1, 1, 1, 0, 0, 0, 0, 3, 1, 1, 1, 0,
+    0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1, 0, 1, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0,
+    0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 3, 0, 0, 0, 0, 3, 1, 0, 2, 0, 0, 0, 0, 3, 1, 0, 2, 0,
+    0, 0, 0, 3, 1, 0, 1, 0, 0, 0, 0, 3, 1, 0, 1, 1, 0, 0, 0, 3, 1, 0, 1, 0, 0, 0, 0, 3, 1, 0, 1, 0,
+    0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 2, 0, 0, 0, 3, 1, 0, 0, 1, 0, 0, 0, 3, 1, 0, 0, 1,
+    0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 1, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 5, 0, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0, 3, 0, 4, 0, 0,
+    0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 3, 0, 3, 1, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0,
+    0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 2, 0, 0, 0, 0, 3, 0, 2, 1, 0, 0, 0, 0, 3, 0, 2, 1, 0,
+    0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 0, 1, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0,
+    0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 3, 0, 0, 0, 0, 3, 0, 1, 2, 0, 0, 0, 0, 3, 0, 1, 2, 0,
+    0, 0, 0, 3, 0, 1, 1, 0, 0, 0, 0, 3, 0, 1, 1, 1, 0, 0, 0, 3, 0, 1, 1, 0, 0, 0, 0, 3, 0, 1, 1, 0,
+    0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 2, 0, 0, 0, 3, 0, 1, 0, 1, 0, 0, 0, 3, 0, 1, 0, 1,
+    0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 1, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 4, 0, 0, 0, 0, 3, 0, 0, 3, 0, 0, 0, 0, 3, 0, 0, 3, 0,
+    0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 2, 1, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 2, 0,
+    0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 2, 0, 0, 0, 3, 0, 0, 1, 1, 0, 0, 0, 3, 0, 0, 1, 1,
+    0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 0, 1, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 3, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 2,
+    0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 1, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 1,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0,
+    1, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0,
+    0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 1, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0,
+    0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 2, 0, 0, 0, 0, 0, 2, 4, 1, 0, 0, 0, 0, 0, 2, 4, 1, 0, 0,
+    0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 1, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0,
+    0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 3, 0, 0, 0, 0, 0, 2, 3, 2, 0, 0, 0, 0, 0, 2, 3, 2, 0, 0,
+    0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 2, 3, 1, 1, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0,
+    0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 2, 0, 0, 0, 0, 2, 3, 0, 1, 0, 0, 0, 0, 2, 3, 0, 1, 0,
+    0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 1, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0,
+    0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 4, 0, 0, 0, 0, 0, 2, 2, 3, 0, 0, 0, 0, 0, 2, 2, 3, 0, 0,
+    0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 1, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0,
+    0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 2, 0, 0, 0, 0, 2, 2, 1, 1, 0, 0, 0, 0, 2, 2, 1, 1, 0,
+    0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 1, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0,
+    0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 3, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 0, 2, 2, 0, 2, 0,
+    0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1, 1, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1, 0,
+    0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 2, 0, 0, 0, 2, 2, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 1,
+    0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0,
+    0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 5, 0, 0, 0, 0, 0, 2, 1, 4, 0, 0, 0, 0, 0, 2, 1, 4, 0, 0,
+    0, 0, 0, 2, 1, 3, 0, 0, 0, 0, 0, 2, 1, 3, 1, 0, 0, 0, 0, 2, 1, 3, 0, 0, 0, 0, 0, 2, 1, 3, 0, 0,
+    0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 2, 0, 0, 0, 0, 2, 1, 2, 1, 0, 0, 0, 0, 2, 1, 2, 1, 0,
+    0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 1, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0,
+    0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 3, 0, 0, 0, 0, 2, 1, 1, 2, 0, 0, 0, 0, 2, 1, 1, 2, 0,
+    0, 0, 0, 2, 1, 1, 1, 0, 0, 0, 0, 2, 1, 1, 1, 1, 0, 0, 0, 2, 1, 1, 1, 0, 0, 0, 0, 2, 1, 1, 1, 0,
+    0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 2, 0, 0, 0, 2, 1, 1, 0, 1, 0, 0, 0, 2, 1, 1, 0, 1,
+    0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 1, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0,
+    0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 4, 0, 0, 0, 0, 2, 1, 0, 3, 0, 0, 0, 0, 2, 1, 0, 3, 0,
+    0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 2, 1, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 2, 0,
+    0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 2, 0, 0, 0, 2, 1, 0, 1, 1, 0, 0, 0, 2, 1, 0, 1, 1,
+    0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 1, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0,
+    0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 3, 0, 0, 0, 2, 1, 0, 0, 2, 0, 0, 0, 2, 1, 0, 0, 2,
+    0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 1, 1, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 1,
+    0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 2, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0,
+    1, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0,
+    0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 1, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0,
+    0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 2, 0, 0, 0, 0, 2, 0, 3, 1, 0, 0, 0, 0, 2, 0, 3, 1, 0,
+    0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 1, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0,
+    0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 3, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 2, 0, 2, 2, 0,
+    0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1, 1, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1, 0,
+    0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 2, 0, 0, 0, 2, 0, 2, 0, 1, 0, 0, 0, 2, 0, 2, 0, 1,
+    0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 1, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0,
+    0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 4, 0, 0, 0, 0, 2, 0, 1, 3, 0, 0, 0, 0, 2, 0, 1, 3, 0,
+    0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2, 1, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2, 0,
+    0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 2, 0, 0, 0, 2, 0, 1, 1, 1, 0, 0, 0, 2, 0, 1, 1, 1,
+    0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 1, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0,
+    0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 3, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0, 0, 2, 0, 1, 0, 2,
+    0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0, 1, 1, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0, 1,
+    0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 2, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0,
+    1, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 4, 0,
+    0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 1, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3, 0,
+    0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 2, 0, 0, 0, 2, 0, 0, 2, 1, 0, 0, 0, 2, 0, 0, 2, 1,
+    0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 1, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0,
+    0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 3, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 1, 2,
+    0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1, 1, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1,
+    0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1, 0,
+    1, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 3,
+    0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 1, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2,
+    0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1,
+    1, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0,
+    2, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0,
+    1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0,
+    0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0,
+    0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 1, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0,
+    0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0,
+    0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 1, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0,
+    0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 3, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0,
+    0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 0, 1, 4, 1, 1, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0,
+    0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 2, 0, 0, 0, 0, 1, 4, 0, 1, 0, 0, 0, 0, 1, 4, 0, 1, 0,
+    0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 1, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0,
+    0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 4, 0, 0, 0, 0, 0, 1, 3, 3, 0, 0, 0, 0, 0, 1, 3, 3, 0, 0,
+    0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 1, 3, 2, 1, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0,
+    0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 1, 2, 0, 0, 0, 0, 1, 3, 1, 1, 0, 0, 0, 0, 1, 3, 1, 1, 0,
+    0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 1, 0, 1, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0,
+    0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 3, 0, 0, 0, 0, 1, 3, 0, 2, 0, 0, 0, 0, 1, 3, 0, 2, 0,
+    0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 1, 3, 0, 1, 1, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 1, 3, 0, 1, 0,
+    0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 2, 0, 0, 0, 1, 3, 0, 0, 1, 0, 0, 0, 1, 3, 0, 0, 1,
+    0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 1, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0,
+    0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 5, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0,
+    0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 1, 2, 3, 1, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0,
+    0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 2, 0, 0, 0, 0, 1, 2, 2, 1, 0, 0, 0, 0, 1, 2, 2, 1, 0,
+    0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 1, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0,
+    0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 3, 0, 0, 0, 0, 1, 2, 1, 2, 0, 0, 0, 0, 1, 2, 1, 2, 0,
+    0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 0, 1, 2, 1, 1, 1, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 0, 1, 2, 1, 1, 0,
+    0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 2, 0, 0, 0, 1, 2, 1, 0, 1, 0, 0, 0, 1, 2, 1, 0, 1,
+    0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 1, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0,
+    0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 4, 0, 0, 0, 0, 1, 2, 0, 3, 0, 0, 0, 0, 1, 2, 0, 3, 0,
+    0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 1, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0,
+    0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 2, 0, 0, 0, 1, 2, 0, 1, 1, 0, 0, 0, 1, 2, 0, 1, 1,
+    0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 1, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0,
+    0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 3, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 2,
+    0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 1, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1,
+    0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0,
+    1, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0,
+    0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 6, 0, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0, 0, 1, 1, 5, 0, 0,
+    0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 1, 1, 4, 1, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0,
+    0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 3, 2, 0, 0, 0, 0, 1, 1, 3, 1, 0, 0, 0, 0, 1, 1, 3, 1, 0,
+    0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 3, 0, 1, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0,
+    0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 3, 0, 0, 0, 0, 1, 1, 2, 2, 0, 0, 0, 0, 1, 1, 2, 2, 0,
+    0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 0, 1, 1, 2, 1, 1, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 0, 1, 1, 2, 1, 0,
+    0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 2, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 0, 1, 1, 2, 0, 1,
+    0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 1, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0,
+    0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 4, 0, 0, 0, 0, 1, 1, 1, 3, 0, 0, 0, 0, 1, 1, 1, 3, 0,
+    0, 0, 0, 1, 1, 1, 2, 0, 0, 0, 0, 1, 1, 1, 2, 1, 0, 0, 0, 1, 1, 1, 2, 0, 0, 0, 0, 1, 1, 1, 2, 0,
+    0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 2, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 1, 1, 1, 1, 1,
+    0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 1, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0,
+    0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 3, 0, 0, 0, 1, 1, 1, 0, 2, 0, 0, 0, 1, 1, 1, 0, 2,
+    0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1,
+    0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 2, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0,
+    1, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0,
+    0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 5, 0, 0, 0, 0, 1, 1, 0, 4, 0, 0, 0, 0, 1, 1, 0, 4, 0,
+    0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 0, 1, 1, 0, 3, 1, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 0, 1, 1, 0, 3, 0,
+    0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 2, 0, 0, 0, 1, 1, 0, 2, 1, 0, 0, 0, 1, 1, 0, 2, 1,
+    0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 1, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0,
+    0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 3, 0, 0, 0, 1, 1, 0, 1, 2, 0, 0, 0, 1, 1, 0, 1, 2,
+    0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1,
+    0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 2, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0,
+    1, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0,
+    0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 4, 0, 0, 0, 1, 1, 0, 0, 3, 0, 0, 0, 1, 1, 0, 0, 3,
+    0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 1, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2,
+    0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 2, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1,
+    1, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1,
+    0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 3, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0,
+    2, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0,
+    1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0,
+    0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 7, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0,
+    0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 5, 1, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0,
+    0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 2, 0, 0, 0, 0, 1, 0, 4, 1, 0, 0, 0, 0, 1, 0, 4, 1, 0,
+    0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 1, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0,
+    0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 3, 0, 0, 0, 0, 1, 0, 3, 2, 0, 0, 0, 0, 1, 0, 3, 2, 0,
+    0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 0, 1, 0, 3, 1, 1, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 0, 1, 0, 3, 1, 0,
+    0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 2, 0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 1, 0, 3, 0, 1,
+    0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 1, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0,
+    0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 4, 0, 0, 0, 0, 1, 0, 2, 3, 0, 0, 0, 0, 1, 0, 2, 3, 0,
+    0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 1, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0,
+    0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 2, 0, 0, 0, 1, 0, 2, 1, 1, 0, 0, 0, 1, 0, 2, 1, 1,
+    0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 1, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0,
+    0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 3, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 1, 0, 2, 0, 2,
+    0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 1, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1,
+    0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0,
+    1, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0,
+    0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 5, 0, 0, 0, 0, 1, 0, 1, 4, 0, 0, 0, 0, 1, 0, 1, 4, 0,
+    0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 0, 1, 0, 1, 3, 1, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 0, 1, 0, 1, 3, 0,
+    0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 2, 0, 0, 0, 1, 0, 1, 2, 1, 0, 0, 0, 1, 0, 1, 2, 1,
+    0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 1, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0,
+    0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 3, 0, 0, 0, 1, 0, 1, 1, 2, 0, 0, 0, 1, 0, 1, 1, 2,
+    0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1,
+    0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 2, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0,
+    1, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0,
+    0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 4, 0, 0, 0, 1, 0, 1, 0, 3, 0, 0, 0, 1, 0, 1, 0, 3,
+    0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 1, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2,
+    0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 2, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1,
+    1, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1,
+    0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 3, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0,
+    2, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0,
+    1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0,
+    0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 5, 0,
+    0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 1, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0,
+    0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 2, 0, 0, 0, 1, 0, 0, 3, 1, 0, 0, 0, 1, 0, 0, 3, 1,
+    0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 1, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0,
+    0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 3, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 1, 0, 0, 2, 2,
+    0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 1, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1,
+    0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 2, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0,
+    1, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0,
+    0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 4, 0, 0, 0, 1, 0, 0, 1, 3, 0, 0, 0, 1, 0, 0, 1, 3,
+    0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 1, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2,
+    0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 2, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1,
+    1, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1,
+    0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 3, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 1, 0,
+    2, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0,
+    1, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0,
+    0, 1, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4,
+    0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 1, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3,
+    0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2,
+    1, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2,
+    0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 3, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1,
+    2, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1,
+    1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1,
+    0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0,
+    3, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0,
+    2, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0,
+    1, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0,
+    1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0,
+    0, 2, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0,
+    0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0,
+    0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0,
+    0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0,
+    0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0,
+    0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0,
+    0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 1, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0,
+    0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1, 0,
+    0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0,
+    0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0,
+    0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 1, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0,
+    0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 2, 0, 0, 0, 0, 0, 4, 1, 1, 0, 0, 0, 0, 0, 4, 1, 1, 0,
+    0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 1, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0,
+    0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 3, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 2, 0,
+    0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 1, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1, 0,
+    0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 1,
+    0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0,
+    0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 3, 1, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0,
+    0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 2, 0, 0, 0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 0, 3, 2, 1, 0,
+    0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 1, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0,
+    0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 3, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 0, 3, 1, 2, 0,
+    0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1, 1, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1, 0,
+    0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 2, 0, 0, 0, 0, 3, 1, 0, 1, 0, 0, 0, 0, 3, 1, 0, 1,
+    0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 1, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 3, 0, 3, 0,
+    0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 1, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2, 0,
+    0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 2, 0, 0, 0, 0, 3, 0, 1, 1, 0, 0, 0, 0, 3, 0, 1, 1,
+    0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0, 1, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 3, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 2,
+    0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 1, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0,
+    1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0,
+    0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 1, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0,
+    0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 2, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 2, 3, 1, 0,
+    0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 1, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0,
+    0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 3, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 2, 0,
+    0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 1, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1, 0,
+    0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 1,
+    0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0,
+    0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 4, 0, 0, 0, 0, 0, 2, 1, 3, 0, 0, 0, 0, 0, 2, 1, 3, 0,
+    0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 1, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2, 0,
+    0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 2, 0, 0, 0, 0, 2, 1, 1, 1, 0, 0, 0, 0, 2, 1, 1, 1,
+    0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0, 1, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 0,
+    0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 3, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 2,
+    0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1, 1, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 1,
+    0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 2, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0,
+    1, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 4, 0,
+    0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 1, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3, 0,
+    0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 1,
+    0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0, 1, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 0,
+    0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 3, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 2,
+    0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 1, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1,
+    0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0,
+    1, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 3,
+    0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 1, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2,
+    0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1,
+    1, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1,
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0,
+    2, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0,
+    1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0,
+    0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0,
+    0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0,
+    0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 0, 1, 4, 1, 0,
+    0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 1, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0,
+    0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 3, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 1, 3, 2, 0,
+    0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 1, 1, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 1, 0,
+    0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 2, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 1, 3, 0, 1,
+    0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 1, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0,
+    0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 1, 2, 3, 0,
+    0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 1, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0,
+    0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 2, 0, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 0, 1, 2, 1, 1,
+    0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 1, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0,
+    0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 3, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2,
+    0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 1, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1,
+    0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0,
+    1, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0,
+    0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 1, 1, 4, 0,
+    0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 3, 1, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 3, 0,
+    0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 2, 0, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 0, 1, 1, 2, 1,
+    0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0,
+    0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 3, 0, 0, 0, 0, 1, 1, 1, 2, 0, 0, 0, 0, 1, 1, 1, 2,
+    0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1,
+    0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 2, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0,
+    1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0,
+    0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 4, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 0, 1, 1, 0, 3,
+    0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 1, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2,
+    0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 2, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1,
+    1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1,
+    0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 3, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0,
+    2, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0,
+    1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0,
+    0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0,
+    0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 1, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0,
+    0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 2, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 0, 1, 0, 3, 1,
+    0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0,
+    0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 3, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2,
+    0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 1, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1,
+    0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0,
+    1, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0,
+    0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 4, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 0, 1, 0, 1, 3,
+    0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 1, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2,
+    0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 2, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1,
+    1, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1,
+    0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 3, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0,
+    2, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0,
+    1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0,
+    0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4,
+    0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 1, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3,
+    0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2,
+    1, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2,
+    0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 3, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1,
+    2, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1,
+    1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1,
+    0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0,
+    3, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0,
+    2, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0,
+    1, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0,
+    1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0,
+    0, 2, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0,
+    0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0,
+    0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0,
+    0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 1, 0,
+    0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0,
+    0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 2, 0,
+    0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 1, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1, 0,
+    0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 1,
+    0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 3, 0,
+    0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2, 0,
+    0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1,
+    0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0, 1, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 0,
+    0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 2,
+    0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1, 1, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 1,
+    0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0,
+    1, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0,
+    0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 4, 0,
+    0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3, 0,
+    0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 1,
+    0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 0,
+    0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 3, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 2,
+    0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 1, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1,
+    0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0,
+    1, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0,
+    0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 3,
+    0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 2,
+    0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1,
+    1, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1,
+    0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0,
+    2, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0,
+    1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0,
+    0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 5, 0,
+    0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4, 0,
+    0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 1,
+    0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 0,
+    0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2,
+    0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 1,
+    0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0,
+    1, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0,
+    0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 3,
+    0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 2,
+    0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 2, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1,
+    1, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1,
+    0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0,
+    2, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0,
+    1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0,
+    0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 4,
+    0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 3,
+    0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2,
+    1, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2,
+    0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1,
+    2, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1,
+    1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1,
+    0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0,
+    3, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0,
+    2, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0,
+    1, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0,
+    1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0,
+    0, 2, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0,
+    0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0,
+    0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5, 0,
+    0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 1,
+    0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0,
+    0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 2,
+    0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 1,
+    0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0,
+    1, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0,
+    0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 3,
+    0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2,
+    0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1,
+    1, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1,
+    0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0,
+    2, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0,
+    1, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0,
+    0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 4,
+    0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 3,
+    0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2,
+    1, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2,
+    0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1,
+    2, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1,
+    1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1,
+    0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0,
+    3, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1, 0,
+    2, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0,
+    1, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0,
+    1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0,
+    0, 2, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0,
+    0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5,
+    0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3,
+    1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2,
+    2, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2,
+    1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2,
+    0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1,
+    3, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1,
+    2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1,
+    1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1,
+    1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 1,
+    0, 2, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1,
+    0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0,
+    2, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0,
+    1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0,
+    0, 3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0,
+];
+///Table containing the total length of the codes decoded with a lookup
+pub const READ_LEN_L2M: &[u8] = &[
+    0, 1, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+    9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 8,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 8, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 9,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 9, 10, 10, 10, 10, 10, 10, 10, 10,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 9,
+    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 9, 10, 10, 10, 10, 10, 10, 10, 9,
+    10, 10, 10, 9, 10, 9, 9, 8, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 8, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 9, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 9, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 9, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 9, 11,
+    11, 11, 11, 11, 11, 11, 9, 11, 11, 11, 9, 11, 9, 9, 8, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11,
+    11, 10, 11, 10, 10, 8, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11,
+    11, 11, 11, 11, 11, 10, 11, 11, 11, 10, 11, 10, 10, 9, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11,
+    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11,
+    11, 10, 11, 10, 10, 9, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11,
+    11, 11, 11, 11, 11, 10, 11, 11, 11, 10, 11, 10, 10, 9, 11, 11, 11, 11, 11, 11, 11, 10, 11, 11,
+    11, 10, 11, 10, 10, 9, 11, 11, 11, 10, 11, 10, 10, 9, 11, 10, 10, 9, 10, 9, 9, 8, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 8, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12,
+    9, 12, 9, 9, 8, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 10, 12, 10, 10, 8, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 10,
+    12, 10, 10, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 10, 12, 10, 10, 9, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 10,
+    12, 10, 10, 9, 12, 12, 12, 12, 12, 12, 12, 10, 12, 12, 12, 10, 12, 10, 10, 9, 12, 12, 12, 10,
+    12, 10, 10, 9, 12, 10, 10, 9, 10, 9, 9, 8, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 11, 12,
+    11, 11, 8, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12,
+    12, 12, 11, 12, 12, 12, 11, 12, 11, 11, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 11, 12,
+    11, 11, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12,
+    12, 12, 11, 12, 12, 12, 11, 12, 11, 11, 9, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 11, 12,
+    11, 11, 9, 12, 12, 12, 11, 12, 11, 11, 9, 12, 11, 11, 9, 11, 9, 9, 8, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 11, 12, 11,
+    11, 10, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 11, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 11, 12, 11, 11, 10, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 11, 12, 11,
+    11, 10, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 11, 12, 11, 11, 10, 12, 12, 12, 11, 12, 11,
+    11, 10, 12, 11, 11, 10, 11, 10, 10, 8, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 11, 12, 11,
+    11, 10, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12, 12,
+    12, 11, 12, 12, 12, 11, 12, 11, 11, 10, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 11, 12, 11,
+    11, 10, 12, 12, 12, 11, 12, 11, 11, 10, 12, 11, 11, 10, 11, 10, 10, 9, 12, 12, 12, 12, 12, 12,
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 11, 12, 11,
+    11, 10, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 11, 12, 11, 11, 10, 12, 12, 12, 11, 12, 11,
+    11, 10, 12, 11, 11, 10, 11, 10, 10, 9, 12, 12, 12, 12, 12, 12, 12, 11, 12, 12, 12, 11, 12, 11,
+    11, 10, 12, 12, 12, 11, 12, 11, 11, 10, 12, 11, 11, 10, 11, 10, 10, 9, 12, 12, 12, 11, 12, 11,
+    11, 10, 12, 11, 11, 10, 11, 10, 10, 9, 12, 11, 11, 10, 11, 10, 10, 9, 11, 10, 10, 9, 10, 9, 9,
+    8,
+];
+///Table containing the number of codes decoded with a lookup
+pub const READ_COUNT_L2M: &[u8] = &[
+    0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    2, 3, 3, 4, 3, 4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    3, 4, 4, 5, 4, 5, 5, 6, 4, 5, 5, 6, 5, 6, 6, 7, 4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    4, 5, 5, 6, 5, 6, 6, 7, 5, 6, 6, 7, 6, 7, 7, 8, 5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    5, 6, 6, 7, 6, 7, 7, 8, 6, 7, 7, 8, 7, 8, 8, 8, 6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    6, 7, 7, 8, 7, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+    7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+];
+///Table containing the values decoded with a lookup, MAX_SYMBOLS
+///for each entry, padded with zeros
+pub const READ_VALUES_L2M: &[u8] = &[
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    2, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    5, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0,
+    2, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    3, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
+    2, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    2, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    6, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0,
+    2, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    3, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0,
+    2, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    4, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+    2, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+    3, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0,
+    2, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    5, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0,
+    2, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    3, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
+    2, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    2, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    7, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0,
+    2, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+    3, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0,
+    2, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
+    4, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0,
+    2, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0,
+    3, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0,
+    2, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    5, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0,
+    2, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0,
+    3, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0,
+    2, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+    4, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0,
+    2, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0,
+    3, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0,
+    2, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    6, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0,
+    2, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    3, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0,
+    2, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    4, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+    2, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+    3, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0,
+    2, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    5, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0,
+    2, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    3, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
+    2, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    2, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    8, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0,
+    2, 5, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0,
+    3, 4, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0,
+    2, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    4, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0,
+    2, 1, 3, 0, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0,
+    3, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0,
+    2, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0,
+    5, 2, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0,
+    2, 2, 2, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0,
+    3, 1, 2, 0, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 1, 1, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0,
+    2, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0,
+    4, 0, 2, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0,
+    2, 1, 0, 2, 0, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0,
+    3, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0,
+    2, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    6, 1, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0,
+    2, 3, 1, 0, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0,
+    3, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0,
+    2, 0, 2, 1, 0, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0,
+    4, 1, 1, 0, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0,
+    2, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0,
+    3, 0, 1, 1, 0, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0,
+    2, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0,
+    5, 0, 1, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0,
+    2, 2, 0, 1, 0, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0,
+    3, 1, 0, 1, 0, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0,
+    2, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0,
+    4, 0, 0, 1, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0,
+    2, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0,
+    3, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0,
+    2, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    7, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0,
+    2, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+    3, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0,
+    2, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
+    4, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0,
+    2, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0,
+    3, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0,
+    2, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    5, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0,
+    2, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0,
+    3, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0,
+    2, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+    4, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0,
+    2, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0,
+    3, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0,
+    2, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    6, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0,
+    2, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    3, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0,
+    2, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    4, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+    2, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+    3, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0,
+    2, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    5, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0,
+    2, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    3, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
+    2, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    2, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    9, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0,
+    2, 6, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0,
+    3, 5, 0, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0,
+    2, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0,
+    4, 4, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0,
+    2, 1, 4, 0, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 1, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0,
+    3, 0, 4, 0, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 1, 1, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0,
+    2, 0, 0, 4, 0, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    5, 3, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 1, 3, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0,
+    2, 2, 3, 0, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 1, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0,
+    3, 1, 3, 0, 0, 0, 0, 0, 0, 2, 1, 3, 0, 0, 0, 0, 1, 1, 1, 3, 0, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0,
+    2, 0, 1, 3, 0, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 1, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0,
+    4, 0, 3, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 1, 2, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0,
+    2, 1, 0, 3, 0, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 1, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0,
+    3, 0, 0, 3, 0, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 1, 1, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0,
+    2, 0, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0,
+    6, 2, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0,
+    2, 3, 2, 0, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 1, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0,
+    3, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 1, 1, 2, 2, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0,
+    2, 0, 2, 2, 0, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0,
+    4, 1, 2, 0, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 1, 2, 1, 2, 0, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0,
+    2, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 1, 2, 0, 0, 0, 1, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0,
+    3, 0, 1, 2, 0, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 1, 1, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0,
+    2, 0, 0, 1, 2, 0, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0,
+    5, 0, 2, 0, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 1, 3, 0, 2, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0,
+    2, 2, 0, 2, 0, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0,
+    3, 1, 0, 2, 0, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 1, 1, 1, 0, 2, 0, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0,
+    2, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0,
+    4, 0, 0, 2, 0, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0,
+    2, 1, 0, 0, 2, 0, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0,
+    3, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0,
+    2, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+    7, 1, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0,
+    2, 4, 1, 0, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 1, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0,
+    3, 3, 1, 0, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 1, 1, 3, 1, 0, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0,
+    2, 0, 3, 1, 0, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 1, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0,
+    4, 2, 1, 0, 0, 0, 0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 1, 2, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0,
+    2, 1, 2, 1, 0, 0, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 1, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0,
+    3, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 1, 1, 0, 2, 1, 0, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0,
+    2, 0, 0, 2, 1, 0, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0,
+    5, 1, 1, 0, 0, 0, 0, 0, 0, 4, 1, 1, 0, 0, 0, 0, 1, 3, 1, 1, 0, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0,
+    2, 2, 1, 1, 0, 0, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 1, 0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0,
+    3, 1, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 1, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0,
+    2, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0,
+    4, 0, 1, 1, 0, 0, 0, 0, 0, 3, 0, 1, 1, 0, 0, 0, 1, 2, 0, 1, 1, 0, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0,
+    2, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0,
+    3, 0, 0, 1, 1, 0, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0,
+    2, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1,
+    6, 0, 1, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 1, 4, 0, 1, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0,
+    2, 3, 0, 1, 0, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0,
+    3, 2, 0, 1, 0, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0,
+    2, 0, 2, 0, 1, 0, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0,
+    4, 1, 0, 1, 0, 0, 0, 0, 0, 3, 1, 0, 1, 0, 0, 0, 1, 2, 1, 0, 1, 0, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0,
+    2, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0,
+    3, 0, 1, 0, 1, 0, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0,
+    2, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1,
+    5, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 1, 3, 0, 0, 1, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0,
+    2, 2, 0, 0, 1, 0, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0,
+    3, 1, 0, 0, 1, 0, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0,
+    2, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1,
+    4, 0, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0,
+    2, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1,
+    3, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1,
+    2, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    8, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0,
+    2, 5, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0,
+    3, 4, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0,
+    2, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    4, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0,
+    2, 1, 3, 0, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0,
+    3, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0,
+    2, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0,
+    5, 2, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0,
+    2, 2, 2, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0,
+    3, 1, 2, 0, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 1, 1, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0,
+    2, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0,
+    4, 0, 2, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0,
+    2, 1, 0, 2, 0, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0,
+    3, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0,
+    2, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    6, 1, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0,
+    2, 3, 1, 0, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0,
+    3, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0,
+    2, 0, 2, 1, 0, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0,
+    4, 1, 1, 0, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0,
+    2, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0,
+    3, 0, 1, 1, 0, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0,
+    2, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0,
+    5, 0, 1, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0,
+    2, 2, 0, 1, 0, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0,
+    3, 1, 0, 1, 0, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0,
+    2, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0,
+    4, 0, 0, 1, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0,
+    2, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0,
+    3, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0,
+    2, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    7, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0,
+    2, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+    3, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0,
+    2, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
+    4, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0,
+    2, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0,
+    3, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0,
+    2, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    5, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0,
+    2, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0,
+    3, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0,
+    2, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+    4, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0,
+    2, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0,
+    3, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0,
+    2, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    6, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0,
+    2, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    3, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0,
+    2, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    4, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+    2, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+    3, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0,
+    2, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    5, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0,
+    2, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    3, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
+    2, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    4, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    2, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+    2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    10, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0,
+    0, 2, 7, 0, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 1, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0,
+    0, 3, 6, 0, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 1, 1, 6, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0,
+    0, 2, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0,
+    0, 4, 5, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 1, 2, 5, 0, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0,
+    0, 2, 1, 5, 0, 0, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0, 1, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0,
+    0, 3, 0, 5, 0, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 1, 1, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0,
+    0, 2, 0, 0, 5, 0, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0,
+    0, 5, 4, 0, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 1, 3, 4, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0,
+    0, 2, 2, 4, 0, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 1, 0, 2, 4, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0,
+    0, 3, 1, 4, 0, 0, 0, 0, 0, 0, 2, 1, 4, 0, 0, 0, 0, 1, 1, 1, 4, 0, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0,
+    0, 2, 0, 1, 4, 0, 0, 0, 0, 0, 1, 0, 1, 4, 0, 0, 0, 1, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0,
+    0, 4, 0, 4, 0, 0, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 1, 2, 0, 4, 0, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0,
+    0, 2, 1, 0, 4, 0, 0, 0, 0, 0, 1, 1, 0, 4, 0, 0, 0, 1, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0,
+    0, 3, 0, 0, 4, 0, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 1, 1, 0, 0, 4, 0, 0, 0, 0, 0, 1, 0, 0, 4, 0,
+    0, 2, 0, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 6, 3, 0, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 1, 4, 3, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0,
+    0, 2, 3, 3, 0, 0, 0, 0, 0, 0, 1, 3, 3, 0, 0, 0, 0, 1, 0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0,
+    0, 3, 2, 3, 0, 0, 0, 0, 0, 0, 2, 2, 3, 0, 0, 0, 0, 1, 1, 2, 3, 0, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0,
+    0, 2, 0, 2, 3, 0, 0, 0, 0, 0, 1, 0, 2, 3, 0, 0, 0, 1, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0,
+    0, 4, 1, 3, 0, 0, 0, 0, 0, 0, 3, 1, 3, 0, 0, 0, 0, 1, 2, 1, 3, 0, 0, 0, 0, 0, 0, 2, 1, 3, 0, 0,
+    0, 2, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 1, 3, 0, 0, 0, 1, 0, 1, 1, 3, 0, 0, 0, 0, 0, 0, 1, 1, 3, 0,
+    0, 3, 0, 1, 3, 0, 0, 0, 0, 0, 2, 0, 1, 3, 0, 0, 0, 1, 1, 0, 1, 3, 0, 0, 0, 0, 0, 1, 0, 1, 3, 0,
+    0, 2, 0, 0, 1, 3, 0, 0, 0, 0, 1, 0, 0, 1, 3, 0, 0, 1, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3,
+    0, 5, 0, 3, 0, 0, 0, 0, 0, 0, 4, 0, 3, 0, 0, 0, 0, 1, 3, 0, 3, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0,
+    0, 2, 2, 0, 3, 0, 0, 0, 0, 0, 1, 2, 0, 3, 0, 0, 0, 1, 0, 2, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0,
+    0, 3, 1, 0, 3, 0, 0, 0, 0, 0, 2, 1, 0, 3, 0, 0, 0, 1, 1, 1, 0, 3, 0, 0, 0, 0, 0, 1, 1, 0, 3, 0,
+    0, 2, 0, 1, 0, 3, 0, 0, 0, 0, 1, 0, 1, 0, 3, 0, 0, 1, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3,
+    0, 4, 0, 0, 3, 0, 0, 0, 0, 0, 3, 0, 0, 3, 0, 0, 0, 1, 2, 0, 0, 3, 0, 0, 0, 0, 0, 2, 0, 0, 3, 0,
+    0, 2, 1, 0, 0, 3, 0, 0, 0, 0, 1, 1, 0, 0, 3, 0, 0, 1, 0, 1, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 3,
+    0, 3, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 1, 1, 0, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 0, 3,
+    0, 2, 0, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0,
+    3, 7, 2, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0,
+    0, 2, 4, 2, 0, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0, 0, 1, 0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0,
+    0, 3, 3, 2, 0, 0, 0, 0, 0, 0, 2, 3, 2, 0, 0, 0, 0, 1, 1, 3, 2, 0, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0,
+    0, 2, 0, 3, 2, 0, 0, 0, 0, 0, 1, 0, 3, 2, 0, 0, 0, 1, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0,
+    0, 4, 2, 2, 0, 0, 0, 0, 0, 0, 3, 2, 2, 0, 0, 0, 0, 1, 2, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0,
+    0, 2, 1, 2, 2, 0, 0, 0, 0, 0, 1, 1, 2, 2, 0, 0, 0, 1, 0, 1, 2, 2, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0,
+    0, 3, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 1, 1, 0, 2, 2, 0, 0, 0, 0, 0, 1, 0, 2, 2, 0,
+    0, 2, 0, 0, 2, 2, 0, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2,
+    0, 5, 1, 2, 0, 0, 0, 0, 0, 0, 4, 1, 2, 0, 0, 0, 0, 1, 3, 1, 2, 0, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0,
+    0, 2, 2, 1, 2, 0, 0, 0, 0, 0, 1, 2, 1, 2, 0, 0, 0, 1, 0, 2, 1, 2, 0, 0, 0, 0, 0, 0, 2, 1, 2, 0,
+    0, 3, 1, 1, 2, 0, 0, 0, 0, 0, 2, 1, 1, 2, 0, 0, 0, 1, 1, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 1, 2, 0,
+    0, 2, 0, 1, 1, 2, 0, 0, 0, 0, 1, 0, 1, 1, 2, 0, 0, 1, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 2,
+    0, 4, 0, 1, 2, 0, 0, 0, 0, 0, 3, 0, 1, 2, 0, 0, 0, 1, 2, 0, 1, 2, 0, 0, 0, 0, 0, 2, 0, 1, 2, 0,
+    0, 2, 1, 0, 1, 2, 0, 0, 0, 0, 1, 1, 0, 1, 2, 0, 0, 1, 0, 1, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 1, 2,
+    0, 3, 0, 0, 1, 2, 0, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 1, 1, 0, 0, 1, 2, 0, 0, 0, 0, 1, 0, 0, 1, 2,
+    0, 2, 0, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1,
+    2, 6, 0, 2, 0, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 1, 4, 0, 2, 0, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0,
+    0, 2, 3, 0, 2, 0, 0, 0, 0, 0, 1, 3, 0, 2, 0, 0, 0, 1, 0, 3, 0, 2, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0,
+    0, 3, 2, 0, 2, 0, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 1, 1, 2, 0, 2, 0, 0, 0, 0, 0, 1, 2, 0, 2, 0,
+    0, 2, 0, 2, 0, 2, 0, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 1, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2,
+    0, 4, 1, 0, 2, 0, 0, 0, 0, 0, 3, 1, 0, 2, 0, 0, 0, 1, 2, 1, 0, 2, 0, 0, 0, 0, 0, 2, 1, 0, 2, 0,
+    0, 2, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 1, 0, 2, 0, 0, 1, 0, 1, 1, 0, 2, 0, 0, 0, 0, 0, 1, 1, 0, 2,
+    0, 3, 0, 1, 0, 2, 0, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0, 1, 1, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 1, 0, 2,
+    0, 2, 0, 0, 1, 0, 2, 0, 0, 0, 1, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0,
+    2, 5, 0, 0, 2, 0, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 1, 3, 0, 0, 2, 0, 0, 0, 0, 0, 3, 0, 0, 2, 0,
+    0, 2, 2, 0, 0, 2, 0, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 2,
+    0, 3, 1, 0, 0, 2, 0, 0, 0, 0, 2, 1, 0, 0, 2, 0, 0, 1, 1, 1, 0, 0, 2, 0, 0, 0, 0, 1, 1, 0, 0, 2,
+    0, 2, 0, 1, 0, 0, 2, 0, 0, 0, 1, 0, 1, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0,
+    2, 4, 0, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 2,
+    0, 2, 1, 0, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0,
+    2, 3, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0,
+    2, 2, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    0, 8, 1, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0,
+    0, 2, 5, 1, 0, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 1, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0,
+    0, 3, 4, 1, 0, 0, 0, 0, 0, 0, 2, 4, 1, 0, 0, 0, 0, 1, 1, 4, 1, 0, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0,
+    0, 2, 0, 4, 1, 0, 0, 0, 0, 0, 1, 0, 4, 1, 0, 0, 0, 1, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0,
+    0, 4, 3, 1, 0, 0, 0, 0, 0, 0, 3, 3, 1, 0, 0, 0, 0, 1, 2, 3, 1, 0, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0,
+    0, 2, 1, 3, 1, 0, 0, 0, 0, 0, 1, 1, 3, 1, 0, 0, 0, 1, 0, 1, 3, 1, 0, 0, 0, 0, 0, 0, 1, 3, 1, 0,
+    0, 3, 0, 3, 1, 0, 0, 0, 0, 0, 2, 0, 3, 1, 0, 0, 0, 1, 1, 0, 3, 1, 0, 0, 0, 0, 0, 1, 0, 3, 1, 0,
+    0, 2, 0, 0, 3, 1, 0, 0, 0, 0, 1, 0, 0, 3, 1, 0, 0, 1, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1,
+    0, 5, 2, 1, 0, 0, 0, 0, 0, 0, 4, 2, 1, 0, 0, 0, 0, 1, 3, 2, 1, 0, 0, 0, 0, 0, 0, 3, 2, 1, 0, 0,
+    0, 2, 2, 2, 1, 0, 0, 0, 0, 0, 1, 2, 2, 1, 0, 0, 0, 1, 0, 2, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2, 1, 0,
+    0, 3, 1, 2, 1, 0, 0, 0, 0, 0, 2, 1, 2, 1, 0, 0, 0, 1, 1, 1, 2, 1, 0, 0, 0, 0, 0, 1, 1, 2, 1, 0,
+    0, 2, 0, 1, 2, 1, 0, 0, 0, 0, 1, 0, 1, 2, 1, 0, 0, 1, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2, 1,
+    0, 4, 0, 2, 1, 0, 0, 0, 0, 0, 3, 0, 2, 1, 0, 0, 0, 1, 2, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 2, 1, 0,
+    0, 2, 1, 0, 2, 1, 0, 0, 0, 0, 1, 1, 0, 2, 1, 0, 0, 1, 0, 1, 0, 2, 1, 0, 0, 0, 0, 0, 1, 0, 2, 1,
+    0, 3, 0, 0, 2, 1, 0, 0, 0, 0, 2, 0, 0, 2, 1, 0, 0, 1, 1, 0, 0, 2, 1, 0, 0, 0, 0, 1, 0, 0, 2, 1,
+    0, 2, 0, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2,
+    1, 6, 1, 1, 0, 0, 0, 0, 0, 0, 5, 1, 1, 0, 0, 0, 0, 1, 4, 1, 1, 0, 0, 0, 0, 0, 0, 4, 1, 1, 0, 0,
+    0, 2, 3, 1, 1, 0, 0, 0, 0, 0, 1, 3, 1, 1, 0, 0, 0, 1, 0, 3, 1, 1, 0, 0, 0, 0, 0, 0, 3, 1, 1, 0,
+    0, 3, 2, 1, 1, 0, 0, 0, 0, 0, 2, 2, 1, 1, 0, 0, 0, 1, 1, 2, 1, 1, 0, 0, 0, 0, 0, 1, 2, 1, 1, 0,
+    0, 2, 0, 2, 1, 1, 0, 0, 0, 0, 1, 0, 2, 1, 1, 0, 0, 1, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1,
+    0, 4, 1, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1, 1, 0, 0, 0, 1, 2, 1, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 1, 0,
+    0, 2, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 1,
+    0, 3, 0, 1, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 1, 0, 0, 1, 1, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 1,
+    0, 2, 0, 0, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1,
+    1, 5, 0, 1, 1, 0, 0, 0, 0, 0, 4, 0, 1, 1, 0, 0, 0, 1, 3, 0, 1, 1, 0, 0, 0, 0, 0, 3, 0, 1, 1, 0,
+    0, 2, 2, 0, 1, 1, 0, 0, 0, 0, 1, 2, 0, 1, 1, 0, 0, 1, 0, 2, 0, 1, 1, 0, 0, 0, 0, 0, 2, 0, 1, 1,
+    0, 3, 1, 0, 1, 1, 0, 0, 0, 0, 2, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1,
+    0, 2, 0, 1, 0, 1, 1, 0, 0, 0, 1, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1,
+    1, 4, 0, 0, 1, 1, 0, 0, 0, 0, 3, 0, 0, 1, 1, 0, 0, 1, 2, 0, 0, 1, 1, 0, 0, 0, 0, 2, 0, 0, 1, 1,
+    0, 2, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 1,
+    1, 3, 0, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 1,
+    1, 2, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 7, 0, 1, 0, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 1, 5, 0, 1, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0,
+    0, 2, 4, 0, 1, 0, 0, 0, 0, 0, 1, 4, 0, 1, 0, 0, 0, 1, 0, 4, 0, 1, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0,
+    0, 3, 3, 0, 1, 0, 0, 0, 0, 0, 2, 3, 0, 1, 0, 0, 0, 1, 1, 3, 0, 1, 0, 0, 0, 0, 0, 1, 3, 0, 1, 0,
+    0, 2, 0, 3, 0, 1, 0, 0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 1, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 1,
+    0, 4, 2, 0, 1, 0, 0, 0, 0, 0, 3, 2, 0, 1, 0, 0, 0, 1, 2, 2, 0, 1, 0, 0, 0, 0, 0, 2, 2, 0, 1, 0,
+    0, 2, 1, 2, 0, 1, 0, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 1, 0, 1, 2, 0, 1, 0, 0, 0, 0, 0, 1, 2, 0, 1,
+    0, 3, 0, 2, 0, 1, 0, 0, 0, 0, 2, 0, 2, 0, 1, 0, 0, 1, 1, 0, 2, 0, 1, 0, 0, 0, 0, 1, 0, 2, 0, 1,
+    0, 2, 0, 0, 2, 0, 1, 0, 0, 0, 1, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0,
+    1, 5, 1, 0, 1, 0, 0, 0, 0, 0, 4, 1, 0, 1, 0, 0, 0, 1, 3, 1, 0, 1, 0, 0, 0, 0, 0, 3, 1, 0, 1, 0,
+    0, 2, 2, 1, 0, 1, 0, 0, 0, 0, 1, 2, 1, 0, 1, 0, 0, 1, 0, 2, 1, 0, 1, 0, 0, 0, 0, 0, 2, 1, 0, 1,
+    0, 3, 1, 1, 0, 1, 0, 0, 0, 0, 2, 1, 1, 0, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1, 0, 1,
+    0, 2, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0,
+    1, 4, 0, 1, 0, 1, 0, 0, 0, 0, 3, 0, 1, 0, 1, 0, 0, 1, 2, 0, 1, 0, 1, 0, 0, 0, 0, 2, 0, 1, 0, 1,
+    0, 2, 1, 0, 1, 0, 1, 0, 0, 0, 1, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0,
+    1, 3, 0, 0, 1, 0, 1, 0, 0, 0, 2, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0,
+    1, 2, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1,
+    0, 6, 0, 0, 1, 0, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 1, 4, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 1, 0,
+    0, 2, 3, 0, 0, 1, 0, 0, 0, 0, 1, 3, 0, 0, 1, 0, 0, 1, 0, 3, 0, 0, 1, 0, 0, 0, 0, 0, 3, 0, 0, 1,
+    0, 3, 2, 0, 0, 1, 0, 0, 0, 0, 2, 2, 0, 0, 1, 0, 0, 1, 1, 2, 0, 0, 1, 0, 0, 0, 0, 1, 2, 0, 0, 1,
+    0, 2, 0, 2, 0, 0, 1, 0, 0, 0, 1, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0,
+    1, 4, 1, 0, 0, 1, 0, 0, 0, 0, 3, 1, 0, 0, 1, 0, 0, 1, 2, 1, 0, 0, 1, 0, 0, 0, 0, 2, 1, 0, 0, 1,
+    0, 2, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0,
+    1, 3, 0, 1, 0, 0, 1, 0, 0, 0, 2, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0,
+    1, 2, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0,
+    0, 5, 0, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 1, 3, 0, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 0, 1,
+    0, 2, 2, 0, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0,
+    1, 3, 1, 0, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0,
+    1, 2, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0,
+    0, 4, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0,
+    1, 2, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0,
+    0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
+    0, 2, 6, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0,
+    0, 3, 5, 0, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0,
+    0, 2, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0,
+    0, 4, 4, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0,
+    0, 2, 1, 4, 0, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 1, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0,
+    0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 1, 1, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0,
+    0, 2, 0, 0, 4, 0, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0,
+    0, 5, 3, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 1, 3, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0,
+    0, 2, 2, 3, 0, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 1, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0,
+    0, 3, 1, 3, 0, 0, 0, 0, 0, 0, 2, 1, 3, 0, 0, 0, 0, 1, 1, 1, 3, 0, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0,
+    0, 2, 0, 1, 3, 0, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 1, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0,
+    0, 4, 0, 3, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 1, 2, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0,
+    0, 2, 1, 0, 3, 0, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 1, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0,
+    0, 3, 0, 0, 3, 0, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 1, 1, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 3, 0,
+    0, 2, 0, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0,
+    0, 2, 3, 2, 0, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 1, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0,
+    0, 3, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 1, 1, 2, 2, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0,
+    0, 2, 0, 2, 2, 0, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0,
+    0, 4, 1, 2, 0, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 1, 2, 1, 2, 0, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0,
+    0, 2, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 1, 2, 0, 0, 0, 1, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0,
+    0, 3, 0, 1, 2, 0, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 1, 1, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 1, 2, 0,
+    0, 2, 0, 0, 1, 2, 0, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2,
+    0, 5, 0, 2, 0, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 1, 3, 0, 2, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0,
+    0, 2, 2, 0, 2, 0, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0,
+    0, 3, 1, 0, 2, 0, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 1, 1, 1, 0, 2, 0, 0, 0, 0, 0, 1, 1, 0, 2, 0,
+    0, 2, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2,
+    0, 4, 0, 0, 2, 0, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0,
+    0, 2, 1, 0, 0, 2, 0, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 2,
+    0, 3, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 2,
+    0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    2, 7, 1, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0,
+    0, 2, 4, 1, 0, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 1, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0,
+    0, 3, 3, 1, 0, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 1, 1, 3, 1, 0, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0,
+    0, 2, 0, 3, 1, 0, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 1, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0,
+    0, 4, 2, 1, 0, 0, 0, 0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 1, 2, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0,
+    0, 2, 1, 2, 1, 0, 0, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 1, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0,
+    0, 3, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 1, 1, 0, 2, 1, 0, 0, 0, 0, 0, 1, 0, 2, 1, 0,
+    0, 2, 0, 0, 2, 1, 0, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1,
+    0, 5, 1, 1, 0, 0, 0, 0, 0, 0, 4, 1, 1, 0, 0, 0, 0, 1, 3, 1, 1, 0, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0,
+    0, 2, 2, 1, 1, 0, 0, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 1, 0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0,
+    0, 3, 1, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 1, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0,
+    0, 2, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1,
+    0, 4, 0, 1, 1, 0, 0, 0, 0, 0, 3, 0, 1, 1, 0, 0, 0, 1, 2, 0, 1, 1, 0, 0, 0, 0, 0, 2, 0, 1, 1, 0,
+    0, 2, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1,
+    0, 3, 0, 0, 1, 1, 0, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1,
+    0, 2, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    1, 6, 0, 1, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 1, 4, 0, 1, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0,
+    0, 2, 3, 0, 1, 0, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0,
+    0, 3, 2, 0, 1, 0, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 0, 0, 0, 1, 2, 0, 1, 0,
+    0, 2, 0, 2, 0, 1, 0, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1,
+    0, 4, 1, 0, 1, 0, 0, 0, 0, 0, 3, 1, 0, 1, 0, 0, 0, 1, 2, 1, 0, 1, 0, 0, 0, 0, 0, 2, 1, 0, 1, 0,
+    0, 2, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1,
+    0, 3, 0, 1, 0, 1, 0, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 1,
+    0, 2, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0,
+    1, 5, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 1, 3, 0, 0, 1, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0,
+    0, 2, 2, 0, 0, 1, 0, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 1,
+    0, 3, 1, 0, 0, 1, 0, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1,
+    0, 2, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0,
+    1, 4, 0, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 1,
+    0, 2, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0,
+    1, 3, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0,
+    1, 2, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0,
+    0, 2, 5, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0,
+    0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0,
+    0, 2, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    0, 4, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0,
+    0, 2, 1, 3, 0, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0,
+    0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0,
+    0, 2, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0,
+    0, 5, 2, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0,
+    0, 2, 2, 2, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0,
+    0, 3, 1, 2, 0, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 1, 1, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0,
+    0, 2, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0,
+    0, 4, 0, 2, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0,
+    0, 2, 1, 0, 2, 0, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0,
+    0, 3, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0,
+    0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 6, 1, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0,
+    0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0,
+    0, 3, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0,
+    0, 2, 0, 2, 1, 0, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0,
+    0, 4, 1, 1, 0, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0,
+    0, 2, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0,
+    0, 3, 0, 1, 1, 0, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0,
+    0, 2, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1,
+    0, 5, 0, 1, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0,
+    0, 2, 2, 0, 1, 0, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0,
+    0, 3, 1, 0, 1, 0, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0,
+    0, 2, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1,
+    0, 4, 0, 0, 1, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0,
+    0, 2, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1,
+    0, 3, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1,
+    0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 7, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0,
+    0, 2, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0,
+    0, 2, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0,
+    0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0,
+    0, 2, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0,
+    0, 3, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0,
+    0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0,
+    0, 2, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0,
+    0, 3, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0,
+    0, 2, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0,
+    0, 4, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0,
+    0, 2, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0,
+    0, 3, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0,
+    0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+    0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
+    0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0,
+    0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0,
+    0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+    0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0,
+    0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+    0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+    0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+    0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+    0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0,
+    0, 0, 2, 8, 0, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 1, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0,
+    0, 0, 3, 7, 0, 0, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 1, 1, 7, 0, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0,
+    0, 0, 2, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 7, 0, 0, 0, 0, 1, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0,
+    0, 0, 4, 6, 0, 0, 0, 0, 0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 1, 2, 6, 0, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0,
+    0, 0, 2, 1, 6, 0, 0, 0, 0, 0, 0, 1, 1, 6, 0, 0, 0, 0, 1, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0,
+    0, 0, 3, 0, 6, 0, 0, 0, 0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 1, 1, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0,
+    0, 0, 2, 0, 0, 6, 0, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 6,
+    0, 0, 5, 5, 0, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 1, 3, 5, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0,
+    0, 0, 2, 2, 5, 0, 0, 0, 0, 0, 0, 1, 2, 5, 0, 0, 0, 0, 1, 0, 2, 5, 0, 0, 0, 0, 0, 0, 0, 2, 5, 0,
+    0, 0, 3, 1, 5, 0, 0, 0, 0, 0, 0, 2, 1, 5, 0, 0, 0, 0, 1, 1, 1, 5, 0, 0, 0, 0, 0, 0, 1, 1, 5, 0,
+    0, 0, 2, 0, 1, 5, 0, 0, 0, 0, 0, 1, 0, 1, 5, 0, 0, 0, 1, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 1, 5,
+    0, 0, 4, 0, 5, 0, 0, 0, 0, 0, 0, 3, 0, 5, 0, 0, 0, 0, 1, 2, 0, 5, 0, 0, 0, 0, 0, 0, 2, 0, 5, 0,
+    0, 0, 2, 1, 0, 5, 0, 0, 0, 0, 0, 1, 1, 0, 5, 0, 0, 0, 1, 0, 1, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 5,
+    0, 0, 3, 0, 0, 5, 0, 0, 0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 1, 1, 0, 0, 5, 0, 0, 0, 0, 0, 1, 0, 0, 5,
+    0, 0, 2, 0, 0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0,
+    5, 0, 6, 4, 0, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 1, 4, 4, 0, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0,
+    0, 0, 2, 3, 4, 0, 0, 0, 0, 0, 0, 1, 3, 4, 0, 0, 0, 0, 1, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0,
+    0, 0, 3, 2, 4, 0, 0, 0, 0, 0, 0, 2, 2, 4, 0, 0, 0, 0, 1, 1, 2, 4, 0, 0, 0, 0, 0, 0, 1, 2, 4, 0,
+    0, 0, 2, 0, 2, 4, 0, 0, 0, 0, 0, 1, 0, 2, 4, 0, 0, 0, 1, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 0, 2, 4,
+    0, 0, 4, 1, 4, 0, 0, 0, 0, 0, 0, 3, 1, 4, 0, 0, 0, 0, 1, 2, 1, 4, 0, 0, 0, 0, 0, 0, 2, 1, 4, 0,
+    0, 0, 2, 1, 1, 4, 0, 0, 0, 0, 0, 1, 1, 1, 4, 0, 0, 0, 1, 0, 1, 1, 4, 0, 0, 0, 0, 0, 0, 1, 1, 4,
+    0, 0, 3, 0, 1, 4, 0, 0, 0, 0, 0, 2, 0, 1, 4, 0, 0, 0, 1, 1, 0, 1, 4, 0, 0, 0, 0, 0, 1, 0, 1, 4,
+    0, 0, 2, 0, 0, 1, 4, 0, 0, 0, 0, 1, 0, 0, 1, 4, 0, 0, 1, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 1,
+    4, 0, 5, 0, 4, 0, 0, 0, 0, 0, 0, 4, 0, 4, 0, 0, 0, 0, 1, 3, 0, 4, 0, 0, 0, 0, 0, 0, 3, 0, 4, 0,
+    0, 0, 2, 2, 0, 4, 0, 0, 0, 0, 0, 1, 2, 0, 4, 0, 0, 0, 1, 0, 2, 0, 4, 0, 0, 0, 0, 0, 0, 2, 0, 4,
+    0, 0, 3, 1, 0, 4, 0, 0, 0, 0, 0, 2, 1, 0, 4, 0, 0, 0, 1, 1, 1, 0, 4, 0, 0, 0, 0, 0, 1, 1, 0, 4,
+    0, 0, 2, 0, 1, 0, 4, 0, 0, 0, 0, 1, 0, 1, 0, 4, 0, 0, 1, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0,
+    4, 0, 4, 0, 0, 4, 0, 0, 0, 0, 0, 3, 0, 0, 4, 0, 0, 0, 1, 2, 0, 0, 4, 0, 0, 0, 0, 0, 2, 0, 0, 4,
+    0, 0, 2, 1, 0, 0, 4, 0, 0, 0, 0, 1, 1, 0, 0, 4, 0, 0, 1, 0, 1, 0, 0, 4, 0, 0, 0, 0, 0, 1, 0, 0,
+    4, 0, 3, 0, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 1, 1, 0, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 0,
+    4, 0, 2, 0, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+    0, 4, 7, 3, 0, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 1, 5, 3, 0, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0,
+    0, 0, 2, 4, 3, 0, 0, 0, 0, 0, 0, 1, 4, 3, 0, 0, 0, 0, 1, 0, 4, 3, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0,
+    0, 0, 3, 3, 3, 0, 0, 0, 0, 0, 0, 2, 3, 3, 0, 0, 0, 0, 1, 1, 3, 3, 0, 0, 0, 0, 0, 0, 1, 3, 3, 0,
+    0, 0, 2, 0, 3, 3, 0, 0, 0, 0, 0, 1, 0, 3, 3, 0, 0, 0, 1, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3,
+    0, 0, 4, 2, 3, 0, 0, 0, 0, 0, 0, 3, 2, 3, 0, 0, 0, 0, 1, 2, 2, 3, 0, 0, 0, 0, 0, 0, 2, 2, 3, 0,
+    0, 0, 2, 1, 2, 3, 0, 0, 0, 0, 0, 1, 1, 2, 3, 0, 0, 0, 1, 0, 1, 2, 3, 0, 0, 0, 0, 0, 0, 1, 2, 3,
+    0, 0, 3, 0, 2, 3, 0, 0, 0, 0, 0, 2, 0, 2, 3, 0, 0, 0, 1, 1, 0, 2, 3, 0, 0, 0, 0, 0, 1, 0, 2, 3,
+    0, 0, 2, 0, 0, 2, 3, 0, 0, 0, 0, 1, 0, 0, 2, 3, 0, 0, 1, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 2,
+    3, 0, 5, 1, 3, 0, 0, 0, 0, 0, 0, 4, 1, 3, 0, 0, 0, 0, 1, 3, 1, 3, 0, 0, 0, 0, 0, 0, 3, 1, 3, 0,
+    0, 0, 2, 2, 1, 3, 0, 0, 0, 0, 0, 1, 2, 1, 3, 0, 0, 0, 1, 0, 2, 1, 3, 0, 0, 0, 0, 0, 0, 2, 1, 3,
+    0, 0, 3, 1, 1, 3, 0, 0, 0, 0, 0, 2, 1, 1, 3, 0, 0, 0, 1, 1, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 1, 3,
+    0, 0, 2, 0, 1, 1, 3, 0, 0, 0, 0, 1, 0, 1, 1, 3, 0, 0, 1, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 0, 1, 1,
+    3, 0, 4, 0, 1, 3, 0, 0, 0, 0, 0, 3, 0, 1, 3, 0, 0, 0, 1, 2, 0, 1, 3, 0, 0, 0, 0, 0, 2, 0, 1, 3,
+    0, 0, 2, 1, 0, 1, 3, 0, 0, 0, 0, 1, 1, 0, 1, 3, 0, 0, 1, 0, 1, 0, 1, 3, 0, 0, 0, 0, 0, 1, 0, 1,
+    3, 0, 3, 0, 0, 1, 3, 0, 0, 0, 0, 2, 0, 0, 1, 3, 0, 0, 1, 1, 0, 0, 1, 3, 0, 0, 0, 0, 1, 0, 0, 1,
+    3, 0, 2, 0, 0, 0, 1, 3, 0, 0, 0, 1, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0,
+    1, 3, 6, 0, 3, 0, 0, 0, 0, 0, 0, 5, 0, 3, 0, 0, 0, 0, 1, 4, 0, 3, 0, 0, 0, 0, 0, 0, 4, 0, 3, 0,
+    0, 0, 2, 3, 0, 3, 0, 0, 0, 0, 0, 1, 3, 0, 3, 0, 0, 0, 1, 0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 3, 0, 3,
+    0, 0, 3, 2, 0, 3, 0, 0, 0, 0, 0, 2, 2, 0, 3, 0, 0, 0, 1, 1, 2, 0, 3, 0, 0, 0, 0, 0, 1, 2, 0, 3,
+    0, 0, 2, 0, 2, 0, 3, 0, 0, 0, 0, 1, 0, 2, 0, 3, 0, 0, 1, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0,
+    3, 0, 4, 1, 0, 3, 0, 0, 0, 0, 0, 3, 1, 0, 3, 0, 0, 0, 1, 2, 1, 0, 3, 0, 0, 0, 0, 0, 2, 1, 0, 3,
+    0, 0, 2, 1, 1, 0, 3, 0, 0, 0, 0, 1, 1, 1, 0, 3, 0, 0, 1, 0, 1, 1, 0, 3, 0, 0, 0, 0, 0, 1, 1, 0,
+    3, 0, 3, 0, 1, 0, 3, 0, 0, 0, 0, 2, 0, 1, 0, 3, 0, 0, 1, 1, 0, 1, 0, 3, 0, 0, 0, 0, 1, 0, 1, 0,
+    3, 0, 2, 0, 0, 1, 0, 3, 0, 0, 0, 1, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 1,
+    0, 3, 5, 0, 0, 3, 0, 0, 0, 0, 0, 4, 0, 0, 3, 0, 0, 0, 1, 3, 0, 0, 3, 0, 0, 0, 0, 0, 3, 0, 0, 3,
+    0, 0, 2, 2, 0, 0, 3, 0, 0, 0, 0, 1, 2, 0, 0, 3, 0, 0, 1, 0, 2, 0, 0, 3, 0, 0, 0, 0, 0, 2, 0, 0,
+    3, 0, 3, 1, 0, 0, 3, 0, 0, 0, 0, 2, 1, 0, 0, 3, 0, 0, 1, 1, 1, 0, 0, 3, 0, 0, 0, 0, 1, 1, 0, 0,
+    3, 0, 2, 0, 1, 0, 0, 3, 0, 0, 0, 1, 0, 1, 0, 0, 3, 0, 1, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0,
+    0, 3, 4, 0, 0, 0, 3, 0, 0, 0, 0, 3, 0, 0, 0, 3, 0, 0, 1, 2, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0,
+    3, 0, 2, 1, 0, 0, 0, 3, 0, 0, 0, 1, 1, 0, 0, 0, 3, 0, 1, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0,
+    0, 3, 3, 0, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 0, 3, 0, 1, 1, 0, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0,
+    0, 3, 2, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0,
+    0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 1, 6, 2, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0,
+    0, 0, 2, 5, 2, 0, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0, 0, 0, 1, 0, 5, 2, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0,
+    0, 0, 3, 4, 2, 0, 0, 0, 0, 0, 0, 2, 4, 2, 0, 0, 0, 0, 1, 1, 4, 2, 0, 0, 0, 0, 0, 0, 1, 4, 2, 0,
+    0, 0, 2, 0, 4, 2, 0, 0, 0, 0, 0, 1, 0, 4, 2, 0, 0, 0, 1, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 4, 2,
+    0, 0, 4, 3, 2, 0, 0, 0, 0, 0, 0, 3, 3, 2, 0, 0, 0, 0, 1, 2, 3, 2, 0, 0, 0, 0, 0, 0, 2, 3, 2, 0,
+    0, 0, 2, 1, 3, 2, 0, 0, 0, 0, 0, 1, 1, 3, 2, 0, 0, 0, 1, 0, 1, 3, 2, 0, 0, 0, 0, 0, 0, 1, 3, 2,
+    0, 0, 3, 0, 3, 2, 0, 0, 0, 0, 0, 2, 0, 3, 2, 0, 0, 0, 1, 1, 0, 3, 2, 0, 0, 0, 0, 0, 1, 0, 3, 2,
+    0, 0, 2, 0, 0, 3, 2, 0, 0, 0, 0, 1, 0, 0, 3, 2, 0, 0, 1, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 3,
+    2, 0, 5, 2, 2, 0, 0, 0, 0, 0, 0, 4, 2, 2, 0, 0, 0, 0, 1, 3, 2, 2, 0, 0, 0, 0, 0, 0, 3, 2, 2, 0,
+    0, 0, 2, 2, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 2, 0, 0, 0, 1, 0, 2, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 2,
+    0, 0, 3, 1, 2, 2, 0, 0, 0, 0, 0, 2, 1, 2, 2, 0, 0, 0, 1, 1, 1, 2, 2, 0, 0, 0, 0, 0, 1, 1, 2, 2,
+    0, 0, 2, 0, 1, 2, 2, 0, 0, 0, 0, 1, 0, 1, 2, 2, 0, 0, 1, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 0, 1, 2,
+    2, 0, 4, 0, 2, 2, 0, 0, 0, 0, 0, 3, 0, 2, 2, 0, 0, 0, 1, 2, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 2,
+    0, 0, 2, 1, 0, 2, 2, 0, 0, 0, 0, 1, 1, 0, 2, 2, 0, 0, 1, 0, 1, 0, 2, 2, 0, 0, 0, 0, 0, 1, 0, 2,
+    2, 0, 3, 0, 0, 2, 2, 0, 0, 0, 0, 2, 0, 0, 2, 2, 0, 0, 1, 1, 0, 0, 2, 2, 0, 0, 0, 0, 1, 0, 0, 2,
+    2, 0, 2, 0, 0, 0, 2, 2, 0, 0, 0, 1, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0,
+    2, 2, 6, 1, 2, 0, 0, 0, 0, 0, 0, 5, 1, 2, 0, 0, 0, 0, 1, 4, 1, 2, 0, 0, 0, 0, 0, 0, 4, 1, 2, 0,
+    0, 0, 2, 3, 1, 2, 0, 0, 0, 0, 0, 1, 3, 1, 2, 0, 0, 0, 1, 0, 3, 1, 2, 0, 0, 0, 0, 0, 0, 3, 1, 2,
+    0, 0, 3, 2, 1, 2, 0, 0, 0, 0, 0, 2, 2, 1, 2, 0, 0, 0, 1, 1, 2, 1, 2, 0, 0, 0, 0, 0, 1, 2, 1, 2,
+    0, 0, 2, 0, 2, 1, 2, 0, 0, 0, 0, 1, 0, 2, 1, 2, 0, 0, 1, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 0, 2, 1,
+    2, 0, 4, 1, 1, 2, 0, 0, 0, 0, 0, 3, 1, 1, 2, 0, 0, 0, 1, 2, 1, 1, 2, 0, 0, 0, 0, 0, 2, 1, 1, 2,
+    0, 0, 2, 1, 1, 1, 2, 0, 0, 0, 0, 1, 1, 1, 1, 2, 0, 0, 1, 0, 1, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 1,
+    2, 0, 3, 0, 1, 1, 2, 0, 0, 0, 0, 2, 0, 1, 1, 2, 0, 0, 1, 1, 0, 1, 1, 2, 0, 0, 0, 0, 1, 0, 1, 1,
+    2, 0, 2, 0, 0, 1, 1, 2, 0, 0, 0, 1, 0, 0, 1, 1, 2, 0, 1, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 1,
+    1, 2, 5, 0, 1, 2, 0, 0, 0, 0, 0, 4, 0, 1, 2, 0, 0, 0, 1, 3, 0, 1, 2, 0, 0, 0, 0, 0, 3, 0, 1, 2,
+    0, 0, 2, 2, 0, 1, 2, 0, 0, 0, 0, 1, 2, 0, 1, 2, 0, 0, 1, 0, 2, 0, 1, 2, 0, 0, 0, 0, 0, 2, 0, 1,
+    2, 0, 3, 1, 0, 1, 2, 0, 0, 0, 0, 2, 1, 0, 1, 2, 0, 0, 1, 1, 1, 0, 1, 2, 0, 0, 0, 0, 1, 1, 0, 1,
+    2, 0, 2, 0, 1, 0, 1, 2, 0, 0, 0, 1, 0, 1, 0, 1, 2, 0, 1, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0,
+    1, 2, 4, 0, 0, 1, 2, 0, 0, 0, 0, 3, 0, 0, 1, 2, 0, 0, 1, 2, 0, 0, 1, 2, 0, 0, 0, 0, 2, 0, 0, 1,
+    2, 0, 2, 1, 0, 0, 1, 2, 0, 0, 0, 1, 1, 0, 0, 1, 2, 0, 1, 0, 1, 0, 0, 1, 2, 0, 0, 0, 0, 1, 0, 0,
+    1, 2, 3, 0, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 0, 1, 2, 0, 1, 1, 0, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 0,
+    1, 2, 2, 0, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0,
+    0, 1, 7, 0, 2, 0, 0, 0, 0, 0, 0, 6, 0, 2, 0, 0, 0, 0, 1, 5, 0, 2, 0, 0, 0, 0, 0, 0, 5, 0, 2, 0,
+    0, 0, 2, 4, 0, 2, 0, 0, 0, 0, 0, 1, 4, 0, 2, 0, 0, 0, 1, 0, 4, 0, 2, 0, 0, 0, 0, 0, 0, 4, 0, 2,
+    0, 0, 3, 3, 0, 2, 0, 0, 0, 0, 0, 2, 3, 0, 2, 0, 0, 0, 1, 1, 3, 0, 2, 0, 0, 0, 0, 0, 1, 3, 0, 2,
+    0, 0, 2, 0, 3, 0, 2, 0, 0, 0, 0, 1, 0, 3, 0, 2, 0, 0, 1, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 0, 3, 0,
+    2, 0, 4, 2, 0, 2, 0, 0, 0, 0, 0, 3, 2, 0, 2, 0, 0, 0, 1, 2, 2, 0, 2, 0, 0, 0, 0, 0, 2, 2, 0, 2,
+    0, 0, 2, 1, 2, 0, 2, 0, 0, 0, 0, 1, 1, 2, 0, 2, 0, 0, 1, 0, 1, 2, 0, 2, 0, 0, 0, 0, 0, 1, 2, 0,
+    2, 0, 3, 0, 2, 0, 2, 0, 0, 0, 0, 2, 0, 2, 0, 2, 0, 0, 1, 1, 0, 2, 0, 2, 0, 0, 0, 0, 1, 0, 2, 0,
+    2, 0, 2, 0, 0, 2, 0, 2, 0, 0, 0, 1, 0, 0, 2, 0, 2, 0, 1, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 2,
+    0, 2, 5, 1, 0, 2, 0, 0, 0, 0, 0, 4, 1, 0, 2, 0, 0, 0, 1, 3, 1, 0, 2, 0, 0, 0, 0, 0, 3, 1, 0, 2,
+    0, 0, 2, 2, 1, 0, 2, 0, 0, 0, 0, 1, 2, 1, 0, 2, 0, 0, 1, 0, 2, 1, 0, 2, 0, 0, 0, 0, 0, 2, 1, 0,
+    2, 0, 3, 1, 1, 0, 2, 0, 0, 0, 0, 2, 1, 1, 0, 2, 0, 0, 1, 1, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 1, 0,
+    2, 0, 2, 0, 1, 1, 0, 2, 0, 0, 0, 1, 0, 1, 1, 0, 2, 0, 1, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 0, 1, 1,
+    0, 2, 4, 0, 1, 0, 2, 0, 0, 0, 0, 3, 0, 1, 0, 2, 0, 0, 1, 2, 0, 1, 0, 2, 0, 0, 0, 0, 2, 0, 1, 0,
+    2, 0, 2, 1, 0, 1, 0, 2, 0, 0, 0, 1, 1, 0, 1, 0, 2, 0, 1, 0, 1, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 1,
+    0, 2, 3, 0, 0, 1, 0, 2, 0, 0, 0, 2, 0, 0, 1, 0, 2, 0, 1, 1, 0, 0, 1, 0, 2, 0, 0, 0, 1, 0, 0, 1,
+    0, 2, 2, 0, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0,
+    1, 0, 6, 0, 0, 2, 0, 0, 0, 0, 0, 5, 0, 0, 2, 0, 0, 0, 1, 4, 0, 0, 2, 0, 0, 0, 0, 0, 4, 0, 0, 2,
+    0, 0, 2, 3, 0, 0, 2, 0, 0, 0, 0, 1, 3, 0, 0, 2, 0, 0, 1, 0, 3, 0, 0, 2, 0, 0, 0, 0, 0, 3, 0, 0,
+    2, 0, 3, 2, 0, 0, 2, 0, 0, 0, 0, 2, 2, 0, 0, 2, 0, 0, 1, 1, 2, 0, 0, 2, 0, 0, 0, 0, 1, 2, 0, 0,
+    2, 0, 2, 0, 2, 0, 0, 2, 0, 0, 0, 1, 0, 2, 0, 0, 2, 0, 1, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0,
+    0, 2, 4, 1, 0, 0, 2, 0, 0, 0, 0, 3, 1, 0, 0, 2, 0, 0, 1, 2, 1, 0, 0, 2, 0, 0, 0, 0, 2, 1, 0, 0,
+    2, 0, 2, 1, 1, 0, 0, 2, 0, 0, 0, 1, 1, 1, 0, 0, 2, 0, 1, 0, 1, 1, 0, 0, 2, 0, 0, 0, 0, 1, 1, 0,
+    0, 2, 3, 0, 1, 0, 0, 2, 0, 0, 0, 2, 0, 1, 0, 0, 2, 0, 1, 1, 0, 1, 0, 0, 2, 0, 0, 0, 1, 0, 1, 0,
+    0, 2, 2, 0, 0, 1, 0, 0, 2, 0, 0, 1, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 1,
+    0, 0, 5, 0, 0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 1, 3, 0, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 0,
+    2, 0, 2, 2, 0, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0,
+    0, 2, 3, 1, 0, 0, 0, 2, 0, 0, 0, 2, 1, 0, 0, 0, 2, 0, 1, 1, 1, 0, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0,
+    0, 2, 2, 0, 1, 0, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0,
+    0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0,
+    0, 2, 2, 1, 0, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0,
+    0, 0, 3, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0,
+    0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 1, 7, 1, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0,
+    0, 0, 2, 6, 1, 0, 0, 0, 0, 0, 0, 1, 6, 1, 0, 0, 0, 0, 1, 0, 6, 1, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0,
+    0, 0, 3, 5, 1, 0, 0, 0, 0, 0, 0, 2, 5, 1, 0, 0, 0, 0, 1, 1, 5, 1, 0, 0, 0, 0, 0, 0, 1, 5, 1, 0,
+    0, 0, 2, 0, 5, 1, 0, 0, 0, 0, 0, 1, 0, 5, 1, 0, 0, 0, 1, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 5, 1,
+    0, 0, 4, 4, 1, 0, 0, 0, 0, 0, 0, 3, 4, 1, 0, 0, 0, 0, 1, 2, 4, 1, 0, 0, 0, 0, 0, 0, 2, 4, 1, 0,
+    0, 0, 2, 1, 4, 1, 0, 0, 0, 0, 0, 1, 1, 4, 1, 0, 0, 0, 1, 0, 1, 4, 1, 0, 0, 0, 0, 0, 0, 1, 4, 1,
+    0, 0, 3, 0, 4, 1, 0, 0, 0, 0, 0, 2, 0, 4, 1, 0, 0, 0, 1, 1, 0, 4, 1, 0, 0, 0, 0, 0, 1, 0, 4, 1,
+    0, 0, 2, 0, 0, 4, 1, 0, 0, 0, 0, 1, 0, 0, 4, 1, 0, 0, 1, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 4,
+    1, 0, 5, 3, 1, 0, 0, 0, 0, 0, 0, 4, 3, 1, 0, 0, 0, 0, 1, 3, 3, 1, 0, 0, 0, 0, 0, 0, 3, 3, 1, 0,
+    0, 0, 2, 2, 3, 1, 0, 0, 0, 0, 0, 1, 2, 3, 1, 0, 0, 0, 1, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 2, 3, 1,
+    0, 0, 3, 1, 3, 1, 0, 0, 0, 0, 0, 2, 1, 3, 1, 0, 0, 0, 1, 1, 1, 3, 1, 0, 0, 0, 0, 0, 1, 1, 3, 1,
+    0, 0, 2, 0, 1, 3, 1, 0, 0, 0, 0, 1, 0, 1, 3, 1, 0, 0, 1, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 0, 1, 3,
+    1, 0, 4, 0, 3, 1, 0, 0, 0, 0, 0, 3, 0, 3, 1, 0, 0, 0, 1, 2, 0, 3, 1, 0, 0, 0, 0, 0, 2, 0, 3, 1,
+    0, 0, 2, 1, 0, 3, 1, 0, 0, 0, 0, 1, 1, 0, 3, 1, 0, 0, 1, 0, 1, 0, 3, 1, 0, 0, 0, 0, 0, 1, 0, 3,
+    1, 0, 3, 0, 0, 3, 1, 0, 0, 0, 0, 2, 0, 0, 3, 1, 0, 0, 1, 1, 0, 0, 3, 1, 0, 0, 0, 0, 1, 0, 0, 3,
+    1, 0, 2, 0, 0, 0, 3, 1, 0, 0, 0, 1, 0, 0, 0, 3, 1, 0, 1, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0,
+    3, 1, 6, 2, 1, 0, 0, 0, 0, 0, 0, 5, 2, 1, 0, 0, 0, 0, 1, 4, 2, 1, 0, 0, 0, 0, 0, 0, 4, 2, 1, 0,
+    0, 0, 2, 3, 2, 1, 0, 0, 0, 0, 0, 1, 3, 2, 1, 0, 0, 0, 1, 0, 3, 2, 1, 0, 0, 0, 0, 0, 0, 3, 2, 1,
+    0, 0, 3, 2, 2, 1, 0, 0, 0, 0, 0, 2, 2, 2, 1, 0, 0, 0, 1, 1, 2, 2, 1, 0, 0, 0, 0, 0, 1, 2, 2, 1,
+    0, 0, 2, 0, 2, 2, 1, 0, 0, 0, 0, 1, 0, 2, 2, 1, 0, 0, 1, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2,
+    1, 0, 4, 1, 2, 1, 0, 0, 0, 0, 0, 3, 1, 2, 1, 0, 0, 0, 1, 2, 1, 2, 1, 0, 0, 0, 0, 0, 2, 1, 2, 1,
+    0, 0, 2, 1, 1, 2, 1, 0, 0, 0, 0, 1, 1, 1, 2, 1, 0, 0, 1, 0, 1, 1, 2, 1, 0, 0, 0, 0, 0, 1, 1, 2,
+    1, 0, 3, 0, 1, 2, 1, 0, 0, 0, 0, 2, 0, 1, 2, 1, 0, 0, 1, 1, 0, 1, 2, 1, 0, 0, 0, 0, 1, 0, 1, 2,
+    1, 0, 2, 0, 0, 1, 2, 1, 0, 0, 0, 1, 0, 0, 1, 2, 1, 0, 1, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 1,
+    2, 1, 5, 0, 2, 1, 0, 0, 0, 0, 0, 4, 0, 2, 1, 0, 0, 0, 1, 3, 0, 2, 1, 0, 0, 0, 0, 0, 3, 0, 2, 1,
+    0, 0, 2, 2, 0, 2, 1, 0, 0, 0, 0, 1, 2, 0, 2, 1, 0, 0, 1, 0, 2, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 2,
+    1, 0, 3, 1, 0, 2, 1, 0, 0, 0, 0, 2, 1, 0, 2, 1, 0, 0, 1, 1, 1, 0, 2, 1, 0, 0, 0, 0, 1, 1, 0, 2,
+    1, 0, 2, 0, 1, 0, 2, 1, 0, 0, 0, 1, 0, 1, 0, 2, 1, 0, 1, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 0, 1, 0,
+    2, 1, 4, 0, 0, 2, 1, 0, 0, 0, 0, 3, 0, 0, 2, 1, 0, 0, 1, 2, 0, 0, 2, 1, 0, 0, 0, 0, 2, 0, 0, 2,
+    1, 0, 2, 1, 0, 0, 2, 1, 0, 0, 0, 1, 1, 0, 0, 2, 1, 0, 1, 0, 1, 0, 0, 2, 1, 0, 0, 0, 0, 1, 0, 0,
+    2, 1, 3, 0, 0, 0, 2, 1, 0, 0, 0, 2, 0, 0, 0, 2, 1, 0, 1, 1, 0, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 0,
+    2, 1, 2, 0, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0,
+    0, 2, 7, 1, 1, 0, 0, 0, 0, 0, 0, 6, 1, 1, 0, 0, 0, 0, 1, 5, 1, 1, 0, 0, 0, 0, 0, 0, 5, 1, 1, 0,
+    0, 0, 2, 4, 1, 1, 0, 0, 0, 0, 0, 1, 4, 1, 1, 0, 0, 0, 1, 0, 4, 1, 1, 0, 0, 0, 0, 0, 0, 4, 1, 1,
+    0, 0, 3, 3, 1, 1, 0, 0, 0, 0, 0, 2, 3, 1, 1, 0, 0, 0, 1, 1, 3, 1, 1, 0, 0, 0, 0, 0, 1, 3, 1, 1,
+    0, 0, 2, 0, 3, 1, 1, 0, 0, 0, 0, 1, 0, 3, 1, 1, 0, 0, 1, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 0, 3, 1,
+    1, 0, 4, 2, 1, 1, 0, 0, 0, 0, 0, 3, 2, 1, 1, 0, 0, 0, 1, 2, 2, 1, 1, 0, 0, 0, 0, 0, 2, 2, 1, 1,
+    0, 0, 2, 1, 2, 1, 1, 0, 0, 0, 0, 1, 1, 2, 1, 1, 0, 0, 1, 0, 1, 2, 1, 1, 0, 0, 0, 0, 0, 1, 2, 1,
+    1, 0, 3, 0, 2, 1, 1, 0, 0, 0, 0, 2, 0, 2, 1, 1, 0, 0, 1, 1, 0, 2, 1, 1, 0, 0, 0, 0, 1, 0, 2, 1,
+    1, 0, 2, 0, 0, 2, 1, 1, 0, 0, 0, 1, 0, 0, 2, 1, 1, 0, 1, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 2,
+    1, 1, 5, 1, 1, 1, 0, 0, 0, 0, 0, 4, 1, 1, 1, 0, 0, 0, 1, 3, 1, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1, 1,
+    0, 0, 2, 2, 1, 1, 1, 0, 0, 0, 0, 1, 2, 1, 1, 1, 0, 0, 1, 0, 2, 1, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1,
+    1, 0, 3, 1, 1, 1, 1, 0, 0, 0, 0, 2, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1,
+    1, 0, 2, 0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 1, 0, 1, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1,
+    1, 1, 4, 0, 1, 1, 1, 0, 0, 0, 0, 3, 0, 1, 1, 1, 0, 0, 1, 2, 0, 1, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1,
+    1, 0, 2, 1, 0, 1, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 1, 0, 1, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1,
+    1, 1, 3, 0, 0, 1, 1, 1, 0, 0, 0, 2, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1,
+    1, 1, 2, 0, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0,
+    1, 1, 6, 0, 1, 1, 0, 0, 0, 0, 0, 5, 0, 1, 1, 0, 0, 0, 1, 4, 0, 1, 1, 0, 0, 0, 0, 0, 4, 0, 1, 1,
+    0, 0, 2, 3, 0, 1, 1, 0, 0, 0, 0, 1, 3, 0, 1, 1, 0, 0, 1, 0, 3, 0, 1, 1, 0, 0, 0, 0, 0, 3, 0, 1,
+    1, 0, 3, 2, 0, 1, 1, 0, 0, 0, 0, 2, 2, 0, 1, 1, 0, 0, 1, 1, 2, 0, 1, 1, 0, 0, 0, 0, 1, 2, 0, 1,
+    1, 0, 2, 0, 2, 0, 1, 1, 0, 0, 0, 1, 0, 2, 0, 1, 1, 0, 1, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 0, 2, 0,
+    1, 1, 4, 1, 0, 1, 1, 0, 0, 0, 0, 3, 1, 0, 1, 1, 0, 0, 1, 2, 1, 0, 1, 1, 0, 0, 0, 0, 2, 1, 0, 1,
+    1, 0, 2, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 0, 1, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0,
+    1, 1, 3, 0, 1, 0, 1, 1, 0, 0, 0, 2, 0, 1, 0, 1, 1, 0, 1, 1, 0, 1, 0, 1, 1, 0, 0, 0, 1, 0, 1, 0,
+    1, 1, 2, 0, 0, 1, 0, 1, 1, 0, 0, 1, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1,
+    0, 1, 5, 0, 0, 1, 1, 0, 0, 0, 0, 4, 0, 0, 1, 1, 0, 0, 1, 3, 0, 0, 1, 1, 0, 0, 0, 0, 3, 0, 0, 1,
+    1, 0, 2, 2, 0, 0, 1, 1, 0, 0, 0, 1, 2, 0, 0, 1, 1, 0, 1, 0, 2, 0, 0, 1, 1, 0, 0, 0, 0, 2, 0, 0,
+    1, 1, 3, 1, 0, 0, 1, 1, 0, 0, 0, 2, 1, 0, 0, 1, 1, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
+    1, 1, 2, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0,
+    0, 1, 4, 0, 0, 0, 1, 1, 0, 0, 0, 3, 0, 0, 0, 1, 1, 0, 1, 2, 0, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 0,
+    1, 1, 2, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0,
+    0, 1, 3, 0, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0,
+    0, 1, 2, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+    0, 0, 8, 0, 1, 0, 0, 0, 0, 0, 0, 7, 0, 1, 0, 0, 0, 0, 1, 6, 0, 1, 0, 0, 0, 0, 0, 0, 6, 0, 1, 0,
+    0, 0, 2, 5, 0, 1, 0, 0, 0, 0, 0, 1, 5, 0, 1, 0, 0, 0, 1, 0, 5, 0, 1, 0, 0, 0, 0, 0, 0, 5, 0, 1,
+    0, 0, 3, 4, 0, 1, 0, 0, 0, 0, 0, 2, 4, 0, 1, 0, 0, 0, 1, 1, 4, 0, 1, 0, 0, 0, 0, 0, 1, 4, 0, 1,
+    0, 0, 2, 0, 4, 0, 1, 0, 0, 0, 0, 1, 0, 4, 0, 1, 0, 0, 1, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 0, 4, 0,
+    1, 0, 4, 3, 0, 1, 0, 0, 0, 0, 0, 3, 3, 0, 1, 0, 0, 0, 1, 2, 3, 0, 1, 0, 0, 0, 0, 0, 2, 3, 0, 1,
+    0, 0, 2, 1, 3, 0, 1, 0, 0, 0, 0, 1, 1, 3, 0, 1, 0, 0, 1, 0, 1, 3, 0, 1, 0, 0, 0, 0, 0, 1, 3, 0,
+    1, 0, 3, 0, 3, 0, 1, 0, 0, 0, 0, 2, 0, 3, 0, 1, 0, 0, 1, 1, 0, 3, 0, 1, 0, 0, 0, 0, 1, 0, 3, 0,
+    1, 0, 2, 0, 0, 3, 0, 1, 0, 0, 0, 1, 0, 0, 3, 0, 1, 0, 1, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 3,
+    0, 1, 5, 2, 0, 1, 0, 0, 0, 0, 0, 4, 2, 0, 1, 0, 0, 0, 1, 3, 2, 0, 1, 0, 0, 0, 0, 0, 3, 2, 0, 1,
+    0, 0, 2, 2, 2, 0, 1, 0, 0, 0, 0, 1, 2, 2, 0, 1, 0, 0, 1, 0, 2, 2, 0, 1, 0, 0, 0, 0, 0, 2, 2, 0,
+    1, 0, 3, 1, 2, 0, 1, 0, 0, 0, 0, 2, 1, 2, 0, 1, 0, 0, 1, 1, 1, 2, 0, 1, 0, 0, 0, 0, 1, 1, 2, 0,
+    1, 0, 2, 0, 1, 2, 0, 1, 0, 0, 0, 1, 0, 1, 2, 0, 1, 0, 1, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 0, 1, 2,
+    0, 1, 4, 0, 2, 0, 1, 0, 0, 0, 0, 3, 0, 2, 0, 1, 0, 0, 1, 2, 0, 2, 0, 1, 0, 0, 0, 0, 2, 0, 2, 0,
+    1, 0, 2, 1, 0, 2, 0, 1, 0, 0, 0, 1, 1, 0, 2, 0, 1, 0, 1, 0, 1, 0, 2, 0, 1, 0, 0, 0, 0, 1, 0, 2,
+    0, 1, 3, 0, 0, 2, 0, 1, 0, 0, 0, 2, 0, 0, 2, 0, 1, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 0, 1, 0, 0, 2,
+    0, 1, 2, 0, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0,
+    2, 0, 6, 1, 0, 1, 0, 0, 0, 0, 0, 5, 1, 0, 1, 0, 0, 0, 1, 4, 1, 0, 1, 0, 0, 0, 0, 0, 4, 1, 0, 1,
+    0, 0, 2, 3, 1, 0, 1, 0, 0, 0, 0, 1, 3, 1, 0, 1, 0, 0, 1, 0, 3, 1, 0, 1, 0, 0, 0, 0, 0, 3, 1, 0,
+    1, 0, 3, 2, 1, 0, 1, 0, 0, 0, 0, 2, 2, 1, 0, 1, 0, 0, 1, 1, 2, 1, 0, 1, 0, 0, 0, 0, 1, 2, 1, 0,
+    1, 0, 2, 0, 2, 1, 0, 1, 0, 0, 0, 1, 0, 2, 1, 0, 1, 0, 1, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 0, 2, 1,
+    0, 1, 4, 1, 1, 0, 1, 0, 0, 0, 0, 3, 1, 1, 0, 1, 0, 0, 1, 2, 1, 1, 0, 1, 0, 0, 0, 0, 2, 1, 1, 0,
+    1, 0, 2, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 1, 0, 1, 0, 1, 0, 1, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1,
+    0, 1, 3, 0, 1, 1, 0, 1, 0, 0, 0, 2, 0, 1, 1, 0, 1, 0, 1, 1, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 1,
+    0, 1, 2, 0, 0, 1, 1, 0, 1, 0, 0, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1,
+    1, 0, 5, 0, 1, 0, 1, 0, 0, 0, 0, 4, 0, 1, 0, 1, 0, 0, 1, 3, 0, 1, 0, 1, 0, 0, 0, 0, 3, 0, 1, 0,
+    1, 0, 2, 2, 0, 1, 0, 1, 0, 0, 0, 1, 2, 0, 1, 0, 1, 0, 1, 0, 2, 0, 1, 0, 1, 0, 0, 0, 0, 2, 0, 1,
+    0, 1, 3, 1, 0, 1, 0, 1, 0, 0, 0, 2, 1, 0, 1, 0, 1, 0, 1, 1, 1, 0, 1, 0, 1, 0, 0, 0, 1, 1, 0, 1,
+    0, 1, 2, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0,
+    1, 0, 4, 0, 0, 1, 0, 1, 0, 0, 0, 3, 0, 0, 1, 0, 1, 0, 1, 2, 0, 0, 1, 0, 1, 0, 0, 0, 2, 0, 0, 1,
+    0, 1, 2, 1, 0, 0, 1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0,
+    1, 0, 3, 0, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 0,
+    1, 0, 2, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 7, 0, 0, 1, 0, 0, 0, 0, 0, 6, 0, 0, 1, 0, 0, 0, 1, 5, 0, 0, 1, 0, 0, 0, 0, 0, 5, 0, 0, 1,
+    0, 0, 2, 4, 0, 0, 1, 0, 0, 0, 0, 1, 4, 0, 0, 1, 0, 0, 1, 0, 4, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0,
+    1, 0, 3, 3, 0, 0, 1, 0, 0, 0, 0, 2, 3, 0, 0, 1, 0, 0, 1, 1, 3, 0, 0, 1, 0, 0, 0, 0, 1, 3, 0, 0,
+    1, 0, 2, 0, 3, 0, 0, 1, 0, 0, 0, 1, 0, 3, 0, 0, 1, 0, 1, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 0, 3, 0,
+    0, 1, 4, 2, 0, 0, 1, 0, 0, 0, 0, 3, 2, 0, 0, 1, 0, 0, 1, 2, 2, 0, 0, 1, 0, 0, 0, 0, 2, 2, 0, 0,
+    1, 0, 2, 1, 2, 0, 0, 1, 0, 0, 0, 1, 1, 2, 0, 0, 1, 0, 1, 0, 1, 2, 0, 0, 1, 0, 0, 0, 0, 1, 2, 0,
+    0, 1, 3, 0, 2, 0, 0, 1, 0, 0, 0, 2, 0, 2, 0, 0, 1, 0, 1, 1, 0, 2, 0, 0, 1, 0, 0, 0, 1, 0, 2, 0,
+    0, 1, 2, 0, 0, 2, 0, 0, 1, 0, 0, 1, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 2,
+    0, 0, 5, 1, 0, 0, 1, 0, 0, 0, 0, 4, 1, 0, 0, 1, 0, 0, 1, 3, 1, 0, 0, 1, 0, 0, 0, 0, 3, 1, 0, 0,
+    1, 0, 2, 2, 1, 0, 0, 1, 0, 0, 0, 1, 2, 1, 0, 0, 1, 0, 1, 0, 2, 1, 0, 0, 1, 0, 0, 0, 0, 2, 1, 0,
+    0, 1, 3, 1, 1, 0, 0, 1, 0, 0, 0, 2, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 1, 0,
+    0, 1, 2, 0, 1, 1, 0, 0, 1, 0, 0, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1,
+    0, 0, 4, 0, 1, 0, 0, 1, 0, 0, 0, 3, 0, 1, 0, 0, 1, 0, 1, 2, 0, 1, 0, 0, 1, 0, 0, 0, 2, 0, 1, 0,
+    0, 1, 2, 1, 0, 1, 0, 0, 1, 0, 0, 1, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 1,
+    0, 0, 3, 0, 0, 1, 0, 0, 1, 0, 0, 2, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1,
+    0, 0, 2, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 6, 0, 0, 0, 1, 0, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 1, 4, 0, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 0,
+    1, 0, 2, 3, 0, 0, 0, 1, 0, 0, 0, 1, 3, 0, 0, 0, 1, 0, 1, 0, 3, 0, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0,
+    0, 1, 3, 2, 0, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 0, 1, 0, 1, 1, 2, 0, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0,
+    0, 1, 2, 0, 2, 0, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0,
+    0, 0, 4, 1, 0, 0, 0, 1, 0, 0, 0, 3, 1, 0, 0, 0, 1, 0, 1, 2, 1, 0, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0,
+    0, 1, 2, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0,
+    0, 0, 3, 0, 1, 0, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0,
+    0, 0, 2, 0, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0,
+    0, 1, 2, 2, 0, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0,
+    0, 0, 3, 1, 0, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0,
+    0, 0, 2, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 4, 0, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0,
+    0, 0, 2, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+    0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0,
+    0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 1, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0,
+    0, 0, 0, 3, 6, 0, 0, 0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 1, 1, 6, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0,
+    0, 0, 0, 2, 0, 6, 0, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 1, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 6,
+    0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 1, 2, 5, 0, 0, 0, 0, 0, 0, 0, 2, 5, 0,
+    0, 0, 0, 2, 1, 5, 0, 0, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0, 1, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 1, 5,
+    0, 0, 0, 3, 0, 5, 0, 0, 0, 0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 1, 1, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 5,
+    0, 0, 0, 2, 0, 0, 5, 0, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0,
+    5, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 1, 3, 4, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0,
+    0, 0, 0, 2, 2, 4, 0, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 1, 0, 2, 4, 0, 0, 0, 0, 0, 0, 0, 2, 4,
+    0, 0, 0, 3, 1, 4, 0, 0, 0, 0, 0, 0, 2, 1, 4, 0, 0, 0, 0, 1, 1, 1, 4, 0, 0, 0, 0, 0, 0, 1, 1, 4,
+    0, 0, 0, 2, 0, 1, 4, 0, 0, 0, 0, 0, 1, 0, 1, 4, 0, 0, 0, 1, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 1,
+    4, 0, 0, 4, 0, 4, 0, 0, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 1, 2, 0, 4, 0, 0, 0, 0, 0, 0, 2, 0, 4,
+    0, 0, 0, 2, 1, 0, 4, 0, 0, 0, 0, 0, 1, 1, 0, 4, 0, 0, 0, 1, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0,
+    4, 0, 0, 3, 0, 0, 4, 0, 0, 0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 1, 1, 0, 0, 4, 0, 0, 0, 0, 0, 1, 0, 0,
+    4, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+    0, 4, 0, 6, 3, 0, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 1, 4, 3, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0,
+    0, 0, 0, 2, 3, 3, 0, 0, 0, 0, 0, 0, 1, 3, 3, 0, 0, 0, 0, 1, 0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3,
+    0, 0, 0, 3, 2, 3, 0, 0, 0, 0, 0, 0, 2, 2, 3, 0, 0, 0, 0, 1, 1, 2, 3, 0, 0, 0, 0, 0, 0, 1, 2, 3,
+    0, 0, 0, 2, 0, 2, 3, 0, 0, 0, 0, 0, 1, 0, 2, 3, 0, 0, 0, 1, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 2,
+    3, 0, 0, 4, 1, 3, 0, 0, 0, 0, 0, 0, 3, 1, 3, 0, 0, 0, 0, 1, 2, 1, 3, 0, 0, 0, 0, 0, 0, 2, 1, 3,
+    0, 0, 0, 2, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 1, 3, 0, 0, 0, 1, 0, 1, 1, 3, 0, 0, 0, 0, 0, 0, 1, 1,
+    3, 0, 0, 3, 0, 1, 3, 0, 0, 0, 0, 0, 2, 0, 1, 3, 0, 0, 0, 1, 1, 0, 1, 3, 0, 0, 0, 0, 0, 1, 0, 1,
+    3, 0, 0, 2, 0, 0, 1, 3, 0, 0, 0, 0, 1, 0, 0, 1, 3, 0, 0, 1, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0,
+    1, 3, 0, 5, 0, 3, 0, 0, 0, 0, 0, 0, 4, 0, 3, 0, 0, 0, 0, 1, 3, 0, 3, 0, 0, 0, 0, 0, 0, 3, 0, 3,
+    0, 0, 0, 2, 2, 0, 3, 0, 0, 0, 0, 0, 1, 2, 0, 3, 0, 0, 0, 1, 0, 2, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0,
+    3, 0, 0, 3, 1, 0, 3, 0, 0, 0, 0, 0, 2, 1, 0, 3, 0, 0, 0, 1, 1, 1, 0, 3, 0, 0, 0, 0, 0, 1, 1, 0,
+    3, 0, 0, 2, 0, 1, 0, 3, 0, 0, 0, 0, 1, 0, 1, 0, 3, 0, 0, 1, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 1,
+    0, 3, 0, 4, 0, 0, 3, 0, 0, 0, 0, 0, 3, 0, 0, 3, 0, 0, 0, 1, 2, 0, 0, 3, 0, 0, 0, 0, 0, 2, 0, 0,
+    3, 0, 0, 2, 1, 0, 0, 3, 0, 0, 0, 0, 1, 1, 0, 0, 3, 0, 0, 1, 0, 1, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0,
+    0, 3, 0, 3, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 1, 1, 0, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0,
+    0, 3, 0, 2, 0, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0,
+    0, 0, 3, 7, 2, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0,
+    0, 0, 0, 2, 4, 2, 0, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0, 0, 1, 0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 4, 2,
+    0, 0, 0, 3, 3, 2, 0, 0, 0, 0, 0, 0, 2, 3, 2, 0, 0, 0, 0, 1, 1, 3, 2, 0, 0, 0, 0, 0, 0, 1, 3, 2,
+    0, 0, 0, 2, 0, 3, 2, 0, 0, 0, 0, 0, 1, 0, 3, 2, 0, 0, 0, 1, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 3,
+    2, 0, 0, 4, 2, 2, 0, 0, 0, 0, 0, 0, 3, 2, 2, 0, 0, 0, 0, 1, 2, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 2,
+    0, 0, 0, 2, 1, 2, 2, 0, 0, 0, 0, 0, 1, 1, 2, 2, 0, 0, 0, 1, 0, 1, 2, 2, 0, 0, 0, 0, 0, 0, 1, 2,
+    2, 0, 0, 3, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 1, 1, 0, 2, 2, 0, 0, 0, 0, 0, 1, 0, 2,
+    2, 0, 0, 2, 0, 0, 2, 2, 0, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0,
+    2, 2, 0, 5, 1, 2, 0, 0, 0, 0, 0, 0, 4, 1, 2, 0, 0, 0, 0, 1, 3, 1, 2, 0, 0, 0, 0, 0, 0, 3, 1, 2,
+    0, 0, 0, 2, 2, 1, 2, 0, 0, 0, 0, 0, 1, 2, 1, 2, 0, 0, 0, 1, 0, 2, 1, 2, 0, 0, 0, 0, 0, 0, 2, 1,
+    2, 0, 0, 3, 1, 1, 2, 0, 0, 0, 0, 0, 2, 1, 1, 2, 0, 0, 0, 1, 1, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 1,
+    2, 0, 0, 2, 0, 1, 1, 2, 0, 0, 0, 0, 1, 0, 1, 1, 2, 0, 0, 1, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 1,
+    1, 2, 0, 4, 0, 1, 2, 0, 0, 0, 0, 0, 3, 0, 1, 2, 0, 0, 0, 1, 2, 0, 1, 2, 0, 0, 0, 0, 0, 2, 0, 1,
+    2, 0, 0, 2, 1, 0, 1, 2, 0, 0, 0, 0, 1, 1, 0, 1, 2, 0, 0, 1, 0, 1, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0,
+    1, 2, 0, 3, 0, 0, 1, 2, 0, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 1, 1, 0, 0, 1, 2, 0, 0, 0, 0, 1, 0, 0,
+    1, 2, 0, 2, 0, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0,
+    0, 1, 2, 6, 0, 2, 0, 0, 0, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 1, 4, 0, 2, 0, 0, 0, 0, 0, 0, 4, 0, 2,
+    0, 0, 0, 2, 3, 0, 2, 0, 0, 0, 0, 0, 1, 3, 0, 2, 0, 0, 0, 1, 0, 3, 0, 2, 0, 0, 0, 0, 0, 0, 3, 0,
+    2, 0, 0, 3, 2, 0, 2, 0, 0, 0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 1, 1, 2, 0, 2, 0, 0, 0, 0, 0, 1, 2, 0,
+    2, 0, 0, 2, 0, 2, 0, 2, 0, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 1, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 2,
+    0, 2, 0, 4, 1, 0, 2, 0, 0, 0, 0, 0, 3, 1, 0, 2, 0, 0, 0, 1, 2, 1, 0, 2, 0, 0, 0, 0, 0, 2, 1, 0,
+    2, 0, 0, 2, 1, 1, 0, 2, 0, 0, 0, 0, 1, 1, 1, 0, 2, 0, 0, 1, 0, 1, 1, 0, 2, 0, 0, 0, 0, 0, 1, 1,
+    0, 2, 0, 3, 0, 1, 0, 2, 0, 0, 0, 0, 2, 0, 1, 0, 2, 0, 0, 1, 1, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 1,
+    0, 2, 0, 2, 0, 0, 1, 0, 2, 0, 0, 0, 1, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0,
+    1, 0, 2, 5, 0, 0, 2, 0, 0, 0, 0, 0, 4, 0, 0, 2, 0, 0, 0, 1, 3, 0, 0, 2, 0, 0, 0, 0, 0, 3, 0, 0,
+    2, 0, 0, 2, 2, 0, 0, 2, 0, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0,
+    0, 2, 0, 3, 1, 0, 0, 2, 0, 0, 0, 0, 2, 1, 0, 0, 2, 0, 0, 1, 1, 1, 0, 0, 2, 0, 0, 0, 0, 1, 1, 0,
+    0, 2, 0, 2, 0, 1, 0, 0, 2, 0, 0, 0, 1, 0, 1, 0, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 1,
+    0, 0, 2, 4, 0, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 1, 2, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0,
+    0, 2, 0, 2, 1, 0, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0,
+    0, 0, 2, 3, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0,
+    0, 0, 2, 2, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+    0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0,
+    0, 0, 0, 2, 5, 1, 0, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 1, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 5, 1,
+    0, 0, 0, 3, 4, 1, 0, 0, 0, 0, 0, 0, 2, 4, 1, 0, 0, 0, 0, 1, 1, 4, 1, 0, 0, 0, 0, 0, 0, 1, 4, 1,
+    0, 0, 0, 2, 0, 4, 1, 0, 0, 0, 0, 0, 1, 0, 4, 1, 0, 0, 0, 1, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 4,
+    1, 0, 0, 4, 3, 1, 0, 0, 0, 0, 0, 0, 3, 3, 1, 0, 0, 0, 0, 1, 2, 3, 1, 0, 0, 0, 0, 0, 0, 2, 3, 1,
+    0, 0, 0, 2, 1, 3, 1, 0, 0, 0, 0, 0, 1, 1, 3, 1, 0, 0, 0, 1, 0, 1, 3, 1, 0, 0, 0, 0, 0, 0, 1, 3,
+    1, 0, 0, 3, 0, 3, 1, 0, 0, 0, 0, 0, 2, 0, 3, 1, 0, 0, 0, 1, 1, 0, 3, 1, 0, 0, 0, 0, 0, 1, 0, 3,
+    1, 0, 0, 2, 0, 0, 3, 1, 0, 0, 0, 0, 1, 0, 0, 3, 1, 0, 0, 1, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0,
+    3, 1, 0, 5, 2, 1, 0, 0, 0, 0, 0, 0, 4, 2, 1, 0, 0, 0, 0, 1, 3, 2, 1, 0, 0, 0, 0, 0, 0, 3, 2, 1,
+    0, 0, 0, 2, 2, 2, 1, 0, 0, 0, 0, 0, 1, 2, 2, 1, 0, 0, 0, 1, 0, 2, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2,
+    1, 0, 0, 3, 1, 2, 1, 0, 0, 0, 0, 0, 2, 1, 2, 1, 0, 0, 0, 1, 1, 1, 2, 1, 0, 0, 0, 0, 0, 1, 1, 2,
+    1, 0, 0, 2, 0, 1, 2, 1, 0, 0, 0, 0, 1, 0, 1, 2, 1, 0, 0, 1, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 1,
+    2, 1, 0, 4, 0, 2, 1, 0, 0, 0, 0, 0, 3, 0, 2, 1, 0, 0, 0, 1, 2, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 2,
+    1, 0, 0, 2, 1, 0, 2, 1, 0, 0, 0, 0, 1, 1, 0, 2, 1, 0, 0, 1, 0, 1, 0, 2, 1, 0, 0, 0, 0, 0, 1, 0,
+    2, 1, 0, 3, 0, 0, 2, 1, 0, 0, 0, 0, 2, 0, 0, 2, 1, 0, 0, 1, 1, 0, 0, 2, 1, 0, 0, 0, 0, 1, 0, 0,
+    2, 1, 0, 2, 0, 0, 0, 2, 1, 0, 0, 0, 1, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0,
+    0, 2, 1, 6, 1, 1, 0, 0, 0, 0, 0, 0, 5, 1, 1, 0, 0, 0, 0, 1, 4, 1, 1, 0, 0, 0, 0, 0, 0, 4, 1, 1,
+    0, 0, 0, 2, 3, 1, 1, 0, 0, 0, 0, 0, 1, 3, 1, 1, 0, 0, 0, 1, 0, 3, 1, 1, 0, 0, 0, 0, 0, 0, 3, 1,
+    1, 0, 0, 3, 2, 1, 1, 0, 0, 0, 0, 0, 2, 2, 1, 1, 0, 0, 0, 1, 1, 2, 1, 1, 0, 0, 0, 0, 0, 1, 2, 1,
+    1, 0, 0, 2, 0, 2, 1, 1, 0, 0, 0, 0, 1, 0, 2, 1, 1, 0, 0, 1, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 2,
+    1, 1, 0, 4, 1, 1, 1, 0, 0, 0, 0, 0, 3, 1, 1, 1, 0, 0, 0, 1, 2, 1, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1,
+    1, 0, 0, 2, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1,
+    1, 1, 0, 3, 0, 1, 1, 1, 0, 0, 0, 0, 2, 0, 1, 1, 1, 0, 0, 1, 1, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1,
+    1, 1, 0, 2, 0, 0, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0,
+    1, 1, 1, 5, 0, 1, 1, 0, 0, 0, 0, 0, 4, 0, 1, 1, 0, 0, 0, 1, 3, 0, 1, 1, 0, 0, 0, 0, 0, 3, 0, 1,
+    1, 0, 0, 2, 2, 0, 1, 1, 0, 0, 0, 0, 1, 2, 0, 1, 1, 0, 0, 1, 0, 2, 0, 1, 1, 0, 0, 0, 0, 0, 2, 0,
+    1, 1, 0, 3, 1, 0, 1, 1, 0, 0, 0, 0, 2, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0,
+    1, 1, 0, 2, 0, 1, 0, 1, 1, 0, 0, 0, 1, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1,
+    0, 1, 1, 4, 0, 0, 1, 1, 0, 0, 0, 0, 3, 0, 0, 1, 1, 0, 0, 1, 2, 0, 0, 1, 1, 0, 0, 0, 0, 2, 0, 0,
+    1, 1, 0, 2, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0,
+    0, 1, 1, 3, 0, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0,
+    0, 1, 1, 2, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
+    0, 0, 1, 7, 0, 1, 0, 0, 0, 0, 0, 0, 6, 0, 1, 0, 0, 0, 0, 1, 5, 0, 1, 0, 0, 0, 0, 0, 0, 5, 0, 1,
+    0, 0, 0, 2, 4, 0, 1, 0, 0, 0, 0, 0, 1, 4, 0, 1, 0, 0, 0, 1, 0, 4, 0, 1, 0, 0, 0, 0, 0, 0, 4, 0,
+    1, 0, 0, 3, 3, 0, 1, 0, 0, 0, 0, 0, 2, 3, 0, 1, 0, 0, 0, 1, 1, 3, 0, 1, 0, 0, 0, 0, 0, 1, 3, 0,
+    1, 0, 0, 2, 0, 3, 0, 1, 0, 0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 1, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 3,
+    0, 1, 0, 4, 2, 0, 1, 0, 0, 0, 0, 0, 3, 2, 0, 1, 0, 0, 0, 1, 2, 2, 0, 1, 0, 0, 0, 0, 0, 2, 2, 0,
+    1, 0, 0, 2, 1, 2, 0, 1, 0, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 1, 0, 1, 2, 0, 1, 0, 0, 0, 0, 0, 1, 2,
+    0, 1, 0, 3, 0, 2, 0, 1, 0, 0, 0, 0, 2, 0, 2, 0, 1, 0, 0, 1, 1, 0, 2, 0, 1, 0, 0, 0, 0, 1, 0, 2,
+    0, 1, 0, 2, 0, 0, 2, 0, 1, 0, 0, 0, 1, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0,
+    2, 0, 1, 5, 1, 0, 1, 0, 0, 0, 0, 0, 4, 1, 0, 1, 0, 0, 0, 1, 3, 1, 0, 1, 0, 0, 0, 0, 0, 3, 1, 0,
+    1, 0, 0, 2, 2, 1, 0, 1, 0, 0, 0, 0, 1, 2, 1, 0, 1, 0, 0, 1, 0, 2, 1, 0, 1, 0, 0, 0, 0, 0, 2, 1,
+    0, 1, 0, 3, 1, 1, 0, 1, 0, 0, 0, 0, 2, 1, 1, 0, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1,
+    0, 1, 0, 2, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1,
+    1, 0, 1, 4, 0, 1, 0, 1, 0, 0, 0, 0, 3, 0, 1, 0, 1, 0, 0, 1, 2, 0, 1, 0, 1, 0, 0, 0, 0, 2, 0, 1,
+    0, 1, 0, 2, 1, 0, 1, 0, 1, 0, 0, 0, 1, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0,
+    1, 0, 1, 3, 0, 0, 1, 0, 1, 0, 0, 0, 2, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0,
+    1, 0, 1, 2, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0,
+    0, 1, 0, 6, 0, 0, 1, 0, 0, 0, 0, 0, 5, 0, 0, 1, 0, 0, 0, 1, 4, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0,
+    1, 0, 0, 2, 3, 0, 0, 1, 0, 0, 0, 0, 1, 3, 0, 0, 1, 0, 0, 1, 0, 3, 0, 0, 1, 0, 0, 0, 0, 0, 3, 0,
+    0, 1, 0, 3, 2, 0, 0, 1, 0, 0, 0, 0, 2, 2, 0, 0, 1, 0, 0, 1, 1, 2, 0, 0, 1, 0, 0, 0, 0, 1, 2, 0,
+    0, 1, 0, 2, 0, 2, 0, 0, 1, 0, 0, 0, 1, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 2,
+    0, 0, 1, 4, 1, 0, 0, 1, 0, 0, 0, 0, 3, 1, 0, 0, 1, 0, 0, 1, 2, 1, 0, 0, 1, 0, 0, 0, 0, 2, 1, 0,
+    0, 1, 0, 2, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1,
+    0, 0, 1, 3, 0, 1, 0, 0, 1, 0, 0, 0, 2, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 1,
+    0, 0, 1, 2, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0,
+    1, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 1, 3, 0, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0,
+    0, 1, 0, 2, 2, 0, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0,
+    0, 0, 1, 3, 1, 0, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0,
+    0, 0, 1, 2, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1,
+    0, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0,
+    0, 0, 1, 2, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0,
+    0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0,
+    0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 1, 1, 5, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0,
+    0, 0, 0, 2, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5,
+    0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 1, 2, 4, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0,
+    0, 0, 0, 2, 1, 4, 0, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 1, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4,
+    0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 1, 1, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 4,
+    0, 0, 0, 2, 0, 0, 4, 0, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0,
+    4, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 1, 3, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0,
+    0, 0, 0, 2, 2, 3, 0, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 1, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3,
+    0, 0, 0, 3, 1, 3, 0, 0, 0, 0, 0, 0, 2, 1, 3, 0, 0, 0, 0, 1, 1, 1, 3, 0, 0, 0, 0, 0, 0, 1, 1, 3,
+    0, 0, 0, 2, 0, 1, 3, 0, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 1, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1,
+    3, 0, 0, 4, 0, 3, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 1, 2, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0, 3,
+    0, 0, 0, 2, 1, 0, 3, 0, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 1, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0,
+    3, 0, 0, 3, 0, 0, 3, 0, 0, 0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 1, 1, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 0,
+    3, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+    0, 3, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 1, 4, 2, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0,
+    0, 0, 0, 2, 3, 2, 0, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 1, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2,
+    0, 0, 0, 3, 2, 2, 0, 0, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 1, 1, 2, 2, 0, 0, 0, 0, 0, 0, 1, 2, 2,
+    0, 0, 0, 2, 0, 2, 2, 0, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 1, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2,
+    2, 0, 0, 4, 1, 2, 0, 0, 0, 0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 1, 2, 1, 2, 0, 0, 0, 0, 0, 0, 2, 1, 2,
+    0, 0, 0, 2, 1, 1, 2, 0, 0, 0, 0, 0, 1, 1, 1, 2, 0, 0, 0, 1, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1,
+    2, 0, 0, 3, 0, 1, 2, 0, 0, 0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 1, 1, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 1,
+    2, 0, 0, 2, 0, 0, 1, 2, 0, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0,
+    1, 2, 0, 5, 0, 2, 0, 0, 0, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 1, 3, 0, 2, 0, 0, 0, 0, 0, 0, 3, 0, 2,
+    0, 0, 0, 2, 2, 0, 2, 0, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0,
+    2, 0, 0, 3, 1, 0, 2, 0, 0, 0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 1, 1, 1, 0, 2, 0, 0, 0, 0, 0, 1, 1, 0,
+    2, 0, 0, 2, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1,
+    0, 2, 0, 4, 0, 0, 2, 0, 0, 0, 0, 0, 3, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0,
+    2, 0, 0, 2, 1, 0, 0, 2, 0, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 1, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0,
+    0, 2, 0, 3, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0,
+    0, 2, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+    0, 0, 2, 7, 1, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 1, 5, 1, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0,
+    0, 0, 0, 2, 4, 1, 0, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 1, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1,
+    0, 0, 0, 3, 3, 1, 0, 0, 0, 0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 1, 1, 3, 1, 0, 0, 0, 0, 0, 0, 1, 3, 1,
+    0, 0, 0, 2, 0, 3, 1, 0, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 1, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3,
+    1, 0, 0, 4, 2, 1, 0, 0, 0, 0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 1, 2, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2, 1,
+    0, 0, 0, 2, 1, 2, 1, 0, 0, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 1, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2,
+    1, 0, 0, 3, 0, 2, 1, 0, 0, 0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 1, 1, 0, 2, 1, 0, 0, 0, 0, 0, 1, 0, 2,
+    1, 0, 0, 2, 0, 0, 2, 1, 0, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0,
+    2, 1, 0, 5, 1, 1, 0, 0, 0, 0, 0, 0, 4, 1, 1, 0, 0, 0, 0, 1, 3, 1, 1, 0, 0, 0, 0, 0, 0, 3, 1, 1,
+    0, 0, 0, 2, 2, 1, 1, 0, 0, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 1, 0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1,
+    1, 0, 0, 3, 1, 1, 1, 0, 0, 0, 0, 0, 2, 1, 1, 1, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1,
+    1, 0, 0, 2, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1,
+    1, 1, 0, 4, 0, 1, 1, 0, 0, 0, 0, 0, 3, 0, 1, 1, 0, 0, 0, 1, 2, 0, 1, 1, 0, 0, 0, 0, 0, 2, 0, 1,
+    1, 0, 0, 2, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0,
+    1, 1, 0, 3, 0, 0, 1, 1, 0, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0,
+    1, 1, 0, 2, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
+    0, 1, 1, 6, 0, 1, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 1, 4, 0, 1, 0, 0, 0, 0, 0, 0, 4, 0, 1,
+    0, 0, 0, 2, 3, 0, 1, 0, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0,
+    1, 0, 0, 3, 2, 0, 1, 0, 0, 0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 0, 0, 0, 1, 2, 0,
+    1, 0, 0, 2, 0, 2, 0, 1, 0, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 1, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2,
+    0, 1, 0, 4, 1, 0, 1, 0, 0, 0, 0, 0, 3, 1, 0, 1, 0, 0, 0, 1, 2, 1, 0, 1, 0, 0, 0, 0, 0, 2, 1, 0,
+    1, 0, 0, 2, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1,
+    0, 1, 0, 3, 0, 1, 0, 1, 0, 0, 0, 0, 2, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1,
+    0, 1, 0, 2, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0,
+    1, 0, 1, 5, 0, 0, 1, 0, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 1, 3, 0, 0, 1, 0, 0, 0, 0, 0, 3, 0, 0,
+    1, 0, 0, 2, 2, 0, 0, 1, 0, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0,
+    0, 1, 0, 3, 1, 0, 0, 1, 0, 0, 0, 0, 2, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0,
+    0, 1, 0, 2, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1,
+    0, 0, 1, 4, 0, 0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0,
+    0, 1, 0, 2, 1, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0,
+    0, 0, 1, 3, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0,
+    0, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+    0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0,
+    0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0,
+    0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 1, 1, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0,
+    0, 0, 0, 2, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0,
+    0, 0, 0, 2, 1, 3, 0, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 1, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3,
+    0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 1, 1, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3,
+    0, 0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0,
+    3, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 1, 3, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0,
+    0, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2,
+    0, 0, 0, 3, 1, 2, 0, 0, 0, 0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 1, 1, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 2,
+    0, 0, 0, 2, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 1, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1,
+    2, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 1, 2, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2,
+    0, 0, 0, 2, 1, 0, 2, 0, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 1, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0,
+    2, 0, 0, 3, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0,
+    2, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 6, 1, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0,
+    0, 0, 0, 2, 3, 1, 0, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 1, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1,
+    0, 0, 0, 3, 2, 1, 0, 0, 0, 0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 1, 1, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2, 1,
+    0, 0, 0, 2, 0, 2, 1, 0, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2,
+    1, 0, 0, 4, 1, 1, 0, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 1, 2, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1,
+    0, 0, 0, 2, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1,
+    1, 0, 0, 3, 0, 1, 1, 0, 0, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1,
+    1, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0,
+    1, 1, 0, 5, 0, 1, 0, 0, 0, 0, 0, 0, 4, 0, 1, 0, 0, 0, 0, 1, 3, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 1,
+    0, 0, 0, 2, 2, 0, 1, 0, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 1, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0,
+    1, 0, 0, 3, 1, 0, 1, 0, 0, 0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0,
+    1, 0, 0, 2, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1,
+    0, 1, 0, 4, 0, 0, 1, 0, 0, 0, 0, 0, 3, 0, 0, 1, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0,
+    1, 0, 0, 2, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0,
+    0, 1, 0, 3, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0,
+    0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+    0, 0, 1, 7, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0,
+    0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0,
+    0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0,
+    0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 1, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3,
+    0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 1, 2, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0,
+    0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 1, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2,
+    0, 0, 0, 3, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 1, 1, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2,
+    0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    2, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 1, 3, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0,
+    0, 0, 0, 2, 2, 1, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 1, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1,
+    0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1,
+    0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1,
+    1, 0, 0, 4, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 1, 2, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1,
+    0, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0,
+    1, 0, 0, 3, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0,
+    1, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+    0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0,
+    0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0,
+    0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+    0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0,
+    0, 0, 0, 2, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1,
+    0, 0, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1,
+    0, 0, 0, 2, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0,
+    0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+    0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0,
+    0, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
+    0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0,
+];
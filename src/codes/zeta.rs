@@ -7,31 +7,164 @@
  */
 
 //! # Zeta
+//! The ζ code of index k of x ∈ N writes in unary the integer h such that
+//! 2^(hk) ≤ x + 1 < 2^((h + 1)k), followed by x + 1 - 2^(hk) in minimal binary
+//! with maximum 2^((h + 1)k) - 2^(hk).
 //!
+//! So that all values in N fit in a [`u64`], this implementation deviates
+//! from the definition for the largest values: if 2^((h + 1)k) > 2^64 + 1,
+//! the maximum of the minimal binary code is 2^64 + 1 - 2^(hk) instead. Thus,
+//! the codes of the values x with x + 1 ≥ 2^(hk), where h = floor(64 / k),
+//! are shorter than the standard ones, but only when k does not divide 64:
+//! otherwise, the only such value is [`u64::MAX`], which has no standard ζ
+//! code, as x + 1 = 2^64 would need an interval of 2^64 values.
+//!
+//! When `USE_TABLE` is true, decoding tables are used if available for k:
+//! the tables for k = 3 are always available, while the tables for k equal to
+//! 2, 4, 5, 6 or 7 are available only if the corresponding `zeta{k}_tables`
+//! feature is enabled (`all_zeta_tables` enables all of them).
+//!
+//! #### Example:
+//! ```
+//! use dsi_bitstream::prelude::*;
+//!
+//! let mut buffer: Vec<u64> = vec![];
+//! {
+//!     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+//!     for value in 0..1000 {
+//!         for k in 1..=8 {
+//!             writer.write_zeta::<true>(value, k).unwrap();
+//!         }
+//!         writer.write_zeta_const::<5, true>(value).unwrap();
+//!     }
+//! }
+//!
+//! let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+//! let mut unbuffered = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+//! for value in 0..1000 {
+//!     for k in 1..=8 {
+//!         assert_eq!(reader.read_zeta::<true>(k).unwrap(), value);
+//!         assert_eq!(unbuffered.read_zeta::<false>(k).unwrap(), value);
+//!     }
+//!     assert_eq!(reader.read_zeta_const::<5, true>().unwrap(), value);
+//!     assert_eq!(unbuffered.read_zeta_const::<5, false>().unwrap(), value);
+//! }
+//! assert_eq!(
+//!     reader.get_position(),
+//!     (0..1000)
+//!         .map(|value| {
+//!             (1..=8).map(|k| len_zeta::<true>(value, k)).sum::<usize>()
+//!                 + len_zeta_const::<5, true>(value)
+//!         })
+//!         .sum::<usize>()
+//! );
+//! ```
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 use super::*;
 use super::{len_minimal_binary, len_unary, zeta_tables, MinimalBinaryRead, MinimalBinaryWrite};
 use crate::traits::*;
 
-/// Returns how long the zeta code for `value` will be
+/// Evaluate `$body` with `$tables` bound to the module of the decoding tables
+/// for the ζ code of index `$k`, or `$default` if no such tables are
+/// available
+macro_rules! with_zeta_tables {
+    ($k:expr, $tables:ident => $body:expr, $default:expr) => {
+        match $k {
+            3 => {
+                use zeta_tables as $tables;
+                $body
+            }
+            #[cfg(feature = "zeta2_tables")]
+            2 => {
+                use super::zeta2_tables as $tables;
+                $body
+            }
+            #[cfg(feature = "zeta4_tables")]
+            4 => {
+                use super::zeta4_tables as $tables;
+                $body
+            }
+            #[cfg(feature = "zeta5_tables")]
+            5 => {
+                use super::zeta5_tables as $tables;
+                $body
+            }
+            #[cfg(feature = "zeta6_tables")]
+            6 => {
+                use super::zeta6_tables as $tables;
+                $body
+            }
+            #[cfg(feature = "zeta7_tables")]
+            7 => {
+                use super::zeta7_tables as $tables;
+                $body
+            }
+            _ => $default,
+        }
+    };
+}
+
+/// Returns how long the zeta code for `value` will be; as noted in
+/// [`ZetaWrite::write_zeta`], the codes of the largest values are shorter
+/// than the standard ones
 ///
 /// `USE_TABLE` enables or disables the use of pre-computed tables
 /// for decoding
 #[must_use]
 #[inline]
-pub fn len_zeta<const USE_TABLE: bool>(mut value: u64, k: u64) -> usize {
-    if USE_TABLE && k == zeta_tables::K {
-        if let Some(idx) = zeta_tables::LEN.get(value as usize) {
-            return *idx as usize;
+pub fn len_zeta<const USE_TABLE: bool>(value: u64, k: u64) -> usize {
+    if USE_TABLE {
+        if let Some(len) = with_zeta_tables!(k, tables => tables::LEN.get(value as usize), None) {
+            return *len as usize;
         }
     }
-    value += 1;
-    let h = (fast_floor_log2(value) as u64) / k;
-    let u = 1 << ((h + 1) * k);
-    let l = 1 << (h * k);
-    len_unary::<false>(h) + len_minimal_binary(value - l, u - l)
+    debug_assert!(k > 0 && k <= 64);
+    let h = fast_floor_log2_succ(value) as u64 / k;
+    let (l, max) = zeta_interval(h, k);
+    len_unary::<false>(h) + len_minimal_binary((value as u128 + 1 - l) as u64, max)
+}
+
+/// Returns how long the ζ code of index `K` for `value` will be
+///
+/// `USE_TABLE` enables or disables the use of pre-computed tables
+/// for decoding
+#[must_use]
+#[inline(always)]
+pub fn len_zeta_const<const K: u64, const USE_TABLE: bool>(value: u64) -> usize {
+    len_zeta::<USE_TABLE>(value, K)
+}
+
+/// Returns how long the ζ code of index `K` for the signed `value` will be
+///
+/// `USE_TABLE` enables or disables the use of pre-computed tables
+/// for decoding
+#[must_use]
+#[inline(always)]
+pub fn len_zeta_const_signed<const K: u64, const USE_TABLE: bool>(value: i64) -> usize {
+    len_zeta::<USE_TABLE>(int2nat(value), K)
+}
+
+/// Returns how long the zeta code for the signed `value` will be
+///
+/// `USE_TABLE` enables or disables the use of pre-computed tables
+/// for decoding
+#[must_use]
+#[inline]
+pub fn len_zeta_signed<const USE_TABLE: bool>(value: i64, k: u64) -> usize {
+    len_zeta::<USE_TABLE>(int2nat(value), k)
+}
+
+/// Return the first value and the size of the interval of the values of
+/// x + 1 whose ζ code of index `k` has unary part `h`. The size of the last
+/// interval is truncated so that x fits in a [`u64`].
+#[inline(always)]
+fn zeta_interval(h: u64, k: u64) -> (u128, u64) {
+    debug_assert!(h * k <= 64);
+    let l = 1_u128 << (h * k);
+    let u = (1_u128 << (h * k + k).min(65)).min((1 << 64) + 1);
+    (l, (u - l) as u64)
 }
 
 /// Trait for objects that can read Zeta codes
@@ -39,20 +172,134 @@ pub trait ZetaRead<BO: BitOrder>: MinimalBinaryRead<BO> {
     /// Generic ζ code reader
     ///
     /// # Errors
-    /// This function fails only if the BitRead backend has problems reading
-    /// bits, as when the stream ended unexpectedly
+    /// This function fails if `k` is not in [1, 64], if the code does not fit
+    /// in a [`u64`], or if the BitRead backend has problems reading bits, as
+    /// when the stream ended unexpectedly
     fn read_zeta<const USE_TABLE: bool>(&mut self, k: u64) -> Result<u64>;
     /// Specialized ζ code reader for k = 3
     ///
     /// # Errors
     /// This function fails only if the BitRead backend has problems reading
-    /// bits, as when the stream ended unexpectedly
+    /// bits, as when the stream ended unexpectedly, or if the code does not
+    /// fit in a [`u64`]
     fn read_zeta3<const USE_TABLE: bool>(&mut self) -> Result<u64>;
+    /// ζ code reader with index known at compile time, so that the
+    /// arithmetic and the choice of the table are resolved when
+    /// monomorphizing. If there are no tables for `K`, it behaves as
+    /// [`ZetaRead::read_zeta`].
+    ///
+    /// # Errors
+    /// The same as [`ZetaRead::read_zeta`]
+    fn read_zeta_const<const K: u64, const USE_TABLE: bool>(&mut self) -> Result<u64>;
+
+    /// Generic ζ code reader mapping the value to a signed one using
+    /// [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`ZetaRead::read_zeta`]
+    #[inline]
+    fn read_zeta_signed<const USE_TABLE: bool>(&mut self, k: u64) -> Result<i64> {
+        self.read_zeta::<USE_TABLE>(k).map(nat2int)
+    }
+    /// Specialized ζ code reader for k = 3 mapping the value to a signed one
+    /// using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`ZetaRead::read_zeta3`]
+    #[inline]
+    fn read_zeta3_signed<const USE_TABLE: bool>(&mut self) -> Result<i64> {
+        self.read_zeta3::<USE_TABLE>().map(nat2int)
+    }
+    /// ζ code reader with index known at compile time mapping the value to a
+    /// signed one using [`nat2int`].
+    ///
+    /// # Errors
+    /// The same as [`ZetaRead::read_zeta_const`]
+    #[inline]
+    fn read_zeta_const_signed<const K: u64, const USE_TABLE: bool>(&mut self) -> Result<i64> {
+        self.read_zeta_const::<K, USE_TABLE>().map(nat2int)
+    }
+
+    /// Read `values.len()` ζ codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`ZetaRead::read_zeta`]
+    #[inline]
+    fn read_zeta_into<const USE_TABLE: bool>(&mut self, values: &mut [u64], k: u64) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_zeta::<USE_TABLE>(k)?;
+        }
+        Ok(())
+    }
+
+    /// Read `values.len()` ζ3 codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`ZetaRead::read_zeta3`]
+    #[inline]
+    fn read_zeta3_into<const USE_TABLE: bool>(&mut self, values: &mut [u64]) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_zeta3::<USE_TABLE>()?;
+        }
+        Ok(())
+    }
+
+    /// Skip a ζ code from the stream without decoding it.
+    ///
+    /// # Errors
+    /// This function fails if `k` is not in [1, 64], if the code does not fit
+    /// in a [`u64`], or if the BitRead backend has problems reading or
+    /// skipping bits, as when the stream ended unexpectedly
+    fn skip_zeta<const USE_TABLE: bool>(&mut self, k: u64) -> Result<()>;
+    /// Skip a ζ code with k = 3 from the stream without decoding it.
+    ///
+    /// # Errors
+    /// The same as [`ZetaRead::skip_zeta`]
+    fn skip_zeta3<const USE_TABLE: bool>(&mut self) -> Result<()>;
+
+    /// Skip `n` ζ codes from the stream without decoding them.
+    ///
+    /// # Errors
+    /// The same as [`ZetaRead::skip_zeta`]
+    #[inline]
+    fn skip_zeta_n<const USE_TABLE: bool>(&mut self, n: usize, k: u64) -> Result<()> {
+        for _ in 0..n {
+            self.skip_zeta::<USE_TABLE>(k)?;
+        }
+        Ok(())
+    }
+
+    /// Skip `n` ζ codes with k = 3 from the stream without decoding them.
+    ///
+    /// # Errors
+    /// The same as [`ZetaRead::skip_zeta`]
+    #[inline]
+    fn skip_zeta3_n<const USE_TABLE: bool>(&mut self, n: usize) -> Result<()> {
+        for _ in 0..n {
+            self.skip_zeta3::<USE_TABLE>()?;
+        }
+        Ok(())
+    }
 }
 
 impl<B: BitRead<M2L>> ZetaRead<M2L> for B {
     #[inline]
     fn read_zeta<const USE_TABLE: bool>(&mut self, k: u64) -> Result<u64> {
+        if USE_TABLE {
+            if let Some(res) = with_zeta_tables!(k, tables => tables::read_table_m2l(self)?, None) {
+                return Ok(res);
+            }
+            #[cfg(feature = "two_level_tables")]
+            if let Some(res) = with_zeta_tables!(
+                k,
+                tables => two_level::read_table_m2l(self, tables::READ2)?,
+                None
+            ) {
+                return Ok(res);
+            }
+        }
         default_read_zeta(self, k)
     }
 
@@ -62,13 +309,64 @@ impl<B: BitRead<M2L>> ZetaRead<M2L> for B {
             if let Some(res) = zeta_tables::read_table_m2l(self)? {
                 return Ok(res);
             }
+            #[cfg(feature = "two_level_tables")]
+            if let Some(res) = two_level::read_table_m2l(self, zeta_tables::READ2)? {
+                return Ok(res);
+            }
         }
         default_read_zeta(self, 3)
     }
+
+    #[inline(always)]
+    fn read_zeta_const<const K: u64, const USE_TABLE: bool>(&mut self) -> Result<u64> {
+        if USE_TABLE {
+            if let Some(res) = with_zeta_tables!(K, tables => tables::read_table_m2l(self)?, None) {
+                return Ok(res);
+            }
+            #[cfg(feature = "two_level_tables")]
+            if let Some(res) = with_zeta_tables!(
+                K,
+                tables => two_level::read_table_m2l(self, tables::READ2)?,
+                None
+            ) {
+                return Ok(res);
+            }
+        }
+        default_read_zeta(self, K)
+    }
+
+    #[inline]
+    fn skip_zeta<const USE_TABLE: bool>(&mut self, k: u64) -> Result<()> {
+        if USE_TABLE && with_zeta_tables!(k, tables => tables::skip_table_m2l(self)?, false) {
+            return Ok(());
+        }
+        default_skip_zeta(self, k)
+    }
+
+    #[inline]
+    fn skip_zeta3<const USE_TABLE: bool>(&mut self) -> Result<()> {
+        if USE_TABLE && zeta_tables::skip_table_m2l(self)? {
+            return Ok(());
+        }
+        default_skip_zeta(self, 3)
+    }
 }
 impl<B: BitRead<L2M>> ZetaRead<L2M> for B {
     #[inline]
     fn read_zeta<const USE_TABLE: bool>(&mut self, k: u64) -> Result<u64> {
+        if USE_TABLE {
+            if let Some(res) = with_zeta_tables!(k, tables => tables::read_table_l2m(self)?, None) {
+                return Ok(res);
+            }
+            #[cfg(feature = "two_level_tables")]
+            if let Some(res) = with_zeta_tables!(
+                k,
+                tables => two_level::read_table_l2m(self, tables::READ2)?,
+                None
+            ) {
+                return Ok(res);
+            }
+        }
         default_read_zeta(self, k)
     }
 
@@ -78,27 +376,99 @@ impl<B: BitRead<L2M>> ZetaRead<L2M> for B {
             if let Some(res) = zeta_tables::read_table_l2m(self)? {
                 return Ok(res);
             }
+            #[cfg(feature = "two_level_tables")]
+            if let Some(res) = two_level::read_table_l2m(self, zeta_tables::READ2)? {
+                return Ok(res);
+            }
         }
         default_read_zeta(self, 3)
     }
+
+    #[inline(always)]
+    fn read_zeta_const<const K: u64, const USE_TABLE: bool>(&mut self) -> Result<u64> {
+        if USE_TABLE {
+            if let Some(res) = with_zeta_tables!(K, tables => tables::read_table_l2m(self)?, None) {
+                return Ok(res);
+            }
+            #[cfg(feature = "two_level_tables")]
+            if let Some(res) = with_zeta_tables!(
+                K,
+                tables => two_level::read_table_l2m(self, tables::READ2)?,
+                None
+            ) {
+                return Ok(res);
+            }
+        }
+        default_read_zeta(self, K)
+    }
+
+    #[inline]
+    fn skip_zeta<const USE_TABLE: bool>(&mut self, k: u64) -> Result<()> {
+        if USE_TABLE && with_zeta_tables!(k, tables => tables::skip_table_l2m(self)?, false) {
+            return Ok(());
+        }
+        default_skip_zeta(self, k)
+    }
+
+    #[inline]
+    fn skip_zeta3<const USE_TABLE: bool>(&mut self) -> Result<()> {
+        if USE_TABLE && zeta_tables::skip_table_l2m(self)? {
+            return Ok(());
+        }
+        default_skip_zeta(self, 3)
+    }
 }
 
 #[inline(always)]
 fn default_read_zeta<BO: BitOrder, B: BitRead<BO>>(backend: &mut B, k: u64) -> Result<u64> {
+    check_zeta_k(k)?;
     let h = backend.read_unary::<false>()?;
-    let u = 1 << ((h + 1) * k);
-    let l = 1 << (h * k);
-    let res = backend.read_minimal_binary(u - l)?;
-    Ok(l + res - 1)
+    if h > 64 / k {
+        bail!("The ζ code does not fit in a u64.");
+    }
+    let (l, max) = zeta_interval(h, k);
+    let res = backend.read_minimal_binary(max)?;
+    Ok((l + res as u128 - 1) as u64)
+}
+
+#[inline(always)]
+fn default_skip_zeta<BO: BitOrder, B: BitRead<BO>>(backend: &mut B, k: u64) -> Result<()> {
+    check_zeta_k(k)?;
+    let h = backend.read_unary::<false>()?;
+    if h > 64 / k {
+        bail!("The ζ code does not fit in a u64.");
+    }
+    let (_, max) = zeta_interval(h, k);
+    backend.skip_minimal_binary(max)
+}
+
+/// Check that the index `k` of a ζ code is in [1, 64]
+#[inline(always)]
+pub(super) fn check_zeta_k(k: u64) -> Result<()> {
+    if k == 0 || k > 64 {
+        bail!(
+            "The index of a ζ code has to be in [1, 64] and {} is not.",
+            k
+        );
+    }
+    Ok(())
 }
 
 /// Trait for objects that can write Zeta codes
 pub trait ZetaWrite<BO: BitOrder>: MinimalBinaryWrite<BO> {
     /// Generic ζ code writer
     ///
+    /// So that all values fit in a [`u64`], the maximum of the minimal binary
+    /// part of the code of x is truncated to 2^64 + 1 - 2^(hk) when
+    /// 2^((h + 1)k) > 2^64 + 1. If k does not divide 64, this makes the codes
+    /// of the values x with x + 1 ≥ 2^(k floor(64 / k)) shorter than the
+    /// standard ones; otherwise, only [`u64::MAX`], which has no standard
+    /// code, is affected.
+    ///
     /// # Errors
-    /// This function fails only if the BitWrite backend has problems writing
-    /// bits, as when the stream ended unexpectedly
+    /// This function fails if `k` is not in [1, 64] or if the BitWrite
+    /// backend has problems writing bits, as when the stream ended
+    /// unexpectedly
     fn write_zeta<const USE_TABLE: bool>(&mut self, value: u64, k: u64) -> Result<()>;
     /// Specialized ζ code writer for k = 3
     ///
@@ -106,38 +476,205 @@ pub trait ZetaWrite<BO: BitOrder>: MinimalBinaryWrite<BO> {
     /// This function fails only if the BitWrite backend has problems writing
     /// bits, as when the stream ended unexpectedly
     fn write_zeta3<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()>;
+    /// ζ code writer with index known at compile time, so that the
+    /// arithmetic and the choice of the table are resolved when
+    /// monomorphizing. If there are no tables for `K`, it behaves as
+    /// [`ZetaWrite::write_zeta`].
+    ///
+    /// # Errors
+    /// The same as [`ZetaWrite::write_zeta`]
+    fn write_zeta_const<const K: u64, const USE_TABLE: bool>(&mut self, value: u64) -> Result<()>;
+
+    /// Generic ζ code writer for a signed value, which is mapped to a natural
+    /// number using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`ZetaWrite::write_zeta`]
+    #[inline]
+    fn write_zeta_signed<const USE_TABLE: bool>(&mut self, value: i64, k: u64) -> Result<()> {
+        self.write_zeta::<USE_TABLE>(int2nat(value), k)
+    }
+    /// Specialized ζ code writer for k = 3 for a signed value, which is
+    /// mapped to a natural number using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`ZetaWrite::write_zeta3`]
+    #[inline]
+    fn write_zeta3_signed<const USE_TABLE: bool>(&mut self, value: i64) -> Result<()> {
+        self.write_zeta3::<USE_TABLE>(int2nat(value))
+    }
+    /// ζ code writer with index known at compile time for a signed value,
+    /// which is mapped to a natural number using [`int2nat`].
+    ///
+    /// # Errors
+    /// The same as [`ZetaWrite::write_zeta_const`]
+    #[inline]
+    fn write_zeta_const_signed<const K: u64, const USE_TABLE: bool>(
+        &mut self,
+        value: i64,
+    ) -> Result<()> {
+        self.write_zeta_const::<K, USE_TABLE>(int2nat(value))
+    }
+
+    /// Write all the `values` on the stream as ζ codes.
+    ///
+    /// # Errors
+    /// The same as [`ZetaWrite::write_zeta`]
+    #[inline]
+    fn write_zeta_all<const USE_TABLE: bool>(&mut self, values: &[u64], k: u64) -> Result<()> {
+        for &value in values {
+            self.write_zeta::<USE_TABLE>(value, k)?;
+        }
+        Ok(())
+    }
+
+    /// Write all the `values` on the stream as ζ3 codes.
+    ///
+    /// # Errors
+    /// The same as [`ZetaWrite::write_zeta3`]
+    #[inline]
+    fn write_zeta3_all<const USE_TABLE: bool>(&mut self, values: &[u64]) -> Result<()> {
+        for &value in values {
+            self.write_zeta3::<USE_TABLE>(value)?;
+        }
+        Ok(())
+    }
 }
 
 impl<B: BitWrite<M2L>> ZetaWrite<M2L> for B {
     #[inline]
     fn write_zeta<const USE_TABLE: bool>(&mut self, value: u64, k: u64) -> Result<()> {
-        default_write_zeta(self, value, k)
+        if USE_TABLE && with_zeta_tables!(k, tables => tables::write_table_m2l(self, value)?, false)
+        {
+            return Ok(());
+        }
+        default_write_zeta::<_, _, USE_TABLE>(self, value, k)
     }
 
     #[inline]
     fn write_zeta3<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
+        if USE_TABLE && zeta_tables::write_table_m2l(self, value)? {
+            return Ok(());
+        }
+        default_write_zeta::<_, _, USE_TABLE>(self, value, 3)
+    }
+
+    #[inline(always)]
+    fn write_zeta_const<const K: u64, const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
+        if USE_TABLE && with_zeta_tables!(K, tables => tables::write_table_m2l(self, value)?, false)
+        {
+            return Ok(());
+        }
+        default_write_zeta::<_, _, USE_TABLE>(self, value, K)
+    }
+
+    #[inline]
+    fn write_zeta_all<const USE_TABLE: bool>(&mut self, values: &[u64], k: u64) -> Result<()> {
         if USE_TABLE {
-            if zeta_tables::write_table_m2l(self, value)? {
-                return Ok(());
+            if let Some(res) = with_zeta_tables!(k, tables => Some(batch::write_packed_m2l(
+                self,
+                values,
+                |value| {
+                    let bits = *tables::WRITE_M2L.get(value as usize)?;
+                    Some((bits, tables::WRITE_LEN_M2L[value as usize] as usize))
+                },
+                |backend, value| default_write_zeta::<_, _, USE_TABLE>(backend, value, k),
+            )), None)
+            {
+                return res;
             }
         }
-        default_write_zeta(self, value, 3)
+        for &value in values {
+            default_write_zeta::<_, _, USE_TABLE>(self, value, k)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_zeta3_all<const USE_TABLE: bool>(&mut self, values: &[u64]) -> Result<()> {
+        if USE_TABLE {
+            return batch::write_packed_m2l(
+                self,
+                values,
+                |value| {
+                    let bits = *zeta_tables::WRITE_M2L.get(value as usize)?;
+                    Some((bits, zeta_tables::WRITE_LEN_M2L[value as usize] as usize))
+                },
+                |backend, value| default_write_zeta::<_, _, USE_TABLE>(backend, value, 3),
+            );
+        }
+        for &value in values {
+            default_write_zeta::<_, _, USE_TABLE>(self, value, 3)?;
+        }
+        Ok(())
     }
 }
 impl<B: BitWrite<L2M>> ZetaWrite<L2M> for B {
     #[inline]
     fn write_zeta<const USE_TABLE: bool>(&mut self, value: u64, k: u64) -> Result<()> {
-        default_write_zeta(self, value, k)
+        if USE_TABLE && with_zeta_tables!(k, tables => tables::write_table_l2m(self, value)?, false)
+        {
+            return Ok(());
+        }
+        default_write_zeta::<_, _, USE_TABLE>(self, value, k)
     }
 
     #[inline]
     fn write_zeta3<const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
+        if USE_TABLE && zeta_tables::write_table_l2m(self, value)? {
+            return Ok(());
+        }
+        default_write_zeta::<_, _, USE_TABLE>(self, value, 3)
+    }
+
+    #[inline(always)]
+    fn write_zeta_const<const K: u64, const USE_TABLE: bool>(&mut self, value: u64) -> Result<()> {
+        if USE_TABLE && with_zeta_tables!(K, tables => tables::write_table_l2m(self, value)?, false)
+        {
+            return Ok(());
+        }
+        default_write_zeta::<_, _, USE_TABLE>(self, value, K)
+    }
+
+    #[inline]
+    fn write_zeta_all<const USE_TABLE: bool>(&mut self, values: &[u64], k: u64) -> Result<()> {
         if USE_TABLE {
-            if zeta_tables::write_table_l2m(self, value)? {
-                return Ok(());
+            if let Some(res) = with_zeta_tables!(k, tables => Some(batch::write_packed_l2m(
+                self,
+                values,
+                |value| {
+                    let bits = *tables::WRITE_L2M.get(value as usize)?;
+                    Some((bits, tables::WRITE_LEN_L2M[value as usize] as usize))
+                },
+                |backend, value| default_write_zeta::<_, _, USE_TABLE>(backend, value, k),
+            )), None)
+            {
+                return res;
             }
         }
-        default_write_zeta(self, value, 3)
+        for &value in values {
+            default_write_zeta::<_, _, USE_TABLE>(self, value, k)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_zeta3_all<const USE_TABLE: bool>(&mut self, values: &[u64]) -> Result<()> {
+        if USE_TABLE {
+            return batch::write_packed_l2m(
+                self,
+                values,
+                |value| {
+                    let bits = *zeta_tables::WRITE_L2M.get(value as usize)?;
+                    Some((bits, zeta_tables::WRITE_LEN_L2M[value as usize] as usize))
+                },
+                |backend, value| default_write_zeta::<_, _, USE_TABLE>(backend, value, 3),
+            );
+        }
+        for &value in values {
+            default_write_zeta::<_, _, USE_TABLE>(self, value, 3)?;
+        }
+        Ok(())
     }
 }
 
@@ -146,20 +683,19 @@ impl<B: BitWrite<L2M>> ZetaWrite<L2M> for B {
 /// # Errors
 /// Forward `read_unary` and `read_bits` errors.
 #[inline(always)]
-fn default_write_zeta<BO: BitOrder, B: BitWrite<BO>>(
+fn default_write_zeta<BO: BitOrder, B: BitWrite<BO>, const USE_TABLE: bool>(
     backend: &mut B,
-    mut value: u64,
+    value: u64,
     k: u64,
 ) -> Result<()> {
-    value += 1;
-    let h = fast_floor_log2(value) as u64 / k;
-    let u = 1 << ((h + 1) * k);
-    let l = 1 << (h * k);
+    check_zeta_k(k)?;
+    let h = fast_floor_log2_succ(value) as u64 / k;
+    let (l, max) = zeta_interval(h, k);
+    let offset = (value as u128 + 1 - l) as u64;
 
-    debug_assert!(l <= value, "{} <= {}", l, value);
-    debug_assert!(value < u, "{} < {}", value, u);
+    debug_assert!(offset < max, "{} < {}", offset, max);
 
     // Write the code
-    backend.write_unary::<true>(h)?;
-    backend.write_minimal_binary(value - l, u - l)
+    backend.write_unary::<USE_TABLE>(h)?;
+    backend.write_minimal_binary(offset, max)
 }
@@ -0,0 +1,29 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Pre-computed constants used to speedup the reading and writing of ζ
+//! codes with k = 7.
+//!
+//! The tables are computed at compile time: the number of bits used for
+//! decoding is set by the environment variable `ZETA_CODE_TABLE_BITS`
+//! (default 12), and the maximum value written using the tables by
+//! `ZETA_CODE_TABLE_MAX` (default 255), which are shared by the tables of
+//! all the ζ codes. See the [crate documentation](crate) for how
+//! to set them.
+
+crate::codes::tables::code_tables!(
+    "ζ",
+    TableCode::Zeta(K),
+    "ZETA_CODE_TABLE_BITS" = 12,
+    "ZETA_CODE_TABLE_MAX" = 255,
+);
+
+/// The K of the zeta codes for these tables
+pub const K: u64 = 7;
+
+crate::codes::tables::second_level_tables!("ζ", K);
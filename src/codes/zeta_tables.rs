@@ -1,761 +1,29 @@
-// THIS FILE HAS BEEN GENERATED WITH THE SCRIPT code_tables_generator.py
-// ~~~~~~~~~~~~~~~~~~~ DO NOT MODIFY ~~~~~~~~~~~~~~~~~~~~~~
-// Pre-computed constants used to speedup the reading and writing of zeta codes
-use crate::traits::{BitRead, BitWrite, UpcastableInto, L2M, M2L};
-use anyhow::Result;
-/// How many bits are needed to read the tables in this
-pub const READ_BITS: usize = 12;
-/// The len we assign to a code that cannot be decoded through the table
-pub const MISSING_VALUE_LEN: u8 = 255;
-/// Maximum value writable using the table(s)
-pub const WRITE_MAX: u64 = 255;
+/*
+ * SPDX-FileCopyrightText: 2023 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
 
-#[inline(always)]
-/// Autogenerated function to lookup a read table, if the result is `Some` the
-/// value was found, otherwise we were not able to decode the value and you
-/// should fallback to the default implementation
-///
-/// # Errors
-/// This function errors if it wasn't able to skip_bits
-pub fn read_table_l2m<B: BitRead<L2M>>(backend: &mut B) -> Result<Option<u64>> {
-    if let Ok(idx) = backend.peek_bits(READ_BITS) {
-        let idx: u64 = idx.upcast();
-        let len = READ_LEN_L2M[idx as usize];
-        if len != MISSING_VALUE_LEN {
-            backend.skip_bits_after_table_lookup(len as usize)?;
-            return Ok(Some(READ_L2M[idx as usize] as u64));
-        }
-    }
-    Ok(None)
-}
+//! Pre-computed constants used to speedup the reading and writing of ζ
+//! codes with k = 3.
+//!
+//! The tables are computed at compile time: the number of bits used for
+//! decoding is set by the environment variable `ZETA_CODE_TABLE_BITS`
+//! (default 12), and the maximum value written using the tables by
+//! `ZETA_CODE_TABLE_MAX` (default 255), which are shared by the tables of
+//! all the ζ codes. See the [crate documentation](crate) for how
+//! to set them.
 
-#[inline(always)]
-/// Autogenerated function to lookup a read table, if the result is `Some` the
-/// value was found, otherwise we were not able to decode the value and you
-/// should fallback to the default implementation
-///
-/// # Errors
-/// This function errors if it wasn't able to skip_bits
-pub fn write_table_l2m<B: BitWrite<L2M>>(backend: &mut B, value: u64) -> Result<bool> {
-    Ok(if let Some(bits) = WRITE_L2M.get(value as usize) {
-        backend.write_bits(*bits as u64, WRITE_LEN_L2M[value as usize] as usize)?;
-        true
-    } else {
-        false
-    })
-}
+crate::codes::tables::code_tables!(
+    "ζ",
+    TableCode::Zeta(K),
+    "ZETA_CODE_TABLE_BITS" = 12,
+    "ZETA_CODE_TABLE_MAX" = 255,
+);
 
-#[inline(always)]
-/// Autogenerated function to lookup a read table, if the result is `Some` the
-/// value was found, otherwise we were not able to decode the value and you
-/// should fallback to the default implementation
-///
-/// # Errors
-/// This function errors if it wasn't able to skip_bits
-pub fn read_table_m2l<B: BitRead<M2L>>(backend: &mut B) -> Result<Option<u64>> {
-    if let Ok(idx) = backend.peek_bits(READ_BITS) {
-        let idx: u64 = idx.upcast();
-        let len = READ_LEN_M2L[idx as usize];
-        if len != MISSING_VALUE_LEN {
-            backend.skip_bits_after_table_lookup(len as usize)?;
-            return Ok(Some(READ_M2L[idx as usize] as u64));
-        }
-    }
-    Ok(None)
-}
-
-#[inline(always)]
-/// Autogenerated function to lookup a read table, if the result is `Some` the
-/// value was found, otherwise we were not able to decode the value and you
-/// should fallback to the default implementation
-///
-/// # Errors
-/// This function errors if it wasn't able to skip_bits
-pub fn write_table_m2l<B: BitWrite<M2L>>(backend: &mut B, value: u64) -> Result<bool> {
-    Ok(if let Some(bits) = WRITE_M2L.get(value as usize) {
-        backend.write_bits(*bits as u64, WRITE_LEN_M2L[value as usize] as usize)?;
-        true
-    } else {
-        false
-    })
-}
-///Table containing the values used to speed up the reading of zeta codes
-pub const READ_M2L: &[u16] = &[
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    63, 63, 64, 64, 65, 65, 66, 66, 67, 67, 68, 68, 69, 69, 70, 70, 71, 71, 72, 72, 73, 73, 74, 74,
-    75, 75, 76, 76, 77, 77, 78, 78, 79, 79, 80, 80, 81, 81, 82, 82, 83, 83, 84, 84, 85, 85, 86, 86,
-    87, 87, 88, 88, 89, 89, 90, 90, 91, 91, 92, 92, 93, 93, 94, 94, 95, 95, 96, 96, 97, 97, 98, 98,
-    99, 99, 100, 100, 101, 101, 102, 102, 103, 103, 104, 104, 105, 105, 106, 106, 107, 107, 108,
-    108, 109, 109, 110, 110, 111, 111, 112, 112, 113, 113, 114, 114, 115, 115, 116, 116, 117, 117,
-    118, 118, 119, 119, 120, 120, 121, 121, 122, 122, 123, 123, 124, 124, 125, 125, 126, 126, 127,
-    128, 129, 130, 131, 132, 133, 134, 135, 136, 137, 138, 139, 140, 141, 142, 143, 144, 145, 146,
-    147, 148, 149, 150, 151, 152, 153, 154, 155, 156, 157, 158, 159, 160, 161, 162, 163, 164, 165,
-    166, 167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178, 179, 180, 181, 182, 183, 184,
-    185, 186, 187, 188, 189, 190, 191, 192, 193, 194, 195, 196, 197, 198, 199, 200, 201, 202, 203,
-    204, 205, 206, 207, 208, 209, 210, 211, 212, 213, 214, 215, 216, 217, 218, 219, 220, 221, 222,
-    223, 224, 225, 226, 227, 228, 229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 239, 240, 241,
-    242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255, 256, 257, 258, 259, 260,
-    261, 262, 263, 264, 265, 266, 267, 268, 269, 270, 271, 272, 273, 274, 275, 276, 277, 278, 279,
-    280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 290, 291, 292, 293, 294, 295, 296, 297, 298,
-    299, 300, 301, 302, 303, 304, 305, 306, 307, 308, 309, 310, 311, 312, 313, 314, 315, 316, 317,
-    318, 319, 320, 321, 322, 323, 324, 325, 326, 327, 328, 329, 330, 331, 332, 333, 334, 335, 336,
-    337, 338, 339, 340, 341, 342, 343, 344, 345, 346, 347, 348, 349, 350, 351, 352, 353, 354, 355,
-    356, 357, 358, 359, 360, 361, 362, 363, 364, 365, 366, 367, 368, 369, 370, 371, 372, 373, 374,
-    375, 376, 377, 378, 379, 380, 381, 382, 383, 384, 385, 386, 387, 388, 389, 390, 391, 392, 393,
-    394, 395, 396, 397, 398, 399, 400, 401, 402, 403, 404, 405, 406, 407, 408, 409, 410, 411, 412,
-    413, 414, 415, 416, 417, 418, 419, 420, 421, 422, 423, 424, 425, 426, 427, 428, 429, 430, 431,
-    432, 433, 434, 435, 436, 437, 438, 439, 440, 441, 442, 443, 444, 445, 446, 447, 448, 449, 450,
-    451, 452, 453, 454, 455, 456, 457, 458, 459, 460, 461, 462, 463, 464, 465, 466, 467, 468, 469,
-    470, 471, 472, 473, 474, 475, 476, 477, 478, 479, 480, 481, 482, 483, 484, 485, 486, 487, 488,
-    489, 490, 491, 492, 493, 494, 495, 496, 497, 498, 499, 500, 501, 502, 503, 504, 505, 506, 507,
-    508, 509, 510, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-    7, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
-    9, 9, 9, 9, 9, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-    10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13,
-    13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14,
-    14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 15, 15, 15, 15,
-    15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
-    16, 16, 16, 16, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 18, 18, 18, 18,
-    18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19,
-    19, 19, 19, 19, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 21, 21, 21, 21,
-    21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22,
-    22, 22, 22, 22, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 24, 24, 24, 24,
-    24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25,
-    25, 25, 25, 25, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 27, 27, 27, 27,
-    27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
-    28, 28, 28, 28, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 30, 30, 30, 30,
-    30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31,
-    31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33,
-    33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34,
-    34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36,
-    36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
-    37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39,
-    39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
-    40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42,
-    42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
-    43, 43, 43, 43, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 45, 45, 45, 45,
-    45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
-    46, 46, 46, 46, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 48, 48, 48, 48,
-    48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 49, 49, 49, 49, 49, 49, 49, 49, 49, 49, 49, 49,
-    49, 49, 49, 49, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 51, 51, 51, 51,
-    51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52,
-    52, 52, 52, 52, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 54, 54, 54, 54,
-    54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 55, 55, 55, 55, 55, 55, 55, 55, 55, 55, 55, 55,
-    55, 55, 55, 55, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 56, 57, 57, 57, 57,
-    57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58, 58,
-    58, 58, 58, 58, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 60, 60, 60, 60,
-    60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 61, 61, 61, 61, 61, 61, 61, 61, 61, 61, 61, 61,
-    61, 61, 61, 61, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 62, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1,
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2,
-    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5,
-    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
-    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 6, 6, 6, 6, 6,
-    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-];
-///Table contaings the lens used to speed up the reading of zeta codes
-pub const READ_LEN_M2L: &[u8] = &[
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 11,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
-    11, 11, 11, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
-    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
-    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-    4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
-];
-///Table containing the values used to speed up the reading of zeta codes
-pub const READ_L2M: &[u16] = &[
-    0, 0, 7, 1, 63, 3, 8, 5, 0, 0, 9, 2, 64, 4, 10, 6, 0, 0, 11, 1, 65, 3, 12, 5, 0, 0, 13, 2, 66,
-    4, 14, 6, 0, 0, 15, 1, 67, 3, 17, 5, 0, 0, 19, 2, 68, 4, 21, 6, 0, 0, 23, 1, 69, 3, 25, 5, 0,
-    0, 27, 2, 70, 4, 29, 6, 0, 0, 31, 1, 71, 3, 33, 5, 0, 0, 35, 2, 72, 4, 37, 6, 0, 0, 39, 1, 73,
-    3, 41, 5, 0, 0, 43, 2, 74, 4, 45, 6, 0, 0, 47, 1, 75, 3, 49, 5, 0, 0, 51, 2, 76, 4, 53, 6, 0,
-    0, 55, 1, 77, 3, 57, 5, 0, 0, 59, 2, 78, 4, 61, 6, 0, 0, 7, 1, 79, 3, 8, 5, 0, 0, 9, 2, 80, 4,
-    10, 6, 0, 0, 11, 1, 81, 3, 12, 5, 0, 0, 13, 2, 82, 4, 14, 6, 0, 0, 16, 1, 83, 3, 18, 5, 0, 0,
-    20, 2, 84, 4, 22, 6, 0, 0, 24, 1, 85, 3, 26, 5, 0, 0, 28, 2, 86, 4, 30, 6, 0, 0, 32, 1, 87, 3,
-    34, 5, 0, 0, 36, 2, 88, 4, 38, 6, 0, 0, 40, 1, 89, 3, 42, 5, 0, 0, 44, 2, 90, 4, 46, 6, 0, 0,
-    48, 1, 91, 3, 50, 5, 0, 0, 52, 2, 92, 4, 54, 6, 0, 0, 56, 1, 93, 3, 58, 5, 0, 0, 60, 2, 94, 4,
-    62, 6, 0, 0, 7, 1, 95, 3, 8, 5, 0, 0, 9, 2, 96, 4, 10, 6, 0, 0, 11, 1, 97, 3, 12, 5, 0, 0, 13,
-    2, 98, 4, 14, 6, 0, 0, 15, 1, 99, 3, 17, 5, 0, 0, 19, 2, 100, 4, 21, 6, 0, 0, 23, 1, 101, 3,
-    25, 5, 0, 0, 27, 2, 102, 4, 29, 6, 0, 0, 31, 1, 103, 3, 33, 5, 0, 0, 35, 2, 104, 4, 37, 6, 0,
-    0, 39, 1, 105, 3, 41, 5, 0, 0, 43, 2, 106, 4, 45, 6, 0, 0, 47, 1, 107, 3, 49, 5, 0, 0, 51, 2,
-    108, 4, 53, 6, 0, 0, 55, 1, 109, 3, 57, 5, 0, 0, 59, 2, 110, 4, 61, 6, 0, 0, 7, 1, 111, 3, 8,
-    5, 0, 0, 9, 2, 112, 4, 10, 6, 0, 0, 11, 1, 113, 3, 12, 5, 0, 0, 13, 2, 114, 4, 14, 6, 0, 0, 16,
-    1, 115, 3, 18, 5, 0, 0, 20, 2, 116, 4, 22, 6, 0, 0, 24, 1, 117, 3, 26, 5, 0, 0, 28, 2, 118, 4,
-    30, 6, 0, 0, 32, 1, 119, 3, 34, 5, 0, 0, 36, 2, 120, 4, 38, 6, 0, 0, 40, 1, 121, 3, 42, 5, 0,
-    0, 44, 2, 122, 4, 46, 6, 0, 0, 48, 1, 123, 3, 50, 5, 0, 0, 52, 2, 124, 4, 54, 6, 0, 0, 56, 1,
-    125, 3, 58, 5, 0, 0, 60, 2, 126, 4, 62, 6, 0, 0, 7, 1, 127, 3, 8, 5, 0, 0, 9, 2, 129, 4, 10, 6,
-    0, 0, 11, 1, 131, 3, 12, 5, 0, 0, 13, 2, 133, 4, 14, 6, 0, 0, 15, 1, 135, 3, 17, 5, 0, 0, 19,
-    2, 137, 4, 21, 6, 0, 0, 23, 1, 139, 3, 25, 5, 0, 0, 27, 2, 141, 4, 29, 6, 0, 0, 31, 1, 143, 3,
-    33, 5, 0, 0, 35, 2, 145, 4, 37, 6, 0, 0, 39, 1, 147, 3, 41, 5, 0, 0, 43, 2, 149, 4, 45, 6, 0,
-    0, 47, 1, 151, 3, 49, 5, 0, 0, 51, 2, 153, 4, 53, 6, 0, 0, 55, 1, 155, 3, 57, 5, 0, 0, 59, 2,
-    157, 4, 61, 6, 0, 0, 7, 1, 159, 3, 8, 5, 0, 0, 9, 2, 161, 4, 10, 6, 0, 0, 11, 1, 163, 3, 12, 5,
-    0, 0, 13, 2, 165, 4, 14, 6, 0, 0, 16, 1, 167, 3, 18, 5, 0, 0, 20, 2, 169, 4, 22, 6, 0, 0, 24,
-    1, 171, 3, 26, 5, 0, 0, 28, 2, 173, 4, 30, 6, 0, 0, 32, 1, 175, 3, 34, 5, 0, 0, 36, 2, 177, 4,
-    38, 6, 0, 0, 40, 1, 179, 3, 42, 5, 0, 0, 44, 2, 181, 4, 46, 6, 0, 0, 48, 1, 183, 3, 50, 5, 0,
-    0, 52, 2, 185, 4, 54, 6, 0, 0, 56, 1, 187, 3, 58, 5, 0, 0, 60, 2, 189, 4, 62, 6, 0, 0, 7, 1,
-    191, 3, 8, 5, 0, 0, 9, 2, 193, 4, 10, 6, 0, 0, 11, 1, 195, 3, 12, 5, 0, 0, 13, 2, 197, 4, 14,
-    6, 0, 0, 15, 1, 199, 3, 17, 5, 0, 0, 19, 2, 201, 4, 21, 6, 0, 0, 23, 1, 203, 3, 25, 5, 0, 0,
-    27, 2, 205, 4, 29, 6, 0, 0, 31, 1, 207, 3, 33, 5, 0, 0, 35, 2, 209, 4, 37, 6, 0, 0, 39, 1, 211,
-    3, 41, 5, 0, 0, 43, 2, 213, 4, 45, 6, 0, 0, 47, 1, 215, 3, 49, 5, 0, 0, 51, 2, 217, 4, 53, 6,
-    0, 0, 55, 1, 219, 3, 57, 5, 0, 0, 59, 2, 221, 4, 61, 6, 0, 0, 7, 1, 223, 3, 8, 5, 0, 0, 9, 2,
-    225, 4, 10, 6, 0, 0, 11, 1, 227, 3, 12, 5, 0, 0, 13, 2, 229, 4, 14, 6, 0, 0, 16, 1, 231, 3, 18,
-    5, 0, 0, 20, 2, 233, 4, 22, 6, 0, 0, 24, 1, 235, 3, 26, 5, 0, 0, 28, 2, 237, 4, 30, 6, 0, 0,
-    32, 1, 239, 3, 34, 5, 0, 0, 36, 2, 241, 4, 38, 6, 0, 0, 40, 1, 243, 3, 42, 5, 0, 0, 44, 2, 245,
-    4, 46, 6, 0, 0, 48, 1, 247, 3, 50, 5, 0, 0, 52, 2, 249, 4, 54, 6, 0, 0, 56, 1, 251, 3, 58, 5,
-    0, 0, 60, 2, 253, 4, 62, 6, 0, 0, 7, 1, 255, 3, 8, 5, 0, 0, 9, 2, 257, 4, 10, 6, 0, 0, 11, 1,
-    259, 3, 12, 5, 0, 0, 13, 2, 261, 4, 14, 6, 0, 0, 15, 1, 263, 3, 17, 5, 0, 0, 19, 2, 265, 4, 21,
-    6, 0, 0, 23, 1, 267, 3, 25, 5, 0, 0, 27, 2, 269, 4, 29, 6, 0, 0, 31, 1, 271, 3, 33, 5, 0, 0,
-    35, 2, 273, 4, 37, 6, 0, 0, 39, 1, 275, 3, 41, 5, 0, 0, 43, 2, 277, 4, 45, 6, 0, 0, 47, 1, 279,
-    3, 49, 5, 0, 0, 51, 2, 281, 4, 53, 6, 0, 0, 55, 1, 283, 3, 57, 5, 0, 0, 59, 2, 285, 4, 61, 6,
-    0, 0, 7, 1, 287, 3, 8, 5, 0, 0, 9, 2, 289, 4, 10, 6, 0, 0, 11, 1, 291, 3, 12, 5, 0, 0, 13, 2,
-    293, 4, 14, 6, 0, 0, 16, 1, 295, 3, 18, 5, 0, 0, 20, 2, 297, 4, 22, 6, 0, 0, 24, 1, 299, 3, 26,
-    5, 0, 0, 28, 2, 301, 4, 30, 6, 0, 0, 32, 1, 303, 3, 34, 5, 0, 0, 36, 2, 305, 4, 38, 6, 0, 0,
-    40, 1, 307, 3, 42, 5, 0, 0, 44, 2, 309, 4, 46, 6, 0, 0, 48, 1, 311, 3, 50, 5, 0, 0, 52, 2, 313,
-    4, 54, 6, 0, 0, 56, 1, 315, 3, 58, 5, 0, 0, 60, 2, 317, 4, 62, 6, 0, 0, 7, 1, 319, 3, 8, 5, 0,
-    0, 9, 2, 321, 4, 10, 6, 0, 0, 11, 1, 323, 3, 12, 5, 0, 0, 13, 2, 325, 4, 14, 6, 0, 0, 15, 1,
-    327, 3, 17, 5, 0, 0, 19, 2, 329, 4, 21, 6, 0, 0, 23, 1, 331, 3, 25, 5, 0, 0, 27, 2, 333, 4, 29,
-    6, 0, 0, 31, 1, 335, 3, 33, 5, 0, 0, 35, 2, 337, 4, 37, 6, 0, 0, 39, 1, 339, 3, 41, 5, 0, 0,
-    43, 2, 341, 4, 45, 6, 0, 0, 47, 1, 343, 3, 49, 5, 0, 0, 51, 2, 345, 4, 53, 6, 0, 0, 55, 1, 347,
-    3, 57, 5, 0, 0, 59, 2, 349, 4, 61, 6, 0, 0, 7, 1, 351, 3, 8, 5, 0, 0, 9, 2, 353, 4, 10, 6, 0,
-    0, 11, 1, 355, 3, 12, 5, 0, 0, 13, 2, 357, 4, 14, 6, 0, 0, 16, 1, 359, 3, 18, 5, 0, 0, 20, 2,
-    361, 4, 22, 6, 0, 0, 24, 1, 363, 3, 26, 5, 0, 0, 28, 2, 365, 4, 30, 6, 0, 0, 32, 1, 367, 3, 34,
-    5, 0, 0, 36, 2, 369, 4, 38, 6, 0, 0, 40, 1, 371, 3, 42, 5, 0, 0, 44, 2, 373, 4, 46, 6, 0, 0,
-    48, 1, 375, 3, 50, 5, 0, 0, 52, 2, 377, 4, 54, 6, 0, 0, 56, 1, 379, 3, 58, 5, 0, 0, 60, 2, 381,
-    4, 62, 6, 0, 0, 7, 1, 383, 3, 8, 5, 0, 0, 9, 2, 385, 4, 10, 6, 0, 0, 11, 1, 387, 3, 12, 5, 0,
-    0, 13, 2, 389, 4, 14, 6, 0, 0, 15, 1, 391, 3, 17, 5, 0, 0, 19, 2, 393, 4, 21, 6, 0, 0, 23, 1,
-    395, 3, 25, 5, 0, 0, 27, 2, 397, 4, 29, 6, 0, 0, 31, 1, 399, 3, 33, 5, 0, 0, 35, 2, 401, 4, 37,
-    6, 0, 0, 39, 1, 403, 3, 41, 5, 0, 0, 43, 2, 405, 4, 45, 6, 0, 0, 47, 1, 407, 3, 49, 5, 0, 0,
-    51, 2, 409, 4, 53, 6, 0, 0, 55, 1, 411, 3, 57, 5, 0, 0, 59, 2, 413, 4, 61, 6, 0, 0, 7, 1, 415,
-    3, 8, 5, 0, 0, 9, 2, 417, 4, 10, 6, 0, 0, 11, 1, 419, 3, 12, 5, 0, 0, 13, 2, 421, 4, 14, 6, 0,
-    0, 16, 1, 423, 3, 18, 5, 0, 0, 20, 2, 425, 4, 22, 6, 0, 0, 24, 1, 427, 3, 26, 5, 0, 0, 28, 2,
-    429, 4, 30, 6, 0, 0, 32, 1, 431, 3, 34, 5, 0, 0, 36, 2, 433, 4, 38, 6, 0, 0, 40, 1, 435, 3, 42,
-    5, 0, 0, 44, 2, 437, 4, 46, 6, 0, 0, 48, 1, 439, 3, 50, 5, 0, 0, 52, 2, 441, 4, 54, 6, 0, 0,
-    56, 1, 443, 3, 58, 5, 0, 0, 60, 2, 445, 4, 62, 6, 0, 0, 7, 1, 447, 3, 8, 5, 0, 0, 9, 2, 449, 4,
-    10, 6, 0, 0, 11, 1, 451, 3, 12, 5, 0, 0, 13, 2, 453, 4, 14, 6, 0, 0, 15, 1, 455, 3, 17, 5, 0,
-    0, 19, 2, 457, 4, 21, 6, 0, 0, 23, 1, 459, 3, 25, 5, 0, 0, 27, 2, 461, 4, 29, 6, 0, 0, 31, 1,
-    463, 3, 33, 5, 0, 0, 35, 2, 465, 4, 37, 6, 0, 0, 39, 1, 467, 3, 41, 5, 0, 0, 43, 2, 469, 4, 45,
-    6, 0, 0, 47, 1, 471, 3, 49, 5, 0, 0, 51, 2, 473, 4, 53, 6, 0, 0, 55, 1, 475, 3, 57, 5, 0, 0,
-    59, 2, 477, 4, 61, 6, 0, 0, 7, 1, 479, 3, 8, 5, 0, 0, 9, 2, 481, 4, 10, 6, 0, 0, 11, 1, 483, 3,
-    12, 5, 0, 0, 13, 2, 485, 4, 14, 6, 0, 0, 16, 1, 487, 3, 18, 5, 0, 0, 20, 2, 489, 4, 22, 6, 0,
-    0, 24, 1, 491, 3, 26, 5, 0, 0, 28, 2, 493, 4, 30, 6, 0, 0, 32, 1, 495, 3, 34, 5, 0, 0, 36, 2,
-    497, 4, 38, 6, 0, 0, 40, 1, 499, 3, 42, 5, 0, 0, 44, 2, 501, 4, 46, 6, 0, 0, 48, 1, 503, 3, 50,
-    5, 0, 0, 52, 2, 505, 4, 54, 6, 0, 0, 56, 1, 507, 3, 58, 5, 0, 0, 60, 2, 509, 4, 62, 6, 0, 0, 7,
-    1, 63, 3, 8, 5, 0, 0, 9, 2, 64, 4, 10, 6, 0, 0, 11, 1, 65, 3, 12, 5, 0, 0, 13, 2, 66, 4, 14, 6,
-    0, 0, 15, 1, 67, 3, 17, 5, 0, 0, 19, 2, 68, 4, 21, 6, 0, 0, 23, 1, 69, 3, 25, 5, 0, 0, 27, 2,
-    70, 4, 29, 6, 0, 0, 31, 1, 71, 3, 33, 5, 0, 0, 35, 2, 72, 4, 37, 6, 0, 0, 39, 1, 73, 3, 41, 5,
-    0, 0, 43, 2, 74, 4, 45, 6, 0, 0, 47, 1, 75, 3, 49, 5, 0, 0, 51, 2, 76, 4, 53, 6, 0, 0, 55, 1,
-    77, 3, 57, 5, 0, 0, 59, 2, 78, 4, 61, 6, 0, 0, 7, 1, 79, 3, 8, 5, 0, 0, 9, 2, 80, 4, 10, 6, 0,
-    0, 11, 1, 81, 3, 12, 5, 0, 0, 13, 2, 82, 4, 14, 6, 0, 0, 16, 1, 83, 3, 18, 5, 0, 0, 20, 2, 84,
-    4, 22, 6, 0, 0, 24, 1, 85, 3, 26, 5, 0, 0, 28, 2, 86, 4, 30, 6, 0, 0, 32, 1, 87, 3, 34, 5, 0,
-    0, 36, 2, 88, 4, 38, 6, 0, 0, 40, 1, 89, 3, 42, 5, 0, 0, 44, 2, 90, 4, 46, 6, 0, 0, 48, 1, 91,
-    3, 50, 5, 0, 0, 52, 2, 92, 4, 54, 6, 0, 0, 56, 1, 93, 3, 58, 5, 0, 0, 60, 2, 94, 4, 62, 6, 0,
-    0, 7, 1, 95, 3, 8, 5, 0, 0, 9, 2, 96, 4, 10, 6, 0, 0, 11, 1, 97, 3, 12, 5, 0, 0, 13, 2, 98, 4,
-    14, 6, 0, 0, 15, 1, 99, 3, 17, 5, 0, 0, 19, 2, 100, 4, 21, 6, 0, 0, 23, 1, 101, 3, 25, 5, 0, 0,
-    27, 2, 102, 4, 29, 6, 0, 0, 31, 1, 103, 3, 33, 5, 0, 0, 35, 2, 104, 4, 37, 6, 0, 0, 39, 1, 105,
-    3, 41, 5, 0, 0, 43, 2, 106, 4, 45, 6, 0, 0, 47, 1, 107, 3, 49, 5, 0, 0, 51, 2, 108, 4, 53, 6,
-    0, 0, 55, 1, 109, 3, 57, 5, 0, 0, 59, 2, 110, 4, 61, 6, 0, 0, 7, 1, 111, 3, 8, 5, 0, 0, 9, 2,
-    112, 4, 10, 6, 0, 0, 11, 1, 113, 3, 12, 5, 0, 0, 13, 2, 114, 4, 14, 6, 0, 0, 16, 1, 115, 3, 18,
-    5, 0, 0, 20, 2, 116, 4, 22, 6, 0, 0, 24, 1, 117, 3, 26, 5, 0, 0, 28, 2, 118, 4, 30, 6, 0, 0,
-    32, 1, 119, 3, 34, 5, 0, 0, 36, 2, 120, 4, 38, 6, 0, 0, 40, 1, 121, 3, 42, 5, 0, 0, 44, 2, 122,
-    4, 46, 6, 0, 0, 48, 1, 123, 3, 50, 5, 0, 0, 52, 2, 124, 4, 54, 6, 0, 0, 56, 1, 125, 3, 58, 5,
-    0, 0, 60, 2, 126, 4, 62, 6, 0, 0, 7, 1, 128, 3, 8, 5, 0, 0, 9, 2, 130, 4, 10, 6, 0, 0, 11, 1,
-    132, 3, 12, 5, 0, 0, 13, 2, 134, 4, 14, 6, 0, 0, 15, 1, 136, 3, 17, 5, 0, 0, 19, 2, 138, 4, 21,
-    6, 0, 0, 23, 1, 140, 3, 25, 5, 0, 0, 27, 2, 142, 4, 29, 6, 0, 0, 31, 1, 144, 3, 33, 5, 0, 0,
-    35, 2, 146, 4, 37, 6, 0, 0, 39, 1, 148, 3, 41, 5, 0, 0, 43, 2, 150, 4, 45, 6, 0, 0, 47, 1, 152,
-    3, 49, 5, 0, 0, 51, 2, 154, 4, 53, 6, 0, 0, 55, 1, 156, 3, 57, 5, 0, 0, 59, 2, 158, 4, 61, 6,
-    0, 0, 7, 1, 160, 3, 8, 5, 0, 0, 9, 2, 162, 4, 10, 6, 0, 0, 11, 1, 164, 3, 12, 5, 0, 0, 13, 2,
-    166, 4, 14, 6, 0, 0, 16, 1, 168, 3, 18, 5, 0, 0, 20, 2, 170, 4, 22, 6, 0, 0, 24, 1, 172, 3, 26,
-    5, 0, 0, 28, 2, 174, 4, 30, 6, 0, 0, 32, 1, 176, 3, 34, 5, 0, 0, 36, 2, 178, 4, 38, 6, 0, 0,
-    40, 1, 180, 3, 42, 5, 0, 0, 44, 2, 182, 4, 46, 6, 0, 0, 48, 1, 184, 3, 50, 5, 0, 0, 52, 2, 186,
-    4, 54, 6, 0, 0, 56, 1, 188, 3, 58, 5, 0, 0, 60, 2, 190, 4, 62, 6, 0, 0, 7, 1, 192, 3, 8, 5, 0,
-    0, 9, 2, 194, 4, 10, 6, 0, 0, 11, 1, 196, 3, 12, 5, 0, 0, 13, 2, 198, 4, 14, 6, 0, 0, 15, 1,
-    200, 3, 17, 5, 0, 0, 19, 2, 202, 4, 21, 6, 0, 0, 23, 1, 204, 3, 25, 5, 0, 0, 27, 2, 206, 4, 29,
-    6, 0, 0, 31, 1, 208, 3, 33, 5, 0, 0, 35, 2, 210, 4, 37, 6, 0, 0, 39, 1, 212, 3, 41, 5, 0, 0,
-    43, 2, 214, 4, 45, 6, 0, 0, 47, 1, 216, 3, 49, 5, 0, 0, 51, 2, 218, 4, 53, 6, 0, 0, 55, 1, 220,
-    3, 57, 5, 0, 0, 59, 2, 222, 4, 61, 6, 0, 0, 7, 1, 224, 3, 8, 5, 0, 0, 9, 2, 226, 4, 10, 6, 0,
-    0, 11, 1, 228, 3, 12, 5, 0, 0, 13, 2, 230, 4, 14, 6, 0, 0, 16, 1, 232, 3, 18, 5, 0, 0, 20, 2,
-    234, 4, 22, 6, 0, 0, 24, 1, 236, 3, 26, 5, 0, 0, 28, 2, 238, 4, 30, 6, 0, 0, 32, 1, 240, 3, 34,
-    5, 0, 0, 36, 2, 242, 4, 38, 6, 0, 0, 40, 1, 244, 3, 42, 5, 0, 0, 44, 2, 246, 4, 46, 6, 0, 0,
-    48, 1, 248, 3, 50, 5, 0, 0, 52, 2, 250, 4, 54, 6, 0, 0, 56, 1, 252, 3, 58, 5, 0, 0, 60, 2, 254,
-    4, 62, 6, 0, 0, 7, 1, 256, 3, 8, 5, 0, 0, 9, 2, 258, 4, 10, 6, 0, 0, 11, 1, 260, 3, 12, 5, 0,
-    0, 13, 2, 262, 4, 14, 6, 0, 0, 15, 1, 264, 3, 17, 5, 0, 0, 19, 2, 266, 4, 21, 6, 0, 0, 23, 1,
-    268, 3, 25, 5, 0, 0, 27, 2, 270, 4, 29, 6, 0, 0, 31, 1, 272, 3, 33, 5, 0, 0, 35, 2, 274, 4, 37,
-    6, 0, 0, 39, 1, 276, 3, 41, 5, 0, 0, 43, 2, 278, 4, 45, 6, 0, 0, 47, 1, 280, 3, 49, 5, 0, 0,
-    51, 2, 282, 4, 53, 6, 0, 0, 55, 1, 284, 3, 57, 5, 0, 0, 59, 2, 286, 4, 61, 6, 0, 0, 7, 1, 288,
-    3, 8, 5, 0, 0, 9, 2, 290, 4, 10, 6, 0, 0, 11, 1, 292, 3, 12, 5, 0, 0, 13, 2, 294, 4, 14, 6, 0,
-    0, 16, 1, 296, 3, 18, 5, 0, 0, 20, 2, 298, 4, 22, 6, 0, 0, 24, 1, 300, 3, 26, 5, 0, 0, 28, 2,
-    302, 4, 30, 6, 0, 0, 32, 1, 304, 3, 34, 5, 0, 0, 36, 2, 306, 4, 38, 6, 0, 0, 40, 1, 308, 3, 42,
-    5, 0, 0, 44, 2, 310, 4, 46, 6, 0, 0, 48, 1, 312, 3, 50, 5, 0, 0, 52, 2, 314, 4, 54, 6, 0, 0,
-    56, 1, 316, 3, 58, 5, 0, 0, 60, 2, 318, 4, 62, 6, 0, 0, 7, 1, 320, 3, 8, 5, 0, 0, 9, 2, 322, 4,
-    10, 6, 0, 0, 11, 1, 324, 3, 12, 5, 0, 0, 13, 2, 326, 4, 14, 6, 0, 0, 15, 1, 328, 3, 17, 5, 0,
-    0, 19, 2, 330, 4, 21, 6, 0, 0, 23, 1, 332, 3, 25, 5, 0, 0, 27, 2, 334, 4, 29, 6, 0, 0, 31, 1,
-    336, 3, 33, 5, 0, 0, 35, 2, 338, 4, 37, 6, 0, 0, 39, 1, 340, 3, 41, 5, 0, 0, 43, 2, 342, 4, 45,
-    6, 0, 0, 47, 1, 344, 3, 49, 5, 0, 0, 51, 2, 346, 4, 53, 6, 0, 0, 55, 1, 348, 3, 57, 5, 0, 0,
-    59, 2, 350, 4, 61, 6, 0, 0, 7, 1, 352, 3, 8, 5, 0, 0, 9, 2, 354, 4, 10, 6, 0, 0, 11, 1, 356, 3,
-    12, 5, 0, 0, 13, 2, 358, 4, 14, 6, 0, 0, 16, 1, 360, 3, 18, 5, 0, 0, 20, 2, 362, 4, 22, 6, 0,
-    0, 24, 1, 364, 3, 26, 5, 0, 0, 28, 2, 366, 4, 30, 6, 0, 0, 32, 1, 368, 3, 34, 5, 0, 0, 36, 2,
-    370, 4, 38, 6, 0, 0, 40, 1, 372, 3, 42, 5, 0, 0, 44, 2, 374, 4, 46, 6, 0, 0, 48, 1, 376, 3, 50,
-    5, 0, 0, 52, 2, 378, 4, 54, 6, 0, 0, 56, 1, 380, 3, 58, 5, 0, 0, 60, 2, 382, 4, 62, 6, 0, 0, 7,
-    1, 384, 3, 8, 5, 0, 0, 9, 2, 386, 4, 10, 6, 0, 0, 11, 1, 388, 3, 12, 5, 0, 0, 13, 2, 390, 4,
-    14, 6, 0, 0, 15, 1, 392, 3, 17, 5, 0, 0, 19, 2, 394, 4, 21, 6, 0, 0, 23, 1, 396, 3, 25, 5, 0,
-    0, 27, 2, 398, 4, 29, 6, 0, 0, 31, 1, 400, 3, 33, 5, 0, 0, 35, 2, 402, 4, 37, 6, 0, 0, 39, 1,
-    404, 3, 41, 5, 0, 0, 43, 2, 406, 4, 45, 6, 0, 0, 47, 1, 408, 3, 49, 5, 0, 0, 51, 2, 410, 4, 53,
-    6, 0, 0, 55, 1, 412, 3, 57, 5, 0, 0, 59, 2, 414, 4, 61, 6, 0, 0, 7, 1, 416, 3, 8, 5, 0, 0, 9,
-    2, 418, 4, 10, 6, 0, 0, 11, 1, 420, 3, 12, 5, 0, 0, 13, 2, 422, 4, 14, 6, 0, 0, 16, 1, 424, 3,
-    18, 5, 0, 0, 20, 2, 426, 4, 22, 6, 0, 0, 24, 1, 428, 3, 26, 5, 0, 0, 28, 2, 430, 4, 30, 6, 0,
-    0, 32, 1, 432, 3, 34, 5, 0, 0, 36, 2, 434, 4, 38, 6, 0, 0, 40, 1, 436, 3, 42, 5, 0, 0, 44, 2,
-    438, 4, 46, 6, 0, 0, 48, 1, 440, 3, 50, 5, 0, 0, 52, 2, 442, 4, 54, 6, 0, 0, 56, 1, 444, 3, 58,
-    5, 0, 0, 60, 2, 446, 4, 62, 6, 0, 0, 7, 1, 448, 3, 8, 5, 0, 0, 9, 2, 450, 4, 10, 6, 0, 0, 11,
-    1, 452, 3, 12, 5, 0, 0, 13, 2, 454, 4, 14, 6, 0, 0, 15, 1, 456, 3, 17, 5, 0, 0, 19, 2, 458, 4,
-    21, 6, 0, 0, 23, 1, 460, 3, 25, 5, 0, 0, 27, 2, 462, 4, 29, 6, 0, 0, 31, 1, 464, 3, 33, 5, 0,
-    0, 35, 2, 466, 4, 37, 6, 0, 0, 39, 1, 468, 3, 41, 5, 0, 0, 43, 2, 470, 4, 45, 6, 0, 0, 47, 1,
-    472, 3, 49, 5, 0, 0, 51, 2, 474, 4, 53, 6, 0, 0, 55, 1, 476, 3, 57, 5, 0, 0, 59, 2, 478, 4, 61,
-    6, 0, 0, 7, 1, 480, 3, 8, 5, 0, 0, 9, 2, 482, 4, 10, 6, 0, 0, 11, 1, 484, 3, 12, 5, 0, 0, 13,
-    2, 486, 4, 14, 6, 0, 0, 16, 1, 488, 3, 18, 5, 0, 0, 20, 2, 490, 4, 22, 6, 0, 0, 24, 1, 492, 3,
-    26, 5, 0, 0, 28, 2, 494, 4, 30, 6, 0, 0, 32, 1, 496, 3, 34, 5, 0, 0, 36, 2, 498, 4, 38, 6, 0,
-    0, 40, 1, 500, 3, 42, 5, 0, 0, 44, 2, 502, 4, 46, 6, 0, 0, 48, 1, 504, 3, 50, 5, 0, 0, 52, 2,
-    506, 4, 54, 6, 0, 0, 56, 1, 508, 3, 58, 5, 0, 0, 60, 2, 510, 4, 62, 6,
-];
-///Table contaings the lens used to speed up the reading of zeta codes
-pub const READ_LEN_L2M: &[u8] = &[
-    255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4,
-    11, 4, 7, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4,
-    11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4,
-    255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 7, 4, 11, 4, 7, 4,
-    255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4,
-    255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 7, 4, 11, 4, 7, 4,
-    255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4,
-    11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 7, 4,
-    11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4, 255, 3, 7, 4, 11, 4, 7, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4,
-    255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 8, 4,
-    11, 4, 8, 4, 255, 3, 8, 4, 11, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4,
-    12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 7, 4, 12, 4, 7, 4,
-    255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 7, 4, 12, 4, 7, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4,
-    12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4, 255, 3, 8, 4, 12, 4, 8, 4,
-    255, 3, 8, 4, 12, 4, 8, 4,
-];
-///Table used to speed up the writing of zeta codes
-pub const WRITE_M2L: &[u16] = &[
-    4, 10, 11, 12, 13, 14, 15, 32, 33, 34, 35, 36, 37, 38, 39, 80, 81, 82, 83, 84, 85, 86, 87, 88,
-    89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109,
-    110, 111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 256,
-    257, 258, 259, 260, 261, 262, 263, 264, 265, 266, 267, 268, 269, 270, 271, 272, 273, 274, 275,
-    276, 277, 278, 279, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 290, 291, 292, 293, 294,
-    295, 296, 297, 298, 299, 300, 301, 302, 303, 304, 305, 306, 307, 308, 309, 310, 311, 312, 313,
-    314, 315, 316, 317, 318, 319, 640, 641, 642, 643, 644, 645, 646, 647, 648, 649, 650, 651, 652,
-    653, 654, 655, 656, 657, 658, 659, 660, 661, 662, 663, 664, 665, 666, 667, 668, 669, 670, 671,
-    672, 673, 674, 675, 676, 677, 678, 679, 680, 681, 682, 683, 684, 685, 686, 687, 688, 689, 690,
-    691, 692, 693, 694, 695, 696, 697, 698, 699, 700, 701, 702, 703, 704, 705, 706, 707, 708, 709,
-    710, 711, 712, 713, 714, 715, 716, 717, 718, 719, 720, 721, 722, 723, 724, 725, 726, 727, 728,
-    729, 730, 731, 732, 733, 734, 735, 736, 737, 738, 739, 740, 741, 742, 743, 744, 745, 746, 747,
-    748, 749, 750, 751, 752, 753, 754, 755, 756, 757, 758, 759, 760, 761, 762, 763, 764, 765, 766,
-    767, 768,
-];
-///Table used to speed up the writing of zeta codes
-pub const WRITE_LEN_M2L: &[u16] = &[
-    3, 4, 4, 4, 4, 4, 4, 7, 7, 7, 7, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12,
-];
-///Table used to speed up the writing of zeta codes
-pub const WRITE_L2M: &[u16] = &[
-    1, 3, 11, 5, 13, 7, 15, 2, 6, 10, 14, 18, 22, 26, 30, 34, 162, 38, 166, 42, 170, 46, 174, 50,
-    178, 54, 182, 58, 186, 62, 190, 66, 194, 70, 198, 74, 202, 78, 206, 82, 210, 86, 214, 90, 218,
-    94, 222, 98, 226, 102, 230, 106, 234, 110, 238, 114, 242, 118, 246, 122, 250, 126, 254, 4, 12,
-    20, 28, 36, 44, 52, 60, 68, 76, 84, 92, 100, 108, 116, 124, 132, 140, 148, 156, 164, 172, 180,
-    188, 196, 204, 212, 220, 228, 236, 244, 252, 260, 268, 276, 284, 292, 300, 308, 316, 324, 332,
-    340, 348, 356, 364, 372, 380, 388, 396, 404, 412, 420, 428, 436, 444, 452, 460, 468, 476, 484,
-    492, 500, 508, 516, 2564, 524, 2572, 532, 2580, 540, 2588, 548, 2596, 556, 2604, 564, 2612,
-    572, 2620, 580, 2628, 588, 2636, 596, 2644, 604, 2652, 612, 2660, 620, 2668, 628, 2676, 636,
-    2684, 644, 2692, 652, 2700, 660, 2708, 668, 2716, 676, 2724, 684, 2732, 692, 2740, 700, 2748,
-    708, 2756, 716, 2764, 724, 2772, 732, 2780, 740, 2788, 748, 2796, 756, 2804, 764, 2812, 772,
-    2820, 780, 2828, 788, 2836, 796, 2844, 804, 2852, 812, 2860, 820, 2868, 828, 2876, 836, 2884,
-    844, 2892, 852, 2900, 860, 2908, 868, 2916, 876, 2924, 884, 2932, 892, 2940, 900, 2948, 908,
-    2956, 916, 2964, 924, 2972, 932, 2980, 940, 2988, 948, 2996, 956, 3004, 964, 3012, 972, 3020,
-    980, 3028, 988, 3036, 996, 3044, 1004, 3052, 1012, 3060, 1020, 3068, 1028,
-];
-///Table used to speed up the writing of zeta codes
-pub const WRITE_LEN_L2M: &[u16] = &[
-    3, 4, 4, 4, 4, 4, 4, 7, 7, 7, 7, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12,
-];
-///Table used to speed up the skipping of zeta codes
-pub const LEN: &[u8] = &[
-    3, 4, 4, 4, 4, 4, 4, 7, 7, 7, 7, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
-    11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
-    12,
-];
 /// The K of the zeta codes for these tables
 pub const K: u64 = 3;
+
+crate::codes::tables::second_level_tables!("ζ", K);
@@ -45,6 +45,38 @@ pub trait BitRead<BO: BitOrder> {
 
     /// Skip n_bits from the stream
     ///
+    /// Skipping past the bits that a reader has already buffered must not
+    /// affect the bits read afterwards:
+    /// ```
+    /// use dsi_bitstream::prelude::*;
+    ///
+    /// let mut buffer: Vec<u64> = vec![];
+    /// {
+    ///     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+    ///     writer.write_bits(u64::MAX, 64).unwrap();
+    ///     writer.write_bits(0, 64).unwrap();
+    ///     writer.write_bits(0x0123_4567_89ab_cdef, 64).unwrap();
+    /// }
+    /// let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+    /// assert_eq!(reader.read_bits(1).unwrap(), 1);
+    /// reader.skip_bits(64).unwrap();
+    /// assert_eq!(reader.read_bits(63).unwrap(), 0);
+    /// assert_eq!(reader.read_bits(64).unwrap(), 0x0123_4567_89ab_cdef);
+    ///
+    /// let mut buffer: Vec<u64> = vec![];
+    /// {
+    ///     let mut writer = BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut buffer));
+    ///     writer.write_bits(u64::MAX, 64).unwrap();
+    ///     writer.write_bits(0, 64).unwrap();
+    ///     writer.write_bits(0x0123_4567_89ab_cdef, 64).unwrap();
+    /// }
+    /// let mut reader = <BufferedBitStreamRead<L2M, u128, _>>::new(MemWordRead::new(&buffer));
+    /// assert_eq!(reader.read_bits(1).unwrap(), 1);
+    /// reader.skip_bits(64).unwrap();
+    /// assert_eq!(reader.read_bits(63).unwrap(), 0);
+    /// assert_eq!(reader.read_bits(64).unwrap(), 0x0123_4567_89ab_cdef);
+    /// ```
+    ///
     /// # Errors
     /// Thi function errors if skipping n_bits the underlying streams ends.
     fn skip_bits(&mut self, n_bits: usize) -> Result<()>;
@@ -63,6 +95,33 @@ pub trait BitRead<BO: BitOrder> {
 
     /// Read an unary code
     ///
+    /// `USE_TABLE` enables or disables the use of the decoding table in
+    /// [`unary_tables`](crate::codes::unary_tables). The readers of this
+    /// crate decode the codes that are not in the table counting the zeros of
+    /// whole words.
+    ///
+    /// #### Example:
+    /// ```
+    /// use dsi_bitstream::prelude::*;
+    ///
+    /// // codes longer than one or two words
+    /// let values = [0, 63, 64, 65, 127, 128, 129, 200, 1000, 1];
+    /// let mut buffer: Vec<u64> = vec![];
+    /// {
+    ///     let mut writer = BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut buffer));
+    ///     writer.write_unary_all::<true>(&values).unwrap();
+    /// }
+    ///
+    /// let mut reader = <BufferedBitStreamRead<L2M, u128, _>>::new(MemWordRead::new(&buffer));
+    /// for value in values {
+    ///     assert_eq!(reader.read_unary::<true>().unwrap(), value);
+    /// }
+    /// let mut reader = <UnbufferedBitStreamRead<L2M, _>>::new(MemWordRead::new(&buffer));
+    /// for value in values {
+    ///     assert_eq!(reader.read_unary::<true>().unwrap(), value);
+    /// }
+    /// ```
+    ///
     /// # Errors
     /// This function return an error if we cannot read the unary code, this
     /// usually happens if we finished the stream.
@@ -76,6 +135,52 @@ pub trait BitRead<BO: BitOrder> {
             count += 1;
         }
     }
+
+    /// Read an unary code from the stream and map it to a signed value
+    /// using [`nat2int`](crate::codes::nat2int).
+    ///
+    /// # Errors
+    /// The same as [`BitRead::read_unary`]
+    #[inline]
+    fn read_unary_signed<const USE_TABLE: bool>(&mut self) -> Result<i64> {
+        self.read_unary::<USE_TABLE>().map(crate::codes::nat2int)
+    }
+
+    /// Read `values.len()` unary codes from the stream into `values`.
+    /// If an error occurs, the content of `values` is unspecified.
+    ///
+    /// # Errors
+    /// The same as [`BitRead::read_unary`]
+    #[inline]
+    fn read_unary_into<const USE_TABLE: bool>(&mut self, values: &mut [u64]) -> Result<()> {
+        for value in values.iter_mut() {
+            *value = self.read_unary::<USE_TABLE>()?;
+        }
+        Ok(())
+    }
+
+    /// Skip an unary code. As the value of an unary code is its length, this
+    /// is the same as reading it.
+    ///
+    /// # Errors
+    /// The same as [`BitRead::read_unary`]
+    #[inline]
+    fn skip_unary<const USE_TABLE: bool>(&mut self) -> Result<()> {
+        self.read_unary::<USE_TABLE>()?;
+        Ok(())
+    }
+
+    /// Skip `n` unary codes.
+    ///
+    /// # Errors
+    /// The same as [`BitRead::read_unary`]
+    #[inline]
+    fn skip_unary_n<const USE_TABLE: bool>(&mut self, n: usize) -> Result<()> {
+        for _ in 0..n {
+            self.skip_unary::<USE_TABLE>()?;
+        }
+        Ok(())
+    }
 }
 
 /// Objects that can read a fixed number of bits and unary codes from a stream
@@ -95,17 +200,122 @@ pub trait BitWrite<BO: BitOrder> {
 
     /// Write `value` as an unary code to the stream
     ///
+    /// The default implementation writes the zeros 64 at a time.
+    ///
+    /// #### Example:
+    /// ```
+    /// use dsi_bitstream::prelude::*;
+    ///
+    /// // a writer using the default implementation of write_unary
+    /// struct Forward<W>(W);
+    /// impl<W: BitWrite<M2L>> BitWrite<M2L> for Forward<W> {
+    ///     fn write_bits(&mut self, value: u64, n_bits: usize) -> anyhow::Result<()> {
+    ///         self.0.write_bits(value, n_bits)
+    ///     }
+    /// }
+    ///
+    /// // codes longer than one or two words
+    /// let values = [0, 63, 64, 65, 127, 128, 129, 200, 1000, 1];
+    /// let mut buffer: Vec<u64> = vec![];
+    /// {
+    ///     let mut writer = Forward(BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer)));
+    ///     writer.write_unary_all::<false>(&values).unwrap();
+    /// }
+    ///
+    /// let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+    /// for value in values {
+    ///     assert_eq!(reader.read_unary::<true>().unwrap(), value);
+    /// }
+    /// let mut reader = <UnbufferedBitStreamRead<M2L, _>>::new(MemWordRead::new(&buffer));
+    /// for value in values {
+    ///     assert_eq!(reader.read_unary::<true>().unwrap(), value);
+    /// }
+    /// ```
+    ///
     /// # Errors
     /// This function return an error if we cannot write the unary code, this
     /// usually happens if we finished the stream.
     fn write_unary<const USE_TABLE: bool>(&mut self, mut value: u64) -> Result<()> {
-        while value > 0 {
-            self.write_bits(0, 1)?;
-            value -= 1;
+        // write the zeros a word at a time
+        while value >= 64 {
+            self.write_bits(0, 64)?;
+            value -= 64;
         }
+        self.write_bits(0, value as usize)?;
         self.write_bits(1, 1)?;
         Ok(())
     }
+
+    /// Write a signed value on the stream as an unary code, mapping it to a
+    /// natural number using [`int2nat`](crate::codes::int2nat).
+    ///
+    /// [`i64::MIN`] cannot be written, as it is mapped to [`u64::MAX`], whose
+    /// unary code would be 2⁶⁴ bits long.
+    ///
+    /// ```
+    /// use dsi_bitstream::prelude::*;
+    ///
+    /// let values = [0, -1, 1, -32, 32, -100, 100];
+    /// let mut buffer: Vec<u64> = vec![];
+    /// {
+    ///     let mut writer = BufferedBitStreamWrite::<M2L, _>::new(MemWordWriteVec::new(&mut buffer));
+    ///     for &value in &values {
+    ///         writer.write_unary_signed::<true>(value).unwrap();
+    ///     }
+    ///     assert!(writer.write_unary_signed::<true>(i64::MIN).is_err());
+    /// }
+    ///
+    /// let mut reader = <BufferedBitStreamRead<M2L, u128, _>>::new(MemWordRead::new(&buffer));
+    /// for &value in &values {
+    ///     assert_eq!(reader.read_unary_signed::<true>().unwrap(), value);
+    /// }
+    /// assert_eq!(
+    ///     reader.get_position(),
+    ///     values.iter().map(|&value| len_unary_signed::<true>(value)).sum::<usize>()
+    /// );
+    /// // the longest code that can be written
+    /// assert_eq!(len_unary_signed::<false>(i64::MAX), usize::MAX);
+    /// ```
+    ///
+    /// # Errors
+    /// This function fails if `value` is [`i64::MIN`], and otherwise as
+    /// [`BitWrite::write_unary`]
+    #[inline]
+    fn write_unary_signed<const USE_TABLE: bool>(&mut self, value: i64) -> Result<()> {
+        let value = crate::codes::int2nat(value);
+        if value == u64::MAX {
+            anyhow::bail!("The unary code of u64::MAX is 2^64 bits long.");
+        }
+        self.write_unary::<USE_TABLE>(value)
+    }
+
+    /// Write all the `values` on the stream as unary codes.
+    ///
+    /// ```
+    /// use dsi_bitstream::prelude::*;
+    ///
+    /// let values = [0, 1, 63, 64, 65, 100, 200, 2];
+    /// let mut buffer: Vec<u64> = vec![];
+    /// {
+    ///     let mut writer = BufferedBitStreamWrite::<L2M, _>::new(MemWordWriteVec::new(&mut buffer));
+    ///     writer.write_unary_all::<false>(&values).unwrap();
+    /// }
+    ///
+    /// let mut reader = <BufferedBitStreamRead<L2M, u128, _>>::new(MemWordRead::new(&buffer));
+    /// for &value in &values {
+    ///     assert_eq!(reader.read_unary::<false>().unwrap(), value);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// The same as [`BitWrite::write_unary`]
+    #[inline]
+    fn write_unary_all<const USE_TABLE: bool>(&mut self, values: &[u64]) -> Result<()> {
+        for &value in values {
+            self.write_unary::<USE_TABLE>(value)?;
+        }
+        Ok(())
+    }
 }
 
 /// [`BitWrite`] objects that use buffering also need to control the flushing